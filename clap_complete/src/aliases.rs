@@ -0,0 +1,53 @@
+//! Suggested shell abbreviation generation
+//!
+//! Emits shell abbreviation definitions (fish `abbr`, zsh `alias`) for the subcommand
+//! paths registered with [`App::suggested_abbrev`][clap::App::suggested_abbrev], so users
+//! discover the short forms for common subcommand paths.
+
+use std::io::Write;
+
+use clap::App;
+
+/// Write fish `abbr` definitions for each suggested abbreviation.
+///
+/// # Examples
+///
+/// ```
+/// use clap::App;
+/// use clap_complete::aliases;
+///
+/// let app = App::new("myapp")
+///     .subcommand(App::new("checkout"))
+///     .suggested_abbrev("co", ["checkout"]);
+/// let mut buf = Vec::new();
+/// aliases::fish_abbrs(&app, "myapp", &mut buf);
+/// assert_eq!(String::from_utf8(buf).unwrap(), "abbr -a co 'myapp checkout'\n");
+/// ```
+pub fn fish_abbrs(app: &App, bin_name: &str, buf: &mut dyn Write) {
+    for (abbrev, path) in app.get_suggested_abbrevs() {
+        writeln!(buf, "abbr -a {} '{} {}'", abbrev, bin_name, path.join(" "))
+            .expect("failed to write completion file");
+    }
+}
+
+/// Write zsh (or bash) `alias` definitions for each suggested abbreviation.
+///
+/// # Examples
+///
+/// ```
+/// use clap::App;
+/// use clap_complete::aliases;
+///
+/// let app = App::new("myapp")
+///     .subcommand(App::new("checkout"))
+///     .suggested_abbrev("co", ["checkout"]);
+/// let mut buf = Vec::new();
+/// aliases::zsh_aliases(&app, "myapp", &mut buf);
+/// assert_eq!(String::from_utf8(buf).unwrap(), "alias co='myapp checkout'\n");
+/// ```
+pub fn zsh_aliases(app: &App, bin_name: &str, buf: &mut dyn Write) {
+    for (abbrev, path) in app.get_suggested_abbrevs() {
+        writeln!(buf, "alias {}='{} {}'", abbrev, bin_name, path.join(" "))
+            .expect("failed to write completion file");
+    }
+}
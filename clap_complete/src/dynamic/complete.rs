@@ -0,0 +1,183 @@
+//! The completion engine behind `myapp complete`-style callbacks
+//!
+//! Instead of baking every candidate into a generated script, the script hands the words on
+//! the command line back to the binary (e.g. `myapp complete --index N -- <words>`) and the
+//! engine walks the live [`App`] to compute them: subcommand names, flags, and values from
+//! [`possible_values`][clap::Arg::possible_values] or user-provided closures. Candidates a
+//! validator would reject can be pruned by registering a closure for that argument.
+
+use std::collections::HashMap;
+
+use clap::{App, Arg};
+
+use crate::dynamic::{CandidateRanker, IdentityRanker};
+
+/// Computes completion candidates from the live `app`
+///
+/// Convenience for a [`Completer`] without value hooks or a ranker; see
+/// [`Completer::complete`] for the meaning of `words` and `index`.
+pub fn complete<S: AsRef<str>>(app: &mut App, words: &[S], index: usize) -> Vec<String> {
+    Completer::new(app).complete(words, index)
+}
+
+/// A configured dynamic completion engine
+///
+/// ```no_run
+/// use clap::{App, Arg};
+/// use clap_complete::dynamic::Completer;
+///
+/// let mut app = App::new("myapp")
+///     .arg(Arg::new("branch").long("branch").takes_value(true))
+///     .subcommand(App::new("push"));
+///
+/// // Typically `words` and `index` come from the shell via a hidden subcommand
+/// let candidates = Completer::new(&mut app)
+///     .value_hook("branch", |prefix| {
+///         list_branches()
+///             .into_iter()
+///             .filter(|b| b.starts_with(prefix))
+///             .collect()
+///     })
+///     .complete(&["myapp", "--branch", "ma"], 2);
+/// # fn list_branches() -> Vec<String> { vec![] }
+/// ```
+pub struct Completer<'app, 'help> {
+    app: &'app mut App<'help>,
+    value_hooks: HashMap<String, Box<dyn Fn(&str) -> Vec<String>>>,
+    ranker: Box<dyn CandidateRanker>,
+}
+
+impl<'app, 'help> Completer<'app, 'help> {
+    /// Creates an engine for `app` with no value hooks and the default ordering
+    pub fn new(app: &'app mut App<'help>) -> Self {
+        Completer {
+            app,
+            value_hooks: HashMap::new(),
+            ranker: Box::new(IdentityRanker),
+        }
+    }
+
+    /// Registers a closure producing value candidates for the argument with this id
+    ///
+    /// The closure receives the partial value being completed and replaces the argument's
+    /// `possible_values` as the candidate source, so values can come from the file system,
+    /// the network, or anything else only known at runtime.
+    #[must_use]
+    pub fn value_hook(
+        mut self,
+        arg_id: impl Into<String>,
+        hook: impl Fn(&str) -> Vec<String> + 'static,
+    ) -> Self {
+        self.value_hooks.insert(arg_id.into(), Box::new(hook));
+        self
+    }
+
+    /// Re-orders candidates with `ranker` before they are returned
+    #[must_use]
+    pub fn ranker(mut self, ranker: impl CandidateRanker + 'static) -> Self {
+        self.ranker = Box::new(ranker);
+        self
+    }
+
+    /// Computes the candidates for the word at `index` within `words`
+    ///
+    /// `words` is the full command line as split by the shell, including the program name at
+    /// `words[0]`; `index` may equal `words.len()` when a new, empty word is being completed.
+    /// Returned candidates all start with the partial word (for `--opt=val` completion they
+    /// include the `--opt=` prefix, as shells expect the whole word back).
+    pub fn complete<S: AsRef<str>>(&mut self, words: &[S], index: usize) -> Vec<String> {
+        self.app._build_all();
+
+        let current = words.get(index).map(S::as_ref).unwrap_or("");
+        let mut app: &App = self.app;
+        let mut value_of: Option<&Arg> = None;
+        let mut positionals_only = false;
+
+        for word in words[..index.min(words.len())].iter().skip(1) {
+            let word = word.as_ref();
+            if value_of.take().is_some() {
+                continue;
+            }
+            if positionals_only {
+                continue;
+            }
+            if word == "--" {
+                positionals_only = true;
+            } else if word.starts_with('-') {
+                if let Some(arg) = find_flag(app, word) {
+                    if arg.is_takes_value_set() && !word.contains('=') {
+                        value_of = Some(arg);
+                    }
+                }
+            } else if let Some(sc) = app.find_subcommand(word) {
+                app = sc;
+            }
+        }
+
+        let mut candidates = Vec::new();
+        if let Some(arg) = value_of {
+            candidates = self.value_candidates(arg, current);
+        } else if !positionals_only && current.starts_with("--") && current.contains('=') {
+            let (flag, prefix) = current.split_once('=').expect("checked above");
+            if let Some(arg) = find_flag(app, flag) {
+                if arg.is_takes_value_set() {
+                    candidates = self
+                        .value_candidates(arg, prefix)
+                        .into_iter()
+                        .map(|v| format!("{}={}", flag, v))
+                        .collect();
+                }
+            }
+        } else if !positionals_only && current.starts_with('-') {
+            for arg in app.get_arguments().filter(|a| !a.is_hide_set()) {
+                if let Some(long) = arg.get_long() {
+                    candidates.push(format!("--{}", long));
+                } else if let Some(short) = arg.get_short() {
+                    candidates.push(format!("-{}", short));
+                }
+            }
+        } else {
+            if !positionals_only {
+                for sc in app.get_subcommands().filter(|sc| !sc.is_hide_set()) {
+                    candidates.push(sc.get_name().to_string());
+                }
+                if app.has_subcommands() {
+                    candidates.push("help".to_string());
+                }
+            }
+            for arg in app.get_positionals().filter(|a| !a.is_hide_set()) {
+                candidates.extend(self.value_candidates(arg, current));
+            }
+        }
+
+        candidates.retain(|c| c.starts_with(current));
+        candidates.dedup();
+        self.ranker.rank(&mut candidates);
+        candidates
+    }
+
+    fn value_candidates(&self, arg: &Arg, prefix: &str) -> Vec<String> {
+        if let Some(hook) = self.value_hooks.get(arg.get_id()) {
+            hook(prefix)
+        } else {
+            arg.get_possible_values()
+                .unwrap_or_default()
+                .iter()
+                .filter(|pv| !pv.is_hide_set())
+                .map(|pv| pv.get_name().to_string())
+                .collect()
+        }
+    }
+}
+
+/// Resolves `-s` or `--long` (optionally with a trailing `=value`) to the matching argument
+fn find_flag<'a, 'help>(app: &'a App<'help>, word: &str) -> Option<&'a Arg<'help>> {
+    if let Some(long) = word.strip_prefix("--") {
+        let long = long.split('=').next().expect("split yields at least one");
+        app.get_arguments().find(|a| a.get_long() == Some(long))
+    } else if let Some(short) = word.strip_prefix('-').and_then(|s| s.chars().last()) {
+        app.get_arguments().find(|a| a.get_short() == Some(short))
+    } else {
+        None
+    }
+}
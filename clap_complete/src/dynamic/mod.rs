@@ -0,0 +1,16 @@
+//! Runtime ("dynamic") completion support
+//!
+//! Unlike the scripts produced by [`generate`][crate::generate], which are written once at
+//! build time, dynamic completion computes candidates while the user is typing. This module
+//! holds the pieces of that subsystem that are independent of any particular shell: the
+//! [completion engine][complete] that walks the live `App` and [ranking hooks][ranking] for
+//! ordering its candidates.
+
+pub mod complete;
+pub mod ranking;
+
+pub use complete::complete;
+pub use complete::Completer;
+pub use ranking::CandidateRanker;
+pub use ranking::FrequencyRanker;
+pub use ranking::IdentityRanker;
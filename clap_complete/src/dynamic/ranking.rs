@@ -0,0 +1,136 @@
+//! Ordering hooks for completion candidates
+//!
+//! Shells that honor the order in which candidates are produced (e.g. zsh when a group is
+//! declared with `-V`) will offer the first candidate most prominently. Applications can
+//! plug in a [`CandidateRanker`] — for example one backed by a shell history file — so the
+//! most frequently used subcommands and values come first. The default [`IdentityRanker`]
+//! leaves the engine's ordering untouched.
+
+use std::collections::HashMap;
+
+/// Re-orders completion candidates before they are handed to the shell
+///
+/// Implementations may only rearrange the list; filtering already happened by the time the
+/// ranker runs. Closures with a matching signature implement this trait, so a one-off hook
+/// does not need a dedicated type:
+///
+/// ```
+/// use clap_complete::dynamic::CandidateRanker;
+///
+/// let ranker = |candidates: &mut [String]| candidates.reverse();
+/// let mut candidates = vec!["add".to_owned(), "commit".to_owned()];
+/// ranker.rank(&mut candidates);
+/// assert_eq!(candidates, ["commit", "add"]);
+/// ```
+pub trait CandidateRanker {
+    /// Rearranges `candidates` into the order they should be offered in
+    fn rank(&self, candidates: &mut [String]);
+}
+
+impl<F> CandidateRanker for F
+where
+    F: Fn(&mut [String]),
+{
+    fn rank(&self, candidates: &mut [String]) {
+        self(candidates)
+    }
+}
+
+/// The default ranker: keeps candidates in the order the engine produced them
+#[derive(Copy, Clone, Debug, Default)]
+pub struct IdentityRanker;
+
+impl CandidateRanker for IdentityRanker {
+    fn rank(&self, _candidates: &mut [String]) {}
+}
+
+/// Ranks candidates by how often they have been used
+///
+/// Counts are supplied by the application, typically loaded from a history file before
+/// completion runs. Candidates with higher counts sort first; ties keep the engine's
+/// original (alphabetical) order, so unseen candidates remain stably sorted at the end.
+///
+/// # Examples
+///
+/// ```
+/// use clap_complete::dynamic::{CandidateRanker, FrequencyRanker};
+///
+/// let mut ranker = FrequencyRanker::new();
+/// ranker.record("push");
+/// ranker.record("push");
+/// ranker.record("pull");
+///
+/// let mut candidates = vec!["fetch".to_owned(), "pull".to_owned(), "push".to_owned()];
+/// ranker.rank(&mut candidates);
+/// assert_eq!(candidates, ["push", "pull", "fetch"]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct FrequencyRanker {
+    counts: HashMap<String, u64>,
+}
+
+impl FrequencyRanker {
+    /// Creates a ranker with no recorded usages
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records one use of `candidate`
+    pub fn record(&mut self, candidate: impl Into<String>) {
+        *self.counts.entry(candidate.into()).or_insert(0) += 1;
+    }
+
+    /// Sets the absolute use count of `candidate`, e.g. when loading a history file
+    pub fn set_count(&mut self, candidate: impl Into<String>, count: u64) {
+        self.counts.insert(candidate.into(), count);
+    }
+
+    /// The recorded use count of `candidate`
+    pub fn count(&self, candidate: &str) -> u64 {
+        self.counts.get(candidate).copied().unwrap_or(0)
+    }
+}
+
+impl CandidateRanker for FrequencyRanker {
+    fn rank(&self, candidates: &mut [String]) {
+        candidates.sort_by_key(|candidate| std::cmp::Reverse(self.count(candidate)));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn candidates() -> Vec<String> {
+        ["add", "commit", "push"]
+            .iter()
+            .map(|s| (*s).to_owned())
+            .collect()
+    }
+
+    #[test]
+    fn identity_keeps_order() {
+        let mut list = candidates();
+        IdentityRanker.rank(&mut list);
+        assert_eq!(list, candidates());
+    }
+
+    #[test]
+    fn frequency_sorts_most_used_first() {
+        let mut ranker = FrequencyRanker::new();
+        ranker.set_count("push", 10);
+        ranker.set_count("commit", 3);
+
+        let mut list = candidates();
+        ranker.rank(&mut list);
+        assert_eq!(list, ["push", "commit", "add"]);
+    }
+
+    #[test]
+    fn frequency_ties_are_stable() {
+        let ranker = FrequencyRanker::new();
+        let mut list = candidates();
+        ranker.rank(&mut list);
+        assert_eq!(list, candidates());
+    }
+}
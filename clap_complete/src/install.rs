@@ -0,0 +1,104 @@
+//! Installing completion scripts into the conventional per-shell location
+//!
+//! This lets apps offer a `myapp completions --install` flow: detect the user's
+//! shell from `$SHELL`, generate the script, and write it where that shell
+//! already looks for completions instead of asking the user to redirect output
+//! themselves.
+
+use std::env;
+use std::io::Error;
+use std::path::PathBuf;
+
+use crate::Shell;
+
+/// Detect the user's shell from the `$SHELL` environment variable.
+///
+/// Convenience re-export of [`Shell::from_env`] for the install flow.
+///
+/// # Examples
+///
+/// ```no_run
+/// let shell = clap_complete::install::detect_shell()
+///     .expect("could not detect shell from $SHELL");
+/// ```
+pub fn detect_shell() -> Option<Shell> {
+    Shell::from_env()
+}
+
+/// The conventional directory for `shell`'s user-installed completions.
+///
+/// - Bash: `$XDG_DATA_HOME/bash-completion/completions`, defaulting to
+///   `~/.local/share/bash-completion/completions`
+/// - Fish: `$XDG_CONFIG_HOME/fish/completions`, defaulting to
+///   `~/.config/fish/completions`
+/// - Zsh: `$ZDOTDIR/.zfunc`, defaulting to `~/.zfunc` — this directory must be
+///   added to `fpath` in `.zshrc`
+///
+/// Returns `None` for shells without a conventional per-user location
+/// (PowerShell sources completions from its profile, Elvish from `rc.elv`) or
+/// when the home directory cannot be determined.
+pub fn install_dir(shell: Shell) -> Option<PathBuf> {
+    match shell {
+        Shell::Bash => Some(
+            xdg_dir("XDG_DATA_HOME", &[".local", "share"])?.join("bash-completion/completions"),
+        ),
+        Shell::Fish => Some(xdg_dir("XDG_CONFIG_HOME", &[".config"])?.join("fish/completions")),
+        Shell::Zsh => match env::var_os("ZDOTDIR") {
+            Some(dir) => Some(PathBuf::from(dir).join(".zfunc")),
+            None => Some(home_dir()?.join(".zfunc")),
+        },
+        _ => None,
+    }
+}
+
+/// Generate the completion script for `shell` and write it into
+/// [`install_dir`], creating the directory if needed.
+///
+/// Returns the path of the installed script.  Fails when the shell has no
+/// conventional location; apps should fall back to printing the script to
+/// stdout in that case.
+///
+/// # Examples
+///
+/// ```no_run
+/// use clap::App;
+/// use clap_complete::install::{detect_shell, install};
+///
+/// let shell = detect_shell().expect("could not detect shell from $SHELL");
+/// let mut app = App::new("myapp");
+/// let path = install(shell, &mut app, "myapp").expect("could not install completions");
+/// println!("installed completions to {}", path.display());
+/// ```
+pub fn install<S: Into<String>>(
+    shell: Shell,
+    app: &mut clap::App,
+    bin_name: S,
+) -> Result<PathBuf, Error> {
+    let dir = install_dir(shell).ok_or_else(|| {
+        Error::new(
+            std::io::ErrorKind::Other,
+            format!("no conventional completions directory for {}", shell),
+        )
+    })?;
+    std::fs::create_dir_all(&dir)?;
+    crate::generate_to(shell, app, bin_name, dir)
+}
+
+fn xdg_dir(var: &str, home_relative: &[&str]) -> Option<PathBuf> {
+    match env::var_os(var) {
+        Some(dir) if !dir.is_empty() => Some(PathBuf::from(dir)),
+        _ => {
+            let mut dir = home_dir()?;
+            dir.extend(home_relative);
+            Some(dir)
+        }
+    }
+}
+
+fn home_dir() -> Option<PathBuf> {
+    #[cfg(windows)]
+    let home = env::var_os("USERPROFILE");
+    #[cfg(not(windows))]
+    let home = env::var_os("HOME");
+    home.filter(|home| !home.is_empty()).map(PathBuf::from)
+}
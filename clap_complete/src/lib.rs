@@ -61,8 +61,14 @@ const INTERNAL_ERROR_MSG: &str = "Fatal internal error. Please consider filing a
 #[allow(missing_docs)]
 mod macros;
 
+pub mod aliases;
+pub mod dynamic;
 pub mod generator;
+pub mod install;
+pub mod man;
 pub mod shells;
+pub mod spec;
+pub mod test;
 
 pub use generator::generate;
 pub use generator::generate_to;
@@ -0,0 +1,250 @@
+//! Man page generation
+//!
+//! Renders an [`App`] to roff so build scripts can produce `myapp.1` without hand-writing
+//! it:
+//!
+//! ```no_run
+//! use clap::App;
+//! use clap_complete::man::Man;
+//!
+//! let app = App::new("myapp").version("1.0").about("Does things");
+//! let mut page = Vec::new();
+//! Man::new(app).render(&mut page).unwrap();
+//! std::fs::write("myapp.1", page).unwrap();
+//! ```
+
+use std::io::{self, Write};
+
+use clap::{App, Arg};
+
+/// A man page renderer for one command
+///
+/// Subcommands are listed in a `SUBCOMMANDS` section; to give each its own page, construct
+/// a `Man` per subcommand from [`App::get_subcommands`].
+pub struct Man<'help> {
+    app: App<'help>,
+    section: String,
+    manual: Option<String>,
+    date: Option<String>,
+}
+
+impl<'help> Man<'help> {
+    /// Creates a renderer for `app`, defaulting to section 1
+    pub fn new(mut app: App<'help>) -> Self {
+        app._build_all();
+        Man {
+            app,
+            section: "1".to_string(),
+            manual: None,
+            date: None,
+        }
+    }
+
+    /// Overrides the man section (the `1` in `myapp.1`)
+    #[must_use]
+    pub fn section(mut self, section: impl Into<String>) -> Self {
+        self.section = section.into();
+        self
+    }
+
+    /// Sets the manual name shown in the page header
+    #[must_use]
+    pub fn manual(mut self, manual: impl Into<String>) -> Self {
+        self.manual = Some(manual.into());
+        self
+    }
+
+    /// Sets the date shown in the page footer
+    #[must_use]
+    pub fn date(mut self, date: impl Into<String>) -> Self {
+        self.date = Some(date.into());
+        self
+    }
+
+    /// Renders the page as roff
+    pub fn render(&self, w: &mut dyn Write) -> io::Result<()> {
+        self.render_title(w)?;
+        self.render_name(w)?;
+        self.render_synopsis(w)?;
+        self.render_description(w)?;
+        self.render_options(w)?;
+        self.render_subcommands(w)?;
+        self.render_version(w)?;
+        self.render_authors(w)?;
+        Ok(())
+    }
+
+    fn render_title(&self, w: &mut dyn Write) -> io::Result<()> {
+        let name = self.app.get_name();
+        write!(
+            w,
+            ".TH \"{}\" \"{}\" \"{}\"",
+            escape(&name.to_uppercase()),
+            escape(&self.section),
+            escape(self.date.as_deref().unwrap_or(""))
+        )?;
+        let source = self
+            .app
+            .get_version()
+            .map(|v| format!("{} {}", name, v))
+            .unwrap_or_else(|| name.to_string());
+        write!(w, " \"{}\"", escape(&source))?;
+        if let Some(manual) = self.manual.as_deref() {
+            write!(w, " \"{}\"", escape(manual))?;
+        }
+        writeln!(w)
+    }
+
+    fn render_name(&self, w: &mut dyn Write) -> io::Result<()> {
+        writeln!(w, ".SH NAME")?;
+        match self.app.get_about() {
+            Some(about) => writeln!(
+                w,
+                "{} \\- {}",
+                escape(self.app.get_name()),
+                escape(about)
+            ),
+            None => writeln!(w, "{}", escape(self.app.get_name())),
+        }
+    }
+
+    fn render_synopsis(&self, w: &mut dyn Write) -> io::Result<()> {
+        writeln!(w, ".SH SYNOPSIS")?;
+        write!(w, "\\fB{}\\fR", escape(self.app.get_name()))?;
+        if self.app.get_arguments().any(|a| !a.is_positional()) {
+            write!(w, " [OPTIONS]")?;
+        }
+        for arg in self.app.get_positionals() {
+            let name = arg
+                .get_value_names()
+                .and_then(|names| names.first().copied())
+                .unwrap_or_else(|| arg.get_id());
+            if arg.is_required_set() {
+                write!(w, " <{}>", escape(name))?;
+            } else {
+                write!(w, " [{}]", escape(name))?;
+            }
+            if arg.is_multiple_values_set() {
+                write!(w, "...")?;
+            }
+        }
+        if self.app.has_subcommands() {
+            write!(w, " [SUBCOMMAND]")?;
+        }
+        writeln!(w)
+    }
+
+    fn render_description(&self, w: &mut dyn Write) -> io::Result<()> {
+        let description = self.app.get_long_about().or_else(|| self.app.get_about());
+        if let Some(description) = description {
+            writeln!(w, ".SH DESCRIPTION")?;
+            writeln!(w, "{}", escape(description))?;
+        }
+        Ok(())
+    }
+
+    fn render_options(&self, w: &mut dyn Write) -> io::Result<()> {
+        let args: Vec<&Arg> = self
+            .app
+            .get_arguments()
+            .filter(|a| !a.is_hide_set())
+            .collect();
+        if args.is_empty() {
+            return Ok(());
+        }
+
+        writeln!(w, ".SH OPTIONS")?;
+        for arg in args {
+            writeln!(w, ".TP")?;
+            let mut header = String::new();
+            if let Some(short) = arg.get_short() {
+                header.push_str(&format!("\\fB\\-{}\\fR", short));
+            }
+            if let Some(long) = arg.get_long() {
+                if !header.is_empty() {
+                    header.push_str(", ");
+                }
+                header.push_str(&format!("\\fB\\-\\-{}\\fR", escape(long)));
+            }
+            if arg.is_takes_value_set() || arg.is_positional() {
+                let name = arg
+                    .get_value_names()
+                    .and_then(|names| names.first().copied())
+                    .unwrap_or_else(|| arg.get_id());
+                if !header.is_empty() {
+                    header.push(' ');
+                }
+                header.push_str(&format!("\\fI{}\\fR", escape(name)));
+            }
+            writeln!(w, "{}", header)?;
+            if let Some(help) = arg.get_long_help().or_else(|| arg.get_help()) {
+                writeln!(w, "{}", escape(help))?;
+            }
+            if let Some(values) = arg.get_possible_values() {
+                let names: Vec<&str> = values
+                    .iter()
+                    .filter(|pv| !pv.is_hide_set())
+                    .map(|pv| pv.get_name())
+                    .collect();
+                if !names.is_empty() {
+                    writeln!(w, "[possible values: {}]", escape(&names.join(", ")))?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn render_subcommands(&self, w: &mut dyn Write) -> io::Result<()> {
+        let subcommands: Vec<&App> = self
+            .app
+            .get_subcommands()
+            .filter(|sc| !sc.is_hide_set())
+            .collect();
+        if subcommands.is_empty() {
+            return Ok(());
+        }
+
+        writeln!(w, ".SH SUBCOMMANDS")?;
+        for sc in subcommands {
+            writeln!(w, ".TP")?;
+            writeln!(
+                w,
+                "{}\\-{}({})",
+                escape(self.app.get_name()),
+                escape(sc.get_name()),
+                escape(&self.section)
+            )?;
+            if let Some(about) = sc.get_long_about().or_else(|| sc.get_about()) {
+                writeln!(w, "{}", escape(about))?;
+            }
+        }
+        Ok(())
+    }
+
+    fn render_version(&self, w: &mut dyn Write) -> io::Result<()> {
+        if let Some(version) = self.app.get_version() {
+            writeln!(w, ".SH VERSION")?;
+            writeln!(w, "v{}", escape(version))?;
+        }
+        Ok(())
+    }
+
+    fn render_authors(&self, w: &mut dyn Write) -> io::Result<()> {
+        if let Some(author) = self.app.get_author() {
+            writeln!(w, ".SH AUTHORS")?;
+            writeln!(w, "{}", escape(author))?;
+        }
+        Ok(())
+    }
+}
+
+/// Escapes roff control characters in body text
+fn escape(s: &str) -> String {
+    let escaped = s.replace('\\', "\\\\");
+    // A leading dot or quote would be taken as a roff request
+    if escaped.starts_with('.') || escaped.starts_with('\'') {
+        format!("\\&{}", escaped)
+    } else {
+        escaped
+    }
+}
@@ -79,23 +79,20 @@ fn generate_inner<'help>(
         format!("{};{}", previous_command_name, &p.get_name())
     };
 
-    let mut completions = String::new();
-    let preamble = String::from("\n            cand ");
+    let mut cands = Vec::new();
 
     for option in p.get_opts() {
         if let Some(shorts) = option.get_short_and_visible_aliases() {
             let tooltip = get_tooltip(option.get_help(), shorts[0]);
             for short in shorts {
-                completions.push_str(&preamble);
-                completions.push_str(format!("-{} '{}'", short, tooltip).as_str());
+                cands.push(format!("cand -{} '{}'", short, tooltip));
             }
         }
 
         if let Some(longs) = option.get_long_and_visible_aliases() {
             let tooltip = get_tooltip(option.get_help(), longs[0]);
             for long in longs {
-                completions.push_str(&preamble);
-                completions.push_str(format!("--{} '{}'", long, tooltip).as_str());
+                cands.push(format!("cand --{} '{}'", long, tooltip));
             }
         }
     }
@@ -104,16 +101,14 @@ fn generate_inner<'help>(
         if let Some(shorts) = flag.get_short_and_visible_aliases() {
             let tooltip = get_tooltip(flag.get_help(), shorts[0]);
             for short in shorts {
-                completions.push_str(&preamble);
-                completions.push_str(format!("-{} '{}'", short, tooltip).as_str());
+                cands.push(format!("cand -{} '{}'", short, tooltip));
             }
         }
 
         if let Some(longs) = flag.get_long_and_visible_aliases() {
             let tooltip = get_tooltip(flag.get_help(), longs[0]);
             for long in longs {
-                completions.push_str(&preamble);
-                completions.push_str(format!("--{} '{}'", long, tooltip).as_str());
+                cands.push(format!("cand --{} '{}'", long, tooltip));
             }
         }
     }
@@ -122,10 +117,64 @@ fn generate_inner<'help>(
         let data = &subcommand.get_name();
         let tooltip = get_tooltip(subcommand.get_about(), data);
 
-        completions.push_str(&preamble);
-        completions.push_str(format!("{} '{}'", data, tooltip).as_str());
+        cands.push(format!("cand {} '{}'", data, tooltip));
     }
 
+    // Options whose value elvish can complete: the case body offers the value
+    // candidates when the word being completed follows one of the option's forms
+    let mut value_cases = Vec::new();
+
+    for option in p.get_opts() {
+        let mut forms = Vec::new();
+        if let Some(shorts) = option.get_short_and_visible_aliases() {
+            forms.extend(shorts.iter().map(|short| format!("-{}", short)));
+        }
+        if let Some(longs) = option.get_long_and_visible_aliases() {
+            forms.extend(longs.iter().map(|long| format!("--{}", long)));
+        }
+        if forms.is_empty() {
+            continue;
+        }
+
+        if let Some(body) = value_completion(option) {
+            let condition = if forms.len() == 1 {
+                format!("(eq $prev '{}')", forms[0])
+            } else {
+                format!(
+                    "(or {})",
+                    forms
+                        .iter()
+                        .map(|form| format!("(eq $prev '{}')", form))
+                        .collect::<Vec<_>>()
+                        .join(" ")
+                )
+            };
+            value_cases.push((condition, body));
+        }
+    }
+
+    let completions = if value_cases.is_empty() {
+        cands
+            .iter()
+            .map(|cand| format!("\n            {}", cand))
+            .collect::<String>()
+    } else {
+        let mut chain = String::from("\n            var prev = $words[-2]");
+        for (index, (condition, body)) in value_cases.iter().enumerate() {
+            let keyword = if index == 0 { "if" } else { "} elif" };
+            chain.push_str(&format!(
+                "\n            {} {} {{{}",
+                keyword, condition, body
+            ));
+        }
+        chain.push_str("\n            } else {");
+        for cand in &cands {
+            chain.push_str(&format!("\n                {}", cand));
+        }
+        chain.push_str("\n            }");
+        chain
+    };
+
     let mut subcommands_cases = format!(
         r"
         &'{}'= {{{}
@@ -140,3 +189,45 @@ fn generate_inner<'help>(
 
     subcommands_cases
 }
+
+fn value_completion(option: &Arg) -> Option<String> {
+    if !option.is_takes_value_set() {
+        return None;
+    }
+
+    if let Some(possible_values) = option.get_possible_values() {
+        let cands: String = possible_values
+            .iter()
+            .filter(|value| !value.is_hide_set())
+            .map(|value| {
+                format!(
+                    "\n                cand {} '{}'",
+                    value.get_name(),
+                    get_tooltip(value.get_help(), value.get_name())
+                )
+            })
+            .collect();
+
+        if cands.is_empty() {
+            None
+        } else {
+            Some(cands)
+        }
+    } else {
+        // NB! If you change this, please also update the table in `ValueHint` documentation.
+        match option.get_value_hint() {
+            // elvish has no built-in support to distinguish these
+            ValueHint::AnyPath
+            | ValueHint::FilePath
+            | ValueHint::ExistingFile
+            | ValueHint::CreatableFile
+            | ValueHint::ExecutablePath
+            | ValueHint::DirPath
+            | ValueHint::ExistingDir => {
+                Some("\n                edit:complete-filename $words[-1]".to_string())
+            }
+            // No value completion for others; elvish falls back to free text
+            _ => None,
+        }
+    }
+}
@@ -18,9 +18,12 @@ impl Generator for PowerShell {
         let bin_name = app
             .get_bin_name()
             .expect("crate::generate should have set the bin_name");
+        let safe_name = bin_name
+            .replace(|c: char| !c.is_ascii_alphanumeric(), "_");
 
         let mut names = vec![];
         let subcommands_cases = generate_inner(app, "", &mut names);
+        let value_cases = generate_value_cases(app, "");
 
         let result = format!(
             r#"
@@ -30,29 +33,51 @@ using namespace System.Management.Automation.Language
 Register-ArgumentCompleter -Native -CommandName '{bin_name}' -ScriptBlock {{
     param($wordToComplete, $commandAst, $cursorPosition)
 
+    function __{safe_name}_complete([string]$spec) {{
+        @(switch ($spec) {{{subcommands_cases}
+        }})
+    }}
+
     $commandElements = $commandAst.CommandElements
+    $lastOption = ''
     $command = @(
         '{bin_name}'
         for ($i = 1; $i -lt $commandElements.Count; $i++) {{
             $element = $commandElements[$i]
             if ($element -isnot [StringConstantExpressionAst] -or
                 $element.StringConstantType -ne [StringConstantType]::BareWord -or
-                $element.Value.StartsWith('-') -or
                 $element.Value -eq $wordToComplete) {{
                 break
-        }}
-        $element.Value
-    }}) -join ';'
+            }}
+            if ($element.Value.StartsWith('-')) {{
+                $lastOption = $element.Value
+                continue
+            }}
+            $lastOption = ''
+            $element.Value
+        }}) -join ';'
 
-    $completions = @(switch ($command) {{{subcommands_cases}
-    }})
+    $completions = @()
+    if ($lastOption -ne '') {{
+        $completions = @(switch ("$command;$lastOption") {{{value_cases}
+        }})
+    }}
+    if ($completions.Count -eq 0) {{
+        $completions = __{safe_name}_complete $command
+    }}
+    while ($completions.Count -eq 0 -and $command -match ';') {{
+        $command = $command -replace ';[^;]*$', ''
+        $completions = __{safe_name}_complete $command
+    }}
 
     $completions.Where{{ $_.CompletionText -like "$wordToComplete*" }} |
         Sort-Object -Property ListItemText
 }}
 "#,
             bin_name = bin_name,
-            subcommands_cases = subcommands_cases
+            safe_name = safe_name,
+            subcommands_cases = subcommands_cases,
+            value_cases = value_cases
         );
 
         w!(buf, result.as_bytes());
@@ -71,6 +96,67 @@ fn get_tooltip<T: ToString>(help: Option<&str>, data: T) -> String {
     }
 }
 
+// Cases keyed by `command;--option` (or `command;-o`) offering the option's possible
+// values, so `myapp --speed <TAB>` completes values instead of flags.
+fn generate_value_cases(p: &App, previous_command_name: &str) -> String {
+    debug!("generate_value_cases");
+
+    let command_name = if previous_command_name.is_empty() {
+        p.get_bin_name().expect(INTERNAL_ERROR_MSG).to_string()
+    } else {
+        format!("{};{}", previous_command_name, &p.get_name())
+    };
+
+    let preamble = String::from("\n            [CompletionResult]::new(");
+    let mut cases = String::new();
+
+    for option in p.get_opts() {
+        let values: Vec<_> = option
+            .get_possible_values()
+            .unwrap_or_default()
+            .iter()
+            .filter(|value| !value.is_hide_set())
+            .map(|value| {
+                let name = escape_string(value.get_name());
+                let tooltip = get_tooltip(value.get_help(), value.get_name());
+                format!(
+                    "{}'{}', '{}', {}, '{}')",
+                    preamble, name, name, "[CompletionResultType]::ParameterValue", tooltip
+                )
+            })
+            .collect();
+        if values.is_empty() {
+            continue;
+        }
+
+        let mut keys = vec![];
+        if let Some(longs) = option.get_long_and_visible_aliases() {
+            keys.extend(longs.into_iter().map(|long| format!("--{}", long)));
+        }
+        if let Some(shorts) = option.get_short_and_visible_aliases() {
+            keys.extend(shorts.into_iter().map(|short| format!("-{}", short)));
+        }
+
+        for key in keys {
+            cases.push_str(&format!(
+                r"
+        '{};{}' {{{}
+            break
+        }}",
+                &command_name,
+                key,
+                values.join("")
+            ));
+        }
+    }
+
+    for subcommand in p.get_subcommands() {
+        cases.push_str(&generate_value_cases(subcommand, &command_name));
+    }
+
+    cases
+}
+
 fn generate_inner<'help>(
     p: &App<'help>,
     previous_command_name: &str,
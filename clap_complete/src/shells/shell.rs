@@ -29,6 +29,25 @@ impl Shell {
             .iter()
             .filter_map(ArgEnum::to_possible_value)
     }
+
+    /// Detect the invoking shell from the `$SHELL` environment variable.
+    ///
+    /// Returns `None` when `$SHELL` is unset or its basename is not a shell
+    /// with auto-generated completion support, so apps can write
+    /// `arg!(--shell <SHELL>)` with [`Shell::possible_values`] and default to
+    /// the user's own shell:
+    ///
+    /// ```no_run
+    /// use clap_complete::Shell;
+    ///
+    /// let shell = Shell::from_env().unwrap_or(Shell::Bash);
+    /// ```
+    pub fn from_env() -> Option<Shell> {
+        let shell = std::env::var_os("SHELL")?;
+        let name = std::path::PathBuf::from(shell);
+        let name = name.file_name()?.to_str()?;
+        name.parse().ok()
+    }
 }
 
 impl Display for Shell {
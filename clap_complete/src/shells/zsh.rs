@@ -400,6 +400,9 @@ fn value_completion(arg: &Arg) -> Option<String> {
                 ValueHint::AnyPath => "_files",
                 ValueHint::FilePath => "_files",
                 ValueHint::DirPath => "_files -/",
+                ValueHint::ExistingFile => "_files",
+                ValueHint::ExistingDir => "_files -/",
+                ValueHint::CreatableFile => "_files",
                 ValueHint::ExecutablePath => "_absolute_command_paths",
                 ValueHint::CommandName => "_command_names -e",
                 ValueHint::CommandString => "_cmdstring",
@@ -0,0 +1,201 @@
+//! Shell-agnostic completion spec generation
+//!
+//! Dumps the full [`App`] tree — flags, options, possible values, value hints, and
+//! subcommands at every depth — as a JSON document that external completion frameworks
+//! (e.g. carapace-bin) can consume, instead of maintaining one generated script per shell:
+//!
+//! ```no_run
+//! use clap::App;
+//! use clap_complete::{generate, spec::Spec};
+//! use std::io;
+//!
+//! let mut app = App::new("myapp").version("1.0").about("Does things");
+//! generate(Spec, &mut app, "myapp", &mut io::stdout());
+//! ```
+
+use std::io::Write;
+
+use clap::{App, Arg, ValueHint};
+
+use crate::generator::{utils, Generator};
+
+/// Generate a JSON completion spec usable by external completion frameworks
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Spec;
+
+impl Generator for Spec {
+    fn file_name(&self, name: &str) -> String {
+        format!("{}.json", name)
+    }
+
+    fn generate(&self, app: &App, buf: &mut dyn Write) {
+        let mut spec = app_spec(app);
+        spec.push('\n');
+        w!(buf, spec.as_bytes());
+    }
+}
+
+fn app_spec(app: &App) -> String {
+    debug!("app_spec: name={}", app.get_name());
+
+    let mut object = Object::new();
+    object.string("name", app.get_name());
+    if let Some(bin_name) = app.get_bin_name() {
+        object.string("bin_name", bin_name);
+    }
+    object.opt_string("version", app.get_version());
+    object.opt_string("about", app.get_about());
+    object.strings("aliases", app.get_visible_aliases().map(String::from));
+
+    object.array("flags", utils::flags(app).iter().map(arg_spec));
+    object.array("options", app.get_opts().map(arg_spec));
+    object.array("positionals", app.get_positionals().map(arg_spec));
+    object.array("subcommands", app.get_subcommands().map(app_spec));
+
+    object.render()
+}
+
+fn arg_spec(arg: &Arg) -> String {
+    let mut object = Object::new();
+    object.string("name", arg.get_id());
+    if let Some(short) = arg.get_short() {
+        object.string("short", &short.to_string());
+    }
+    object.opt_string("long", arg.get_long());
+    object.strings(
+        "aliases",
+        arg.get_visible_aliases()
+            .unwrap_or_default()
+            .into_iter()
+            .map(String::from),
+    );
+    object.strings(
+        "short_aliases",
+        arg.get_visible_short_aliases()
+            .unwrap_or_default()
+            .into_iter()
+            .map(|short| short.to_string()),
+    );
+    object.opt_string("help", arg.get_help());
+    object.flag("required", arg.is_required_set());
+    object.flag("hidden", arg.is_hide_set());
+
+    if arg.is_takes_value_set() {
+        object.flag("takes_value", true);
+        object.flag(
+            "multiple",
+            arg.is_multiple_values_set() || arg.is_multiple_occurrences_set(),
+        );
+        object.strings(
+            "value_names",
+            arg.get_value_names()
+                .unwrap_or_default()
+                .iter()
+                .map(|name| String::from(*name)),
+        );
+        if arg.get_value_hint() != ValueHint::Unknown {
+            object.string("hint", &format!("{:?}", arg.get_value_hint()));
+        }
+        object.array(
+            "values",
+            arg.get_possible_values()
+                .unwrap_or_default()
+                .iter()
+                .filter(|value| !value.is_hide_set())
+                .map(|value| {
+                    let mut spec = Object::new();
+                    spec.string("name", value.get_name());
+                    spec.opt_string("help", value.get_help());
+                    spec.render()
+                }),
+        );
+    }
+
+    object.render()
+}
+
+/// A pretty-printed JSON object under construction; members that would be empty are omitted
+struct Object {
+    members: Vec<String>,
+}
+
+impl Object {
+    fn new() -> Self {
+        Object { members: vec![] }
+    }
+
+    fn string(&mut self, key: &str, value: &str) {
+        self.members
+            .push(format!("{}: {}", quote(key), quote(value)));
+    }
+
+    fn opt_string(&mut self, key: &str, value: Option<&str>) {
+        if let Some(value) = value {
+            self.string(key, value);
+        }
+    }
+
+    fn flag(&mut self, key: &str, value: bool) {
+        if value {
+            self.members.push(format!("{}: true", quote(key)));
+        }
+    }
+
+    fn strings(&mut self, key: &str, values: impl IntoIterator<Item = String>) {
+        self.array(key, values.into_iter().map(|value| quote(&value)));
+    }
+
+    fn array(&mut self, key: &str, items: impl IntoIterator<Item = String>) {
+        let items: Vec<String> = items.into_iter().collect();
+        if items.is_empty() {
+            return;
+        }
+
+        let mut rendered = String::from("[");
+        for (index, item) in items.iter().enumerate() {
+            rendered.push_str("\n  ");
+            rendered.push_str(&indent_tail(item));
+            if index + 1 != items.len() {
+                rendered.push(',');
+            }
+        }
+        rendered.push_str("\n]");
+        self.members.push(format!("{}: {}", quote(key), rendered));
+    }
+
+    fn render(&self) -> String {
+        let mut rendered = String::from("{");
+        for (index, member) in self.members.iter().enumerate() {
+            rendered.push_str("\n  ");
+            rendered.push_str(&indent_tail(member));
+            if index + 1 != self.members.len() {
+                rendered.push(',');
+            }
+        }
+        rendered.push_str("\n}");
+        rendered
+    }
+}
+
+/// Indents every line after the first by one level, for nesting inside objects and arrays
+fn indent_tail(value: &str) -> String {
+    value.replace('\n', "\n  ")
+}
+
+fn quote(string: &str) -> String {
+    let mut quoted = String::with_capacity(string.len() + 2);
+    quoted.push('"');
+    for c in string.chars() {
+        match c {
+            '"' => quoted.push_str("\\\""),
+            '\\' => quoted.push_str("\\\\"),
+            '\n' => quoted.push_str("\\n"),
+            '\r' => quoted.push_str("\\r"),
+            '\t' => quoted.push_str("\\t"),
+            c if (c as u32) < 0x20 => quoted.push_str(&format!("\\u{:04x}", c as u32)),
+            c => quoted.push(c),
+        }
+    }
+    quoted.push('"');
+    quoted
+}
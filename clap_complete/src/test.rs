@@ -0,0 +1,87 @@
+//! Testing utilities for completion behavior
+//!
+//! Downstream apps can write regression tests for their completions without spawning a real
+//! shell: [`complete`] splits a simulated command line the way the shell would, hands the
+//! words to the [dynamic engine][crate::dynamic], and returns the candidate list.
+//!
+//! ```rust
+//! use clap::{App, Arg};
+//! use clap_complete::{test, Shell};
+//!
+//! let mut app = App::new("myapp")
+//!     .arg(Arg::new("verbose").long("verbose"))
+//!     .subcommand(App::new("push"));
+//!
+//! let line = "myapp --ver";
+//! let candidates = test::complete(Shell::Bash, &mut app, line, line.len());
+//! assert_eq!(candidates, ["--verbose"]);
+//! ```
+
+use clap::App;
+
+use crate::Shell;
+
+/// Computes the completion candidates for `line` with the cursor at byte `point`
+///
+/// The line is truncated at `point` (as shells do) and split into words honoring single
+/// quotes, double quotes, and backslash escapes; `shell` selects the word-splitting dialect,
+/// though the supported shells currently share these POSIX-style rules. The first word is
+/// taken as the program name. Trailing whitespace means a new, empty word is being completed.
+pub fn complete(shell: Shell, app: &mut App, line: &str, point: usize) -> Vec<String> {
+    let point = point.min(line.len());
+    let (words, index) = split_line(shell, &line[..point]);
+    crate::dynamic::complete(app, &words, index)
+}
+
+/// Splits the line into words and the index of the word under the cursor
+fn split_line(_shell: Shell, line: &str) -> (Vec<String>, usize) {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut in_word = false;
+    let mut chars = line.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut current));
+                    in_word = false;
+                }
+            }
+            '\'' => {
+                in_word = true;
+                for c in chars.by_ref() {
+                    if c == '\'' {
+                        break;
+                    }
+                    current.push(c);
+                }
+            }
+            '"' => {
+                in_word = true;
+                while let Some(c) = chars.next() {
+                    match c {
+                        '"' => break,
+                        '\\' => current.extend(chars.next()),
+                        c => current.push(c),
+                    }
+                }
+            }
+            '\\' => {
+                in_word = true;
+                current.extend(chars.next());
+            }
+            c => {
+                in_word = true;
+                current.push(c);
+            }
+        }
+    }
+    if in_word {
+        words.push(current);
+        let index = words.len() - 1;
+        (words, index)
+    } else {
+        let index = words.len();
+        (words, index)
+    }
+}
@@ -196,3 +196,29 @@ complete -c my_app -n "__fish_seen_subcommand_from some_cmd; and __fish_seen_sub
 complete -c my_app -n "__fish_seen_subcommand_from some_cmd; and __fish_seen_subcommand_from help" -s h -l help -d 'Print help information'
 complete -c my_app -n "__fish_seen_subcommand_from some_cmd; and __fish_seen_subcommand_from help" -s V -l version -d 'Print version information'
 "#;
+
+#[test]
+fn fish_with_reassigned_help_short() {
+    let mut app = build_app_with_reassigned_help_short();
+    common(Fish, &mut app, "my_app", FISH_REASSIGNED_HELP_SHORT);
+}
+
+fn build_app_with_reassigned_help_short() -> App<'static> {
+    build_app_with_name("my_app").arg(
+        Arg::new("host")
+            .short('h')
+            .long("host")
+            .takes_value(true)
+            .help("the host to connect to"),
+    )
+}
+
+static FISH_REASSIGNED_HELP_SHORT: &str = r#"complete -c my_app -n "__fish_use_subcommand" -s h -l host -d 'the host to connect to' -r
+complete -c my_app -n "__fish_use_subcommand" -l help -d 'Print help information'
+complete -c my_app -n "__fish_use_subcommand" -s V -l version -d 'Print version information'
+complete -c my_app -n "__fish_use_subcommand" -f -a "test" -d 'tests things'
+complete -c my_app -n "__fish_use_subcommand" -f -a "help" -d 'Print this message or the help of the given subcommand(s)'
+complete -c my_app -n "__fish_seen_subcommand_from test" -l case -d 'the case to test' -r
+complete -c my_app -n "__fish_seen_subcommand_from test" -s h -l help -d 'Print help information'
+complete -c my_app -n "__fish_seen_subcommand_from test" -s V -l version -d 'Print version information'
+"#;
@@ -45,21 +45,8 @@ using namespace System.Management.Automation.Language
 Register-ArgumentCompleter -Native -CommandName 'my_app' -ScriptBlock {
     param($wordToComplete, $commandAst, $cursorPosition)
 
-    $commandElements = $commandAst.CommandElements
-    $command = @(
-        'my_app'
-        for ($i = 1; $i -lt $commandElements.Count; $i++) {
-            $element = $commandElements[$i]
-            if ($element -isnot [StringConstantExpressionAst] -or
-                $element.StringConstantType -ne [StringConstantType]::BareWord -or
-                $element.Value.StartsWith('-') -or
-                $element.Value -eq $wordToComplete) {
-                break
-        }
-        $element.Value
-    }) -join ';'
-
-    $completions = @(switch ($command) {
+    function __my_app_complete([string]$spec) {
+        @(switch ($spec) {
         'my_app' {
             [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
             [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
@@ -84,7 +71,40 @@ Register-ArgumentCompleter -Native -CommandName 'my_app' -ScriptBlock {
         'my_app;help' {
             break
         }
-    })
+        })
+    }
+
+    $commandElements = $commandAst.CommandElements
+    $lastOption = ''
+    $command = @(
+        'my_app'
+        for ($i = 1; $i -lt $commandElements.Count; $i++) {
+            $element = $commandElements[$i]
+            if ($element -isnot [StringConstantExpressionAst] -or
+                $element.StringConstantType -ne [StringConstantType]::BareWord -or
+                $element.Value -eq $wordToComplete) {
+                break
+            }
+            if ($element.Value.StartsWith('-')) {
+                $lastOption = $element.Value
+                continue
+            }
+            $lastOption = ''
+            $element.Value
+        }) -join ';'
+
+    $completions = @()
+    if ($lastOption -ne '') {
+        $completions = @(switch ("$command;$lastOption") {
+        })
+    }
+    if ($completions.Count -eq 0) {
+        $completions = __my_app_complete $command
+    }
+    while ($completions.Count -eq 0 -and $command -match ';') {
+        $command = $command -replace ';[^;]*$', ''
+        $completions = __my_app_complete $command
+    }
 
     $completions.Where{ $_.CompletionText -like "$wordToComplete*" } |
         Sort-Object -Property ListItemText
@@ -117,21 +137,8 @@ using namespace System.Management.Automation.Language
 Register-ArgumentCompleter -Native -CommandName 'my_app' -ScriptBlock {
     param($wordToComplete, $commandAst, $cursorPosition)
 
-    $commandElements = $commandAst.CommandElements
-    $command = @(
-        'my_app'
-        for ($i = 1; $i -lt $commandElements.Count; $i++) {
-            $element = $commandElements[$i]
-            if ($element -isnot [StringConstantExpressionAst] -or
-                $element.StringConstantType -ne [StringConstantType]::BareWord -or
-                $element.Value.StartsWith('-') -or
-                $element.Value -eq $wordToComplete) {
-                break
-        }
-        $element.Value
-    }) -join ';'
-
-    $completions = @(switch ($command) {
+    function __my_app_complete([string]$spec) {
+        @(switch ($spec) {
         'my_app' {
             [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
             [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
@@ -173,7 +180,40 @@ Register-ArgumentCompleter -Native -CommandName 'my_app' -ScriptBlock {
         'my_app;help' {
             break
         }
-    })
+        })
+    }
+
+    $commandElements = $commandAst.CommandElements
+    $lastOption = ''
+    $command = @(
+        'my_app'
+        for ($i = 1; $i -lt $commandElements.Count; $i++) {
+            $element = $commandElements[$i]
+            if ($element -isnot [StringConstantExpressionAst] -or
+                $element.StringConstantType -ne [StringConstantType]::BareWord -or
+                $element.Value -eq $wordToComplete) {
+                break
+            }
+            if ($element.Value.StartsWith('-')) {
+                $lastOption = $element.Value
+                continue
+            }
+            $lastOption = ''
+            $element.Value
+        }) -join ';'
+
+    $completions = @()
+    if ($lastOption -ne '') {
+        $completions = @(switch ("$command;$lastOption") {
+        })
+    }
+    if ($completions.Count -eq 0) {
+        $completions = __my_app_complete $command
+    }
+    while ($completions.Count -eq 0 -and $command -match ';') {
+        $command = $command -replace ';[^;]*$', ''
+        $completions = __my_app_complete $command
+    }
 
     $completions.Where{ $_.CompletionText -like "$wordToComplete*" } |
         Sort-Object -Property ListItemText
@@ -217,37 +257,173 @@ using namespace System.Management.Automation.Language
 Register-ArgumentCompleter -Native -CommandName 'cmd' -ScriptBlock {
     param($wordToComplete, $commandAst, $cursorPosition)
 
+    function __cmd_complete([string]$spec) {
+        @(switch ($spec) {
+        'cmd' {
+            [CompletionResult]::new('-o', 'o', [CompletionResultType]::ParameterName, 'cmd option')
+            [CompletionResult]::new('-O', 'O', [CompletionResultType]::ParameterName, 'cmd option')
+            [CompletionResult]::new('--option', 'option', [CompletionResultType]::ParameterName, 'cmd option')
+            [CompletionResult]::new('--opt', 'opt', [CompletionResultType]::ParameterName, 'cmd option')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('-V', 'V', [CompletionResultType]::ParameterName, 'Print version information')
+            [CompletionResult]::new('--version', 'version', [CompletionResultType]::ParameterName, 'Print version information')
+            [CompletionResult]::new('-f', 'f', [CompletionResultType]::ParameterName, 'cmd flag')
+            [CompletionResult]::new('-F', 'F', [CompletionResultType]::ParameterName, 'cmd flag')
+            [CompletionResult]::new('--flag', 'flag', [CompletionResultType]::ParameterName, 'cmd flag')
+            [CompletionResult]::new('--flg', 'flg', [CompletionResultType]::ParameterName, 'cmd flag')
+            break
+        }
+        })
+    }
+
     $commandElements = $commandAst.CommandElements
+    $lastOption = ''
     $command = @(
         'cmd'
         for ($i = 1; $i -lt $commandElements.Count; $i++) {
             $element = $commandElements[$i]
             if ($element -isnot [StringConstantExpressionAst] -or
                 $element.StringConstantType -ne [StringConstantType]::BareWord -or
-                $element.Value.StartsWith('-') -or
                 $element.Value -eq $wordToComplete) {
                 break
-        }
-        $element.Value
-    }) -join ';'
+            }
+            if ($element.Value.StartsWith('-')) {
+                $lastOption = $element.Value
+                continue
+            }
+            $lastOption = ''
+            $element.Value
+        }) -join ';'
 
-    $completions = @(switch ($command) {
-        'cmd' {
-            [CompletionResult]::new('-o', 'o', [CompletionResultType]::ParameterName, 'cmd option')
-            [CompletionResult]::new('-O', 'O', [CompletionResultType]::ParameterName, 'cmd option')
-            [CompletionResult]::new('--option', 'option', [CompletionResultType]::ParameterName, 'cmd option')
-            [CompletionResult]::new('--opt', 'opt', [CompletionResultType]::ParameterName, 'cmd option')
+    $completions = @()
+    if ($lastOption -ne '') {
+        $completions = @(switch ("$command;$lastOption") {
+        })
+    }
+    if ($completions.Count -eq 0) {
+        $completions = __cmd_complete $command
+    }
+    while ($completions.Count -eq 0 -and $command -match ';') {
+        $command = $command -replace ';[^;]*$', ''
+        $completions = __cmd_complete $command
+    }
+
+    $completions.Where{ $_.CompletionText -like "$wordToComplete*" } |
+        Sort-Object -Property ListItemText
+}
+"#;
+
+#[test]
+fn powershell_with_possible_values() {
+    let mut app = build_app_with_possible_values();
+    common(PowerShell, &mut app, "my_app", POWERSHELL_VALUES);
+}
+
+fn build_app_with_possible_values() -> App<'static> {
+    App::new("my_app")
+        .version("3.0")
+        .about("Tests completions")
+        .arg(
+            Arg::new("speed")
+                .long("speed")
+                .short('s')
+                .takes_value(true)
+                .help("how fast to run")
+                .possible_values([
+                    clap::PossibleValue::new("fast").help("Run fast"),
+                    clap::PossibleValue::new("slow").help("Run slowly"),
+                    clap::PossibleValue::new("ludicrous").hide(true),
+                ]),
+        )
+        .subcommand(
+            App::new("build").about("builds things").arg(
+                Arg::new("profile")
+                    .long("profile")
+                    .takes_value(true)
+                    .possible_values(["debug", "release"]),
+            ),
+        )
+}
+
+static POWERSHELL_VALUES: &str = r#"
+using namespace System.Management.Automation
+using namespace System.Management.Automation.Language
+
+Register-ArgumentCompleter -Native -CommandName 'my_app' -ScriptBlock {
+    param($wordToComplete, $commandAst, $cursorPosition)
+
+    function __my_app_complete([string]$spec) {
+        @(switch ($spec) {
+        'my_app' {
+            [CompletionResult]::new('-s', 's', [CompletionResultType]::ParameterName, 'how fast to run')
+            [CompletionResult]::new('--speed', 'speed', [CompletionResultType]::ParameterName, 'how fast to run')
             [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
             [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
             [CompletionResult]::new('-V', 'V', [CompletionResultType]::ParameterName, 'Print version information')
             [CompletionResult]::new('--version', 'version', [CompletionResultType]::ParameterName, 'Print version information')
-            [CompletionResult]::new('-f', 'f', [CompletionResultType]::ParameterName, 'cmd flag')
-            [CompletionResult]::new('-F', 'F', [CompletionResultType]::ParameterName, 'cmd flag')
-            [CompletionResult]::new('--flag', 'flag', [CompletionResultType]::ParameterName, 'cmd flag')
-            [CompletionResult]::new('--flg', 'flg', [CompletionResultType]::ParameterName, 'cmd flag')
+            [CompletionResult]::new('build', 'build', [CompletionResultType]::ParameterValue, 'builds things')
+            [CompletionResult]::new('help', 'help', [CompletionResultType]::ParameterValue, 'Print this message or the help of the given subcommand(s)')
+            break
+        }
+        'my_app;build' {
+            [CompletionResult]::new('--profile', 'profile', [CompletionResultType]::ParameterName, 'profile')
+            [CompletionResult]::new('-h', 'h', [CompletionResultType]::ParameterName, 'Print help information')
+            [CompletionResult]::new('--help', 'help', [CompletionResultType]::ParameterName, 'Print help information')
+            break
+        }
+        'my_app;help' {
+            break
+        }
+        })
+    }
+
+    $commandElements = $commandAst.CommandElements
+    $lastOption = ''
+    $command = @(
+        'my_app'
+        for ($i = 1; $i -lt $commandElements.Count; $i++) {
+            $element = $commandElements[$i]
+            if ($element -isnot [StringConstantExpressionAst] -or
+                $element.StringConstantType -ne [StringConstantType]::BareWord -or
+                $element.Value -eq $wordToComplete) {
+                break
+            }
+            if ($element.Value.StartsWith('-')) {
+                $lastOption = $element.Value
+                continue
+            }
+            $lastOption = ''
+            $element.Value
+        }) -join ';'
+
+    $completions = @()
+    if ($lastOption -ne '') {
+        $completions = @(switch ("$command;$lastOption") {
+        'my_app;--speed' {
+            [CompletionResult]::new('fast', 'fast', [CompletionResultType]::ParameterValue, 'Run fast')
+            [CompletionResult]::new('slow', 'slow', [CompletionResultType]::ParameterValue, 'Run slowly')
+            break
+        }
+        'my_app;-s' {
+            [CompletionResult]::new('fast', 'fast', [CompletionResultType]::ParameterValue, 'Run fast')
+            [CompletionResult]::new('slow', 'slow', [CompletionResultType]::ParameterValue, 'Run slowly')
+            break
+        }
+        'my_app;build;--profile' {
+            [CompletionResult]::new('debug', 'debug', [CompletionResultType]::ParameterValue, 'debug')
+            [CompletionResult]::new('release', 'release', [CompletionResultType]::ParameterValue, 'release')
             break
         }
-    })
+        })
+    }
+    if ($completions.Count -eq 0) {
+        $completions = __my_app_complete $command
+    }
+    while ($completions.Count -eq 0 -and $command -match ';') {
+        $command = $command -replace ';[^;]*$', ''
+        $completions = __my_app_complete $command
+    }
 
     $completions.Where{ $_.CompletionText -like "$wordToComplete*" } |
         Sort-Object -Property ListItemText
@@ -570,3 +570,67 @@ _my_app_commands() {
 
 _my_app "$@"
 "#;
+
+#[test]
+fn zsh_with_possible_value_help() {
+    let mut app = build_app_with_value_help();
+    common(Zsh, &mut app, "myapp", ZSH_VALUE_HELP);
+}
+
+fn build_app_with_value_help() -> App<'static> {
+    App::new("myapp")
+        .version("3.0")
+        .arg(
+            Arg::new("speed")
+                .long("speed")
+                .takes_value(true)
+                .help("how fast to run")
+                .possible_values([
+                    clap::PossibleValue::new("fast").help("Run fast"),
+                    clap::PossibleValue::new("slow").help("Run slowly"),
+                    clap::PossibleValue::new("ludicrous").hide(true),
+                ]),
+        )
+        .arg(
+            Arg::new("mode")
+                .long("mode")
+                .takes_value(true)
+                .possible_values(["debug", "release"]),
+        )
+}
+
+static ZSH_VALUE_HELP: &str = r#"#compdef myapp
+
+autoload -U is-at-least
+
+_myapp() {
+    typeset -A opt_args
+    typeset -a _arguments_options
+    local ret=1
+
+    if is-at-least 5.2; then
+        _arguments_options=(-s -S -C)
+    else
+        _arguments_options=(-s -C)
+    fi
+
+    local context curcontext="$curcontext" state line
+    _arguments "${_arguments_options[@]}" \
+'--speed=[how fast to run]: :((fast\:"Run fast"
+slow\:"Run slowly"))' \
+'--mode=[]: :(debug release)' \
+'-h[Print help information]' \
+'--help[Print help information]' \
+'-V[Print version information]' \
+'--version[Print version information]' \
+&& ret=0
+}
+
+(( $+functions[_myapp_commands] )) ||
+_myapp_commands() {
+    local commands; commands=()
+    _describe -t commands 'myapp commands' commands "$@"
+}
+
+_myapp "$@"
+"#;
@@ -0,0 +1,82 @@
+use clap::{App, Arg};
+use clap_complete::dynamic::{complete, Completer};
+
+fn build_app() -> App<'static> {
+    App::new("myapp")
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .short('f')
+                .takes_value(true)
+                .possible_values(["json", "yaml", "toml"]),
+        )
+        .arg(Arg::new("verbose").long("verbose"))
+        .subcommand(App::new("push").arg(Arg::new("force").long("force")))
+        .subcommand(App::new("pull"))
+}
+
+#[test]
+fn completes_subcommand_names() {
+    let mut app = build_app();
+    let candidates = complete(&mut app, &["myapp", "pu"], 1);
+    assert_eq!(candidates, ["push", "pull"]);
+
+    let candidates = complete(&mut app, &["myapp", ""], 1);
+    assert_eq!(candidates, ["push", "pull", "help"]);
+}
+
+#[test]
+fn completes_flags() {
+    let mut app = build_app();
+    let candidates = complete(&mut app, &["myapp", "--"], 1);
+    assert_eq!(candidates, ["--help", "--format", "--verbose"]);
+
+    // Flags of the subcommand the line descended into
+    let candidates = complete(&mut app, &["myapp", "push", "--f"], 2);
+    assert_eq!(candidates, ["--force"]);
+}
+
+#[test]
+fn completes_option_values_from_possible_values() {
+    let mut app = build_app();
+    let candidates = complete(&mut app, &["myapp", "--format", ""], 2);
+    assert_eq!(candidates, ["json", "yaml", "toml"]);
+
+    let candidates = complete(&mut app, &["myapp", "-f", "to"], 2);
+    assert_eq!(candidates, ["toml"]);
+
+    // `--opt=val` words are completed whole
+    let candidates = complete(&mut app, &["myapp", "--format=y"], 1);
+    assert_eq!(candidates, ["--format=yaml"]);
+}
+
+#[test]
+fn completes_values_from_hook() {
+    let mut app = build_app();
+    let candidates = Completer::new(&mut app)
+        .value_hook("format", |prefix| {
+            ["msgpack", "json5"]
+                .iter()
+                .filter(|v| v.starts_with(prefix))
+                .map(|v| v.to_string())
+                .collect()
+        })
+        .complete(&["myapp", "--format", "msg"], 2);
+    assert_eq!(candidates, ["msgpack"]);
+}
+
+#[test]
+fn ranker_reorders_candidates() {
+    let mut app = build_app();
+    let candidates = Completer::new(&mut app)
+        .ranker(|candidates: &mut [String]| candidates.sort())
+        .complete(&["myapp", "--format", ""], 2);
+    assert_eq!(candidates, ["json", "toml", "yaml"]);
+}
+
+#[test]
+fn new_empty_word_past_the_end() {
+    let mut app = build_app();
+    let candidates = complete(&mut app, &["myapp", "--format"], 2);
+    assert_eq!(candidates, ["json", "yaml", "toml"]);
+}
@@ -0,0 +1,57 @@
+use clap::App;
+use clap_complete::install::{detect_shell, install, install_dir};
+use clap_complete::Shell;
+
+// Single test because the checks share process-wide environment variables
+#[test]
+fn install_into_conventional_locations() {
+    let tmp = std::env::temp_dir().join(format!("clap-complete-install-{}", std::process::id()));
+    std::fs::create_dir_all(&tmp).unwrap();
+    std::env::set_var("HOME", &tmp);
+    std::env::remove_var("XDG_DATA_HOME");
+    std::env::remove_var("XDG_CONFIG_HOME");
+    std::env::remove_var("ZDOTDIR");
+
+    // Conventional directories derived from the fallback home
+    assert_eq!(
+        install_dir(Shell::Bash).unwrap(),
+        tmp.join(".local/share/bash-completion/completions")
+    );
+    assert_eq!(
+        install_dir(Shell::Fish).unwrap(),
+        tmp.join(".config/fish/completions")
+    );
+    assert_eq!(install_dir(Shell::Zsh).unwrap(), tmp.join(".zfunc"));
+    assert_eq!(install_dir(Shell::PowerShell), None);
+
+    // Explicit overrides win over the home-relative fallback
+    std::env::set_var("XDG_DATA_HOME", tmp.join("xdg"));
+    assert_eq!(
+        install_dir(Shell::Bash).unwrap(),
+        tmp.join("xdg/bash-completion/completions")
+    );
+    std::env::set_var("ZDOTDIR", tmp.join("zdot"));
+    assert_eq!(install_dir(Shell::Zsh).unwrap(), tmp.join("zdot/.zfunc"));
+
+    // Shell detection from $SHELL
+    std::env::set_var("SHELL", "/usr/bin/zsh");
+    assert_eq!(detect_shell(), Some(Shell::Zsh));
+    std::env::set_var("SHELL", "/bin/sh");
+    assert_eq!(detect_shell(), None);
+
+    // End to end: the script lands in the conventional location
+    let mut app = App::new("myapp");
+    let path = install(Shell::Zsh, &mut app, "myapp").unwrap();
+    assert_eq!(path, tmp.join("zdot/.zfunc/_myapp"));
+    let script = std::fs::read_to_string(path).unwrap();
+    assert!(script.contains("#compdef myapp"), "{}", script);
+
+    let err = install(Shell::PowerShell, &mut App::new("myapp"), "myapp").unwrap_err();
+    assert!(
+        err.to_string().contains("no conventional completions directory"),
+        "{}",
+        err
+    );
+
+    std::fs::remove_dir_all(&tmp).unwrap();
+}
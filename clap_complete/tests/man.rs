@@ -0,0 +1,90 @@
+use clap::{App, Arg};
+use clap_complete::man::Man;
+
+fn render(man: Man) -> String {
+    let mut buf = Vec::new();
+    man.render(&mut buf).unwrap();
+    String::from_utf8(buf).unwrap()
+}
+
+fn build_app() -> App<'static> {
+    App::new("myapp")
+        .version("3.0")
+        .author("Kevin K. <kbknapp@gmail.com>")
+        .about("Does things")
+        .long_about("Does things.\nAt length.")
+        .arg(
+            Arg::new("config")
+                .short('c')
+                .long("config")
+                .takes_value(true)
+                .value_name("FILE")
+                .help("Sets a config file"),
+        )
+        .arg(Arg::new("input").required(true).help("The input file"))
+        .subcommand(App::new("test").about("tests things"))
+}
+
+#[test]
+fn renders_standard_sections() {
+    let page = render(Man::new(build_app()));
+
+    assert!(page.starts_with(".TH \"MYAPP\" \"1\""), "{}", page);
+    assert!(page.contains(".SH NAME\nmyapp \\- Does things\n"), "{}", page);
+    assert!(
+        page.contains(".SH SYNOPSIS\n\\fBmyapp\\fR [OPTIONS] <input> [SUBCOMMAND]\n"),
+        "{}",
+        page
+    );
+    assert!(
+        page.contains(".SH DESCRIPTION\nDoes things.\nAt length.\n"),
+        "{}",
+        page
+    );
+    assert!(
+        page.contains(".TP\n\\fB\\-c\\fR, \\fB\\-\\-config\\fR \\fIFILE\\fR\nSets a config file\n"),
+        "{}",
+        page
+    );
+    assert!(
+        page.contains(".SH SUBCOMMANDS\n.TP\nmyapp\\-test(1)\ntests things\n"),
+        "{}",
+        page
+    );
+    assert!(page.contains(".SH VERSION\nv3.0\n"), "{}", page);
+    assert!(
+        page.contains(".SH AUTHORS\nKevin K. <kbknapp@gmail.com>\n"),
+        "{}",
+        page
+    );
+}
+
+#[test]
+fn section_manual_and_date_reach_the_header() {
+    let page = render(
+        Man::new(App::new("myapp").version("3.0"))
+            .section("8")
+            .manual("System Administration")
+            .date("2022-02-02"),
+    );
+
+    assert!(
+        page.starts_with(".TH \"MYAPP\" \"8\" \"2022-02-02\" \"myapp 3.0\" \"System Administration\"\n"),
+        "{}",
+        page
+    );
+}
+
+#[test]
+fn hidden_args_and_subcommands_are_omitted() {
+    let page = render(Man::new(
+        App::new("myapp")
+            .arg(Arg::new("secret").long("secret").hide(true))
+            .subcommand(App::new("internal").hide(true)),
+    ));
+
+    assert!(!page.contains("secret"), "{}", page);
+    assert!(!page.contains("internal"), "{}", page);
+    // The auto-generated help subcommand is still listed
+    assert!(page.contains("myapp\\-help(1)"), "{}", page);
+}
@@ -0,0 +1,161 @@
+use clap::{App, Arg, ValueHint};
+use clap_complete::{generate, spec::Spec};
+use pretty_assertions::assert_eq;
+
+fn build_app() -> App<'static> {
+    App::new("myapp")
+        .version("3.0")
+        .about("Tests completions")
+        .arg(
+            Arg::new("file")
+                .value_hint(ValueHint::FilePath)
+                .help("some input file"),
+        )
+        .arg(
+            Arg::new("config")
+                .short('c')
+                .visible_short_alias('C')
+                .long("config")
+                .visible_alias("conf")
+                .help("some config file"),
+        )
+        .arg(
+            Arg::new("speed")
+                .long("speed")
+                .takes_value(true)
+                .possible_values([
+                    clap::PossibleValue::new("fast").help("Run fast"),
+                    clap::PossibleValue::new("slow").help("Run slowly"),
+                    clap::PossibleValue::new("ludicrous").hide(true),
+                ]),
+        )
+        .subcommand(
+            App::new("test").about("tests things").arg(
+                Arg::new("case")
+                    .long("case")
+                    .takes_value(true)
+                    .help("the case to test"),
+            ),
+        )
+}
+
+fn render(app: &mut App) -> String {
+    let mut buf = Vec::new();
+    generate(Spec, app, "my_app", &mut buf);
+    String::from_utf8(buf).unwrap()
+}
+
+#[test]
+fn dumps_the_full_app_tree() {
+    assert_eq!(render(&mut build_app()), SPEC);
+}
+
+#[test]
+fn escapes_json_strings() {
+    let mut app = App::new("myapp").about("quotes \"and\" a \\ backslash");
+    let spec = render(&mut app);
+
+    assert!(
+        spec.contains(r#""about": "quotes \"and\" a \\ backslash""#),
+        "{}",
+        spec
+    );
+}
+
+static SPEC: &str = r#"{
+  "name": "myapp",
+  "bin_name": "my_app",
+  "version": "3.0",
+  "about": "Tests completions",
+  "flags": [
+    {
+      "name": "help",
+      "short": "h",
+      "long": "help",
+      "help": "Print help information"
+    },
+    {
+      "name": "version",
+      "short": "V",
+      "long": "version",
+      "help": "Print version information"
+    },
+    {
+      "name": "config",
+      "short": "c",
+      "long": "config",
+      "aliases": [
+        "conf"
+      ],
+      "short_aliases": [
+        "C"
+      ],
+      "help": "some config file"
+    }
+  ],
+  "options": [
+    {
+      "name": "speed",
+      "long": "speed",
+      "takes_value": true,
+      "values": [
+        {
+          "name": "fast",
+          "help": "Run fast"
+        },
+        {
+          "name": "slow",
+          "help": "Run slowly"
+        }
+      ]
+    }
+  ],
+  "positionals": [
+    {
+      "name": "file",
+      "help": "some input file",
+      "takes_value": true,
+      "hint": "FilePath"
+    }
+  ],
+  "subcommands": [
+    {
+      "name": "test",
+      "bin_name": "my_app test",
+      "about": "tests things",
+      "flags": [
+        {
+          "name": "help",
+          "short": "h",
+          "long": "help",
+          "help": "Print help information"
+        }
+      ],
+      "options": [
+        {
+          "name": "case",
+          "long": "case",
+          "help": "the case to test",
+          "takes_value": true
+        }
+      ]
+    },
+    {
+      "name": "help",
+      "bin_name": "my_app help",
+      "about": "Print this message or the help of the given subcommand(s)",
+      "positionals": [
+        {
+          "name": "subcommand",
+          "help": "The subcommand whose help message to display",
+          "takes_value": true,
+          "multiple": true,
+          "value_names": [
+            "SUBCOMMAND"
+          ]
+        }
+      ]
+    }
+  ]
+}
+"#;
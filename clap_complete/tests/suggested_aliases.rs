@@ -0,0 +1,37 @@
+use clap::App;
+use clap_complete::aliases;
+
+fn build_app() -> App<'static> {
+    App::new("myapp")
+        .subcommand(App::new("checkout"))
+        .subcommand(App::new("remote").subcommand(App::new("add")))
+        .suggested_abbrev("co", ["checkout"])
+        .suggested_abbrev("ra", ["remote", "add"])
+}
+
+#[test]
+fn fish_abbrs() {
+    let mut buf = Vec::new();
+    aliases::fish_abbrs(&build_app(), "myapp", &mut buf);
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        "abbr -a co 'myapp checkout'\nabbr -a ra 'myapp remote add'\n"
+    );
+}
+
+#[test]
+fn zsh_aliases() {
+    let mut buf = Vec::new();
+    aliases::zsh_aliases(&build_app(), "myapp", &mut buf);
+    assert_eq!(
+        String::from_utf8(buf).unwrap(),
+        "alias co='myapp checkout'\nalias ra='myapp remote add'\n"
+    );
+}
+
+#[test]
+fn no_abbrevs_is_empty() {
+    let mut buf = Vec::new();
+    aliases::fish_abbrs(&App::new("myapp"), "myapp", &mut buf);
+    assert!(buf.is_empty());
+}
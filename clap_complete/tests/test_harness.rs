@@ -0,0 +1,64 @@
+use clap::{App, Arg};
+use clap_complete::{test, Shell};
+
+fn build_app() -> App<'static> {
+    App::new("myapp")
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .takes_value(true)
+                .possible_values(["json", "yaml", "toml"]),
+        )
+        .arg(Arg::new("verbose").long("verbose"))
+        .subcommand(App::new("push").arg(Arg::new("force").long("force")))
+        .subcommand(App::new("pull"))
+}
+
+#[test]
+fn completes_at_end_of_line() {
+    let mut app = build_app();
+    let line = "myapp pu";
+    let candidates = test::complete(Shell::Bash, &mut app, line, line.len());
+    assert_eq!(candidates, ["push", "pull"]);
+}
+
+#[test]
+fn trailing_space_completes_a_new_word() {
+    let mut app = build_app();
+    let line = "myapp ";
+    let candidates = test::complete(Shell::Bash, &mut app, line, line.len());
+    assert_eq!(candidates, ["push", "pull", "help"]);
+}
+
+#[test]
+fn point_truncates_the_line() {
+    let mut app = build_app();
+    let line = "myapp pu --verbose";
+    let candidates = test::complete(Shell::Zsh, &mut app, line, "myapp pu".len());
+    assert_eq!(candidates, ["push", "pull"]);
+}
+
+#[test]
+fn completes_option_values_mid_line() {
+    let mut app = build_app();
+    let line = "myapp --format ya";
+    let candidates = test::complete(Shell::Fish, &mut app, line, line.len());
+    assert_eq!(candidates, ["yaml"]);
+
+    let line = "myapp --format=t";
+    let candidates = test::complete(Shell::Bash, &mut app, line, line.len());
+    assert_eq!(candidates, ["--format=toml"]);
+}
+
+#[test]
+fn quoted_words_are_single_words() {
+    let mut app = App::new("myapp").arg(
+        Arg::new("message")
+            .long("message")
+            .takes_value(true)
+            .possible_values(["hello world", "hello there"]),
+    );
+    let line = "myapp --message 'hello w";
+    let candidates = test::complete(Shell::Bash, &mut app, line, line.len());
+    assert_eq!(candidates, ["hello world"]);
+}
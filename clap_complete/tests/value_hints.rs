@@ -158,3 +158,71 @@ fn fish_with_value_hints() {
     let mut app = build_app_with_value_hints();
     common(Fish, &mut app, "my_app", FISH_VALUE_HINTS);
 }
+
+static ELVISH_VALUE_HINTS: &str = r#"
+use builtin;
+use str;
+
+set edit:completion:arg-completer[my_app] = {|@words|
+    fn spaces {|n|
+        builtin:repeat $n ' ' | str:join ''
+    }
+    fn cand {|text desc|
+        edit:complex-candidate $text &display=$text' '(spaces (- 14 (wcswidth $text)))$desc
+    }
+    var command = 'my_app'
+    for word $words[1..-1] {
+        if (str:has-prefix $word '-') {
+            break
+        }
+        set command = $command';'$word
+    }
+    var completions = [
+        &'my_app'= {
+            var prev = $words[-2]
+            if (eq $prev '--choice') {
+                cand bash 'bash'
+                cand fish 'fish'
+                cand zsh 'zsh'
+            } elif (or (eq $prev '-p') (eq $prev '--path')) {
+                edit:complete-filename $words[-1]
+            } elif (or (eq $prev '-f') (eq $prev '--file')) {
+                edit:complete-filename $words[-1]
+            } elif (or (eq $prev '-d') (eq $prev '--dir')) {
+                edit:complete-filename $words[-1]
+            } elif (or (eq $prev '-e') (eq $prev '--exe')) {
+                edit:complete-filename $words[-1]
+            } else {
+                cand --choice 'choice'
+                cand --unknown 'unknown'
+                cand --other 'other'
+                cand -p 'p'
+                cand --path 'path'
+                cand -f 'f'
+                cand --file 'file'
+                cand -d 'd'
+                cand --dir 'dir'
+                cand -e 'e'
+                cand --exe 'exe'
+                cand --cmd-name 'cmd-name'
+                cand -c 'c'
+                cand --cmd 'cmd'
+                cand -u 'u'
+                cand --user 'user'
+                cand -h 'h'
+                cand --host 'host'
+                cand --url 'url'
+                cand --email 'email'
+                cand --help 'Print help information'
+            }
+        }
+    ]
+    $completions[$command]
+}
+"#;
+
+#[test]
+fn elvish_with_value_hints() {
+    let mut app = build_app_with_value_hints();
+    common(Elvish, &mut app, "my_app", ELVISH_VALUE_HINTS);
+}
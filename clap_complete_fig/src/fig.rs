@@ -48,10 +48,15 @@ fn gen_fig_inner(
     app: &App,
     buffer: &mut String,
 ) {
-    if app.has_subcommands() {
+    let subcommands: Vec<_> = app
+        .get_subcommands()
+        .filter(|subcommand| !subcommand.is_hide_set())
+        .collect();
+
+    if !subcommands.is_empty() {
         buffer.push_str(&format!("{:indent$}subcommands: [\n", "", indent = indent));
         // generate subcommands
-        for subcommand in app.get_subcommands() {
+        for subcommand in subcommands {
             buffer.push_str(&format!(
                 "{:indent$}{{\n{:indent$}  name: \"{}\",\n",
                 "",
@@ -86,7 +91,10 @@ fn gen_fig_inner(
 
     buffer.push_str(&gen_options(app, indent));
 
-    let args = app.get_positionals().collect::<Vec<_>>();
+    let args = app
+        .get_positionals()
+        .filter(|arg| !arg.is_hide_set())
+        .collect::<Vec<_>>();
 
     match args.len() {
         0 => {}
@@ -110,7 +118,7 @@ fn gen_options(app: &App, indent: usize) -> String {
 
     buffer.push_str(&format!("{:indent$}options: [\n", "", indent = indent));
 
-    for option in app.get_opts() {
+    for option in app.get_opts().filter(|option| !option.is_hide_set()) {
         buffer.push_str(&format!("{:indent$}{{\n", "", indent = indent + 2));
 
         let mut names = vec![];
@@ -160,7 +168,10 @@ fn gen_options(app: &App, indent: usize) -> String {
         buffer.push_str(&format!("{:indent$}}},\n", "", indent = indent + 2));
     }
 
-    for flag in generator::utils::flags(app) {
+    for flag in generator::utils::flags(app)
+        .iter()
+        .filter(|flag| !flag.is_hide_set())
+    {
         buffer.push_str(&format!("{:indent$}{{\n", "", indent = indent + 2));
 
         let mut flags = vec![];
@@ -248,7 +259,7 @@ fn gen_args(arg: &Arg, indent: usize) -> String {
             indent = indent + 2
         ));
 
-        for value in data {
+        for value in data.iter().filter(|value| !value.is_hide_set()) {
             buffer.push_str(&format!(
                 "{:indent$}{{\n{:indent$}  name: \"{}\",\n",
                 "",
@@ -272,14 +283,18 @@ fn gen_args(arg: &Arg, indent: usize) -> String {
         buffer.push_str(&format!("{:indent$}]\n", "", indent = indent + 2));
     } else {
         match arg.get_value_hint() {
-            ValueHint::AnyPath | ValueHint::FilePath | ValueHint::ExecutablePath => {
+            ValueHint::AnyPath
+            | ValueHint::FilePath
+            | ValueHint::ExistingFile
+            | ValueHint::CreatableFile
+            | ValueHint::ExecutablePath => {
                 buffer.push_str(&format!(
                     "{:indent$}template: \"filepaths\",\n",
                     "",
                     indent = indent + 2
                 ));
             }
-            ValueHint::DirPath => {
+            ValueHint::DirPath | ValueHint::ExistingDir => {
                 buffer.push_str(&format!(
                     "{:indent$}template: \"folders\",\n",
                     "",
@@ -475,3 +475,80 @@ static FIG_SUB_SUBCMDS: &str = r#"const completion: Fig.Spec = {
 
 export default completion;
 "#;
+
+#[test]
+fn fig_hidden_items_are_filtered() {
+    let mut app = App::new("my_app")
+        .about("Tests hidden filtering")
+        .arg(Arg::new("visible").long("visible").help("visible flag"))
+        .arg(
+            Arg::new("secret")
+                .long("secret")
+                .hide(true)
+                .help("hidden flag"),
+        )
+        .arg(
+            Arg::new("choice")
+                .long("choice")
+                .takes_value(true)
+                .possible_values([
+                    clap::PossibleValue::new("normal"),
+                    clap::PossibleValue::new("secret").hide(true),
+                ]),
+        )
+        .subcommand(App::new("visible-sub").about("a subcommand"))
+        .subcommand(App::new("hidden-sub").hide(true));
+    common(Fig, &mut app, "my_app", FIG_HIDDEN);
+}
+
+static FIG_HIDDEN: &str = r#"const completion: Fig.Spec = {
+  name: "my_app",
+  description: "Tests hidden filtering",
+  subcommands: [
+    {
+      name: "visible-sub",
+      description: "a subcommand",
+      options: [
+        {
+          name: ["-h", "--help"],
+          description: "Print help information",
+        },
+      ],
+    },
+    {
+      name: "help",
+      description: "Print this message or the help of the given subcommand(s)",
+      options: [
+      ],
+      args: {
+        name: "subcommand",
+        isOptional: true,
+      },
+    },
+  ],
+  options: [
+    {
+      name: "--choice",
+      args: {
+        name: "choice",
+        isOptional: true,
+        suggestions: [
+          {
+            name: "normal",
+          },
+        ]
+      },
+    },
+    {
+      name: ["-h", "--help"],
+      description: "Print help information",
+    },
+    {
+      name: "--visible",
+      description: "visible flag",
+    },
+  ],
+};
+
+export default completion;
+"#;
@@ -15,15 +15,16 @@
 use crate::{
     attrs::{Attrs, Kind, Name, ParserKind, DEFAULT_CASING, DEFAULT_ENV_CASING},
     dummies,
+    parse::{parse_clap_attributes, ClapAttr},
     utils::{inner_type, sub_type, Sp, Ty},
 };
 
-use proc_macro2::{Ident, Span, TokenStream};
+use proc_macro2::{Ident, Span, TokenStream, TokenTree};
 use proc_macro_error::{abort, abort_call_site};
 use quote::{format_ident, quote, quote_spanned};
 use syn::{
     punctuated::Punctuated, spanned::Spanned, token::Comma, Attribute, Data, DataStruct,
-    DeriveInput, Field, Fields, Generics, Type,
+    DeriveInput, Expr, ExprLit, Field, Fields, Generics, Lit, LitStr, Type,
 };
 
 pub fn derive_args(input: &DeriveInput) -> TokenStream {
@@ -152,6 +153,9 @@ pub fn gen_augment(
     parent_attribute: &Attrs,
     override_required: bool,
 ) -> TokenStream {
+    if !override_required {
+        check_arg_name_references(fields, parent_attribute);
+    }
     let mut subcmds = fields.iter().filter_map(|field| {
         let attrs = Attrs::from_field(
             field,
@@ -212,22 +216,74 @@ pub fn gen_augment(
             | Kind::FromGlobal(_)
             | Kind::ExternalSubcommand => None,
             Kind::Flatten => {
-                let ty = &field.ty;
+                let ty = Ty::from_syn_ty(&field.ty);
+                let inner_ty = match (*ty, sub_type(&field.ty)) {
+                    (Ty::Option, Some(sub_type)) => sub_type,
+                    _ => &field.ty,
+                };
                 let old_heading_var = format_ident!("__clap_old_heading");
                 let next_help_heading = attrs.next_help_heading();
                 let next_display_order = attrs.next_display_order();
-                if override_required {
+                let augment = if override_required {
+                    quote! {
+                        let #app_var = <#inner_ty as clap::Args>::augment_args_for_update(#app_var);
+                    }
+                } else {
+                    quote! {
+                        let #app_var = <#inner_ty as clap::Args>::augment_args(#app_var);
+                    }
+                };
+                if *ty == Ty::Option {
+                    // Collect the args contributed by the flattened struct into an
+                    // `ArgGroup` so `from_arg_matches` can tell whether any of them
+                    // was given, and so required members only kick in once the
+                    // group is used at all.
+                    let group_name = attrs.cased_name();
+                    let requires = if override_required {
+                        quote!()
+                    } else {
+                        quote! {
+                            __clap_group = __clap_group.requires(__clap_id);
+                        }
+                    };
                     Some(quote_spanned! { kind.span()=>
                         let #old_heading_var = #app_var.get_next_help_heading();
                         let #app_var = #app_var #next_help_heading #next_display_order;
-                        let #app_var = <#ty as clap::Args>::augment_args_for_update(#app_var);
+                        let __clap_prior: ::std::vec::Vec<&str> =
+                            #app_var.get_arguments().map(clap::Arg::get_id).collect();
+                        #augment
+                        let #app_var = {
+                            let __clap_members: ::std::vec::Vec<&str> = #app_var
+                                .get_arguments()
+                                .map(clap::Arg::get_id)
+                                .filter(|__clap_id| !__clap_prior.contains(__clap_id))
+                                .collect();
+                            let __clap_required: ::std::vec::Vec<&str> = #app_var
+                                .get_arguments()
+                                .filter(|__clap_arg| {
+                                    __clap_members.contains(&__clap_arg.get_id())
+                                        && __clap_arg.is_required_set()
+                                })
+                                .map(clap::Arg::get_id)
+                                .collect();
+                            let mut #app_var = #app_var;
+                            let mut __clap_group = clap::ArgGroup::new(#group_name)
+                                .multiple(true)
+                                .args(&__clap_members);
+                            for __clap_id in __clap_required {
+                                #app_var = #app_var
+                                    .mut_arg(__clap_id, |__clap_arg| __clap_arg.required(false));
+                                #requires
+                            }
+                            #app_var.group(__clap_group)
+                        };
                         let #app_var = #app_var.next_help_heading(#old_heading_var);
                     })
                 } else {
                     Some(quote_spanned! { kind.span()=>
                         let #old_heading_var = #app_var.get_next_help_heading();
                         let #app_var = #app_var #next_help_heading #next_display_order;
-                        let #app_var = <#ty as clap::Args>::augment_args(#app_var);
+                        #augment
                         let #app_var = #app_var.next_help_heading(#old_heading_var);
                     })
                 }
@@ -366,6 +422,186 @@ pub fn gen_augment(
     }}
 }
 
+/// Check that arguments referenced by name from relation attributes
+/// (`requires`, `required_if_eq_any`, `conflicts_with_all`, ...) exist in
+/// this struct. Those names are plain strings to clap, so a typo otherwise
+/// only surfaces as a debug assertion when the app is first built; the
+/// derive knows every argument the struct defines and can reject the typo
+/// at expansion time instead.
+///
+/// The check is skipped when the full set of valid names isn't known at
+/// expansion time: when the struct flattens other `Args`, or when an
+/// argument is named by a non-literal expression. Group names mentioned in
+/// top-level or field-level `group` attributes count as known, since
+/// relation attributes may reference groups as well as arguments.
+fn check_arg_name_references(fields: &Punctuated<Field, Comma>, parent_attribute: &Attrs) {
+    let mut known = Vec::new();
+    collect_str_literals(parent_attribute.initial_top_level_methods(), &mut known);
+    collect_str_literals(parent_attribute.final_top_level_methods(), &mut known);
+
+    for field in fields.iter() {
+        let attrs = Attrs::from_field(
+            field,
+            parent_attribute.casing(),
+            parent_attribute.env_casing(),
+        );
+        match &*attrs.kind() {
+            Kind::Flatten => return,
+            Kind::Arg(_) | Kind::FromGlobal(_) => match syn::parse2::<LitStr>(attrs.cased_name()) {
+                Ok(name) => known.push(name.value()),
+                Err(_) => return,
+            },
+            _ => continue,
+        }
+        for attr in parse_clap_attributes(&field.attrs) {
+            if let ClapAttr::MethodCall(name, args) = &attr {
+                if *name == "group" {
+                    known.extend(args.iter().filter_map(|arg| Some(lit_str(arg)?.value())));
+                }
+            }
+        }
+    }
+
+    for field in fields.iter() {
+        for attr in parse_clap_attributes(&field.attrs) {
+            if let ClapAttr::MethodCall(name, args) = &attr {
+                for lit in referenced_arg_names(&name.to_string(), args) {
+                    if !known.iter().any(|known| *known == lit.value()) {
+                        // The usual typo is the raw field name where the
+                        // cased name is needed; suggest the cased one then.
+                        if let Some(cased) = known
+                            .iter()
+                            .find(|known| eq_ignoring_case_style(known, &lit.value()))
+                        {
+                            abort!(
+                                lit,
+                                "`{}` is not the name of an argument in this struct", lit.value();
+                                help = "arguments are referenced by their cased name; \
+                                    did you mean `{}`?", cased
+                            );
+                        }
+                        abort!(
+                            lit,
+                            "`{}` is not the name of an argument in this struct", lit.value();
+                            help = "arguments are referenced by their cased name; \
+                                known names are: {}", known.join(", ")
+                        );
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// The argument names a relation method call references, given where each
+/// method keeps them: a lone name, one element of a `(value, arg)`-style
+/// tuple, or a slice of either. Anything that isn't a string literal in the
+/// expected position is left for rustc to complain about.
+fn referenced_arg_names(method: &str, args: &[Expr]) -> Vec<LitStr> {
+    match method {
+        "requires" | "required_unless_present" | "conflicts_with" | "overrides_with" => {
+            args.iter().filter_map(lit_str).collect()
+        }
+        "required_if_eq" | "default_value_if" | "default_value_if_os" => {
+            args.first().and_then(lit_str).into_iter().collect()
+        }
+        "requires_if" => args.get(1).and_then(lit_str).into_iter().collect(),
+        "requires_all" | "conflicts_with_all" | "required_unless_present_all"
+        | "required_unless_present_any" | "overrides_with_all" => args
+            .first()
+            .map(|list| slice_arg_names(list, None))
+            .unwrap_or_default(),
+        "required_if_eq_any" | "required_if_eq_all" | "default_value_ifs"
+        | "default_value_ifs_os" => args
+            .first()
+            .map(|list| slice_arg_names(list, Some(0)))
+            .unwrap_or_default(),
+        "requires_ifs" => args
+            .first()
+            .map(|list| slice_arg_names(list, Some(1)))
+            .unwrap_or_default(),
+        _ => vec![],
+    }
+}
+
+/// The argument names inside a `&[...]` expression; `tuple_index` picks an
+/// element out of each tuple for the `&[(value, arg)]`-style methods.
+fn slice_arg_names(expr: &Expr, tuple_index: Option<usize>) -> Vec<LitStr> {
+    let expr = match expr {
+        Expr::Reference(reference) => &*reference.expr,
+        _ => expr,
+    };
+    let elems = match expr {
+        Expr::Array(array) => array.elems.iter(),
+        _ => return vec![],
+    };
+    elems
+        .filter_map(|elem| match tuple_index {
+            None => lit_str(elem),
+            Some(index) => match elem {
+                Expr::Tuple(tuple) => tuple.elems.iter().nth(index).and_then(lit_str),
+                _ => None,
+            },
+        })
+        .collect()
+}
+
+/// Whether two names differ only in casing style, e.g. `dry_run` vs
+/// `dry-run` vs `DryRun`.
+fn eq_ignoring_case_style(a: &str, b: &str) -> bool {
+    let normalize = |name: &str| {
+        name.chars()
+            .filter(|c| *c != '-' && *c != '_')
+            .flat_map(char::to_lowercase)
+            .collect::<String>()
+    };
+    normalize(a) == normalize(b)
+}
+
+fn lit_str(expr: &Expr) -> Option<LitStr> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(lit), ..
+        }) => Some(lit.clone()),
+        _ => None,
+    }
+}
+
+/// Every string literal in `tokens`, recursively. Top-level methods carry
+/// arbitrary expressions (e.g. `group(ArgGroup::new("exclusive"))`), so any
+/// name mentioned there is conservatively treated as known rather than
+/// trying to interpret the expression.
+fn collect_str_literals(tokens: TokenStream, out: &mut Vec<String>) {
+    for tree in tokens {
+        match tree {
+            TokenTree::Group(group) => collect_str_literals(group.stream(), out),
+            TokenTree::Literal(literal) => {
+                if let Ok(lit) = syn::parse_str::<LitStr>(&literal.to_string()) {
+                    out.push(lit.value());
+                }
+            }
+            _ => {}
+        }
+    }
+}
+
+/// Reconstruction function given as `#[clap(skip = "path::to::fn")]`.
+///
+/// A plain literal (`skip = "key"`) keeps its value semantics; only a string
+/// that names a path (contains `::`) is taken as a function to call with the
+/// `ArgMatches`. Local functions can be referenced as `"self::func"`.
+fn skip_reconstruct_fn(expr: &Expr) -> Option<syn::Path> {
+    if let Expr::Lit(ExprLit {
+        lit: Lit::Str(lit), ..
+    }) = expr
+    {
+        if lit.value().contains("::") {
+            return lit.parse().ok();
+        }
+    }
+    None
+}
+
 fn gen_arg_enum_possible_values(ty: &Type) -> TokenStream {
     quote_spanned! { ty.span()=>
         .possible_values(<#ty as clap::ArgEnum>::value_variants().iter().filter_map(clap::ArgEnum::to_possible_value))
@@ -415,13 +651,30 @@ pub fn gen_constructor(fields: &Punctuated<Field, Comma>, parent_attribute: &Att
                 }
             }
 
-            Kind::Flatten => quote_spanned! { kind.span()=>
-                #field_name: clap::FromArgMatches::from_arg_matches(#arg_matches)?
+            Kind::Flatten => match (*Ty::from_syn_ty(&field.ty), sub_type(&field.ty)) {
+                (Ty::Option, Some(_)) => {
+                    let group_name = attrs.cased_name();
+                    quote_spanned! { kind.span()=>
+                        #field_name: if #arg_matches.is_present(#group_name) {
+                            Some(clap::FromArgMatches::from_arg_matches(#arg_matches)?)
+                        } else {
+                            None
+                        }
+                    }
+                }
+                _ => quote_spanned! { kind.span()=>
+                    #field_name: clap::FromArgMatches::from_arg_matches(#arg_matches)?
+                },
             },
 
             Kind::Skip(val) => match val {
                 None => quote_spanned!(kind.span()=> #field_name: Default::default()),
-                Some(val) => quote_spanned!(kind.span()=> #field_name: (#val).into()),
+                Some(val) => match skip_reconstruct_fn(val) {
+                    Some(func) => quote_spanned! { kind.span()=>
+                        #field_name: #func(#arg_matches)
+                    },
+                    None => quote_spanned!(kind.span()=> #field_name: (#val).into()),
+                },
             },
 
             Kind::Arg(ty) | Kind::FromGlobal(ty) => {
@@ -477,12 +730,20 @@ pub fn gen_updater(
 
                 let updater = match **ty {
                     Ty::Option => quote_spanned! { kind.span()=>
-                        if let Some(#field_name) = #field_name.as_mut() {
-                            #updater
-                        } else {
-                            *#field_name = Some(<#subcmd_type as clap::FromArgMatches>::from_arg_matches(
-                                #arg_matches
-                            )?);
+                        // Only touch the field when the new command line names one of
+                        // the enum's subcommands; otherwise keep what's there.
+                        if #arg_matches
+                            .subcommand_name()
+                            .map(<#subcmd_type as clap::Subcommand>::has_subcommand)
+                            .unwrap_or(false)
+                        {
+                            if let Some(#field_name) = #field_name.as_mut() {
+                                #updater
+                            } else {
+                                *#field_name = Some(<#subcmd_type as clap::FromArgMatches>::from_arg_matches(
+                                    #arg_matches
+                                )?);
+                            }
                         }
                     },
                     _ => quote_spanned! { kind.span()=>
@@ -498,10 +759,31 @@ pub fn gen_updater(
                 }
             }
 
-            Kind::Flatten => quote_spanned! { kind.span()=> {
-                    #access
-                    clap::FromArgMatches::update_from_arg_matches(#field_name, #arg_matches)?;
+            Kind::Flatten => match (*Ty::from_syn_ty(&field.ty), sub_type(&field.ty)) {
+                (Ty::Option, Some(_)) => {
+                    let group_name = attrs.cased_name();
+                    quote_spanned! { kind.span()=> {
+                            #access
+                            if #arg_matches.is_present(#group_name) {
+                                if let Some(#field_name) = #field_name.as_mut() {
+                                    clap::FromArgMatches::update_from_arg_matches(
+                                        #field_name,
+                                        #arg_matches,
+                                    )?;
+                                } else {
+                                    *#field_name = Some(clap::FromArgMatches::from_arg_matches(
+                                        #arg_matches,
+                                    )?);
+                                }
+                            }
+                        }
+                    }
                 }
+                _ => quote_spanned! { kind.span()=> {
+                        #access
+                        clap::FromArgMatches::update_from_arg_matches(#field_name, #arg_matches)?;
+                    }
+                },
             },
 
             Kind::Skip(_) => quote!(),
@@ -152,13 +152,15 @@ fn gen_augment(
 
                 Kind::ExternalSubcommand => {
                     let ty = match variant.fields {
-                        Unnamed(ref fields) if fields.unnamed.len() == 1 => &fields.unnamed[0].ty,
+                        Unnamed(ref fields) if matches!(fields.unnamed.len(), 1 | 2) => {
+                            &fields.unnamed.last().unwrap().ty
+                        }
 
                         _ => abort!(
                             variant,
                             "The enum variant marked with `external_subcommand` must be \
-                             a single-typed tuple, and the type must be either `Vec<String>` \
-                             or `Vec<OsString>`."
+                             a tuple of `Vec<String>` or `Vec<OsString>`, optionally \
+                             preceded by the subcommand name as `String` or `OsString`."
                         ),
                     };
                     let subcommand = match subty_if_name(ty, "Vec") {
@@ -419,17 +421,36 @@ fn gen_from_arg_matches(
                     );
                 }
 
-                let ty = match variant.fields {
-                    Unnamed(ref fields) if fields.unnamed.len() == 1 => &fields.unnamed[0].ty,
+                let (name_field, ty) = match variant.fields {
+                    Unnamed(ref fields) if fields.unnamed.len() == 1 => {
+                        (None, &fields.unnamed[0].ty)
+                    }
+                    Unnamed(ref fields) if fields.unnamed.len() == 2 => {
+                        (Some(&fields.unnamed[0].ty), &fields.unnamed[1].ty)
+                    }
 
                     _ => abort!(
                         variant,
                         "The enum variant marked with `external_subcommand` must be \
-                         a single-typed tuple, and the type must be either `Vec<String>` \
-                         or `Vec<OsString>`."
+                         a tuple of `Vec<String>` or `Vec<OsString>`, optionally \
+                         preceded by the subcommand name as `String` or `OsString`."
                     ),
                 };
 
+                let name_ty = name_field.map(|ty| {
+                    if is_simple_ty(ty, "String") {
+                        quote!(::std::string::String)
+                    } else if is_simple_ty(ty, "OsString") {
+                        quote!(::std::ffi::OsString)
+                    } else {
+                        abort!(
+                            ty.span(),
+                            "The subcommand name field must be either `String` or \
+                             `OsString` to be used with `external_subcommand`."
+                        );
+                    }
+                });
+
                 let (span, str_ty, values_of) = match subty_if_name(ty, "Vec") {
                     Some(subty) => {
                         if is_simple_ty(subty, "String") {
@@ -460,7 +481,7 @@ fn gen_from_arg_matches(
                     ),
                 };
 
-                ext_subcmd = Some((span, &variant.ident, str_ty, values_of));
+                ext_subcmd = Some((span, &variant.ident, name_ty, str_ty, values_of));
                 None
             } else {
                 Some((variant, attrs))
@@ -510,7 +531,15 @@ fn gen_from_arg_matches(
     });
 
     let wildcard = match ext_subcmd {
-        Some((span, var_name, str_ty, values_of)) => quote_spanned! { span=>
+        Some((span, var_name, Some(name_ty), str_ty, values_of)) => quote_spanned! { span=>
+                ::std::result::Result::Ok(#name::#var_name(
+                    #name_ty::from(#subcommand_name_var),
+                    #sub_arg_matches_var.#values_of("").into_iter().flatten().map(#str_ty::from)
+                        .collect::<::std::vec::Vec<_>>()
+                ))
+        },
+
+        Some((span, var_name, None, str_ty, values_of)) => quote_spanned! { span=>
                 ::std::result::Result::Ok(#name::#var_name(
                     ::std::iter::once(#str_ty::from(#subcommand_name_var))
                     .chain(
@@ -113,6 +113,10 @@ impl<'a> Man<'a> {
             self._render_subcommands_section(&mut roff);
         }
 
+        if app_has_examples(&self.app) {
+            self._render_examples_section(&mut roff);
+        }
+
         if self.app.get_after_long_help().is_some() || self.app.get_after_help().is_some() {
             self._render_extra_section(&mut roff);
         }
@@ -211,6 +215,18 @@ impl<'a> Man<'a> {
         render::subcommands(roff, &self.app, &self.section);
     }
 
+    /// Render the EXAMPLES section into the writer.
+    pub fn render_examples_section(&self, w: &mut dyn Write) -> Result<(), std::io::Error> {
+        let mut roff = Roff::default();
+        self._render_examples_section(&mut roff);
+        roff.to_writer(w)
+    }
+
+    fn _render_examples_section(&self, roff: &mut Roff) {
+        roff.control("SH", ["EXAMPLES"]);
+        render::examples(roff, &self.app);
+    }
+
     /// Render the EXTRA section into the writer.
     pub fn render_extra_section(&self, w: &mut dyn Write) -> Result<(), std::io::Error> {
         let mut roff = Roff::default();
@@ -266,3 +282,8 @@ fn app_has_arguments(app: &clap::App) -> bool {
 fn app_has_subcommands(app: &clap::App) -> bool {
     app.get_subcommands().any(|i| !i.is_hide_set())
 }
+
+// Does the application have any usage examples?
+fn app_has_examples(app: &clap::App) -> bool {
+    app.get_examples().next().is_some()
+}
@@ -167,6 +167,14 @@ pub(crate) fn version(app: &clap::App) -> String {
     )
 }
 
+pub(crate) fn examples(roff: &mut Roff, app: &clap::App) {
+    for (invocation, description) in app.get_examples() {
+        roff.control("TP", []);
+        roff.text([bold(invocation)]);
+        roff.text([roman(description)]);
+    }
+}
+
 pub(crate) fn after_help(roff: &mut Roff, app: &clap::App) {
     if let Some(about) = app.get_after_long_help().or_else(|| app.get_after_help()) {
         for line in about.lines() {
@@ -1,6 +1,8 @@
 #![allow(deprecated)]
 
 // Std
+#[cfg(feature = "yaml")]
+use std::path::PathBuf;
 use std::{
     collections::HashMap,
     env,
@@ -19,12 +21,23 @@ use yaml_rust::Yaml;
 // Internal
 use crate::build::app_settings::{AppFlags, AppSettings};
 use crate::build::arg_settings::ArgSettings;
-use crate::build::{arg::ArgProvider, Arg, ArgGroup, ArgPredicate};
+use crate::build::{
+    arg::ArgProvider, Arg, ArgGroup, ArgPredicate, ConstraintGraph, MergeError,
+    SubcommandValuePolicy, ValueNameCasing,
+};
 use crate::error::ErrorKind;
+use crate::error::FormatterHook;
 use crate::error::Result as ClapResult;
 use crate::mkeymap::MKeyMap;
-use crate::output::{fmt::Colorizer, Help, HelpWriter, Usage};
-use crate::parse::{ArgMatcher, ArgMatches, Input, Parser};
+use crate::output::{
+    fmt::{Colorizer, Theme, WarningWriter},
+    localizer::{Localization, Localizer},
+    Help, HelpWriter, Usage,
+};
+use crate::parse::features::suggestions::{self, Confidence};
+#[cfg(feature = "yaml")]
+use crate::parse::{parse_config, ConfigValue};
+use crate::parse::{ArgMatcher, ArgMatches, Input, Parser, ValueSource};
 use crate::util::{color::ColorChoice, Id, Key};
 use crate::{Error, INTERNAL_ERROR_MSG};
 
@@ -91,12 +104,43 @@ pub struct App<'help> {
     pub(crate) g_settings: AppFlags,
     pub(crate) args: MKeyMap<'help>,
     pub(crate) subcommands: Vec<App<'help>>,
+    pub(crate) lazy_subcommands: Vec<LazySubcommand<'help>>,
+    pub(crate) external_subcommand_prefix: Option<String>,
     pub(crate) replacers: HashMap<&'help str, &'help [&'help str]>,
     pub(crate) groups: Vec<ArgGroup<'help>>,
     pub(crate) current_help_heading: Option<&'help str>,
     pub(crate) current_disp_ord: Option<usize>,
     pub(crate) subcommand_value_name: Option<&'help str>,
     pub(crate) subcommand_heading: Option<&'help str>,
+    pub(crate) promote_common_args: bool,
+    pub(crate) suggested_abbrevs: Vec<(&'help str, Vec<&'help str>)>,
+    pub(crate) subcommand_value_policy: Option<SubcommandValuePolicy>,
+    pub(crate) subcommand_required_unless: Vec<Id>,
+    pub(crate) usage_suppressed_kinds: Vec<ErrorKind>,
+    pub(crate) after_error_help: Vec<(ErrorKind, &'help str)>,
+    pub(crate) val_name_casing: Option<ValueNameCasing>,
+    pub(crate) error_formatter: Option<FormatterHook>,
+    #[cfg(feature = "yaml")]
+    pub(crate) config_file: Option<PathBuf>,
+    #[cfg(feature = "yaml")]
+    pub(crate) config_values: Vec<(String, ConfigValue)>,
+    pub(crate) suggestion_confidence: Option<Confidence>,
+    pub(crate) suggestion_words: Vec<&'help str>,
+    pub(crate) warning_writer: Option<WarningWriter>,
+    #[cfg(feature = "env")]
+    pub(crate) env_prefix: Option<String>,
+    #[cfg(feature = "env")]
+    pub(crate) env_file_arg: Option<&'help str>,
+    pub(crate) theme: Option<Theme>,
+    pub(crate) help_sections: Vec<(&'help str, &'help str)>,
+    pub(crate) version_sections: Vec<(&'help str, &'help str)>,
+    pub(crate) help_all_flag: Option<&'help str>,
+    pub(crate) examples: Vec<(&'help str, &'help str)>,
+    pub(crate) matches_validator: Option<MatchesValidator<'help>>,
+    pub(crate) value_detection: Option<ValueDetection<'help>>,
+    pub(crate) file_subcommand_hint: Option<FileSubcommandHint<'help>>,
+    pub(crate) flag_prefixes: Vec<char>,
+    pub(crate) localizer: Option<Localization>,
 }
 
 /// Basic API
@@ -213,6 +257,111 @@ impl<'help> App<'help> {
         self
     }
 
+    /// Register a suggested shell abbreviation for a subcommand path.
+    ///
+    /// This is metadata only; clap itself doesn't act on it.  Completion tooling (see
+    /// `clap_complete`) can use it to emit shell abbreviation definitions (fish `abbr`,
+    /// zsh `alias`) so users discover short forms for common subcommand paths.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::App;
+    /// let app = App::new("prog")
+    ///     .subcommand(App::new("checkout"))
+    ///     .suggested_abbrev("co", ["checkout"]);
+    /// let abbrevs: Vec<_> = app.get_suggested_abbrevs().collect();
+    /// assert_eq!(abbrevs, [("co", &["checkout"][..])]);
+    /// ```
+    #[must_use]
+    pub fn suggested_abbrev(
+        mut self,
+        abbrev: &'help str,
+        path: impl IntoIterator<Item = &'help str>,
+    ) -> Self {
+        self.suggested_abbrevs
+            .push((abbrev, path.into_iter().collect()));
+        self
+    }
+
+    /// Tune how close a typo has to be before a "Did you mean" hint is offered.
+    ///
+    /// `threshold` is a [Jaro-Winkler] confidence between `0.0` and `1.0`; a candidate
+    /// is only suggested when its similarity to the unknown argument exceeds it.  The
+    /// default is `0.8`.  Raise it to quiet spurious hints for short flag names, lower
+    /// it to suggest more aggressively.  The value propagates to subcommands that
+    /// don't set their own.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let res = App::new("prog")
+    ///     .suggestion_threshold(0.99)
+    ///     .arg(Arg::new("config").long("config"))
+    ///     .try_get_matches_from(["prog", "--confg"]);
+    /// // With such a strict threshold, no "Did you mean" hint is offered.
+    /// assert!(!res.unwrap_err().to_string().contains("Did you mean"));
+    /// ```
+    ///
+    /// [Jaro-Winkler]: https://en.wikipedia.org/wiki/Jaro%E2%80%93Winkler_distance
+    #[must_use]
+    pub fn suggestion_threshold(mut self, threshold: f64) -> Self {
+        self.suggestion_confidence = Some(Confidence(threshold));
+        self
+    }
+
+    /// Register extra candidate words for "Did you mean" hints.
+    ///
+    /// Words are compared against unknown long flags and subcommand-like tokens without
+    /// any leading dashes, so pass bare names (e.g. `"colour"` to suggest `--colour`).
+    /// This lets hints cover hidden aliases or deprecated names that aren't part of the
+    /// visible interface.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let res = App::new("prog")
+    ///     .arg(Arg::new("color").long("color").alias("colour"))
+    ///     .suggestion_words(["colour"])
+    ///     .try_get_matches_from(["prog", "--colourr"]);
+    /// let rendered = res.unwrap_err().to_string();
+    /// assert!(rendered.contains("Did you mean '--colour'?"), "{}", rendered);
+    /// ```
+    #[must_use]
+    pub fn suggestion_words(mut self, words: impl IntoIterator<Item = &'help str>) -> Self {
+        self.suggestion_words.extend(words);
+        self
+    }
+
+    /// Promote args shared by every subcommand to this `App` as [global] args during build.
+    ///
+    /// An argument is promoted when every subcommand defines it identically (same id, flags,
+    /// help, value settings), the parent doesn't define it already, and it is neither
+    /// positional nor required.  The promoted definition is removed from the subcommands and
+    /// re-added here with [`Arg::global(true)`], so CLIs that grew organically don't have to
+    /// repeat `--verbose` in every subcommand by hand.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .promote_common_args(true)
+    ///     .subcommand(App::new("add").arg(Arg::new("verbose").long("verbose")))
+    ///     .subcommand(App::new("remove").arg(Arg::new("verbose").long("verbose")))
+    ///     .get_matches_from(vec!["prog", "--verbose", "add"]);
+    /// assert!(m.is_present("verbose"));
+    /// ```
+    /// [global]: Arg::global()
+    #[inline]
+    #[must_use]
+    pub fn promote_common_args(mut self, yes: bool) -> Self {
+        self.promote_common_args = yes;
+        self
+    }
+
     /// Allows one to mutate an [`Arg`] after it's been added to an [`App`].
     ///
     /// This can be useful for modifying the auto-generated help or version arguments.
@@ -260,6 +409,89 @@ impl<'help> App<'help> {
         self
     }
 
+    /// Allows one to mutate a subcommand after it's been added to an [`App`].
+    ///
+    /// This can be useful for wrappers that receive an already-built `App` (e.g., from
+    /// derive) and need to tweak a nested subcommand without rebuilding the tree.  The
+    /// lookup honors [aliases].  If the subcommand is not found, a new one is created
+    /// with the given name and passed to the closure.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::App;
+    /// let app = App::new("foo")
+    ///     .subcommand(App::new("bar"))
+    ///     .mut_subcommand("bar", |sc| sc.about("Does bar things"));
+    ///
+    /// let sc = app.find_subcommand("bar").unwrap();
+    /// assert_eq!(sc.get_about(), Some("Does bar things"));
+    /// ```
+    /// [aliases]: App::alias()
+    #[must_use]
+    pub fn mut_subcommand<F>(mut self, name: &str, f: F) -> Self
+    where
+        F: FnOnce(App<'help>) -> App<'help>,
+    {
+        let pos = self.subcommands.iter().position(|sc| sc.aliases_to(name));
+
+        let subcmd = if let Some(idx) = pos {
+            self.subcommands.remove(idx)
+        } else {
+            App::new(name)
+        };
+
+        self.subcommands.push(f(subcmd));
+        self
+    }
+
+    /// Allows one to mutate all [`Arg`]s matching a predicate, recursing into subcommands.
+    ///
+    /// Each argument of this `App` and of every subcommand (at any depth) for which
+    /// `predicate` returns `true` is passed through `f`.  This is the bulk counterpart
+    /// of [`App::mut_arg`] for tweaking an already-built tree, e.g. hiding every
+    /// auto-generated `--version` flag at once.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let app = App::new("foo")
+    ///     .arg(Arg::new("verbose").long("verbose"))
+    ///     .subcommand(App::new("bar").arg(Arg::new("verbose").long("verbose")))
+    ///     .mut_args(|a| a.get_name() == "verbose", |a| a.hide(true));
+    ///
+    /// assert!(app.get_arguments().find(|a| a.get_name() == "verbose").unwrap().is_hide_set());
+    /// ```
+    #[must_use]
+    pub fn mut_args<P, F>(mut self, mut predicate: P, mut f: F) -> Self
+    where
+        P: FnMut(&Arg<'help>) -> bool,
+        F: FnMut(Arg<'help>) -> Arg<'help>,
+    {
+        self._mut_args(&mut predicate, &mut f);
+        self
+    }
+
+    fn _mut_args<P, F>(&mut self, predicate: &mut P, f: &mut F)
+    where
+        P: FnMut(&Arg<'help>) -> bool,
+        F: FnMut(Arg<'help>) -> Arg<'help>,
+    {
+        for a in self.args.args_mut() {
+            if predicate(a) {
+                let mut arg = f(std::mem::take(a));
+                if arg.provider == ArgProvider::Generated {
+                    arg.provider = ArgProvider::GeneratedMutated;
+                }
+                *a = arg;
+            }
+        }
+        for sc in &mut self.subcommands {
+            sc._mut_args(predicate, f);
+        }
+    }
+
     /// Adds an [`ArgGroup`] to the application.
     ///
     /// [`ArgGroup`]s are a family of related arguments.
@@ -385,6 +617,167 @@ impl<'help> App<'help> {
         self
     }
 
+    /// Adds a subcommand whose `App` is only constructed when needed.
+    ///
+    /// The closure runs when the subcommand is dispatched to, or when help output,
+    /// suggestions, or completions require the full definition.  For CLIs with
+    /// many subcommands (cargo/kubectl-style plugins) this keeps startup cost
+    /// proportional to the one subcommand actually used.
+    ///
+    /// The `App` returned by the closure must be named `name`.  Lazy subcommands
+    /// are matched by that name only: [aliases], [`App::short_flag`], and
+    /// [`App::long_flag`] of the built `App` are not visible before construction,
+    /// so subcommands relying on those must be added with [`App::subcommand`].
+    /// Likewise, [`App::find_subcommand`] and [`App::get_subcommands`] only see
+    /// lazy subcommands that have already been built.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .subcommand_lazy("config", || {
+    ///         App::new("config")
+    ///             .about("Controls configuration features")
+    ///             .arg(Arg::new("debug").long("debug"))
+    ///     })
+    ///     .get_matches_from(vec!["prog", "config", "--debug"]);
+    ///
+    /// assert_eq!(m.subcommand_name(), Some("config"));
+    /// ```
+    /// [aliases]: App::alias()
+    #[must_use]
+    pub fn subcommand_lazy<S, F>(mut self, name: S, f: F) -> Self
+    where
+        S: Into<String>,
+        F: Fn() -> App<'help> + Send + Sync + 'help,
+    {
+        self.lazy_subcommands.push(LazySubcommand::new(name.into(), f));
+        self
+    }
+
+    /// Resolves unknown subcommands to `<prefix><name>` executables on `PATH`.
+    ///
+    /// This is the plugin model of `cargo` and `git`: `myapp frobnicate` is
+    /// accepted when a `myapp-frobnicate` executable is found on `PATH`, and
+    /// surfaces in the matches exactly like
+    /// [`App::allow_external_subcommands`] (which this implies) — the name plus
+    /// its raw arguments under the `""` id.  Unlike plain
+    /// `allow_external_subcommands`, tokens that resolve to no executable still
+    /// produce an error.  Discovered plugins are also listed in help output
+    /// alongside the declared subcommands.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::App;
+    /// // `myapp frob --level 3` dispatches to `myapp-frob` if it is on PATH
+    /// let m = App::new("myapp")
+    ///     .external_subcommand_prefix("myapp-")
+    ///     .get_matches();
+    ///
+    /// if let Some((external, ext_m)) = m.subcommand() {
+    ///     let args: Vec<_> = ext_m.values_of("").unwrap_or_default().collect();
+    ///     // run `myapp-<external>` with `args`
+    /// }
+    /// ```
+    /// [`App::allow_external_subcommands`]: App::allow_external_subcommands()
+    #[must_use]
+    pub fn external_subcommand_prefix<S: Into<String>>(mut self, prefix: S) -> Self {
+        self.external_subcommand_prefix = Some(prefix.into());
+        self.setting(AppSettings::AllowExternalSubcommands)
+    }
+
+    /// Combine the arguments and subcommands of another `App` fragment into this one.
+    ///
+    /// This supports incrementally assembling a CLI from separately built pieces, e.g.
+    /// workspaces where each crate contributes its own flags.  Unlike adding the pieces
+    /// directly with [`App::arg`] and [`App::subcommand`] — where a duplicate definition
+    /// only surfaces as a debug assertion deep inside the build step — conflicts between
+    /// the fragments (same id, same long, same short, same subcommand name) are reported
+    /// as a [`MergeError`] naming both sides.
+    ///
+    /// The auto-generated `help` and `version` arguments of `other` are not carried over.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, MergeError};
+    /// let base = App::new("prog").arg(Arg::new("verbose").short('v').long("verbose"));
+    /// let extra = App::new("extra").arg(Arg::new("config").long("config"));
+    ///
+    /// let app = base.clone().try_merge(extra).unwrap();
+    ///
+    /// let conflicting = App::new("extra").arg(Arg::new("loud").short('v'));
+    /// assert_eq!(
+    ///     base.try_merge(conflicting),
+    ///     Err(MergeError::DuplicateShort {
+    ///         short: 'v',
+    ///         existing: "verbose".into(),
+    ///         new: "loud".into(),
+    ///     })
+    /// );
+    /// ```
+    pub fn try_merge(mut self, other: App<'help>) -> Result<App<'help>, MergeError> {
+        for arg in other.args.args() {
+            if arg.provider == ArgProvider::Generated {
+                continue;
+            }
+            if self.args.args().any(|a| a.id == arg.id)
+                || self.groups.iter().any(|g| g.id == arg.id)
+            {
+                return Err(MergeError::DuplicateArgId {
+                    id: arg.name.to_owned(),
+                });
+            }
+            if let Some(l) = arg.long {
+                if let Some(existing) = self.args.args().find(|a| a.long == Some(l)) {
+                    return Err(MergeError::DuplicateLong {
+                        long: l.to_owned(),
+                        existing: existing.name.to_owned(),
+                        new: arg.name.to_owned(),
+                    });
+                }
+            }
+            if let Some(s) = arg.short {
+                if let Some(existing) = self.args.args().find(|a| a.short == Some(s)) {
+                    return Err(MergeError::DuplicateShort {
+                        short: s,
+                        existing: existing.name.to_owned(),
+                        new: arg.name.to_owned(),
+                    });
+                }
+            }
+            self.args.push(arg.clone());
+        }
+
+        for group in &other.groups {
+            if self.groups.iter().any(|g| g.id == group.id)
+                || self.args.args().any(|a| a.id == group.id)
+            {
+                return Err(MergeError::DuplicateArgId {
+                    id: group.name.to_owned(),
+                });
+            }
+            self.groups.push(group.clone());
+        }
+
+        for sc in &other.subcommands {
+            if self.subcommands.iter().any(|existing| {
+                existing.name == sc.name
+                    || existing.aliases.iter().any(|(alias, _)| *alias == sc.name)
+                    || sc.aliases.iter().any(|(alias, _)| *alias == existing.name)
+            }) {
+                return Err(MergeError::DuplicateSubcommand {
+                    name: sc.name.clone(),
+                });
+            }
+            self.subcommands.push(sc.clone());
+        }
+
+        Ok(self)
+    }
+
     /// Catch problems earlier in the development cycle.
     ///
     /// Most error states are handled as asserts under the assumption they are programming mistake
@@ -419,6 +812,44 @@ impl<'help> App<'help> {
         self._build_all();
     }
 
+    /// Check the definition for consistency problems and report them, rather
+    /// than panicking like the `debug_assert`-style checks.
+    ///
+    /// This covers the common definition mistakes — duplicate short or long
+    /// flags, reused ids and positional indexes, groups naming undefined
+    /// members, and requirements or conflicts pointing at undefined ids — for
+    /// the whole command tree. Unlike [`App::debug_assert`], it is available
+    /// in release builds, so tests can assert CLI validity regardless of
+    /// profile. An empty result means no problems were found.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let problems = App::new("myprog")
+    ///     .arg(Arg::new("verbose").short('v'))
+    ///     .arg(Arg::new("version").short('v'))
+    ///     .validate();
+    ///
+    /// assert_eq!(problems.len(), 1);
+    /// ```
+    ///
+    /// [`App::debug_assert`]: App::debug_assert()
+    pub fn validate(&mut self) -> Vec<crate::DefinitionProblem> {
+        debug!("App::validate");
+        if !self.settings.is_set(AppSettings::Built) {
+            self._build_self();
+            self.settings.set(AppSettings::Built);
+        }
+        self._materialize_all_lazy_subcommands();
+
+        let mut problems = crate::build::validation::check_app(self);
+        for sc in &mut self.subcommands {
+            problems.extend(sc.validate());
+        }
+        problems
+    }
+
     /// Custom error message for post-parsing validation
     ///
     /// # Examples
@@ -511,6 +942,56 @@ impl<'help> App<'help> {
         self.try_get_matches_from(&mut env::args_os())
     }
 
+    /// Parse [`env::args_os`], layering in the config file, returning a [`clap::Result`]
+    /// on failure.
+    ///
+    /// Like [`App::try_get_matches`], but first reads the file registered with
+    /// [`App::config_file`] and merges its values beneath command-line and env sources.
+    ///
+    /// [`env::args_os`]: std::env::args_os()
+    /// [`clap::Result`]: Result
+    #[cfg(feature = "yaml")]
+    #[inline]
+    pub fn try_get_matches_with_config(self) -> ClapResult<ArgMatches> {
+        self.try_get_matches_from_with_config(&mut env::args_os())
+    }
+
+    /// Parse the specified arguments, layering in the config file, returning a
+    /// [`clap::Result`] on failure.
+    ///
+    /// Like [`App::try_get_matches_from`], but first reads the file registered with
+    /// [`App::config_file`] and merges its values beneath command-line and env sources.
+    ///
+    /// [`clap::Result`]: Result
+    #[cfg(feature = "yaml")]
+    pub fn try_get_matches_from_with_config<I, T>(mut self, itr: I) -> ClapResult<ArgMatches>
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<OsString> + Clone,
+    {
+        self.load_config_file()?;
+        self.try_get_matches_from_mut(itr)
+    }
+
+    #[cfg(feature = "yaml")]
+    fn load_config_file(&mut self) -> ClapResult<()> {
+        if let Some(path) = self.config_file.clone() {
+            let text = std::fs::read_to_string(&path).map_err(|e| {
+                Error::raw(
+                    ErrorKind::Io,
+                    format!("could not read config file `{}`: {}\n", path.display(), e),
+                )
+            })?;
+            self.config_values = parse_config(&text).map_err(|e| {
+                Error::raw(
+                    ErrorKind::Io,
+                    format!("invalid config file `{}`: {}\n", path.display(), e),
+                )
+            })?;
+        }
+        Ok(())
+    }
+
     /// Parse the specified arguments, exiting on failure.
     ///
     /// **NOTE:** The first argument will be parsed as the binary name unless
@@ -587,6 +1068,41 @@ impl<'help> App<'help> {
         self.try_get_matches_from_mut(itr)
     }
 
+    /// Parse a single line, splitting it into words shell-style first,
+    /// returning a [`clap::Result`] on failure.
+    ///
+    /// This is intended for REPLs and interactive shells built on clap, where
+    /// input arrives as one string rather than pre-split arguments.
+    /// Whitespace separates words; single quotes keep their contents
+    /// verbatim; double quotes keep whitespace but allow `\"` and `\\`
+    /// escapes; a backslash outside quotes escapes the next character. An
+    /// unclosed quote or trailing backslash fails with
+    /// [`ErrorKind::InvalidLineSyntax`] and a message pointing at the
+    /// offending column of the line.
+    ///
+    /// **NOTE:** As with [`App::try_get_matches_from`], the first word is
+    /// parsed as the binary name unless [`App::no_binary_name`] is used.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("message").long("message").takes_value(true))
+    ///     .try_get_matches_from_line("prog --message 'hello world'")
+    ///     .unwrap();
+    ///
+    /// assert_eq!(m.value_of("message"), Some("hello world"));
+    /// ```
+    ///
+    /// [`clap::Result`]: Result
+    /// [`ErrorKind::InvalidLineSyntax`]: crate::ErrorKind::InvalidLineSyntax
+    /// [`App::no_binary_name`]: App::no_binary_name()
+    pub fn try_get_matches_from_line(self, line: &str) -> ClapResult<ArgMatches> {
+        let words = crate::parse::split_line(line)?;
+        self.try_get_matches_from(words)
+    }
+
     /// Parse the specified arguments, returning a [`clap::Result`] on failure.
     ///
     /// Like [`App::try_get_matches_from`] but doesn't consume the `App`.
@@ -623,9 +1139,46 @@ impl<'help> App<'help> {
         I: IntoIterator<Item = T>,
         T: Into<OsString> + Clone,
     {
-        let mut it = Input::from(itr.into_iter());
+        let it = Input::from(itr.into_iter());
+        self._parse_input(it)
+    }
+
+    /// Parse from an iterator of arguments without buffering them all upfront.
+    ///
+    /// Unlike [`App::try_get_matches_from`], which clones and collects every item before
+    /// parsing starts, this moves tokens into the parser as parsing advances and keeps
+    /// only a small lookahead window ahead of the cursor, so xargs-style programs
+    /// invoked with tens of thousands of arguments don't pay for a second copy of their
+    /// argv.
+    ///
+    /// **NOTE:** The first argument will be parsed as the binary name unless
+    /// [`App::no_binary_name`] is used.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// use std::ffi::OsString;
+    ///
+    /// let args = ["prog", "one.txt", "two.txt"]
+    ///     .iter()
+    ///     .map(OsString::from);
+    /// let matches = App::new("prog")
+    ///     .arg(Arg::new("input").multiple_values(true))
+    ///     .try_parse_iter(args)
+    ///     .unwrap();
+    /// assert_eq!(matches.values_of("input").unwrap().count(), 2);
+    /// ```
+    pub fn try_parse_iter<I>(&mut self, itr: I) -> ClapResult<ArgMatches>
+    where
+        I: IntoIterator<Item = OsString>,
+        I::IntoIter: 'static,
+    {
+        let it = Input::from_lazy(itr.into_iter());
+        self._parse_input(it)
+    }
 
-        #[cfg(feature = "unstable-multicall")]
+    fn _parse_input(&mut self, mut it: Input) -> ClapResult<ArgMatches> {
         if self.settings.is_set(AppSettings::Multicall) {
             if let Some((argv0, _)) = it.next() {
                 let argv0 = Path::new(&argv0);
@@ -671,34 +1224,97 @@ impl<'help> App<'help> {
         self._do_parse(&mut it)
     }
 
-    /// Prints the short help message (`-h`) to [`io::stdout()`].
+    /// Parse from a native Windows wide (UTF-16) argv, returning a [`clap::Result`] on failure.
     ///
-    /// See also [`App::print_long_help`].
+    /// Like [`App::try_get_matches_from`], but each element is a slice of UTF-16 units as
+    /// obtained from the Windows API, avoiding a lossy UTF-16 → UTF-8 round trip for
+    /// arguments that aren't valid Unicode.
+    ///
+    /// **NOTE:** The first argument will be parsed as the binary name unless
+    /// [`App::no_binary_name`] is used.
     ///
     /// # Examples
     ///
-    /// ```rust
+    /// ```no_run
     /// # use clap::App;
-    /// let mut app = App::new("myprog");
-    /// app.print_help();
+    /// let argv: Vec<Vec<u16>> = vec!["my_prog".encode_utf16().collect()];
+    ///
+    /// let matches = App::new("myprog")
+    ///     // Args and options go here...
+    ///     .try_get_matches_from_wide(&argv)
+    ///     .unwrap_or_else(|e| e.exit());
     /// ```
-    /// [`io::stdout()`]: std::io::stdout()
-    pub fn print_help(&mut self) -> io::Result<()> {
-        self._build();
-        let color = self.get_color();
-
-        let mut c = Colorizer::new(false, color);
-        let parser = Parser::new(self);
-        let usage = Usage::new(parser.app, &parser.required);
-        Help::new(HelpWriter::Buffer(&mut c), parser.app, &usage, false).write_help()?;
-        c.print()
+    /// [`App::try_get_matches_from`]: App::try_get_matches_from()
+    /// [`clap::Result`]: Result
+    #[cfg(windows)]
+    pub fn try_get_matches_from_wide<I, T>(self, itr: I) -> ClapResult<ArgMatches>
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<[u16]>,
+    {
+        use std::os::windows::ffi::OsStringExt;
+        self.try_get_matches_from(itr.into_iter().map(|arg| OsString::from_wide(arg.as_ref())))
     }
 
-    /// Prints the long help message (`--help`) to [`io::stdout()`].
-    ///
-    /// See also [`App::print_help`].
+    /// Parse from a raw Windows wide (UTF-16) command line, returning a [`clap::Result`] on
+    /// failure.
     ///
-    /// # Examples
+    /// The command line is split into arguments with the same rules as
+    /// `CommandLineToArgvW`, so services and GUI applications can feed clap the string
+    /// returned by `GetCommandLineW` directly.
+    ///
+    /// **NOTE:** The first argument will be parsed as the binary name unless
+    /// [`App::no_binary_name`] is used.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::App;
+    /// let cmdline: Vec<u16> = "my_prog some args".encode_utf16().collect();
+    ///
+    /// let matches = App::new("myprog")
+    ///     // Args and options go here...
+    ///     .try_get_matches_from_raw_wide(&cmdline)
+    ///     .unwrap_or_else(|e| e.exit());
+    /// ```
+    /// [`App::try_get_matches_from_wide`]: App::try_get_matches_from_wide()
+    /// [`clap::Result`]: Result
+    #[cfg(windows)]
+    pub fn try_get_matches_from_raw_wide(self, cmdline: &[u16]) -> ClapResult<ArgMatches> {
+        self.try_get_matches_from_wide(crate::util::wide::split_wide(cmdline))
+    }
+
+    /// Prints the short help message (`-h`) to [`io::stdout()`].
+    ///
+    /// See also [`App::print_long_help`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::App;
+    /// let mut app = App::new("myprog");
+    /// app.print_help();
+    /// ```
+    /// [`io::stdout()`]: std::io::stdout()
+    pub fn print_help(&mut self) -> io::Result<()> {
+        self._build();
+        self._materialize_all_lazy_subcommands();
+        let color = self.get_color();
+
+        let mut c = Colorizer::new(false, color)
+            .strip_ansi_on_redirect(self.is_strip_ansi_on_redirect_set())
+            .with_theme(self.theme);
+        let parser = Parser::new(self);
+        let usage = Usage::new(parser.app, &parser.required);
+        Help::new(HelpWriter::Buffer(&mut c), parser.app, &usage, false).write_help()?;
+        c.print()
+    }
+
+    /// Prints the long help message (`--help`) to [`io::stdout()`].
+    ///
+    /// See also [`App::print_help`].
+    ///
+    /// # Examples
     ///
     /// ```rust
     /// # use clap::App;
@@ -711,9 +1327,12 @@ impl<'help> App<'help> {
     /// [`--help` (long)]: Arg::long_help()
     pub fn print_long_help(&mut self) -> io::Result<()> {
         self._build();
+        self._materialize_all_lazy_subcommands();
         let color = self.get_color();
 
-        let mut c = Colorizer::new(false, color);
+        let mut c = Colorizer::new(false, color)
+            .strip_ansi_on_redirect(self.is_strip_ansi_on_redirect_set())
+            .with_theme(self.theme);
         let parser = Parser::new(self);
         let usage = Usage::new(parser.app, &parser.required);
         Help::new(HelpWriter::Buffer(&mut c), parser.app, &usage, true).write_help()?;
@@ -738,6 +1357,7 @@ impl<'help> App<'help> {
     /// [`--help` (long)]: Arg::long_help()
     pub fn write_help<W: Write>(&mut self, w: &mut W) -> io::Result<()> {
         self._build();
+        self._materialize_all_lazy_subcommands();
 
         let parser = Parser::new(self);
         let usage = Usage::new(parser.app, &parser.required);
@@ -763,6 +1383,7 @@ impl<'help> App<'help> {
     /// [`--help` (long)]: Arg::long_help()
     pub fn write_long_help<W: Write>(&mut self, w: &mut W) -> io::Result<()> {
         self._build();
+        self._materialize_all_lazy_subcommands();
 
         let parser = Parser::new(self);
         let usage = Usage::new(parser.app, &parser.required);
@@ -836,6 +1457,172 @@ impl<'help> App<'help> {
         let parser = Parser::new(self);
         Usage::new(parser.app, &parser.required).create_usage_with_title(&[])
     }
+
+    /// Renders the usage statement of the subcommand at `path`, as if the
+    /// user had misused that subcommand directly.
+    ///
+    /// This saves applications from navigating and building the subcommand
+    /// tree themselves when embedding a nested subcommand's usage into their
+    /// own error messages or docs. Returns `None` when `path` doesn't name a
+    /// subcommand; an empty `path` renders this command's own usage.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let mut app = App::new("myprog")
+    ///     .subcommand(
+    ///         App::new("remote")
+    ///             .subcommand(App::new("add").arg(Arg::new("url").required(true))),
+    ///     );
+    ///
+    /// let usage = app.render_usage_for(&["remote", "add"]).unwrap();
+    /// assert!(usage.contains("myprog remote add <url>"));
+    /// assert_eq!(app.render_usage_for(&["remote", "remove"]), None);
+    /// ```
+    pub fn render_usage_for(&mut self, path: &[&str]) -> Option<String> {
+        self._build();
+        // Outside of parsing, nothing has derived a bin name from `argv[0]`;
+        // fall back to the command name so subcommand usage lines still show
+        // the full path.
+        if self.bin_name.is_none() {
+            self.bin_name = Some(self.name.clone());
+        }
+        self._build_bin_names();
+
+        let mut app: &mut Self = self;
+        for name in path {
+            app = app.find_subcommand_mut(*name)?;
+            app._build();
+        }
+
+        let parser = Parser::new(app);
+        Some(Usage::new(parser.app, &parser.required).create_usage_with_title(&[]))
+    }
+
+    /// Renders the short help message (`-h`) to a `String` suitable for
+    /// golden-file tests.
+    ///
+    /// Unlike capturing [`App::write_help`] into a buffer, the output is
+    /// deterministic: the wrapping width is pinned (to [`App::term_width`] if
+    /// set, otherwise 100 columns) instead of following the terminal the test
+    /// happens to run in. With `plain` set, any ANSI escape codes that made it
+    /// into help text or templates are stripped as well.
+    ///
+    /// See also [`App::render_long_help_to_string`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let mut app = App::new("myprog")
+    ///     .arg(Arg::new("config").long("config").help("Sets a config file"));
+    /// let help = app.render_help_to_string(true);
+    /// assert!(help.contains("--config"));
+    /// ```
+    ///
+    /// [`App::term_width`]: App::term_width()
+    pub fn render_help_to_string(&mut self, plain: bool) -> String {
+        self._render_help_to_string(plain, false)
+    }
+
+    /// Renders the long help message (`--help`) to a `String` suitable for
+    /// golden-file tests, in the same deterministic manner as
+    /// [`App::render_help_to_string`].
+    pub fn render_long_help_to_string(&mut self, plain: bool) -> String {
+        self._render_help_to_string(plain, true)
+    }
+
+    fn _render_help_to_string(&mut self, plain: bool, use_long: bool) -> String {
+        // Pin the wrapping width so the output doesn't depend on the
+        // dimensions of whatever terminal renders it.
+        let term_w = self.term_w;
+        if term_w.is_none() {
+            self.term_w = Some(100);
+        }
+        let mut buf = Vec::new();
+        let res = if use_long {
+            self.write_long_help(&mut buf)
+        } else {
+            self.write_help(&mut buf)
+        };
+        self.term_w = term_w;
+        res.expect(INTERNAL_ERROR_MSG);
+
+        let rendered = String::from_utf8(buf).expect(INTERNAL_ERROR_MSG);
+        if plain {
+            strip_ansi(&rendered)
+        } else {
+            rendered
+        }
+    }
+
+    /// Renders the help of this command and every visible subcommand as Markdown.
+    ///
+    /// Each command becomes a section with its about text, usage line, arguments,
+    /// options, and subcommand index, nested one heading level per subcommand
+    /// depth — suitable for websites and GitHub wikis.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::App;
+    /// let mut app = App::new("myprog").about("Does things");
+    /// let markdown = app.render_help_markdown();
+    /// assert!(markdown.starts_with("# myprog"));
+    /// ```
+    pub fn render_help_markdown(&mut self) -> String {
+        self._build();
+
+        crate::output::render_markdown(self)
+    }
+
+    /// Renders the help of this command and every visible subcommand as HTML.
+    ///
+    /// Structured like [`App::render_help_markdown`], with argument lists as
+    /// definition lists; the fragment carries no `<html>`/`<body>` wrapper so it
+    /// can be embedded in an existing page.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::App;
+    /// let mut app = App::new("myprog").about("Does things");
+    /// let html = app.render_help_html();
+    /// assert!(html.starts_with("<h1>myprog</h1>"));
+    /// ```
+    /// [`App::render_help_markdown`]: App::render_help_markdown()
+    pub fn render_help_html(&mut self) -> String {
+        self._build();
+
+        crate::output::render_html(self)
+    }
+
+    /// Resolves this command's requires/conflicts/groups web into a queryable graph.
+    ///
+    /// The graph covers the current command only; call it on each subcommand of
+    /// interest via [`App::find_subcommand_mut`]. Render it with
+    /// [`ConstraintGraph::to_dot`] to visualize large constraint webs with graphviz.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ConstraintKind};
+    /// let mut app = App::new("myprog")
+    ///     .arg(Arg::new("input"))
+    ///     .arg(Arg::new("output").requires("input"));
+    ///
+    /// let graph = app.constraint_graph();
+    /// let edge = graph.edges_from("output").next().unwrap();
+    /// assert_eq!(edge.target().name(), "input");
+    /// assert_eq!(edge.kind(), ConstraintKind::Requires);
+    /// ```
+    /// [`App::find_subcommand_mut`]: App::find_subcommand_mut()
+    pub fn constraint_graph(&mut self) -> ConstraintGraph<'help> {
+        self._build();
+
+        ConstraintGraph::from_app(self)
+    }
 }
 
 /// App-wide Settings
@@ -951,6 +1738,36 @@ impl<'help> App<'help> {
         }
     }
 
+    /// Re-check the output destination when printing and strip color if it is no longer
+    /// a terminal.
+    ///
+    /// By default [`ColorChoice::Always`] emits escape codes unconditionally.  Apps that
+    /// construct errors early but print them after redirecting stdout/stderr (e.g. when
+    /// daemonizing) can opt in to a fresh terminal check at print time, so escape codes
+    /// don't end up in log files.
+    ///
+    /// **NOTE:** This choice is propagated to all child subcommands.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::{App, ColorChoice};
+    /// App::new("myprog")
+    ///     .color(ColorChoice::Always)
+    ///     .strip_ansi_on_redirect(true)
+    ///     .get_matches();
+    /// ```
+    /// [`ColorChoice::Always`]: crate::ColorChoice::Always
+    #[cfg(feature = "color")]
+    #[inline]
+    pub fn strip_ansi_on_redirect(self, yes: bool) -> Self {
+        if yes {
+            self.global_setting(AppSettings::StripAnsiOnRedirect)
+        } else {
+            self.unset_global_setting(AppSettings::StripAnsiOnRedirect)
+        }
+    }
+
     /// Sets when to color output.
     ///
     /// **NOTE:** This choice is propagated to all child subcommands.
@@ -1030,6 +1847,32 @@ impl<'help> App<'help> {
         self
     }
 
+    /// Sets the terminal width from the `COLUMNS` environment variable.
+    ///
+    /// Useful when output is piped but the invoking shell exported its width, where
+    /// terminal detection would otherwise fall back to the default of 100. Does
+    /// nothing if `COLUMNS` is unset or not a number; an explicit
+    /// [`App::term_width`] afterwards still wins.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::App;
+    /// App::new("myprog")
+    ///     .term_width_from_env()
+    /// # ;
+    /// ```
+    #[must_use]
+    pub fn term_width_from_env(mut self) -> Self {
+        if let Some(width) = std::env::var("COLUMNS")
+            .ok()
+            .and_then(|s| s.trim().parse().ok())
+        {
+            self.term_w = Some(width);
+        }
+        self
+    }
+
     /// Disables `-V` and `--version` flag.
     ///
     /// # Examples
@@ -1106,78 +1949,403 @@ impl<'help> App<'help> {
         }
     }
 
-    /// Disables `-h` and `--help` flag.
+    /// Reflow multi-paragraph about text with paragraph-aware wrapping.
+    ///
+    /// See [`AppSettings::SmartWrap`] for the wrapping rules.
+    #[inline]
+    #[must_use]
+    pub fn smart_wrap(self, yes: bool) -> Self {
+        if yes {
+            self.global_setting(AppSettings::SmartWrap)
+        } else {
+            self.unset_global_setting(AppSettings::SmartWrap)
+        }
+    }
+
+    /// List visible subcommand aliases as their own rows in the subcommands section.
+    ///
+    /// By default, visible aliases are appended to their subcommand's row as
+    /// `[aliases: ...]`.  With this setting, each visible alias instead gets its own row
+    /// pointing at the canonical command (`co → checkout`), making short forms easier to
+    /// spot when scanning the list.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::App;
+    /// App::new("myprog")
+    ///     .list_subcommand_aliases(true)
+    ///     .subcommand(App::new("checkout").visible_alias("co"))
+    ///     .get_matches();
+    /// ```
+    #[inline]
+    pub fn list_subcommand_aliases(self, yes: bool) -> Self {
+        if yes {
+            self.setting(AppSettings::ListSubcommandAliases)
+        } else {
+            self.unset_setting(AppSettings::ListSubcommandAliases)
+        }
+    }
+
+    /// Disables `-h` and `--help` flag.
+    ///
+    /// **NOTE:** This choice is propagated to all child subcommands.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, ErrorKind};
+    /// let res = App::new("myprog")
+    ///     .disable_help_flag(true)
+    ///     .try_get_matches_from(vec![
+    ///         "myprog", "-h"
+    ///     ]);
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind(), ErrorKind::UnknownArgument);
+    /// ```
+    #[inline]
+    pub fn disable_help_flag(self, yes: bool) -> Self {
+        if yes {
+            self.global_setting(AppSettings::DisableHelpFlag)
+        } else {
+            self.unset_global_setting(AppSettings::DisableHelpFlag)
+        }
+    }
+
+    /// Disables the `help` [`subcommand`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, ErrorKind};
+    /// let res = App::new("myprog")
+    ///     .disable_help_subcommand(true)
+    ///     // Normally, creating a subcommand causes a `help` subcommand to automatically
+    ///     // be generated as well
+    ///     .subcommand(App::new("test"))
+    ///     .try_get_matches_from(vec![
+    ///         "myprog", "help"
+    ///     ]);
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind(), ErrorKind::UnknownArgument);
+    /// ```
+    ///
+    /// [`subcommand`]: crate::App::subcommand()
+    #[inline]
+    pub fn disable_help_subcommand(self, yes: bool) -> Self {
+        if yes {
+            self.global_setting(AppSettings::DisableHelpSubcommand)
+        } else {
+            self.unset_global_setting(AppSettings::DisableHelpSubcommand)
+        }
+    }
+
+    /// Disables colorized help messages.
+    ///
+    /// **NOTE:** This choice is propagated to all child subcommands.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::App;
+    /// App::new("myprog")
+    ///     .disable_colored_help(true)
+    ///     .get_matches();
+    /// ```
+    #[inline]
+    pub fn disable_colored_help(self, yes: bool) -> Self {
+        if yes {
+            self.global_setting(AppSettings::DisableColoredHelp)
+        } else {
+            self.unset_global_setting(AppSettings::DisableColoredHelp)
+        }
+    }
+
+    /// Omits the usage block from all error messages.
+    ///
+    /// The try-help footer is kept, so users are still pointed at `--help` without
+    /// repeating the full usage wall on every mistake. To suppress usage only for
+    /// selected error kinds, see [`App::disable_usage_for_error`].
+    ///
+    /// **NOTE:** This choice is propagated to all child subcommands.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::App;
+    /// App::new("myprog")
+    ///     .disable_usage_in_errors(true)
+    ///     .get_matches();
+    /// ```
+    #[inline]
+    pub fn disable_usage_in_errors(self, yes: bool) -> Self {
+        if yes {
+            self.global_setting(AppSettings::DisableUsageInErrors)
+        } else {
+            self.unset_global_setting(AppSettings::DisableUsageInErrors)
+        }
+    }
+
+    /// Omits the usage block from errors of the given kind.
+    ///
+    /// Unlike [`App::disable_usage_in_errors`], other error kinds keep their usage
+    /// block. May be called multiple times. The try-help footer is always kept.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let err = App::new("myprog")
+    ///     .disable_usage_for_error(ErrorKind::ValueValidation)
+    ///     .arg(Arg::new("port").validator(|s| s.parse::<u16>().map(|_| ())))
+    ///     .try_get_matches_from(vec!["myprog", "not-a-port"])
+    ///     .unwrap_err();
+    /// assert!(!err.to_string().contains("USAGE:"));
+    /// ```
+    #[must_use]
+    pub fn disable_usage_for_error(mut self, kind: ErrorKind) -> Self {
+        self.usage_suppressed_kinds.push(kind);
+        self
+    }
+
+    /// Appends guidance text to errors of the given kind.
+    ///
+    /// The text is rendered after the usage block of every error of that kind,
+    /// letting specific failures carry targeted hints without inflating the
+    /// message of every error. May be called multiple times, once per kind;
+    /// the first registration for a kind wins.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, ErrorKind};
+    /// let err = App::new("myprog")
+    ///     .after_error_help(
+    ///         ErrorKind::UnknownArgument,
+    ///         "run `myprog plugins list` to see plugin-provided flags",
+    ///     )
+    ///     .try_get_matches_from(vec!["myprog", "--frobnicate"])
+    ///     .unwrap_err();
+    /// assert!(err.to_string().contains("plugins list"));
+    /// ```
+    #[must_use]
+    pub fn after_error_help(mut self, kind: ErrorKind, text: &'help str) -> Self {
+        self.after_error_help.push((kind, text));
+        self
+    }
+
+    /// Installs a custom renderer for parse errors.
+    ///
+    /// The formatter fully controls how the error's kind and context become
+    /// text, replacing the default `error:` prefix, usage block, and
+    /// suggestions; help and version output are unaffected. This choice is
+    /// propagated to all child subcommands.
+    ///
+    /// See [`ErrorFormatter`] for writing one and [`KindFormatter`] for a
+    /// ready-made single-line renderer.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::App;
+    /// use clap::error::KindFormatter;
+    ///
+    /// App::new("myprog")
+    ///     .error_formatter::<KindFormatter>()
+    ///     .get_matches();
+    /// ```
+    /// [`ErrorFormatter`]: crate::error::ErrorFormatter
+    /// [`KindFormatter`]: crate::error::KindFormatter
+    #[must_use]
+    pub fn error_formatter<F: crate::error::ErrorFormatter>(mut self) -> Self {
+        self.error_formatter = Some(FormatterHook(F::format_error));
+        self
+    }
+
+    /// Redirects parser warnings (e.g. [`Arg::deprecated_alias`]) to the given writer.
+    ///
+    /// By default warnings are styled and printed to stderr. Applications that manage
+    /// their own terminal output, or tests, can capture them instead:
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// use std::sync::{Arc, Mutex};
+    ///
+    /// let warnings = Arc::new(Mutex::new(Vec::new()));
+    /// let m = App::new("prog")
+    ///     .warning_writer(warnings.clone())
+    ///     .arg(Arg::new("color")
+    ///         .long("color")
+    ///         .deprecated_alias("colour")
+    ///         .takes_value(true))
+    ///     .get_matches_from(vec!["prog", "--colour", "auto"]);
+    ///
+    /// let warnings = String::from_utf8(warnings.lock().unwrap().clone()).unwrap();
+    /// assert!(warnings.contains("'--colour' is deprecated"));
+    /// ```
+    /// [`Arg::deprecated_alias`]: crate::Arg::deprecated_alias()
+    #[must_use]
+    pub fn warning_writer(
+        mut self,
+        writer: std::sync::Arc<std::sync::Mutex<dyn std::io::Write + Send>>,
+    ) -> Self {
+        self.warning_writer = Some(WarningWriter::new(writer));
+        self
+    }
+
+    /// Sets the [`Localizer`] used to translate clap's built-in strings.
+    ///
+    /// Strings such as the `error:` prefix, the `USAGE:` heading and the
+    /// missing-required-arguments message are looked up through the localizer
+    /// before being rendered, so they can be translated per-locale without
+    /// replacing the formatter. Strings the localizer returns `None` for keep
+    /// their English defaults. It applies to this command and all subcommands
+    /// that don't set their own.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, DefaultText, Localizer};
+    /// use std::borrow::Cow;
+    ///
+    /// struct German;
+    ///
+    /// impl Localizer for German {
+    ///     fn translate(&self, text: DefaultText) -> Option<Cow<'static, str>> {
+    ///         match text {
+    ///             DefaultText::ErrorPrefix => Some("Fehler:".into()),
+    ///             DefaultText::UsageHeading => Some("VERWENDUNG:".into()),
+    ///             _ => None,
+    ///         }
+    ///     }
+    /// }
+    ///
+    /// let err = App::new("prog")
+    ///     .localizer(German)
+    ///     .arg(Arg::new("input").required(true))
+    ///     .try_get_matches_from(vec!["prog"])
+    ///     .unwrap_err();
+    ///
+    /// assert!(err.to_string().contains("Fehler:"));
+    /// assert!(err.to_string().contains("VERWENDUNG:"));
+    /// ```
+    #[must_use]
+    pub fn localizer(mut self, localizer: impl Localizer + Send + Sync + 'static) -> Self {
+        self.localizer = Some(Localization::new(localizer));
+        self
+    }
+
+    /// Sets the [`Theme`] used to color this application's output.
     ///
-    /// **NOTE:** This choice is propagated to all child subcommands.
+    /// A theme customizes or disables the colors applied to error, warning,
+    /// and literal segments as well as help headings, and can supply a callback
+    /// deciding color support instead of terminal detection. It applies to
+    /// this command and all subcommands that don't set their own.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use clap::{App, ErrorKind};
-    /// let res = App::new("myprog")
-    ///     .disable_help_flag(true)
-    ///     .try_get_matches_from(vec![
-    ///         "myprog", "-h"
-    ///     ]);
-    /// assert!(res.is_err());
-    /// assert_eq!(res.unwrap_err().kind(), ErrorKind::UnknownArgument);
+    /// # use clap::{App, Theme, ThemeStyle, ThemeColor};
+    /// let app = App::new("prog")
+    ///     .theme(
+    ///         Theme::default()
+    ///             .error(ThemeStyle::new().color(ThemeColor::Magenta).bold(true))
+    ///             .heading(ThemeStyle::new()),
+    ///     );
     /// ```
-    #[inline]
-    pub fn disable_help_flag(self, yes: bool) -> Self {
-        if yes {
-            self.global_setting(AppSettings::DisableHelpFlag)
-        } else {
-            self.unset_global_setting(AppSettings::DisableHelpFlag)
-        }
+    #[must_use]
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = Some(theme);
+        self
     }
 
-    /// Disables the `help` [`subcommand`].
+    /// Derives an environment variable fallback for every argument from the given prefix.
+    ///
+    /// An argument named `port` falls back to `<PREFIX>_PORT` without needing
+    /// [`Arg::env`] on each argument; hyphens in argument names become underscores.
+    /// The prefix is scoped through subcommands, so `port` on subcommand `serve`
+    /// reads `<PREFIX>_SERVE_PORT`. An explicit [`Arg::env`] always wins over the
+    /// derived name.
     ///
     /// # Examples
     ///
     /// ```rust
-    /// # use clap::{App, ErrorKind};
-    /// let res = App::new("myprog")
-    ///     .disable_help_subcommand(true)
-    ///     // Normally, creating a subcommand causes a `help` subcommand to automatically
-    ///     // be generated as well
-    ///     .subcommand(App::new("test"))
-    ///     .try_get_matches_from(vec![
-    ///         "myprog", "help"
-    ///     ]);
-    /// assert!(res.is_err());
-    /// assert_eq!(res.unwrap_err().kind(), ErrorKind::UnknownArgument);
+    /// # use clap::{App, Arg};
+    /// std::env::set_var("MYAPP_PORT", "8080");
+    /// let m = App::new("myapp")
+    ///     .env_prefix("MYAPP")
+    ///     .arg(Arg::new("port").long("port").takes_value(true))
+    ///     .get_matches_from(vec!["myapp"]);
+    /// assert_eq!(m.value_of("port"), Some("8080"));
     /// ```
     ///
-    /// [`subcommand`]: crate::App::subcommand()
-    #[inline]
-    pub fn disable_help_subcommand(self, yes: bool) -> Self {
-        if yes {
-            self.global_setting(AppSettings::DisableHelpSubcommand)
-        } else {
-            self.unset_global_setting(AppSettings::DisableHelpSubcommand)
-        }
+    /// [`Arg::env`]: crate::Arg::env()
+    #[cfg(feature = "env")]
+    #[must_use]
+    pub fn env_prefix<S: Into<String>>(mut self, prefix: S) -> Self {
+        self.env_prefix = Some(prefix.into());
+        self
     }
 
-    /// Disables colorized help messages.
+    /// Adds an option whose value names a file of `KEY=VAL` environment overrides.
     ///
-    /// **NOTE:** This choice is propagated to all child subcommands.
+    /// The file is read before [`Arg::env`] (and [`App::env_prefix`]) resolution,
+    /// and its pairs take precedence over the process environment; values given
+    /// on the command line still win over both. Blank lines and lines starting
+    /// with `#` are ignored. The option itself is registered for you under the
+    /// given name.
     ///
     /// # Examples
     ///
     /// ```no_run
-    /// # use clap::App;
-    /// App::new("myprog")
-    ///     .disable_colored_help(true)
-    ///     .get_matches();
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myapp")
+    ///     .env_file_arg("env-file")
+    ///     .arg(Arg::new("port").long("port").env("PORT").takes_value(true))
+    ///     .get_matches_from(vec!["myapp", "--env-file", ".env"]);
     /// ```
-    #[inline]
-    pub fn disable_colored_help(self, yes: bool) -> Self {
-        if yes {
-            self.global_setting(AppSettings::DisableColoredHelp)
-        } else {
-            self.unset_global_setting(AppSettings::DisableColoredHelp)
-        }
+    ///
+    /// [`Arg::env`]: crate::Arg::env()
+    #[cfg(feature = "env")]
+    #[must_use]
+    pub fn env_file_arg(mut self, name: &'help str) -> Self {
+        let name = name.trim_start_matches(|c| c == '-');
+        self.env_file_arg = Some(name);
+        self.arg(
+            Arg::new(name)
+                .long(name)
+                .takes_value(true)
+                .value_name("FILE"),
+        )
+    }
+
+    /// Registers a config file whose values layer beneath command-line and env sources.
+    ///
+    /// The file is only read by [`App::try_get_matches_with_config`] and
+    /// [`App::try_get_matches_from_with_config`]; the other `get_matches` entry points
+    /// never touch the filesystem.  The file is parsed as YAML and only flat documents
+    /// are supported: top-level keys matching argument names, mapping to scalars,
+    /// booleans (for flags), or lists of scalars.  Values picked up this way report
+    /// [`ValueSource::ConfigFile`][crate::ValueSource::ConfigFile] from
+    /// [`ArgMatches::value_source`][crate::ArgMatches::value_source].
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myprog")
+    ///     .arg(Arg::new("port").long("port").takes_value(true))
+    ///     .config_file("~/.myprogrc.yaml")
+    ///     .try_get_matches_with_config()
+    ///     .unwrap_or_else(|e| e.exit());
+    /// ```
+    #[cfg(feature = "yaml")]
+    #[must_use]
+    pub fn config_file<P: Into<PathBuf>>(mut self, path: P) -> Self {
+        self.config_file = Some(path.into());
+        self
     }
 
     /// Panic if help descriptions are omitted.
@@ -1685,6 +2853,154 @@ impl<'help> App<'help> {
         self
     }
 
+    /// Registers a custom help section rendered after the generated arguments.
+    ///
+    /// Sections appear in registration order, each as its heading followed by the
+    /// indented text, in the style of the generated `OPTIONS` section. A
+    /// [`App::help_template`] can place a section individually via a tag derived
+    /// from its heading by lowercasing and replacing spaces with dashes:
+    /// `"EXIT CODES"` becomes `{exit-codes}`. The `{sections}` tag renders all
+    /// registered sections at once.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::App;
+    /// App::new("myprog")
+    ///     .help_section("EXAMPLES", "myprog --all\n    Frobs every widget")
+    ///     .help_section("EXIT CODES", "0 on success, 1 on frobbing failure")
+    ///     .get_matches();
+    /// ```
+    #[must_use]
+    pub fn help_section(mut self, heading: &'help str, text: &'help str) -> Self {
+        self.help_sections.push((heading, text));
+        self
+    }
+
+    /// Set a long flag that prints the verbose help tier.
+    ///
+    /// The verbose tier renders long help with every argument visible, including
+    /// ones marked [`Arg::hide_short_help`] or [`Arg::hide_unless_verbose`]. It
+    /// is also reachable without this flag by passing the help flag twice (e.g.
+    /// `--help --help`).
+    ///
+    /// **NOTE:** Any leading `-` characters may be omitted.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::{App, Arg};
+    /// App::new("myprog")
+    ///     .help_all_flag("help-all")
+    ///     .arg(Arg::new("expert-knob")
+    ///         .long("expert-knob")
+    ///         .hide_unless_verbose(true))
+    ///     .get_matches();
+    /// ```
+    ///
+    /// [`Arg::hide_short_help`]: crate::Arg::hide_short_help()
+    /// [`Arg::hide_unless_verbose`]: crate::Arg::hide_unless_verbose()
+    #[must_use]
+    pub fn help_all_flag(mut self, long: &'help str) -> Self {
+        self.help_all_flag = Some(long.trim_start_matches(|c| c == '-'));
+        self
+    }
+
+    /// Add a labelled line of build metadata to the long `--version` output.
+    ///
+    /// Instead of packing the git sha, build date, or enabled features into one
+    /// [`App::long_version`] string, each section renders as its own `label: value`
+    /// line below the usual `name version` line. Sections appear in registration
+    /// order. The short `-V` output stays a single line.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, ErrorKind};
+    /// let err = App::new("myprog")
+    ///     .version("1.0.0")
+    ///     .version_section("commit", "deadbeef")
+    ///     .version_section("built", "2024-05-01")
+    ///     .try_get_matches_from(vec!["myprog", "--version"])
+    ///     .unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::DisplayVersion);
+    /// assert_eq!(
+    ///     err.to_string(),
+    ///     "myprog 1.0.0\ncommit: deadbeef\nbuilt: 2024-05-01\n"
+    /// );
+    /// ```
+    /// [`App::long_version`]: App::long_version()
+    #[must_use]
+    pub fn version_section(mut self, label: &'help str, value: &'help str) -> Self {
+        self.version_sections.push((label, value));
+        self
+    }
+
+    /// Add a usage example rendered in an `EXAMPLES` help section.
+    ///
+    /// Each example is an invocation line paired with a one-line description,
+    /// shown in registration order by `-h`/`--help`, the man page generator, and
+    /// the Markdown/HTML export, so examples live next to the CLI definition
+    /// instead of being hand-maintained in [`App::after_help`] text.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::App;
+    /// App::new("myapp")
+    ///     .example("myapp sub --flag foo", "Does X")
+    ///     .example("myapp --all", "Does everything")
+    ///     .get_matches();
+    /// ```
+    /// [`App::after_help`]: App::after_help()
+    #[must_use]
+    pub fn example(mut self, invocation: &'help str, description: &'help str) -> Self {
+        self.examples.push((invocation, description));
+        self
+    }
+
+    /// Validate the fully parsed [`ArgMatches`] before they are returned.
+    ///
+    /// The closure runs after all per-argument validation has passed, making it
+    /// the place to enforce invariants that span several arguments. Returning an
+    /// error aborts parsing with an [`ErrorKind::ValueValidation`] error carrying
+    /// the closure's message.
+    ///
+    /// **NOTE:** The hook only runs for the command it is registered on; register
+    /// it on each subcommand whose matches need cross-checking.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let res = App::new("prog")
+    ///     .arg(Arg::new("min").long("min").takes_value(true))
+    ///     .arg(Arg::new("max").long("max").takes_value(true))
+    ///     .validator(|m| -> Result<(), String> {
+    ///         let min: u32 = m.value_of_t("min").unwrap_or(0);
+    ///         let max: u32 = m.value_of_t("max").unwrap_or(u32::MAX);
+    ///         if min > max {
+    ///             return Err("--min must not exceed --max".into());
+    ///         }
+    ///         Ok(())
+    ///     })
+    ///     .try_get_matches_from(vec!["prog", "--min", "10", "--max", "3"]);
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind(), ErrorKind::ValueValidation);
+    /// ```
+    /// [`ErrorKind::ValueValidation`]: crate::ErrorKind::ValueValidation
+    #[must_use]
+    pub fn validator<F, E>(mut self, mut f: F) -> Self
+    where
+        F: FnMut(&ArgMatches) -> Result<(), E> + Send + 'help,
+        E: Into<Box<dyn std::error::Error + Send + Sync + 'static>>,
+    {
+        self.matches_validator = Some(MatchesValidator::new(move |m: &ArgMatches| {
+            f(m).map_err(|e| e.into())
+        }));
+        self
+    }
+
     /// Apply a setting for the current command or subcommand.
     ///
     /// See [`App::global_setting`] to apply a setting to this command and all subcommands.
@@ -1806,7 +3122,22 @@ impl<'help> App<'help> {
     /// This will be used for any arg that hasn't had [`Arg::help_heading`] called.
     ///
     /// This is useful if the default `OPTIONS` or `ARGS` headings are
-    /// not specific enough for one's use case.
+    /// not specific enough for one's use case, e.g. organizing a large CLI's
+    /// flags into categories:
+    ///
+    /// ```no_run
+    /// # use clap::{App, Arg};
+    /// App::new("myprog")
+    ///     .next_help_heading(Some("NETWORK OPTIONS"))
+    ///     .arg(Arg::new("proxy").long("proxy").takes_value(true))
+    ///     .arg(Arg::new("retries").long("retries").takes_value(true))
+    ///     .next_help_heading(Some("OUTPUT OPTIONS"))
+    ///     .arg(Arg::new("quiet").long("quiet"))
+    ///     // Back to the default `OPTIONS` heading
+    ///     .next_help_heading(None)
+    ///     .arg(Arg::new("config").long("config").takes_value(true))
+    ///     .get_matches();
+    /// ```
     ///
     /// For subcommands, see [`App::subcommand_help_heading`]
     ///
@@ -2028,6 +3359,102 @@ impl<'help> App<'help> {
         }
     }
 
+    /// Treat leading-hyphen tokens matching the predicate as values instead of flags.
+    ///
+    /// [`App::allow_negative_numbers`] only covers tokens that parse as numbers. Domains
+    /// where values legitimately start with a hyphen but aren't plain numbers — UTC
+    /// offsets like `-05:00` or ranges like `-10..10` — can opt the exact patterns they
+    /// expect into value treatment without accepting every hyphenated token the way
+    /// [`App::allow_hyphen_values`] does. Tokens the predicate rejects still parse as
+    /// flags, so unknown flags keep producing errors.
+    ///
+    /// The predicate receives the full token, including its leading hyphen.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let app = App::new("myprog")
+    ///     .value_detection(|token| token[1..].chars().all(|c| c.is_ascii_digit() || c == ':'))
+    ///     .arg(Arg::new("offset").long("offset").takes_value(true));
+    ///
+    /// let m = app
+    ///     .clone()
+    ///     .try_get_matches_from(vec!["myprog", "--offset", "-05:00"])
+    ///     .unwrap();
+    /// assert_eq!(m.value_of("offset"), Some("-05:00"));
+    ///
+    /// // Tokens the predicate rejects are still treated as flags
+    /// let res = app.try_get_matches_from(vec!["myprog", "--offset", "-bogus"]);
+    /// assert_eq!(res.unwrap_err().kind(), ErrorKind::UnknownArgument);
+    /// ```
+    #[must_use]
+    pub fn value_detection<F>(mut self, predicate: F) -> Self
+    where
+        F: Fn(&str) -> bool + Send + Sync + 'help,
+    {
+        self.value_detection = Some(ValueDetection::new(predicate));
+        self
+    }
+
+    /// Suggest a subcommand when an unrecognized first positional names an existing file.
+    ///
+    /// When a token matches no subcommand but does exist as a file or directory,
+    /// the hook receives its path and can name the subcommand that accepts such
+    /// paths. The [`ErrorKind::InvalidSubcommand`][crate::ErrorKind::InvalidSubcommand]
+    /// error then suggests the full invocation, e.g.
+    /// ``did you mean `myapp run ./script.sh`?``. Returning `None` falls back to
+    /// the usual suggestions.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::App;
+    /// App::new("myapp")
+    ///     .subcommand(App::new("run"))
+    ///     .file_subcommand_hint(|path| {
+    ///         path.extension()
+    ///             .filter(|ext| *ext == "sh")
+    ///             .map(|_| "run".to_string())
+    ///     })
+    ///     .get_matches();
+    /// ```
+    #[must_use]
+    pub fn file_subcommand_hint<F>(mut self, hint: F) -> Self
+    where
+        F: Fn(&std::path::Path) -> Option<String> + Send + Sync + 'help,
+    {
+        self.file_subcommand_hint = Some(FileSubcommandHint::new(hint));
+        self
+    }
+
+    /// Register an additional flag prefix character alongside `-`.
+    ///
+    /// Tokens starting with the prefix are parsed as the argument they name,
+    /// exactly like their `-`/`--` spelling, and
+    /// [`ArgMatches::flag_prefix`][crate::ArgMatches::flag_prefix] reports which
+    /// prefix introduced the last occurrence. Shells and JVM-like launchers use
+    /// this for `+x`/`-x` enable/disable pairs. Tokens that do not name a
+    /// defined argument are left alone. May be called once per prefix.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("sh")
+    ///     .flag_prefix('+')
+    ///     .arg(Arg::new("xtrace").short('x'))
+    ///     .get_matches_from(vec!["sh", "+x"]);
+    /// assert!(m.is_present("xtrace"));
+    /// assert_eq!(m.flag_prefix("xtrace"), Some('+'));
+    /// ```
+    #[must_use]
+    pub fn flag_prefix(mut self, prefix: char) -> Self {
+        debug_assert!(prefix != '-', "'-' is already the standard flag prefix");
+        self.flag_prefixes.push(prefix);
+        self
+    }
+
     /// Specifies that the final positional argument is a "VarArg" and that `clap` should not
     /// attempt to parse any further args.
     ///
@@ -2057,6 +3484,41 @@ impl<'help> App<'help> {
         }
     }
 
+    /// Treat every `--` after the first one as another separator instead of a literal value.
+    ///
+    /// By default (POSIX-style), only the first `--` ends argument parsing; any later `--`
+    /// is captured as an ordinary positional value.  Some ecosystems (e.g. npm-style
+    /// runners) instead drop each `--`, so tools that forward trailing arguments see the
+    /// same behavior at every nesting level.  Enable this to match those ecosystems.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, arg};
+    /// let m = App::new("myprog")
+    ///     .arg(arg!([args] ... "trailing args"))
+    ///     .get_matches_from(vec!["myprog", "--", "a", "--", "b"]);
+    ///
+    /// // By default a second `--` is a literal value
+    /// let trail: Vec<&str> = m.values_of("args").unwrap().collect();
+    /// assert_eq!(trail, ["a", "--", "b"]);
+    ///
+    /// let m = App::new("myprog")
+    ///     .repeated_double_dash_as_separator(true)
+    ///     .arg(arg!([args] ... "trailing args"))
+    ///     .get_matches_from(vec!["myprog", "--", "a", "--", "b"]);
+    ///
+    /// let trail: Vec<&str> = m.values_of("args").unwrap().collect();
+    /// assert_eq!(trail, ["a", "b"]);
+    /// ```
+    pub fn repeated_double_dash_as_separator(self, yes: bool) -> Self {
+        if yes {
+            self.setting(AppSettings::RepeatedDoubleDashAsSeparator)
+        } else {
+            self.unset_setting(AppSettings::RepeatedDoubleDashAsSeparator)
+        }
+    }
+
     /// Allows one to implement two styles of CLIs where positionals can be used out of order.
     ///
     /// The first example is a CLI where the second to last positional argument is optional, but
@@ -2679,6 +4141,37 @@ impl<'help> App<'help> {
         }
     }
 
+    /// Require a [`subcommand`] unless the given argument is present at runtime.
+    ///
+    /// This lets utility flags (`--list`, `--version`-style queries, etc.) be used on
+    /// their own while normal operation still requires a subcommand. Implies
+    /// [`App::subcommand_required`]; may be called multiple times to allow several
+    /// exempting arguments. The resulting [`ErrorKind::MissingSubcommand`] error
+    /// mentions the exempting arguments.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let app = || App::new("myprog")
+    ///     .subcommand_required_unless_present("list")
+    ///     .arg(Arg::new("list").long("list"))
+    ///     .subcommand(App::new("test"));
+    ///
+    /// let m = app().try_get_matches_from(vec!["myprog", "--list"]);
+    /// assert!(m.is_ok());
+    ///
+    /// let err = app().try_get_matches_from(vec!["myprog"]);
+    /// assert_eq!(err.unwrap_err().kind(), ErrorKind::MissingSubcommand);
+    /// ```
+    ///
+    /// [`subcommand`]: crate::App::subcommand()
+    /// [`ErrorKind::MissingSubcommand`]: crate::ErrorKind::MissingSubcommand
+    pub fn subcommand_required_unless_present<T: Key>(mut self, arg_id: T) -> Self {
+        self.subcommand_required_unless.push(Id::from(arg_id));
+        self.setting(AppSettings::SubcommandRequired)
+    }
+
     /// Assume unexpected positional arguments are a [`subcommand`].
     ///
     /// **NOTE:** Use this setting with caution,
@@ -2852,12 +4345,65 @@ impl<'help> App<'help> {
     /// );
     /// assert!(matches.subcommand_matches("sub").is_some());
     /// ```
-    pub fn subcommand_precedence_over_arg(self, yes: bool) -> Self {
-        if yes {
-            self.setting(AppSettings::SubcommandPrecedenceOverArg)
-        } else {
-            self.unset_setting(AppSettings::SubcommandPrecedenceOverArg)
-        }
+    pub fn subcommand_precedence_over_arg(self, yes: bool) -> Self {
+        if yes {
+            self.setting(AppSettings::SubcommandPrecedenceOverArg)
+        } else {
+            self.unset_setting(AppSettings::SubcommandPrecedenceOverArg)
+        }
+    }
+
+    /// How to resolve a token that matches both a subcommand name and a positional's
+    /// possible value.
+    ///
+    /// When a positional argument restricts its input with [`Arg::possible_values`] and one
+    /// of those values is also the name (or alias) of a subcommand, a matching token on the
+    /// command line is ambiguous.  By default clap dispatches to the subcommand; in debug
+    /// builds such a grammar panics until a policy is chosen explicitly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, SubcommandValuePolicy};
+    /// let m = App::new("prog")
+    ///     .subcommand_value_policy(SubcommandValuePolicy::PreferPositional)
+    ///     .subcommand(App::new("list"))
+    ///     .arg(Arg::new("mode").possible_values(["list", "tree"]))
+    ///     .get_matches_from(vec!["prog", "list"]);
+    ///
+    /// assert_eq!(m.value_of("mode"), Some("list"));
+    /// assert!(m.subcommand_name().is_none());
+    /// ```
+    /// [`Arg::possible_values`]: crate::Arg::possible_values
+    #[must_use]
+    pub fn subcommand_value_policy(mut self, policy: SubcommandValuePolicy) -> Self {
+        self.subcommand_value_policy = Some(policy);
+        self
+    }
+
+    /// How value names are derived from argument ids when [`Arg::value_name`] isn't set.
+    ///
+    /// The policy is applied consistently across help, usage, and error messages, and is
+    /// propagated to all child subcommands. Explicitly set value names are never
+    /// transformed. Use [`ValueNameCasing::Custom`] to plug in your own transformation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ValueNameCasing};
+    /// let mut app = App::new("myprog")
+    ///     .value_name_casing(ValueNameCasing::ScreamingSnake)
+    ///     .arg(Arg::new("out-file").long("out-file").takes_value(true).required(true));
+    ///
+    /// let usage = app.render_usage();
+    /// assert!(usage.contains("--out-file <OUT_FILE>"), "{}", usage);
+    /// ```
+    ///
+    /// [`Arg::value_name`]: crate::Arg::value_name()
+    #[must_use]
+    pub fn value_name_casing(mut self, casing: ValueNameCasing) -> Self {
+        self.val_name_casing = Some(casing);
+        self
     }
 
     /// Allows [`subcommands`] to override all requirements of the parent command.
@@ -3007,7 +4553,6 @@ impl<'help> App<'help> {
     /// [`App::subcommand_value_name`]: crate::App::subcommand_value_name
     /// [`App::subcommand_help_heading`]: crate::App::subcommand_help_heading
     #[inline]
-    #[cfg(feature = "unstable-multicall")]
     pub fn multicall(self, yes: bool) -> Self {
         if yes {
             self.setting(AppSettings::Multicall)
@@ -3212,6 +4757,15 @@ impl<'help> App<'help> {
         self.about
     }
 
+    /// Iterate through the suggested abbreviations registered via [`App::suggested_abbrev`].
+    ///
+    /// [`App::suggested_abbrev`]: App::suggested_abbrev()
+    pub fn get_suggested_abbrevs(&self) -> impl Iterator<Item = (&'help str, &[&'help str])> + '_ {
+        self.suggested_abbrevs
+            .iter()
+            .map(|(abbrev, path)| (*abbrev, path.as_slice()))
+    }
+
     /// Get the help message specified via [`App::long_about`].
     ///
     /// [`App::long_about`]: App::long_about()
@@ -3235,6 +4789,14 @@ impl<'help> App<'help> {
         self.current_help_heading
     }
 
+    /// Get the policy specified via [`App::subcommand_value_policy`], if any.
+    ///
+    /// [`App::subcommand_value_policy`]: App::subcommand_value_policy()
+    #[inline]
+    pub fn get_subcommand_value_policy(&self) -> Option<SubcommandValuePolicy> {
+        self.subcommand_value_policy
+    }
+
     /// Iterate through the *visible* aliases for this subcommand.
     #[inline]
     pub fn get_visible_aliases(&self) -> impl Iterator<Item = &'help str> + '_ {
@@ -3310,6 +4872,12 @@ impl<'help> App<'help> {
         }
     }
 
+    /// The [`Theme`] set via [`App::theme`], if any.
+    #[inline]
+    pub fn get_theme(&self) -> Option<&Theme> {
+        self.theme.as_ref()
+    }
+
     /// Iterate through the set of subcommands, getting a reference to each.
     #[inline]
     pub fn get_subcommands(&self) -> impl Iterator<Item = &App<'help>> {
@@ -3325,7 +4893,7 @@ impl<'help> App<'help> {
     /// Returns `true` if this `App` has subcommands.
     #[inline]
     pub fn has_subcommands(&self) -> bool {
-        !self.subcommands.is_empty()
+        !self.subcommands.is_empty() || !self.lazy_subcommands.is_empty()
     }
 
     /// Returns the help heading for listing subcommands.
@@ -3340,6 +4908,12 @@ impl<'help> App<'help> {
         self.subcommand_value_name
     }
 
+    /// Returns the usage examples registered via [`App::example`].
+    #[inline]
+    pub fn get_examples(&self) -> impl Iterator<Item = (&'help str, &'help str)> + '_ {
+        self.examples.iter().copied()
+    }
+
     /// Returns the help heading for listing subcommands.
     #[inline]
     pub fn get_after_help(&self) -> Option<&str> {
@@ -3393,6 +4967,112 @@ impl<'help> App<'help> {
             .filter(|a| a.is_takes_value_set() && !a.is_positional())
     }
 
+    /// Reconstruct a normalized argv vector from parsed matches.
+    ///
+    /// Arguments are re-serialized in definition order using each argument's
+    /// long form where one exists, with option values as separate tokens and
+    /// positionals last (behind a `--` separator when one of their values
+    /// could be mistaken for a flag), followed by the subcommand chain. Set
+    /// `skip_defaults` to leave out values that did not come from the command
+    /// line, such as defaults and environment variables. The program name is
+    /// not included. Useful for "print the effective command" features and
+    /// for re-execing across process boundaries.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let mut app = App::new("prog")
+    ///     .arg(Arg::new("port").short('p').long("port").takes_value(true))
+    ///     .arg(Arg::new("verbose").short('v'));
+    /// let m = app.clone().get_matches_from(vec!["prog", "-v", "-p", "8080"]);
+    /// assert_eq!(app.to_cmdline(&m, false), ["--port", "8080", "-v"]);
+    /// ```
+    pub fn to_cmdline(&mut self, matches: &ArgMatches, skip_defaults: bool) -> Vec<String> {
+        self._build();
+        let lossy = |val: &std::ffi::OsString| val.to_string_lossy().into_owned();
+        let mut argv = Vec::new();
+        for arg in self.args.args().filter(|a| !a.is_positional()) {
+            let ma = match matches.args.get(&arg.id) {
+                Some(ma) => ma,
+                None => continue,
+            };
+            if skip_defaults && ma.source() != Some(ValueSource::CommandLine) {
+                continue;
+            }
+            let spelling = match (arg.long, arg.short) {
+                (Some(long), _) if ma.is_negated() => format!("--no-{}", long),
+                (Some(long), _) => format!("--{}", long),
+                (None, Some(short)) => format!("-{}", short),
+                (None, None) => continue,
+            };
+            if arg.is_takes_value_set() {
+                for occurrence in ma.vals() {
+                    argv.push(spelling.clone());
+                    argv.extend(occurrence.iter().map(lossy));
+                }
+            } else {
+                // Flags pulled in from the environment have no occurrences,
+                // but still need to be spelled out once.
+                for _ in 0..std::cmp::max(ma.get_occurrences(), 1) {
+                    argv.push(spelling.clone());
+                }
+            }
+        }
+        let mut positionals: Vec<_> = self.get_positionals().collect();
+        positionals.sort_by_key(|a| a.index.unwrap_or(0));
+        let mut pos_vals = Vec::new();
+        for arg in positionals {
+            let ma = match matches.args.get(&arg.id) {
+                Some(ma) => ma,
+                None => continue,
+            };
+            if skip_defaults && ma.source() != Some(ValueSource::CommandLine) {
+                continue;
+            }
+            pos_vals.extend(ma.vals_flatten().map(lossy));
+        }
+        if pos_vals.iter().any(|val| val.starts_with('-')) {
+            argv.push("--".to_owned());
+        }
+        argv.append(&mut pos_vals);
+        if let Some(ref sc) = matches.subcommand {
+            argv.push(sc.name.clone());
+            if let Some(sub) = self.find_subcommand_mut(&sc.name) {
+                argv.extend(sub.to_cmdline(&sc.matches, skip_defaults));
+            } else if let Some(external) = sc.matches.args.get(&Id::empty_hash()) {
+                // External subcommands keep their raw arguments under the
+                // empty id.
+                argv.extend(external.vals_flatten().map(lossy));
+            }
+        }
+        argv
+    }
+
+    /// Reconstruct a single shell-quoted command line from parsed matches.
+    ///
+    /// The tokens from [`App::to_cmdline`] joined with spaces, single-quoting
+    /// any token a shell would otherwise split or expand.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let mut app = App::new("prog")
+    ///     .arg(Arg::new("message").long("message").takes_value(true));
+    /// let m = app
+    ///     .clone()
+    ///     .get_matches_from(vec!["prog", "--message", "hello world"]);
+    /// assert_eq!(app.to_cmdline_string(&m, false), "--message 'hello world'");
+    /// ```
+    pub fn to_cmdline_string(&mut self, matches: &ArgMatches, skip_defaults: bool) -> String {
+        self.to_cmdline(matches, skip_defaults)
+            .iter()
+            .map(|token| shell_quote(token))
+            .collect::<Vec<_>>()
+            .join(" ")
+    }
+
     /// Get a list of all arguments the given argument conflicts with.
     ///
     /// If the provided argument is declared as global, the conflicts will be determined
@@ -3504,6 +5184,11 @@ impl<'help> App<'help> {
         self.is_set(AppSettings::NextLineHelp)
     }
 
+    /// Report whether [`App::smart_wrap`] is set
+    pub fn is_smart_wrap_set(&self) -> bool {
+        self.is_set(AppSettings::SmartWrap)
+    }
+
     /// Report whether [`App::disable_help_flag`] is set
     pub fn is_disable_help_flag_set(&self) -> bool {
         self.is_set(AppSettings::DisableHelpFlag)
@@ -3560,6 +5245,100 @@ impl<'help> App<'help> {
         self.is_set(AppSettings::TrailingVarArg)
     }
 
+    /// Report whether [`App::repeated_double_dash_as_separator`] is set
+    pub fn is_repeated_double_dash_as_separator_set(&self) -> bool {
+        self.is_set(AppSettings::RepeatedDoubleDashAsSeparator)
+    }
+
+    /// Report whether [`App::list_subcommand_aliases`] is set
+    pub fn is_list_subcommand_aliases_set(&self) -> bool {
+        self.is_set(AppSettings::ListSubcommandAliases)
+    }
+
+    /// Report whether [`App::strip_ansi_on_redirect`] is set
+    pub fn is_strip_ansi_on_redirect_set(&self) -> bool {
+        self.is_set(AppSettings::StripAnsiOnRedirect)
+    }
+
+    /// Report whether [`App::disable_usage_in_errors`] is set
+    pub fn is_disable_usage_in_errors_set(&self) -> bool {
+        self.is_set(AppSettings::DisableUsageInErrors)
+    }
+
+    /// Report whether [`AppSettings::CollectAllErrors`] is set
+    pub fn is_collect_all_errors_set(&self) -> bool {
+        self.is_set(AppSettings::CollectAllErrors)
+    }
+
+    /// Report whether [`AppSettings::AllowResponseFiles`] is set
+    pub fn is_allow_response_files_set(&self) -> bool {
+        self.is_set(AppSettings::AllowResponseFiles)
+    }
+
+    /// Report whether [`AppSettings::PromptMissing`] is set
+    pub fn is_prompt_missing_set(&self) -> bool {
+        self.is_set(AppSettings::PromptMissing)
+    }
+
+    /// Report whether [`AppSettings::StopAtUnknown`] is set
+    pub fn is_stop_at_unknown_set(&self) -> bool {
+        self.is_set(AppSettings::StopAtUnknown)
+    }
+
+    /// Report whether [`AppSettings::AutoCorrectSubcommands`] is set
+    pub fn is_auto_correct_subcommands_set(&self) -> bool {
+        self.is_set(AppSettings::AutoCorrectSubcommands)
+    }
+
+    /// Report whether [`AppSettings::NoAutoCorrect`] is set
+    pub fn is_no_auto_correct_set(&self) -> bool {
+        self.is_set(AppSettings::NoAutoCorrect)
+    }
+
+    /// Report whether [`AppSettings::AllowSlashFlags`] is set
+    pub fn is_allow_slash_flags_set(&self) -> bool {
+        self.is_set(AppSettings::AllowSlashFlags)
+    }
+
+    /// Report whether [`AppSettings::EchoCommandLine`] is set
+    pub fn is_echo_command_line_set(&self) -> bool {
+        self.is_set(AppSettings::EchoCommandLine)
+    }
+
+    /// Report whether [`AppSettings::AllowUnclaimedTrailingArgs`] is set
+    pub fn is_allow_unclaimed_trailing_args_set(&self) -> bool {
+        self.is_set(AppSettings::AllowUnclaimedTrailingArgs)
+    }
+
+    /// Whether the [`App::value_detection`] predicate claims this leading-hyphen token.
+    pub(crate) fn token_looks_like_value(&self, token: &str) -> bool {
+        token.starts_with('-')
+            && self
+                .value_detection
+                .as_ref()
+                .map_or(false, |detection| detection.is_value(token))
+    }
+
+    /// The effective "Did you mean" confidence threshold.
+    pub(crate) fn get_suggestion_confidence(&self) -> f64 {
+        self.suggestion_confidence
+            .map(|c| c.0)
+            .unwrap_or(suggestions::DEFAULT_CONFIDENCE)
+    }
+
+    /// Should errors of the given kind omit their usage block?
+    pub(crate) fn is_usage_suppressed_for(&self, kind: ErrorKind) -> bool {
+        self.is_disable_usage_in_errors_set() || self.usage_suppressed_kinds.contains(&kind)
+    }
+
+    /// The guidance text registered via [`App::after_error_help`] for this kind, if any.
+    pub(crate) fn after_error_help_for(&self, kind: ErrorKind) -> Option<&'help str> {
+        self.after_error_help
+            .iter()
+            .find(|(k, _)| *k == kind)
+            .map(|(_, text)| *text)
+    }
+
     /// Report whether [`App::allow_missing_positional`] is set
     pub fn is_allow_missing_positional_set(&self) -> bool {
         self.is_set(AppSettings::AllowMissingPositional)
@@ -3601,7 +5380,6 @@ impl<'help> App<'help> {
     }
 
     /// Report whether [`App::multicall`] is set
-    #[cfg(feature = "unstable-multicall")]
     pub fn is_multicall_set(&self) -> bool {
         self.is_set(AppSettings::Multicall)
     }
@@ -3901,6 +5679,8 @@ impl<'help> App<'help> {
         if let Err(error) = parser.get_matches_with(&mut matcher, it) {
             if self.is_set(AppSettings::IgnoreErrors) {
                 debug!("App::_do_parse: ignoring error: {}", error);
+            } else if self.is_set(AppSettings::EchoCommandLine) {
+                return Err(error.with_command_line(it.items()));
             } else {
                 return Err(error);
             }
@@ -3929,10 +5709,28 @@ impl<'help> App<'help> {
     pub fn _build(&mut self) {
         debug!("App::_build");
         if !self.settings.is_set(AppSettings::Built) {
+            self._build_self();
+
+            #[cfg(debug_assertions)]
+            assert_app(self);
+            self.settings.set(AppSettings::Built);
+        } else {
+            debug!("App::_build: already built");
+        }
+    }
+
+    // The bulk of `_build`, separate so `App::validate` can prepare the app
+    // without tripping the debug assertions on the problems it is there to
+    // report.
+    fn _build_self(&mut self) {
+        {
             // Make sure all the globally set flags apply to us as well
             self.settings = self.settings | self.g_settings;
 
             self._propagate();
+            if self.promote_common_args {
+                self._promote_common_args();
+            }
             self._check_help_and_version();
             self._propagate_global_args();
             self._derive_display_order();
@@ -3965,6 +5763,9 @@ impl<'help> App<'help> {
                     let self_id = a.id.clone();
                     a.overrides.push(self_id);
                 }
+                if a.val_name_casing.is_none() {
+                    a.val_name_casing = self.val_name_casing;
+                }
                 a._build();
                 if a.is_positional() && a.index.is_none() {
                     a.index = Some(pos_counter);
@@ -3973,12 +5774,48 @@ impl<'help> App<'help> {
             }
 
             self.args._build();
+        }
+    }
 
-            #[cfg(debug_assertions)]
-            assert_app(self);
-            self.settings.set(AppSettings::Built);
-        } else {
-            debug!("App::_build: already built");
+    fn _promote_common_args(&mut self) {
+        debug!("App::_promote_common_args");
+        if self.subcommands.len() < 2 {
+            return;
+        }
+
+        let candidates: Vec<Id> = self.subcommands[0]
+            .args
+            .args()
+            .filter(|a| {
+                a.provider == ArgProvider::User && !a.is_positional() && !a.is_required_set()
+            })
+            .map(|a| a.id.clone())
+            .collect();
+
+        for id in candidates {
+            if self.find(&id).is_some() {
+                continue;
+            }
+            let proto = self.subcommands[0]
+                .args
+                .args()
+                .find(|a| a.id == id)
+                .expect(INTERNAL_ERROR_MSG)
+                .clone();
+            let shared_by_all = self.subcommands.iter().all(|sc| {
+                sc.args
+                    .args()
+                    .find(|a| a.id == id)
+                    .map_or(false, |a| a.same_definition_as(&proto))
+            });
+            if !shared_by_all {
+                continue;
+            }
+            debug!("App::_promote_common_args: promoting {:?}", id);
+            for sc in &mut self.subcommands {
+                sc.args.remove_by_name(&id);
+            }
+            self.args.push(proto.global(true));
         }
     }
 
@@ -4055,6 +5892,96 @@ impl<'help> App<'help> {
     }
 
     /// Propagate settings
+    /// Build the lazy subcommand registered under `name`, if any, and add it to
+    /// `self.subcommands`.
+    pub(crate) fn _materialize_lazy_subcommand(&mut self, name: &str) {
+        if let Some(pos) = self
+            .lazy_subcommands
+            .iter()
+            .position(|lazy| lazy.name() == name)
+        {
+            let lazy = self.lazy_subcommands.remove(pos);
+            self._materialize(lazy);
+        }
+    }
+
+    /// Build every remaining lazy subcommand, e.g. because help output or
+    /// completions need the full set.
+    pub(crate) fn _materialize_all_lazy_subcommands(&mut self) {
+        for lazy in std::mem::take(&mut self.lazy_subcommands) {
+            self._materialize(lazy);
+        }
+    }
+
+    fn _materialize(&mut self, lazy: LazySubcommand<'help>) {
+        debug!("App::_materialize: {}", lazy.name());
+        let mut sc = lazy.call();
+        debug_assert!(
+            sc.name == lazy.name(),
+            "Lazy subcommand `{}` built an App named `{}`",
+            lazy.name(),
+            sc.name
+        );
+        // The parent has already been built, so the propagation pass this
+        // subcommand missed is applied now.
+        if self.settings.is_set(AppSettings::Built) {
+            self._propagate_subcommand(&mut sc);
+        }
+        self.subcommands.push(sc);
+    }
+
+    /// Whether `name` may be dispatched as an external subcommand.
+    ///
+    /// Always true without an [`App::external_subcommand_prefix`]; with one,
+    /// only when a matching executable is found on `PATH`.
+    pub(crate) fn _external_subcommand_exists(&self, name: &str) -> bool {
+        let prefix = match self.external_subcommand_prefix.as_deref() {
+            Some(prefix) => prefix,
+            None => return true,
+        };
+        let file = format!("{}{}", prefix, name);
+        env::var_os("PATH").map_or(false, |paths| {
+            env::split_paths(&paths).any(|dir| is_executable_file(&dir.join(&file)))
+        })
+    }
+
+    /// The names of `<prefix><name>` executables found on `PATH`, for listing in
+    /// help output.  Names shadowed by a declared subcommand are skipped.
+    pub(crate) fn _discover_external_subcommands(&self) -> Vec<String> {
+        let prefix = match self.external_subcommand_prefix.as_deref() {
+            Some(prefix) => prefix,
+            None => return Vec::new(),
+        };
+        let mut plugins: Vec<String> = Vec::new();
+        if let Some(paths) = env::var_os("PATH") {
+            for dir in env::split_paths(&paths) {
+                let entries = match std::fs::read_dir(dir) {
+                    Ok(entries) => entries,
+                    Err(_) => continue,
+                };
+                for entry in entries.flatten() {
+                    let file_name = entry.file_name();
+                    let file_name = match file_name.to_str() {
+                        Some(file_name) => file_name,
+                        None => continue,
+                    };
+                    let name = match file_name.strip_prefix(prefix) {
+                        Some(name) if !name.is_empty() => name.strip_suffix(env::consts::EXE_SUFFIX).unwrap_or(name),
+                        _ => continue,
+                    };
+                    if is_executable_file(&entry.path())
+                        && self.find_subcommand(name).is_none()
+                        && !plugins.iter().any(|plugin| plugin == name)
+                    {
+                        plugins.push(name.to_string());
+                    }
+                }
+            }
+        }
+        plugins.sort();
+        plugins
+    }
+
     pub(crate) fn _propagate(&mut self) {
         debug!("App::_propagate:{}", self.name);
         let mut subcommands = std::mem::take(&mut self.subcommands);
@@ -4081,6 +6008,32 @@ impl<'help> App<'help> {
             sc.g_settings = sc.g_settings | self.g_settings;
             sc.term_w = self.term_w;
             sc.max_w = self.max_w;
+            if sc.val_name_casing.is_none() {
+                sc.val_name_casing = self.val_name_casing;
+            }
+            if sc.error_formatter.is_none() {
+                sc.error_formatter = self.error_formatter;
+            }
+            if sc.suggestion_confidence.is_none() {
+                sc.suggestion_confidence = self.suggestion_confidence;
+            }
+            if sc.warning_writer.is_none() {
+                sc.warning_writer = self.warning_writer.clone();
+            }
+            if sc.theme.is_none() {
+                sc.theme = self.theme;
+            }
+            if sc.localizer.is_none() {
+                sc.localizer = self.localizer.clone();
+            }
+            #[cfg(feature = "env")]
+            if sc.env_prefix.is_none() {
+                // Scope the prefix through the subcommand, e.g. `MYAPP` -> `MYAPP_SERVE`
+                sc.env_prefix = self
+                    .env_prefix
+                    .as_ref()
+                    .map(|prefix| format!("{}_{}", prefix, env_component(&sc.name)));
+            }
         }
     }
 
@@ -4255,6 +6208,7 @@ To change `help`s short, call `app.arg(Arg::new(\"help\")...)`.",
         debug!("App::_build_bin_names");
 
         if !self.is_set(AppSettings::BinNameBuilt) {
+            self._materialize_all_lazy_subcommands();
             for mut sc in &mut self.subcommands {
                 debug!("App::_build_bin_names:iter: bin_name set...");
 
@@ -4294,7 +6248,7 @@ To change `help`s short, call `app.arg(Arg::new(\"help\")...)`.",
         } else {
             self.version.or(self.long_version).unwrap_or("")
         };
-        if let Some(bn) = self.bin_name.as_ref() {
+        let mut rendered = if let Some(bn) = self.bin_name.as_ref() {
             if bn.contains(' ') {
                 // In case we're dealing with subcommands i.e. git mv is translated to git-mv
                 format!("{} {}\n", bn.replace(' ', "-"), ver)
@@ -4303,7 +6257,13 @@ To change `help`s short, call `app.arg(Arg::new(\"help\")...)`.",
             }
         } else {
             format!("{} {}\n", &self.name[..], ver)
+        };
+        if use_long {
+            for (label, value) in &self.version_sections {
+                rendered.push_str(&format!("{}: {}\n", label, value));
+            }
         }
+        rendered
     }
 
     pub(crate) fn format_group(&self, g: &Id) -> String {
@@ -4439,11 +6399,13 @@ impl<'help> App<'help> {
     /// Iterate through all the names of all subcommands (not recursively), including aliases.
     /// Used for suggestions.
     pub(crate) fn all_subcommand_names(&self) -> impl Iterator<Item = &str> + Captures<'help> {
-        self.get_subcommands().flat_map(|sc| {
-            let name = sc.get_name();
-            let aliases = sc.get_all_aliases();
-            std::iter::once(name).chain(aliases)
-        })
+        self.get_subcommands()
+            .flat_map(|sc| {
+                let name = sc.get_name();
+                let aliases = sc.get_all_aliases();
+                std::iter::once(name).chain(aliases)
+            })
+            .chain(self.lazy_subcommands.iter().map(|lazy| lazy.name()))
     }
 
     pub(crate) fn unroll_args_in_group(&self, group: &Id) -> Vec<Id> {
@@ -4556,12 +6518,43 @@ impl<'help> Default for App<'help> {
             g_settings: Default::default(),
             args: Default::default(),
             subcommands: Default::default(),
+            lazy_subcommands: Default::default(),
+            external_subcommand_prefix: Default::default(),
             replacers: Default::default(),
             groups: Default::default(),
             current_help_heading: Default::default(),
             current_disp_ord: Some(0),
             subcommand_value_name: Default::default(),
             subcommand_heading: Default::default(),
+            promote_common_args: Default::default(),
+            suggested_abbrevs: Default::default(),
+            subcommand_value_policy: Default::default(),
+            subcommand_required_unless: Default::default(),
+            usage_suppressed_kinds: Default::default(),
+            after_error_help: Default::default(),
+            val_name_casing: Default::default(),
+            error_formatter: Default::default(),
+            #[cfg(feature = "yaml")]
+            config_file: Default::default(),
+            #[cfg(feature = "yaml")]
+            config_values: Default::default(),
+            suggestion_confidence: Default::default(),
+            suggestion_words: Default::default(),
+            warning_writer: Default::default(),
+            #[cfg(feature = "env")]
+            env_prefix: Default::default(),
+            #[cfg(feature = "env")]
+            env_file_arg: Default::default(),
+            theme: Default::default(),
+            help_sections: Default::default(),
+            version_sections: Default::default(),
+            help_all_flag: Default::default(),
+            examples: Default::default(),
+            matches_validator: Default::default(),
+            value_detection: Default::default(),
+            file_subcommand_hint: Default::default(),
+            flag_prefixes: Default::default(),
+            localizer: Default::default(),
         }
     }
 }
@@ -4580,6 +6573,219 @@ impl fmt::Display for App<'_> {
     }
 }
 
+// Drop ANSI escape sequences (CSI `ESC [ ... <letter>`, or a lone ESC plus
+// the following byte) while keeping everything else intact.
+fn strip_ansi(rendered: &str) -> String {
+    let mut out = String::with_capacity(rendered.len());
+    let mut chars = rendered.chars();
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            out.push(c);
+            continue;
+        }
+        match chars.next() {
+            Some('[') => {
+                for c in chars.by_ref() {
+                    if c.is_ascii_alphabetic() {
+                        break;
+                    }
+                }
+            }
+            Some(_) | None => {}
+        }
+    }
+    out
+}
+
+fn is_executable_file(path: &Path) -> bool {
+    let metadata = match path.metadata() {
+        Ok(metadata) => metadata,
+        Err(_) => return false,
+    };
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        metadata.is_file() && metadata.permissions().mode() & 0o111 != 0
+    }
+    #[cfg(not(unix))]
+    {
+        metadata.is_file()
+    }
+}
+
+type MatchesValidatorInner<'help> = dyn FnMut(&ArgMatches) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
+    + Send
+    + 'help;
+
+/// Post-parse hook registered with [`App::validator`].
+#[derive(Clone)]
+pub(crate) struct MatchesValidator<'help>(std::sync::Arc<std::sync::Mutex<MatchesValidatorInner<'help>>>);
+
+impl<'help> MatchesValidator<'help> {
+    fn new<F>(f: F) -> Self
+    where
+        F: FnMut(&ArgMatches) -> Result<(), Box<dyn std::error::Error + Send + Sync>>
+            + Send
+            + 'help,
+    {
+        MatchesValidator(std::sync::Arc::new(std::sync::Mutex::new(f)))
+    }
+
+    pub(crate) fn validate(
+        &self,
+        matches: &ArgMatches,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        (self.0.lock().expect(INTERNAL_ERROR_MSG))(matches)
+    }
+}
+
+impl<'help> fmt::Debug for MatchesValidator<'help> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("MatchesValidator").finish()
+    }
+}
+
+impl<'help> PartialEq for MatchesValidator<'help> {
+    fn eq(&self, other: &Self) -> bool {
+        std::sync::Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<'help> Eq for MatchesValidator<'help> {}
+
+type ValueDetectionInner<'help> = dyn Fn(&str) -> bool + Send + Sync + 'help;
+
+/// "Looks like a value" predicate registered with [`App::value_detection`].
+#[derive(Clone)]
+pub(crate) struct ValueDetection<'help>(std::sync::Arc<ValueDetectionInner<'help>>);
+
+impl<'help> ValueDetection<'help> {
+    fn new<F>(f: F) -> Self
+    where
+        F: Fn(&str) -> bool + Send + Sync + 'help,
+    {
+        ValueDetection(std::sync::Arc::new(f))
+    }
+
+    pub(crate) fn is_value(&self, token: &str) -> bool {
+        (self.0)(token)
+    }
+}
+
+impl<'help> fmt::Debug for ValueDetection<'help> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("ValueDetection").finish()
+    }
+}
+
+impl<'help> PartialEq for ValueDetection<'help> {
+    fn eq(&self, other: &Self) -> bool {
+        std::sync::Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<'help> Eq for ValueDetection<'help> {}
+
+type FileSubcommandHintInner<'help> =
+    dyn Fn(&std::path::Path) -> Option<String> + Send + Sync + 'help;
+
+/// File-aware subcommand suggestion hook registered with [`App::file_subcommand_hint`].
+#[derive(Clone)]
+pub(crate) struct FileSubcommandHint<'help>(std::sync::Arc<FileSubcommandHintInner<'help>>);
+
+impl<'help> FileSubcommandHint<'help> {
+    fn new<F>(f: F) -> Self
+    where
+        F: Fn(&std::path::Path) -> Option<String> + Send + Sync + 'help,
+    {
+        FileSubcommandHint(std::sync::Arc::new(f))
+    }
+
+    pub(crate) fn suggest(&self, path: &std::path::Path) -> Option<String> {
+        (self.0)(path)
+    }
+}
+
+impl<'help> fmt::Debug for FileSubcommandHint<'help> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("FileSubcommandHint").finish()
+    }
+}
+
+impl<'help> PartialEq for FileSubcommandHint<'help> {
+    fn eq(&self, other: &Self) -> bool {
+        std::sync::Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<'help> Eq for FileSubcommandHint<'help> {}
+
+type LazySubcommandInner<'help> = dyn Fn() -> App<'help> + Send + Sync + 'help;
+
+/// Deferred subcommand constructor registered with [`App::subcommand_lazy`].
+#[derive(Clone)]
+pub(crate) struct LazySubcommand<'help> {
+    name: String,
+    build: std::sync::Arc<LazySubcommandInner<'help>>,
+}
+
+impl<'help> LazySubcommand<'help> {
+    fn new<F>(name: String, f: F) -> Self
+    where
+        F: Fn() -> App<'help> + Send + Sync + 'help,
+    {
+        LazySubcommand {
+            name,
+            build: std::sync::Arc::new(f),
+        }
+    }
+
+    pub(crate) fn name(&self) -> &str {
+        &self.name
+    }
+
+    fn call(&self) -> App<'help> {
+        (self.build)()
+    }
+}
+
+impl<'help> fmt::Debug for LazySubcommand<'help> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("LazySubcommand")
+            .field("name", &self.name)
+            .finish()
+    }
+}
+
+impl<'help> PartialEq for LazySubcommand<'help> {
+    fn eq(&self, other: &Self) -> bool {
+        self.name == other.name && std::sync::Arc::ptr_eq(&self.build, &other.build)
+    }
+}
+
+impl<'help> Eq for LazySubcommand<'help> {}
+
+/// Turns an arg or subcommand name into an environment variable component, e.g.
+/// `log-level` -> `LOG_LEVEL`
+#[cfg(feature = "env")]
+pub(crate) fn env_component(name: &str) -> String {
+    name.to_uppercase().replace('-', "_")
+}
+
+/// Quotes a token for a POSIX shell when it contains anything a shell would
+/// split or expand, e.g. `hello world` -> `'hello world'`.
+fn shell_quote(token: &str) -> String {
+    let safe = !token.is_empty()
+        && token
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || "_-./=:@+,".contains(c));
+    if safe {
+        token.to_owned()
+    } else {
+        format!("'{}'", token.replace('\'', "'\\''"))
+    }
+}
+
 fn two_elements_of<I, T>(mut iter: I) -> Option<(T, T)>
 where
     I: Iterator<Item = T>,
@@ -153,7 +153,6 @@ pub enum AppSettings {
         since = "3.1.0",
         note = "Replaced with `App::multicall` and `App::is_multicall_set`"
     )]
-    #[cfg(feature = "unstable-multicall")]
     Multicall,
 
     /// Deprecated, replaced with [`App::allow_invalid_utf8_for_external_subcommands`] and [`App::is_allow_invalid_utf8_for_external_subcommands_set`]
@@ -337,6 +336,268 @@ pub enum AppSettings {
     /// ```
     NoAutoVersion,
 
+    /// Pipe the help message through a pager (`$PAGER`, falling back to `less`) when printing
+    /// it to a terminal.
+    ///
+    /// When help was requested for a specific flag via the `help` subcommand (e.g.
+    /// `prog help --config`), the pager is opened pre-searched to that flag's entry.
+    ///
+    /// If the pager cannot be spawned, output is not a terminal, or the help message
+    /// already fits on screen, the help message is printed normally.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::{App, AppSettings};
+    /// App::new("myprog")
+    ///     .setting(AppSettings::PageHelp)
+    ///     .get_matches();
+    /// ```
+    PageHelp,
+
+    /// Treat every `--` after the first one as another separator instead of a literal value.
+    ///
+    /// See [`App::repeated_double_dash_as_separator`][crate::App::repeated_double_dash_as_separator].
+    RepeatedDoubleDashAsSeparator,
+
+    /// List visible subcommand aliases as their own rows in the help's subcommands section.
+    ///
+    /// See [`App::list_subcommand_aliases`][crate::App::list_subcommand_aliases].
+    ListSubcommandAliases,
+
+    /// Re-check the output destination when printing and strip color if it is no longer
+    /// a terminal.
+    ///
+    /// See [`App::strip_ansi_on_redirect`][crate::App::strip_ansi_on_redirect].
+    StripAnsiOnRedirect,
+
+    /// Omit the usage block from error messages, keeping the try-help footer.
+    ///
+    /// See [`App::disable_usage_in_errors`][crate::App::disable_usage_in_errors].
+    DisableUsageInErrors,
+
+    /// Keep parsing past recoverable problems and report them all in one error.
+    ///
+    /// Instead of bailing at the first failure, unknown arguments and validation
+    /// problems (conflicts, missing required arguments, invalid values) are collected
+    /// and aggregated into a single [`Error`][crate::Error] whose message lists every
+    /// problem, with the context of each preserved.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, AppSettings, Arg};
+    /// let res = App::new("myprog")
+    ///     .setting(AppSettings::CollectAllErrors)
+    ///     .arg(Arg::new("input").required(true))
+    ///     .try_get_matches_from(vec!["myprog", "--bogus"]);
+    /// let err = res.unwrap_err();
+    /// assert!(err.to_string().contains("--bogus"));
+    /// assert!(err.to_string().contains("<input>"));
+    /// ```
+    CollectAllErrors,
+
+    /// Expand `@file` tokens into the arguments read from `file` before parsing.
+    ///
+    /// Response files follow MSVC/javac conventions for long command lines: arguments
+    /// are separated by whitespace (including newlines), single or double quotes group
+    /// an argument containing whitespace, and a backslash escapes the next character
+    /// inside double quotes.  Response files may reference further response files;
+    /// expansion stops with an [`ErrorKind::Io`][crate::ErrorKind::Io] error if a file
+    /// can't be read or files nest too deeply.
+    ///
+    /// Tokens after `--` are never expanded.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::{App, AppSettings, Arg};
+    /// let m = App::new("myprog")
+    ///     .setting(AppSettings::AllowResponseFiles)
+    ///     .arg(Arg::new("input").multiple_values(true))
+    ///     .get_matches_from(vec!["myprog", "@args.txt"]);
+    /// ```
+    AllowResponseFiles,
+
+    /// Reflow multi-paragraph about text with paragraph-aware wrapping.
+    ///
+    /// The default wrapping fills each source line independently. With this setting,
+    /// consecutive lines of [`App::long_about`] reflow as one paragraph, bullet lists
+    /// keep a hanging indent, and lines indented by four or more spaces (code blocks)
+    /// are left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::{App, AppSettings};
+    /// App::new("myprog")
+    ///     .setting(AppSettings::SmartWrap)
+    ///     .long_about(
+    ///         "A tool that does a thing.\n\n\
+    ///          - first point\n\
+    ///          - second point",
+    ///     )
+    ///     .get_matches();
+    /// ```
+    ///
+    /// [`App::long_about`]: crate::App::long_about()
+    SmartWrap,
+
+    /// Prompt interactively for missing required argument values instead of erroring.
+    ///
+    /// When a required argument that takes a value is still absent after command
+    /// line, environment, config file, and default sources, and stdin is a
+    /// terminal, clap prompts for the value on stderr instead of producing
+    /// [`ErrorKind::MissingRequiredArgument`][crate::ErrorKind::MissingRequiredArgument].
+    /// [`Arg::prompt`] customizes the prompt text per argument (and opts a single
+    /// argument in without this setting). The typed value is echoed by the
+    /// terminal as usual, so prompting is not suitable for passwords or other
+    /// secrets.
+    ///
+    /// When stdin is not a terminal the usual error is produced, so scripts fail
+    /// fast instead of hanging on a prompt.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::{App, AppSettings, Arg};
+    /// let m = App::new("myprog")
+    ///     .setting(AppSettings::PromptMissing)
+    ///     .arg(Arg::new("user").long("user").takes_value(true).required(true))
+    ///     .get_matches();
+    /// ```
+    ///
+    /// [`Arg::prompt`]: crate::Arg::prompt()
+    PromptMissing,
+
+    /// Halt parsing at the first unrecognized token instead of erroring.
+    ///
+    /// The unrecognized token and everything after it are exposed through
+    /// [`ArgMatches::remaining`][crate::ArgMatches::remaining], so wrapper programs
+    /// (`time`, `env`-style commands) can parse their own flags and forward the raw
+    /// tail to the program they run. Everything up to that token parses as usual,
+    /// including required-argument validation.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, AppSettings, Arg};
+    /// let m = App::new("timer")
+    ///     .setting(AppSettings::StopAtUnknown)
+    ///     .arg(Arg::new("verbose").short('v'))
+    ///     .get_matches_from(vec!["timer", "-v", "make", "-j4"]);
+    /// assert_eq!(m.remaining().count(), 2);
+    /// ```
+    StopAtUnknown,
+
+    /// Automatically dispatch to the unambiguous closest subcommand on a typo.
+    ///
+    /// Where clap normally stops at a "Did you mean" suggestion, this setting
+    /// runs the suggested subcommand when exactly one candidate clears the
+    /// confidence threshold (git's `help.autocorrect` behaviour), printing a
+    /// notice to stderr first. The threshold is the one configured with
+    /// [`App::suggestion_confidence`][crate::App::suggestion_confidence].
+    /// Individual subcommands can refuse to be dispatched to this way with
+    /// [`AppSettings::NoAutoCorrect`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, AppSettings};
+    /// let m = App::new("myprog")
+    ///     .setting(AppSettings::AutoCorrectSubcommands)
+    ///     .subcommand(App::new("status"))
+    ///     .get_matches_from(vec!["myprog", "stauts"]);
+    /// assert_eq!(m.subcommand_name(), Some("status"));
+    /// ```
+    AutoCorrectSubcommands,
+
+    /// Exempt this subcommand from [`AppSettings::AutoCorrectSubcommands`] dispatch.
+    ///
+    /// Set it on subcommands too destructive to run off the back of a typo;
+    /// near-misses then produce the usual suggestion error instead.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, AppSettings, ErrorKind};
+    /// let res = App::new("myprog")
+    ///     .setting(AppSettings::AutoCorrectSubcommands)
+    ///     .subcommand(App::new("destroy").setting(AppSettings::NoAutoCorrect))
+    ///     .try_get_matches_from(vec!["myprog", "destory"]);
+    /// assert_eq!(res.unwrap_err().kind(), ErrorKind::InvalidSubcommand);
+    /// ```
+    NoAutoCorrect,
+
+    /// Accept Windows-style `/flag` syntax alongside the usual `-`/`--` forms.
+    ///
+    /// Tokens beginning with `/` whose name matches a defined long (or
+    /// single-character short) are parsed as that argument, with either `:` or
+    /// `=` separating an attached value — so `/out:file` and `/out=file` both
+    /// set `--out`. Tokens that do not name a defined argument, such as Unix
+    /// paths, are left alone and parse as positionals. This lets tools ported
+    /// from Windows keep their traditional invocation syntax.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, AppSettings, Arg};
+    /// let m = App::new("cl")
+    ///     .setting(AppSettings::AllowSlashFlags)
+    ///     .arg(Arg::new("out").long("out").takes_value(true))
+    ///     .get_matches_from(vec!["cl", "/out:main.exe"]);
+    /// assert_eq!(m.value_of("out"), Some("main.exe"));
+    /// ```
+    AllowSlashFlags,
+
+    /// Echo the offending command line in error messages, underlining the
+    /// token the error is about.
+    ///
+    /// When a parse error names an argument that appears verbatim in `argv`
+    /// (possibly with an attached `=value`), the rendered message repeats the
+    /// command line as typed with a `^^^` marker under that token. Errors
+    /// whose offending argument cannot be located, such as grouped short
+    /// flags, render as usual.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, AppSettings, Arg, ErrorKind};
+    /// let res = App::new("prog")
+    ///     .setting(AppSettings::EchoCommandLine)
+    ///     .arg(Arg::new("opt").long("opt"))
+    ///     .try_get_matches_from(vec!["prog", "--unknown"]);
+    /// let err = res.unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::UnknownArgument);
+    /// assert!(err.to_string().contains("prog --unknown"));
+    /// ```
+    EchoCommandLine,
+
+    /// Accept tokens after `--` that no positional argument claims.
+    ///
+    /// By default such tokens produce
+    /// [`ErrorKind::UnknownArgument`][crate::ErrorKind::UnknownArgument], the
+    /// same as before the separator. With this set they parse successfully and
+    /// are exposed only through
+    /// [`ArgMatches::trailing_args`][crate::ArgMatches::trailing_args], so
+    /// wrappers can forward a trailing command line without defining a
+    /// catch-all positional.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, AppSettings};
+    /// # use std::ffi::OsStr;
+    /// let m = App::new("wrapper")
+    ///     .setting(AppSettings::AllowUnclaimedTrailingArgs)
+    ///     .get_matches_from(vec!["wrapper", "--", "make", "-j4"]);
+    /// assert_eq!(
+    ///     m.trailing_args().collect::<Vec<_>>(),
+    ///     [OsStr::new("make"), OsStr::new("-j4")]
+    /// );
+    /// ```
+    AllowUnclaimedTrailingArgs,
+
     /// Deprecated, replaced with [`AppSettings::AllowHyphenValues`]
     #[deprecated(
         since = "3.0.0",
@@ -466,8 +727,22 @@ bitflags! {
         const USE_LONG_FORMAT_FOR_HELP_SC    = 1 << 42;
         const INFER_LONG_ARGS                = 1 << 43;
         const IGNORE_ERRORS                  = 1 << 44;
-        #[cfg(feature = "unstable-multicall")]
         const MULTICALL                      = 1 << 45;
+        const PAGE_HELP                      = 1 << 46;
+        const REPEATED_DOUBLE_DASH_AS_SEP    = 1 << 47;
+        const LIST_SC_ALIASES                = 1 << 48;
+        const STRIP_ANSI_ON_REDIRECT         = 1 << 49;
+        const DISABLE_USAGE_IN_ERRORS        = 1 << 50;
+        const COLLECT_ALL_ERRORS             = 1 << 51;
+        const ALLOW_RESPONSE_FILES           = 1 << 52;
+        const SMART_WRAP                     = 1 << 53;
+        const PROMPT_MISSING                 = 1 << 54;
+        const STOP_AT_UNKNOWN                = 1 << 55;
+        const AUTO_CORRECT_SC                = 1 << 56;
+        const NO_AUTO_CORRECT                = 1 << 57;
+        const ALLOW_SLASH_FLAGS              = 1 << 58;
+        const ECHO_COMMAND_LINE              = 1 << 59;
+        const ALLOW_UNCLAIMED_TRAILING_ARGS  = 1 << 60;
         const NO_OP                          = 0;
     }
 }
@@ -533,13 +808,42 @@ impl_settings! { AppSettings, AppFlags,
         => Flags::HELP_REQUIRED,
     Hidden
         => Flags::HIDDEN,
-    #[cfg(feature = "unstable-multicall")]
     Multicall
         => Flags::MULTICALL,
     NoAutoHelp
         => Flags::NO_AUTO_HELP,
     NoAutoVersion
         => Flags::NO_AUTO_VERSION,
+    PageHelp
+        => Flags::PAGE_HELP,
+    RepeatedDoubleDashAsSeparator
+        => Flags::REPEATED_DOUBLE_DASH_AS_SEP,
+    ListSubcommandAliases
+        => Flags::LIST_SC_ALIASES,
+    StripAnsiOnRedirect
+        => Flags::STRIP_ANSI_ON_REDIRECT,
+    DisableUsageInErrors
+        => Flags::DISABLE_USAGE_IN_ERRORS,
+    CollectAllErrors
+        => Flags::COLLECT_ALL_ERRORS,
+    AllowResponseFiles
+        => Flags::ALLOW_RESPONSE_FILES,
+    SmartWrap
+        => Flags::SMART_WRAP,
+    PromptMissing
+        => Flags::PROMPT_MISSING,
+    StopAtUnknown
+        => Flags::STOP_AT_UNKNOWN,
+    AutoCorrectSubcommands
+        => Flags::AUTO_CORRECT_SC,
+    NoAutoCorrect
+        => Flags::NO_AUTO_CORRECT,
+    AllowSlashFlags
+        => Flags::ALLOW_SLASH_FLAGS,
+    EchoCommandLine
+        => Flags::ECHO_COMMAND_LINE,
+    AllowUnclaimedTrailingArgs
+        => Flags::ALLOW_UNCLAIMED_TRAILING_ARGS,
     NoBinaryName
         => Flags::NO_BIN_NAME,
     SubcommandsNegateReqs
@@ -613,6 +917,21 @@ impl FromStr for AppSettings {
             "hidden" => Ok(AppSettings::Hidden),
             "noautohelp" => Ok(AppSettings::NoAutoHelp),
             "noautoversion" => Ok(AppSettings::NoAutoVersion),
+            "pagehelp" => Ok(AppSettings::PageHelp),
+            "repeateddoubledashasseparator" => Ok(AppSettings::RepeatedDoubleDashAsSeparator),
+            "listsubcommandaliases" => Ok(AppSettings::ListSubcommandAliases),
+            "stripansionredirect" => Ok(AppSettings::StripAnsiOnRedirect),
+            "disableusageinerrors" => Ok(AppSettings::DisableUsageInErrors),
+            "collectallerrors" => Ok(AppSettings::CollectAllErrors),
+            "allowresponsefiles" => Ok(AppSettings::AllowResponseFiles),
+            "smartwrap" => Ok(AppSettings::SmartWrap),
+            "promptmissing" => Ok(AppSettings::PromptMissing),
+            "stopatunknown" => Ok(AppSettings::StopAtUnknown),
+            "autocorrectsubcommands" => Ok(AppSettings::AutoCorrectSubcommands),
+            "noautocorrect" => Ok(AppSettings::NoAutoCorrect),
+            "allowslashflags" => Ok(AppSettings::AllowSlashFlags),
+            "echocommandline" => Ok(AppSettings::EchoCommandLine),
+            "allowunclaimedtrailingargs" => Ok(AppSettings::AllowUnclaimedTrailingArgs),
             "nobinaryname" => Ok(AppSettings::NoBinaryName),
             "subcommandsnegatereqs" => Ok(AppSettings::SubcommandsNegateReqs),
             "subcommandrequired" => Ok(AppSettings::SubcommandRequired),
@@ -1,6 +1,9 @@
 #![allow(deprecated)]
 
 // Std
+#[cfg(feature = "env")]
+use std::env;
+use std::ffi::OsString;
 use std::{
     borrow::Cow,
     cmp::{Ord, Ordering},
@@ -10,8 +13,6 @@ use std::{
     str,
     sync::{Arc, Mutex},
 };
-#[cfg(feature = "env")]
-use std::{env, ffi::OsString};
 
 #[cfg(feature = "yaml")]
 use yaml_rust::Yaml;
@@ -20,8 +21,12 @@ use yaml_rust::Yaml;
 use crate::build::usage_parser::UsageParser;
 use crate::build::ArgPredicate;
 use crate::util::{Id, Key};
+use crate::ArgMatches;
 use crate::PossibleValue;
 use crate::ValueHint;
+use crate::ValueNameCasing;
+use crate::ValueParser;
+use crate::ValueTransform;
 use crate::INTERNAL_ERROR_MSG;
 use crate::{ArgFlags, ArgSettings};
 
@@ -70,18 +75,30 @@ pub struct Arg<'help> {
     pub(crate) long: Option<&'help str>,
     pub(crate) aliases: Vec<(&'help str, bool)>, // (name, visible)
     pub(crate) short_aliases: Vec<(char, bool)>, // (name, visible)
+    pub(crate) deprecated_aliases: Vec<&'help str>,
     pub(crate) disp_ord: DisplayOrder,
     pub(crate) possible_vals: Vec<PossibleValue<'help>>,
     pub(crate) val_names: Vec<&'help str>,
+    pub(crate) val_name_casing: Option<ValueNameCasing>,
     pub(crate) num_vals: Option<usize>,
     pub(crate) max_occurs: Option<usize>,
+    pub(crate) occurs_multiple_of: Option<usize>,
+    pub(crate) paired_with: Vec<Id>,
     pub(crate) max_vals: Option<usize>,
     pub(crate) min_vals: Option<usize>,
     pub(crate) validator: Option<Arc<Mutex<Validator<'help>>>>,
     pub(crate) validator_os: Option<Arc<Mutex<ValidatorOs<'help>>>>,
+    pub(crate) value_parser: Option<ValueParser>,
+    pub(crate) value_transforms: Vec<ValueTransform>,
+    pub(crate) canonicalize: bool,
+    pub(crate) allow_hyphen_values_once: bool,
+    pub(crate) hide_unless_verbose: bool,
+    pub(crate) no_abbreviation: bool,
+    pub(crate) validate_hint: bool,
     pub(crate) val_delim: Option<char>,
     pub(crate) default_vals: Vec<&'help OsStr>,
     pub(crate) default_vals_ifs: Vec<(Id, ArgPredicate<'help>, Option<&'help OsStr>)>,
+    pub(crate) default_value_fn: Option<Arc<DefaultValueFn<'help>>>,
     pub(crate) default_missing_vals: Vec<&'help OsStr>,
     #[cfg(feature = "env")]
     pub(crate) env: Option<(&'help OsStr, Option<OsString>)>,
@@ -89,6 +106,8 @@ pub struct Arg<'help> {
     pub(crate) index: Option<usize>,
     pub(crate) help_heading: Option<Option<&'help str>>,
     pub(crate) value_hint: ValueHint,
+    pub(crate) prompt: Option<&'help str>,
+    pub(crate) value_range: Option<ValueRange>,
 }
 
 impl<'help> Arg<'help> {
@@ -223,6 +242,35 @@ impl<'help> Arg<'help> {
         self
     }
 
+    /// Add an alias for a previous name of this argument.
+    ///
+    /// The old spelling still parses like [`Arg::alias`], but using it emits a
+    /// deprecation note so users migrate to the new name. The note is styled and
+    /// printed to stderr unless the application redirects it with
+    /// [`App::warning_writer`][crate::App::warning_writer].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///             .arg(Arg::new("color")
+    ///             .long("color")
+    ///             .deprecated_alias("colour")
+    ///             .takes_value(true))
+    ///        .get_matches_from(vec![
+    ///             "prog", "--colour", "auto"
+    ///         ]);
+    /// assert_eq!(m.value_of("color"), Some("auto"));
+    /// ```
+    #[must_use]
+    pub fn deprecated_alias<S: Into<&'help str>>(mut self, name: S) -> Self {
+        let name = name.into();
+        self.deprecated_aliases.push(name);
+        self.aliases.push((name, false));
+        self
+    }
+
     /// Add an alias, which functions as a hidden short flag.
     ///
     /// This is more efficient, and easier than creating multiple hidden arguments as one only
@@ -719,6 +767,41 @@ impl<'help> Arg<'help> {
         }
     }
 
+    /// Prompt interactively for this argument's value when it is missing.
+    ///
+    /// When the argument is still absent after command line, environment, config
+    /// file, and default sources, and stdin is a terminal, clap writes `text` to
+    /// stderr and reads the value from stdin instead of erroring. With
+    /// [`AppSettings::PromptMissing`] every missing required argument is prompted
+    /// for; this method additionally opts in a single argument and overrides the
+    /// prompt text.
+    ///
+    /// **NOTE:** When stdin is not a terminal the usual
+    /// `MissingRequiredArgument` error is produced.
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(
+    ///         Arg::new("user")
+    ///             .long("user")
+    ///             .takes_value(true)
+    ///             .required(true)
+    ///             .prompt("Username: "),
+    ///     )
+    ///     .get_matches();
+    /// ```
+    ///
+    /// [`AppSettings::PromptMissing`]: crate::AppSettings::PromptMissing
+    #[inline]
+    #[must_use]
+    pub fn prompt(mut self, text: &'help str) -> Self {
+        self.prompt = Some(text);
+        self.takes_value(true)
+    }
+
     /// Specifies that an argument can be matched to all child [`Subcommand`]s.
     ///
     /// **NOTE:** Global arguments *only* propagate down, **not** up (to parent commands), however
@@ -877,6 +960,150 @@ impl<'help> Arg<'help> {
         }
     }
 
+    /// Saturate at [`Arg::max_occurrences`] instead of erroring.
+    ///
+    /// By default, exceeding the maximum number of occurrences produces
+    /// [`ErrorKind::TooManyOccurrences`].  With this set, extra occurrences are
+    /// silently ignored and the count reported by
+    /// [`ArgMatches::occurrences_of`] and [`ArgMatches::count_of`] is capped at
+    /// the maximum.  This is typically what counted flags like `-v` want: `-vvvvv`
+    /// should behave like the highest supported verbosity, not be an error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("verbosity")
+    ///         .short('v')
+    ///         .max_occurrences(3)
+    ///         .saturating_occurrences(true))
+    ///     .get_matches_from(vec![
+    ///         "prog", "-vvvvv"
+    ///     ]);
+    ///
+    /// assert_eq!(m.count_of("verbosity"), 3);
+    /// ```
+    /// [`ErrorKind::TooManyOccurrences`]: crate::ErrorKind::TooManyOccurrences
+    /// [`ArgMatches::occurrences_of`]: crate::ArgMatches::occurrences_of()
+    /// [`ArgMatches::count_of`]: crate::ArgMatches::count_of()
+    #[inline]
+    #[must_use]
+    pub fn saturating_occurrences(self, yes: bool) -> Self {
+        if yes {
+            self.setting(ArgSettings::SaturatingOccurrences)
+        } else {
+            self.unset_setting(ArgSettings::SaturatingOccurrences)
+        }
+    }
+
+    /// Also accept the flag as `--no-<long>` to unset it.
+    ///
+    /// A negatable flag can be given as either `--<long>` or `--no-<long>`; the
+    /// form that appears *last* on the command line wins.  The resolved boolean
+    /// is available via [`ArgMatches::flag_of`], and help output shows the flag
+    /// as `--[no-]<long>` to advertise both spellings.
+    ///
+    /// **NOTE:** This is only meaningful for flags which have a
+    /// [long][Arg::long] and don't take a value.  The short form, if any,
+    /// always counts as the positive spelling.
+    ///
+    /// **NOTE:** This implies [`Arg::multiple_occurrences(true)`] so that a
+    /// later occurrence can override an earlier one.
+    ///
+    /// [`Arg::multiple_occurrences(true)`]: Arg::multiple_occurrences()
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("color")
+    ///         .long("color")
+    ///         .negatable(true))
+    ///     .get_matches_from(vec![
+    ///         "prog", "--color", "--no-color"
+    ///     ]);
+    ///
+    /// assert_eq!(m.flag_of("color"), Some(false));
+    /// ```
+    /// [`ArgMatches::flag_of`]: crate::ArgMatches::flag_of()
+    #[inline]
+    #[must_use]
+    pub fn negatable(self, yes: bool) -> Self {
+        if yes {
+            self.setting(ArgSettings::Negatable)
+                .multiple_occurrences(true)
+        } else {
+            self.unset_setting(ArgSettings::Negatable)
+        }
+    }
+
+    /// The number of occurrences must be a multiple of `n`.
+    ///
+    /// This is validated after parsing, producing
+    /// [`ErrorKind::InvalidOccurrencePattern`] when it doesn't hold.  Zero occurrences
+    /// always satisfy the rule.
+    ///
+    /// Implies [`Arg::multiple_occurrences(true)`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let res = App::new("prog")
+    ///     .arg(Arg::new("map")
+    ///         .long("map")
+    ///         .occurrences_multiple_of(2))
+    ///     .try_get_matches_from(vec![
+    ///         "prog", "--map", "--map", "--map"
+    ///     ]);
+    ///
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind(), ErrorKind::InvalidOccurrencePattern);
+    /// ```
+    /// [`ErrorKind::InvalidOccurrencePattern`]: crate::ErrorKind::InvalidOccurrencePattern
+    /// [`Arg::multiple_occurrences(true)`]: Arg::multiple_occurrences()
+    #[inline]
+    #[must_use]
+    pub fn occurrences_multiple_of(mut self, n: usize) -> Self {
+        self.occurs_multiple_of = Some(n);
+        self.multiple_occurrences(true)
+    }
+
+    /// Each occurrence of this argument must be followed by an occurrence of `arg_id`
+    /// before this argument appears again.
+    ///
+    /// This is validated after parsing, producing
+    /// [`ErrorKind::InvalidOccurrencePattern`] when it doesn't hold, so rules like
+    /// "each `--src` must be followed by a `--dst`" can be expressed directly.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let res = App::new("prog")
+    ///     .arg(Arg::new("src")
+    ///         .long("src")
+    ///         .multiple_occurrences(true)
+    ///         .paired_with("dst"))
+    ///     .arg(Arg::new("dst")
+    ///         .long("dst")
+    ///         .multiple_occurrences(true))
+    ///     .try_get_matches_from(vec![
+    ///         "prog", "--src", "--src", "--dst"
+    ///     ]);
+    ///
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind(), ErrorKind::InvalidOccurrencePattern);
+    /// ```
+    /// [`ErrorKind::InvalidOccurrencePattern`]: crate::ErrorKind::InvalidOccurrencePattern
+    #[must_use]
+    pub fn paired_with<T: Key>(mut self, arg_id: T) -> Self {
+        self.paired_with.push(arg_id.into());
+        self
+    }
+
     /// Check if the [`ArgSettings`] variant is currently set on the argument.
     ///
     /// [`ArgSettings`]: crate::ArgSettings
@@ -1534,6 +1761,78 @@ impl<'help> Arg<'help> {
         self
     }
 
+    /// Parse the argument's values into a typed value at parse time.
+    ///
+    /// Malformed values are rejected while matching with an
+    /// [`ErrorKind::ValueValidation`] error, and the parsed result is retrieved
+    /// with [`ArgMatches::get_one`] (or [`ArgMatches::get_many`] for multiple
+    /// values) instead of re-parsing the string. See [`ValueParser`] for
+    /// building parsers from [`FromStr`] implementations or custom functions.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind, ValueParser};
+    /// let app = || {
+    ///     App::new("prog").arg(
+    ///         Arg::new("port")
+    ///             .long("port")
+    ///             .takes_value(true)
+    ///             .value_parser(ValueParser::new::<u16>()),
+    ///     )
+    /// };
+    ///
+    /// let m = app().try_get_matches_from(["prog", "--port", "8080"]).unwrap();
+    /// assert_eq!(m.get_one::<u16>("port"), Some(&8080));
+    ///
+    /// let err = app().try_get_matches_from(["prog", "--port", "banana"]).unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::ValueValidation);
+    /// ```
+    /// [`ErrorKind::ValueValidation`]: crate::ErrorKind::ValueValidation
+    /// [`ArgMatches::get_one`]: crate::ArgMatches::get_one()
+    /// [`ArgMatches::get_many`]: crate::ArgMatches::get_many()
+    /// [`FromStr`]: std::str::FromStr
+    #[must_use]
+    pub fn value_parser(mut self, parser: ValueParser) -> Self {
+        self.value_parser = Some(parser);
+        self
+    }
+
+    /// Only accept integer values inside `range`.
+    ///
+    /// Values that don't parse as an integer or fall outside the range are
+    /// rejected with an [`ErrorKind::ValueValidation`] error describing the
+    /// accepted bounds, e.g. `value must be between 1 and 65535`. The range also
+    /// shows up in the help output next to defaults and possible values.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let app = || {
+    ///     App::new("prog").arg(
+    ///         Arg::new("port")
+    ///             .long("port")
+    ///             .value_range(1..=65535),
+    ///     )
+    /// };
+    ///
+    /// let m = app().try_get_matches_from(["prog", "--port", "8080"]).unwrap();
+    /// assert_eq!(m.value_of("port"), Some("8080"));
+    ///
+    /// let err = app().try_get_matches_from(["prog", "--port", "0"]).unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::ValueValidation);
+    /// ```
+    /// [`ErrorKind::ValueValidation`]: crate::ErrorKind::ValueValidation
+    #[must_use]
+    pub fn value_range<R>(mut self, range: R) -> Self
+    where
+        R: std::ops::RangeBounds<i64>,
+    {
+        self.value_range = Some(ValueRange::new(&range));
+        self.takes_value(true)
+    }
+
     /// Perform a custom validation on the argument value.
     ///
     /// See [validator][Arg::validator].
@@ -1577,6 +1876,94 @@ impl<'help> Arg<'help> {
         self
     }
 
+    /// Apply a transformation to each value before validation and storage.
+    ///
+    /// Transforms run in the order they were added. Validators and `possible_values`
+    /// checks see the transformed value, but error messages show the value as the user
+    /// originally typed it.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ValueTransform};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("format")
+    ///         .long("format")
+    ///         .takes_value(true)
+    ///         .value_transform(ValueTransform::TrimWhitespace)
+    ///         .value_transform(ValueTransform::Lowercase))
+    ///     .get_matches_from(vec!["prog", "--format", " JSON "]);
+    /// assert_eq!(m.value_of("format"), Some("json"));
+    /// ```
+    #[must_use]
+    pub fn value_transform(mut self, transform: ValueTransform) -> Self {
+        self.value_transforms.push(transform);
+        self.takes_value(true)
+    }
+
+    /// Resolve each value to a canonical, absolute path after parsing.
+    ///
+    /// The value as the user typed it remains available through the regular accessors
+    /// ([`ArgMatches::value_of`] and friends), while the resolved path can be retrieved with
+    /// [`ArgMatches::canonical_value_of`].  This is useful for daemons that change their
+    /// working directory after startup.
+    ///
+    /// Resolution happens at parse time, so a path that does not exist produces an
+    /// [`ErrorKind::ValueValidation`] error naming this argument.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("config")
+    ///         .long("config")
+    ///         .canonicalize(true))
+    ///     .get_matches_from(vec!["prog", "--config", "Cargo.toml"]);
+    /// assert_eq!(m.value_of("config"), Some("Cargo.toml"));
+    /// assert!(m.canonical_value_of("config").unwrap().is_absolute());
+    /// ```
+    /// [`ArgMatches::value_of`]: crate::ArgMatches::value_of()
+    /// [`ArgMatches::canonical_value_of`]: crate::ArgMatches::canonical_value_of()
+    /// [`ErrorKind::ValueValidation`]: crate::ErrorKind::ValueValidation
+    #[must_use]
+    pub fn canonicalize(mut self, yes: bool) -> Self {
+        self.canonicalize = yes;
+        self.takes_value(true)
+    }
+
+    /// Accept a leading-hyphen value only for the token immediately following the flag.
+    ///
+    /// [`Arg::allow_hyphen_values`] keeps consuming hyphenated tokens for as long as the
+    /// argument accepts values, which with [`Arg::multiple_values`] can swallow the next
+    /// legitimate flag. This mode scopes hyphen acceptance to the first value of each
+    /// occurrence (`--expr -x+1`); any later hyphenated token is parsed as a flag again.
+    ///
+    /// Implies [`Arg::allow_hyphen_values`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("expr")
+    ///         .long("expr")
+    ///         .multiple_values(true)
+    ///         .allow_hyphen_values_once(true))
+    ///     .arg(Arg::new("verbose").short('v'))
+    ///     .get_matches_from(vec!["prog", "--expr", "-x+1", "y", "-v"]);
+    /// assert_eq!(
+    ///     m.values_of("expr").unwrap().collect::<Vec<_>>(),
+    ///     ["-x+1", "y"]
+    /// );
+    /// assert!(m.is_present("verbose"));
+    /// ```
+    #[must_use]
+    pub fn allow_hyphen_values_once(mut self, yes: bool) -> Self {
+        self.allow_hyphen_values_once = yes;
+        self.allow_hyphen_values(yes).takes_value(true)
+    }
+
     /// Validates the argument via the given regular expression.
     ///
     /// As regular expressions are not very user friendly, the additional `err_message` should
@@ -2172,6 +2559,51 @@ impl<'help> Arg<'help> {
         self.takes_value(true).use_value_delimiter(true)
     }
 
+    /// Treat each value as a `KEY=VAL` pair collected into an ordered map.
+    ///
+    /// Every value must contain a `=` with a non-empty key; malformed pairs and
+    /// duplicate keys are rejected with [`ErrorKind::ValueValidation`].  The
+    /// accumulated pairs are available in command-line order via
+    /// [`ArgMatches::get_map`].
+    ///
+    /// **NOTE:** This implies [`Arg::takes_value(true)`] and
+    /// [`Arg::multiple_occurrences(true)`], as maps are typically built up one
+    /// `--define KEY=VAL` at a time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let app = App::new("prog")
+    ///     .arg(Arg::new("define")
+    ///         .long("define")
+    ///         .key_value_map(true));
+    ///
+    /// let m = app.clone().get_matches_from(vec![
+    ///     "prog", "--define", "opt=3", "--define", "debug=false"
+    /// ]);
+    /// let map = m.get_map("define").unwrap();
+    /// assert_eq!(map.get("opt"), Some(&"3"));
+    ///
+    /// let res = app.try_get_matches_from(vec!["prog", "--define", "nopair"]);
+    /// assert_eq!(res.unwrap_err().kind(), ErrorKind::ValueValidation);
+    /// ```
+    /// [`ErrorKind::ValueValidation`]: crate::ErrorKind::ValueValidation
+    /// [`ArgMatches::get_map`]: crate::ArgMatches::get_map()
+    /// [`Arg::takes_value(true)`]: Arg::takes_value()
+    /// [`Arg::multiple_occurrences(true)`]: Arg::multiple_occurrences()
+    #[inline]
+    #[must_use]
+    pub fn key_value_map(self, yes: bool) -> Self {
+        if yes {
+            self.setting(ArgSettings::KeyValueMap)
+                .takes_value(true)
+                .multiple_occurrences(true)
+        } else {
+            self.unset_setting(ArgSettings::KeyValueMap)
+        }
+    }
+
     /// Specifies that *multiple values* may only be set using the delimiter.
     ///
     /// This means if an option is encountered, and no delimiter is found, it is assumed that no
@@ -3282,6 +3714,93 @@ impl<'help> Arg<'help> {
             self.unset_setting(ArgSettings::HiddenLongHelp)
         }
     }
+
+    /// Hides an argument from help output unless the verbose help tier is requested.
+    ///
+    /// The verbose tier is shown when the user passes the help flag twice (e.g.
+    /// `--help --help` or `-h -h`) or passes the flag configured with
+    /// [`App::help_all_flag`]. It also reveals arguments marked with
+    /// [`Arg::hide_short_help`], enabling progressive disclosure of expert
+    /// options.
+    ///
+    /// **NOTE:** This does **not** hide the argument from usage strings on error
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("internal-timeout")
+    ///         .long("internal-timeout")
+    ///         .hide_unless_verbose(true)
+    ///         .help("Expert-only escape hatch"))
+    ///     .get_matches_from(vec![
+    ///         "prog"
+    ///     ]);
+    /// ```
+    ///
+    /// With the above, `--internal-timeout` is absent from both `-h` and `--help`
+    /// but appears when the user runs `prog --help --help`.
+    ///
+    /// [`App::help_all_flag`]: crate::App::help_all_flag
+    #[inline]
+    #[must_use]
+    pub fn hide_unless_verbose(mut self, yes: bool) -> Self {
+        self.hide_unless_verbose = yes;
+        self
+    }
+
+    /// Exempts this argument from long-flag abbreviation.
+    ///
+    /// Under [`AppSettings::InferLongArgs`][crate::AppSettings::InferLongArgs] every
+    /// unique prefix of a long flag resolves to it. For flags where running off an
+    /// abbreviation is dangerous (e.g. `--force`), this requires the long to be
+    /// spelled out in full; prefixes of it neither match nor count toward
+    /// ambiguity.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, AppSettings, Arg, ErrorKind};
+    /// let res = App::new("prog")
+    ///     .setting(AppSettings::InferLongArgs)
+    ///     .arg(Arg::new("force").long("force").no_abbreviation(true))
+    ///     .try_get_matches_from(vec!["prog", "--for"]);
+    /// assert_eq!(res.unwrap_err().kind(), ErrorKind::UnknownArgument);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn no_abbreviation(mut self, yes: bool) -> Self {
+        self.no_abbreviation = yes;
+        self
+    }
+
+    /// Validates path-typed [value hints][Arg::value_hint] at parse time.
+    ///
+    /// By default a [`ValueHint`][crate::ValueHint] only informs shell
+    /// completion. With this set, [`ExistingFile`][crate::ValueHint::ExistingFile],
+    /// [`ExistingDir`][crate::ValueHint::ExistingDir], and
+    /// [`CreatableFile`][crate::ValueHint::CreatableFile] additionally reject
+    /// values whose path does not satisfy the hint with an
+    /// [`ErrorKind::ValueValidation`][crate::ErrorKind::ValueValidation] error.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind, ValueHint};
+    /// let res = App::new("prog")
+    ///     .arg(Arg::new("input")
+    ///         .value_hint(ValueHint::ExistingFile)
+    ///         .validate_hint(true))
+    ///     .try_get_matches_from(vec!["prog", "/no/such/file"]);
+    /// assert_eq!(res.unwrap_err().kind(), ErrorKind::ValueValidation);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn validate_hint(mut self, yes: bool) -> Self {
+        self.validate_hint = yes;
+        self
+    }
 }
 
 /// Advanced argument relations
@@ -3601,6 +4120,75 @@ impl<'help> Arg<'help> {
         self
     }
 
+    /// Specifies a function that computes the default value from the other
+    /// arguments at runtime.
+    ///
+    /// The function is called after parsing, but only when the user didn't
+    /// provide a value themselves (or via [`Arg::env`]). It receives the
+    /// [`ArgMatches`] built so far and returns the default to use, or `None`
+    /// to fall back to [`Arg::default_value`] (if any). This generalizes
+    /// [`Arg::default_value_if`]: the default can depend on several other
+    /// arguments or be computed from their values.
+    ///
+    /// **NOTE:** [`Arg::default_value_if`] conditions are checked first; the
+    /// function only runs when none of them applied.
+    ///
+    /// **NOTE:** This implicitly sets [`Arg::takes_value(true)`].
+    ///
+    /// # Examples
+    ///
+    /// Derive the default output path from the input path:
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("input")
+    ///         .required(true))
+    ///     .arg(Arg::new("output")
+    ///         .long("output")
+    ///         .default_value_fn(|m| {
+    ///             m.value_of("input").map(|input| format!("{}.out", input).into())
+    ///         }))
+    ///     .get_matches_from(vec![
+    ///         "prog", "in.txt"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("output"), Some("in.txt.out"));
+    /// ```
+    ///
+    /// A value provided at runtime wins over the computed default:
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("input")
+    ///         .required(true))
+    ///     .arg(Arg::new("output")
+    ///         .long("output")
+    ///         .default_value_fn(|m| {
+    ///             m.value_of("input").map(|input| format!("{}.out", input).into())
+    ///         }))
+    ///     .get_matches_from(vec![
+    ///         "prog", "in.txt", "--output", "custom.txt"
+    ///     ]);
+    ///
+    /// assert_eq!(m.value_of("output"), Some("custom.txt"));
+    /// ```
+    ///
+    /// [`Arg::takes_value(true)`]: Arg::takes_value()
+    /// [`Arg::default_value`]: Arg::default_value()
+    /// [`Arg::default_value_if`]: Arg::default_value_if()
+    /// [`Arg::env`]: Arg::env()
+    /// [`ArgMatches`]: crate::ArgMatches
+    #[must_use]
+    pub fn default_value_fn<F>(mut self, f: F) -> Self
+    where
+        F: Fn(&ArgMatches) -> Option<OsString> + Send + Sync + 'help,
+    {
+        self.default_value_fn = Some(Arc::new(f));
+        self.takes_value(true)
+    }
+
     /// Set this arg as [required] as long as the specified argument is not present at runtime.
     ///
     /// **Pro Tip:** Using `Arg::required_unless_present` implies [`Arg::required`] and is therefore not
@@ -4747,6 +5335,31 @@ impl<'help> Arg<'help> {
         self.is_set(ArgSettings::HidePossibleValues)
     }
 
+    /// Get the prompt text specified via [`Arg::prompt`]
+    pub fn get_prompt(&self) -> Option<&'help str> {
+        self.prompt
+    }
+
+    /// Get the range specified via [`Arg::value_range`]
+    pub fn get_value_range(&self) -> Option<ValueRange> {
+        self.value_range
+    }
+
+    /// Report whether [`Arg::saturating_occurrences`] is set
+    pub fn is_saturating_occurrences_set(&self) -> bool {
+        self.is_set(ArgSettings::SaturatingOccurrences)
+    }
+
+    /// Report whether [`Arg::negatable`] is set
+    pub fn is_negatable_set(&self) -> bool {
+        self.is_set(ArgSettings::Negatable)
+    }
+
+    /// Report whether [`Arg::key_value_map`] is set
+    pub fn is_key_value_map_set(&self) -> bool {
+        self.is_set(ArgSettings::KeyValueMap)
+    }
+
     /// Report whether [`Arg::hide_env`] is set
     #[cfg(feature = "env")]
     pub fn is_hide_env_set(&self) -> bool {
@@ -4769,6 +5382,21 @@ impl<'help> Arg<'help> {
         self.is_set(ArgSettings::HiddenLongHelp)
     }
 
+    /// Report whether [`Arg::hide_unless_verbose`] is set
+    pub fn is_hide_unless_verbose_set(&self) -> bool {
+        self.hide_unless_verbose
+    }
+
+    /// Report whether [`Arg::no_abbreviation`] is set
+    pub fn is_no_abbreviation_set(&self) -> bool {
+        self.no_abbreviation
+    }
+
+    /// Report whether [`Arg::validate_hint`] is set
+    pub fn is_validate_hint_set(&self) -> bool {
+        self.validate_hint
+    }
+
     /// Report whether [`Arg::use_value_delimiter`] is set
     pub fn is_use_value_delimiter_set(&self) -> bool {
         self.is_set(ArgSettings::UseValueDelimiter)
@@ -5070,6 +5698,14 @@ impl<'help> Arg<'help> {
         }
     }
 
+    // The value name shown when `value_name` wasn't set, after any casing policy
+    pub(crate) fn default_val_name(&self) -> Cow<'help, str> {
+        match self.val_name_casing {
+            Some(casing) => casing.apply(self.name),
+            None => Cow::Borrowed(self.name),
+        }
+    }
+
     // Used for positionals when printing
     pub(crate) fn name_no_brackets(&self) -> Cow<str> {
         debug!("Arg::name_no_brackets:{}", self.name);
@@ -5095,7 +5731,7 @@ impl<'help> Arg<'help> {
             }
         } else {
             debug!("Arg::name_no_brackets: just name");
-            Cow::Borrowed(self.name)
+            self.default_val_name()
         }
     }
 
@@ -5107,6 +5743,52 @@ impl<'help> Arg<'help> {
     pub(crate) fn get_display_order(&self) -> usize {
         self.disp_ord.get_explicit()
     }
+
+    /// Whether two args are interchangeable definitions, used when merging args shared by
+    /// every subcommand into the parent.  Validators and transforms can't be compared, so
+    /// args carrying either are never considered identical.
+    pub(crate) fn same_definition_as(&self, other: &Arg<'help>) -> bool {
+        self.id == other.id
+            && self.name == other.name
+            && self.short == other.short
+            && self.long == other.long
+            && self.aliases == other.aliases
+            && self.short_aliases == other.short_aliases
+            && self.help == other.help
+            && self.long_help == other.long_help
+            && self.settings == other.settings
+            && self.num_vals == other.num_vals
+            && self.val_names == other.val_names
+            && self.val_name_casing == other.val_name_casing
+            && self.possible_vals == other.possible_vals
+            && self.val_delim == other.val_delim
+            && self.default_vals == other.default_vals
+            && self.index == other.index
+            && self.value_hint == other.value_hint
+            && self.validator.is_none()
+            && other.validator.is_none()
+            && self.validator_os.is_none()
+            && other.validator_os.is_none()
+            && self.value_parser.is_none()
+            && other.value_parser.is_none()
+            && self.value_transforms.is_empty()
+            && other.value_transforms.is_empty()
+    }
+
+    pub(crate) fn is_canonicalize_set(&self) -> bool {
+        self.canonicalize
+    }
+
+    pub(crate) fn is_allow_hyphen_values_once_set(&self) -> bool {
+        self.allow_hyphen_values_once
+    }
+
+    pub(crate) fn transform_value(&self, mut val: OsString) -> OsString {
+        for transform in &self.value_transforms {
+            val = transform.apply(val);
+        }
+        val
+    }
 }
 
 impl<'help> From<&'_ Arg<'help>> for Arg<'help> {
@@ -5139,7 +5821,11 @@ impl<'help> Display for Arg<'help> {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         // Write the name such --long or -l
         if let Some(l) = self.long {
-            write!(f, "--{}", l)?;
+            if self.is_negatable_set() {
+                write!(f, "--[no-]{}", l)?;
+            } else {
+                write!(f, "--{}", l)?;
+            }
         } else if let Some(s) = self.short {
             write!(f, "-{}", s)?;
         }
@@ -5197,6 +5883,7 @@ impl<'help> fmt::Debug for Arg<'help> {
             .field("disp_ord", &self.disp_ord)
             .field("possible_vals", &self.possible_vals)
             .field("val_names", &self.val_names)
+            .field("val_name_casing", &self.val_name_casing)
             .field("num_vals", &self.num_vals)
             .field("max_vals", &self.max_vals)
             .field("min_vals", &self.min_vals)
@@ -5208,14 +5895,25 @@ impl<'help> fmt::Debug for Arg<'help> {
                 "validator_os",
                 &self.validator_os.as_ref().map_or("None", |_| "Some(FnMut)"),
             )
+            .field("value_parser", &self.value_parser)
+            .field("value_transforms", &self.value_transforms)
             .field("val_delim", &self.val_delim)
             .field("default_vals", &self.default_vals)
             .field("default_vals_ifs", &self.default_vals_ifs)
+            .field(
+                "default_value_fn",
+                &self
+                    .default_value_fn
+                    .as_ref()
+                    .map_or("None", |_| "Some(Fn)"),
+            )
             .field("terminator", &self.terminator)
             .field("index", &self.index)
             .field("help_heading", &self.help_heading)
             .field("value_hint", &self.value_hint)
-            .field("default_missing_vals", &self.default_missing_vals);
+            .field("default_missing_vals", &self.default_missing_vals)
+            .field("prompt", &self.prompt)
+            .field("value_range", &self.value_range);
 
         #[cfg(feature = "env")]
         {
@@ -5228,6 +5926,76 @@ impl<'help> fmt::Debug for Arg<'help> {
 
 type Validator<'a> = dyn FnMut(&str) -> Result<(), Box<dyn Error + Send + Sync>> + Send + 'a;
 type ValidatorOs<'a> = dyn FnMut(&OsStr) -> Result<(), Box<dyn Error + Send + Sync>> + Send + 'a;
+type DefaultValueFn<'a> = dyn Fn(&ArgMatches) -> Option<OsString> + Send + Sync + 'a;
+
+/// Integer range accepted by an argument, set via [`Arg::value_range`].
+///
+/// Exclusive bounds are normalized to their inclusive equivalents at
+/// construction time, so `1..3` and `1..=2` compare equal.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ValueRange {
+    start: Option<i64>,
+    end: Option<i64>,
+}
+
+impl ValueRange {
+    pub(crate) fn new<R>(range: &R) -> Self
+    where
+        R: std::ops::RangeBounds<i64>,
+    {
+        use std::ops::Bound;
+        ValueRange {
+            start: match range.start_bound() {
+                Bound::Included(&start) => Some(start),
+                Bound::Excluded(&start) => Some(start.saturating_add(1)),
+                Bound::Unbounded => None,
+            },
+            end: match range.end_bound() {
+                Bound::Included(&end) => Some(end),
+                Bound::Excluded(&end) => Some(end.saturating_sub(1)),
+                Bound::Unbounded => None,
+            },
+        }
+    }
+
+    /// The smallest accepted value, if bounded below.
+    pub fn start(&self) -> Option<i64> {
+        self.start
+    }
+
+    /// The largest accepted value, if bounded above.
+    pub fn end(&self) -> Option<i64> {
+        self.end
+    }
+
+    /// Whether `val` falls inside the range.
+    pub fn contains(&self, val: i64) -> bool {
+        self.start.map_or(true, |start| val >= start) && self.end.map_or(true, |end| val <= end)
+    }
+
+    /// Human-readable description of the accepted values, used in error messages.
+    pub(crate) fn describe(&self) -> String {
+        match (self.start, self.end) {
+            (Some(start), Some(end)) => format!("between {} and {}", start, end),
+            (Some(start), None) => format!("at least {}", start),
+            (None, Some(end)) => format!("at most {}", end),
+            (None, None) => "an integer".to_owned(),
+        }
+    }
+}
+
+impl Display for ValueRange {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if let Some(start) = self.start {
+            write!(f, "{}", start)?;
+        }
+        f.write_str("..")?;
+        if let Some(end) = self.end {
+            write!(f, "={}", end)?;
+        }
+        Ok(())
+    }
+}
 
 #[derive(Debug, Clone, Eq, PartialEq)]
 pub(crate) enum ArgProvider {
@@ -5285,7 +6053,7 @@ where
         }
     } else if let Some(num_vals) = arg.num_vals {
         // If number_of_values is specified, display the value multiple times.
-        let arg_name = format!("<{}>", arg.name);
+        let arg_name = format!("<{}>", arg.default_val_name());
         for n in 1..=num_vals {
             write(&arg_name, true)?;
             if n != num_vals {
@@ -5294,14 +6062,14 @@ where
         }
     } else if arg.is_positional() {
         // Value of positional argument with no num_vals and val_names.
-        write(&format!("<{}>", arg.name), true)?;
+        write(&format!("<{}>", arg.default_val_name()), true)?;
 
         if mult_val || mult_occ {
             write("...", true)?;
         }
     } else {
         // value of flag argument with no num_vals and val_names.
-        write(&format!("<{}>", arg.name), true)?;
+        write(&format!("<{}>", arg.default_val_name()), true)?;
         if mult_val {
             write("...", true)?;
         }
@@ -86,6 +86,8 @@ pub struct ArgGroup<'help> {
     pub(crate) requires: Vec<Id>,
     pub(crate) conflicts: Vec<Id>,
     pub(crate) multiple: bool,
+    pub(crate) at_least: Option<usize>,
+    pub(crate) at_most: Option<usize>,
 }
 
 impl<'help> ArgGroup<'help> {
@@ -279,6 +281,104 @@ impl<'help> ArgGroup<'help> {
         self
     }
 
+    /// Require exactly `n` arguments from the group to be present.
+    ///
+    /// This implies [`ArgGroup::required`] and, for `n > 1`,
+    /// [`ArgGroup::multiple`].  Errors name the group's arguments and the
+    /// count that was expected.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ArgGroup, ErrorKind};
+    /// let result = App::new("myprog")
+    ///     .arg(Arg::new("major").long("major"))
+    ///     .arg(Arg::new("minor").long("minor"))
+    ///     .arg(Arg::new("patch").long("patch"))
+    ///     .group(ArgGroup::new("vers")
+    ///         .args(&["major", "minor", "patch"])
+    ///         .required_count(2))
+    ///     .try_get_matches_from(vec!["myprog", "--major"]);
+    /// // Only one argument of the group was used
+    /// assert!(result.is_err());
+    /// let err = result.unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::InvalidGroupCount);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn required_count(mut self, n: usize) -> Self {
+        self.at_least = Some(n);
+        self.at_most = Some(n);
+        self.required = n >= 1;
+        if n > 1 {
+            self.multiple = true;
+        }
+        self
+    }
+
+    /// Require at least `n` arguments from the group to be present.
+    ///
+    /// This implies [`ArgGroup::required`] and, for `n > 1`,
+    /// [`ArgGroup::multiple`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ArgGroup, ErrorKind};
+    /// let result = App::new("myprog")
+    ///     .arg(Arg::new("major").long("major"))
+    ///     .arg(Arg::new("minor").long("minor"))
+    ///     .arg(Arg::new("patch").long("patch"))
+    ///     .group(ArgGroup::new("vers")
+    ///         .args(&["major", "minor", "patch"])
+    ///         .at_least(2))
+    ///     .try_get_matches_from(vec!["myprog", "--major", "--minor"]);
+    /// assert!(result.is_ok());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn at_least(mut self, n: usize) -> Self {
+        self.at_least = Some(n);
+        self.required = n >= 1;
+        if n > 1 {
+            self.multiple = true;
+        }
+        self
+    }
+
+    /// Allow at most `n` arguments from the group to be present.
+    ///
+    /// For `n > 1` this implies [`ArgGroup::multiple`]; the group stays
+    /// optional unless combined with [`ArgGroup::required`] or
+    /// [`ArgGroup::at_least`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ArgGroup, ErrorKind};
+    /// let result = App::new("myprog")
+    ///     .arg(Arg::new("major").long("major"))
+    ///     .arg(Arg::new("minor").long("minor"))
+    ///     .arg(Arg::new("patch").long("patch"))
+    ///     .group(ArgGroup::new("vers")
+    ///         .args(&["major", "minor", "patch"])
+    ///         .at_most(2))
+    ///     .try_get_matches_from(vec!["myprog", "--major", "--minor", "--patch"]);
+    /// // More arguments of the group than allowed were used
+    /// assert!(result.is_err());
+    /// let err = result.unwrap_err();
+    /// assert_eq!(err.kind(), ErrorKind::InvalidGroupCount);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn at_most(mut self, n: usize) -> Self {
+        self.at_most = Some(n);
+        if n > 1 {
+            self.multiple = true;
+        }
+        self
+    }
+
     /// Specify an argument or group that must be present when this group is.
     ///
     /// This is not to be confused with a [required group]. Requirement rules function just like
@@ -461,6 +561,8 @@ impl<'help> From<&'_ ArgGroup<'help>> for ArgGroup<'help> {
             requires: g.requires.clone(),
             conflicts: g.conflicts.clone(),
             multiple: g.multiple,
+            at_least: g.at_least,
+            at_most: g.at_most,
         }
     }
 }
@@ -619,6 +721,8 @@ impl Clone for ArgGroup<'_> {
             requires: self.requires.clone(),
             conflicts: self.conflicts.clone(),
             multiple: self.multiple,
+            at_least: self.at_least,
+            at_most: self.at_most,
         }
     }
 }
@@ -191,6 +191,25 @@ pub enum ArgSettings {
         note = "Replaced with `Arg::exclusive` and `Arg::is_exclusive_set`"
     )]
     Exclusive,
+    /// Silently cap the occurrence count at [`Arg::max_occurrences`] instead of erroring.
+    ///
+    /// Set via [`Arg::saturating_occurrences`].
+    ///
+    /// [`Arg::max_occurrences`]: crate::Arg::max_occurrences()
+    /// [`Arg::saturating_occurrences`]: crate::Arg::saturating_occurrences()
+    SaturatingOccurrences,
+    /// Also accept the flag as `--no-<long>` to unset it.
+    ///
+    /// Set via [`Arg::negatable`].
+    ///
+    /// [`Arg::negatable`]: crate::Arg::negatable()
+    Negatable,
+    /// Treat each value as a `KEY=VAL` pair collected into an ordered map.
+    ///
+    /// Set via [`Arg::key_value_map`].
+    ///
+    /// [`Arg::key_value_map`]: crate::Arg::key_value_map()
+    KeyValueMap,
 }
 
 bitflags! {
@@ -221,6 +240,9 @@ bitflags! {
         const HIDE_ENV         = 1 << 21;
         const UTF8_NONE        = 1 << 22;
         const EXCLUSIVE        = 1 << 23;
+        const SATURATING_OCC   = 1 << 25;
+        const NEGATABLE        = 1 << 26;
+        const KEY_VALUE_MAP    = 1 << 27;
         const NO_OP            = 0;
     }
 }
@@ -252,7 +274,10 @@ impl_settings! { ArgSettings, ArgFlags,
     HiddenShortHelp => Flags::HIDDEN_SHORT_H,
     HiddenLongHelp => Flags::HIDDEN_LONG_H,
     AllowInvalidUtf8 => Flags::UTF8_NONE,
-    Exclusive => Flags::EXCLUSIVE
+    Exclusive => Flags::EXCLUSIVE,
+    SaturatingOccurrences => Flags::SATURATING_OCC,
+    Negatable => Flags::NEGATABLE,
+    KeyValueMap => Flags::KEY_VALUE_MAP
 }
 
 /// Deprecated in [Issue #3087](https://github.com/clap-rs/clap/issues/3087), maybe [`clap::Parser`][crate::Parser] would fit your use case?
@@ -290,6 +315,9 @@ impl FromStr for ArgSettings {
             "hiddenlonghelp" => Ok(ArgSettings::HiddenLongHelp),
             "allowinvalidutf8" => Ok(ArgSettings::AllowInvalidUtf8),
             "exclusive" => Ok(ArgSettings::Exclusive),
+            "saturatingoccurrences" => Ok(ArgSettings::SaturatingOccurrences),
+            "negatable" => Ok(ArgSettings::Negatable),
+            "keyvaluemap" => Ok(ArgSettings::KeyValueMap),
             _ => Err(format!("unknown AppSetting: `{}`", s)),
         }
     }
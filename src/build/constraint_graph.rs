@@ -0,0 +1,267 @@
+use std::ffi::OsStr;
+use std::fmt::Write as _;
+
+use crate::build::arg_predicate::ArgPredicate;
+use crate::util::Id;
+use crate::App;
+
+/// A participant in the constraint graph: an argument or a group.
+///
+/// See [`App::constraint_graph`].
+///
+/// [`App::constraint_graph`]: crate::App::constraint_graph()
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum ConstraintNode<'help> {
+    /// An [`Arg`], identified by its id
+    ///
+    /// [`Arg`]: crate::Arg
+    Arg(&'help str),
+    /// An [`ArgGroup`], identified by its name
+    ///
+    /// [`ArgGroup`]: crate::ArgGroup
+    Group(&'help str),
+}
+
+impl<'help> ConstraintNode<'help> {
+    /// The argument id or group name
+    pub fn name(&self) -> &'help str {
+        match self {
+            Self::Arg(name) | Self::Group(name) => name,
+        }
+    }
+}
+
+/// The relationship a [`ConstraintEdge`] expresses.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ConstraintKind<'help> {
+    /// The source requires the target to also be present
+    ///
+    /// Produced by [`Arg::requires`] and [`ArgGroup::requires`].
+    ///
+    /// [`Arg::requires`]: crate::Arg::requires()
+    /// [`ArgGroup::requires`]: crate::ArgGroup::requires()
+    Requires,
+    /// The source requires the target when the source equals this value
+    ///
+    /// Produced by [`Arg::requires_if`].
+    ///
+    /// [`Arg::requires_if`]: crate::Arg::requires_if()
+    RequiresIfValue(&'help OsStr),
+    /// The source is required when the target equals this value
+    ///
+    /// Produced by [`Arg::required_if_eq`] and [`Arg::required_if_eq_all`].
+    ///
+    /// [`Arg::required_if_eq`]: crate::Arg::required_if_eq()
+    /// [`Arg::required_if_eq_all`]: crate::Arg::required_if_eq_all()
+    RequiredIfValue(&'help OsStr),
+    /// The source is required unless the target is present
+    ///
+    /// Produced by [`Arg::required_unless_present`] and its `_all`/`_any`
+    /// variants.
+    ///
+    /// [`Arg::required_unless_present`]: crate::Arg::required_unless_present()
+    RequiredUnlessPresent,
+    /// The source cannot be used together with the target
+    ///
+    /// Produced by [`Arg::conflicts_with`] and [`ArgGroup::conflicts`].
+    ///
+    /// [`Arg::conflicts_with`]: crate::Arg::conflicts_with()
+    /// [`ArgGroup::conflicts`]: crate::ArgGroup::conflicts()
+    Conflicts,
+    /// The source group contains the target argument
+    ///
+    /// Produced by [`ArgGroup::arg`] and [`Arg::group`].
+    ///
+    /// [`ArgGroup::arg`]: crate::ArgGroup::arg()
+    /// [`Arg::group`]: crate::Arg::group()
+    GroupMember,
+}
+
+/// A single resolved constraint between two [`ConstraintNode`]s.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct ConstraintEdge<'help> {
+    source: ConstraintNode<'help>,
+    target: ConstraintNode<'help>,
+    kind: ConstraintKind<'help>,
+}
+
+impl<'help> ConstraintEdge<'help> {
+    /// The node the constraint originates from
+    pub fn source(&self) -> ConstraintNode<'help> {
+        self.source
+    }
+
+    /// The node the constraint points at
+    pub fn target(&self) -> ConstraintNode<'help> {
+        self.target
+    }
+
+    /// The relationship between [`source`] and [`target`]
+    ///
+    /// [`source`]: ConstraintEdge::source()
+    /// [`target`]: ConstraintEdge::target()
+    pub fn kind(&self) -> ConstraintKind<'help> {
+        self.kind
+    }
+}
+
+/// The fully-resolved requires/conflicts/groups web of one command.
+///
+/// Obtained from [`App::constraint_graph`]; query it directly or render it with
+/// [`ConstraintGraph::to_dot`] for visualization.
+///
+/// [`App::constraint_graph`]: crate::App::constraint_graph()
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ConstraintGraph<'help> {
+    name: String,
+    nodes: Vec<ConstraintNode<'help>>,
+    edges: Vec<ConstraintEdge<'help>>,
+}
+
+impl<'help> ConstraintGraph<'help> {
+    pub(crate) fn from_app(app: &App<'help>) -> Self {
+        let mut graph = ConstraintGraph {
+            name: app.get_name().to_string(),
+            nodes: Vec::new(),
+            edges: Vec::new(),
+        };
+
+        for arg in app.args.args() {
+            let source = ConstraintNode::Arg(arg.name);
+
+            for id in &arg.blacklist {
+                graph.push(app, source, id, ConstraintKind::Conflicts);
+            }
+            for (predicate, id) in &arg.requires {
+                let kind = match predicate {
+                    ArgPredicate::IsPresent => ConstraintKind::Requires,
+                    ArgPredicate::Equals(val) => ConstraintKind::RequiresIfValue(val),
+                };
+                graph.push(app, source, id, kind);
+            }
+            for (id, val) in arg.r_ifs.iter().chain(arg.r_ifs_all.iter()) {
+                let kind = ConstraintKind::RequiredIfValue(OsStr::new(*val));
+                graph.push(app, source, id, kind);
+            }
+            for id in arg.r_unless.iter().chain(arg.r_unless_all.iter()) {
+                graph.push(app, source, id, ConstraintKind::RequiredUnlessPresent);
+            }
+        }
+
+        for group in &app.groups {
+            let source = ConstraintNode::Group(group.name);
+
+            for id in &group.args {
+                graph.push(app, source, id, ConstraintKind::GroupMember);
+            }
+            for id in &group.requires {
+                graph.push(app, source, id, ConstraintKind::Requires);
+            }
+            for id in &group.conflicts {
+                graph.push(app, source, id, ConstraintKind::Conflicts);
+            }
+        }
+
+        graph
+    }
+
+    fn push(&mut self, app: &App<'help>, source: ConstraintNode<'help>, target: &Id, kind: ConstraintKind<'help>) {
+        let target = if let Some(arg) = app.find(target) {
+            ConstraintNode::Arg(arg.name)
+        } else if let Some(group) = app.groups.iter().find(|g| g.id == *target) {
+            ConstraintNode::Group(group.name)
+        } else {
+            // Unknown ids are reported by the debug assertions; skip them here
+            // so release builds still produce a graph
+            return;
+        };
+
+        for node in [source, target] {
+            if !self.nodes.contains(&node) {
+                self.nodes.push(node);
+            }
+        }
+        self.edges.push(ConstraintEdge {
+            source,
+            target,
+            kind,
+        });
+    }
+
+    /// All arguments and groups that participate in at least one constraint
+    pub fn nodes(&self) -> &[ConstraintNode<'help>] {
+        &self.nodes
+    }
+
+    /// All resolved constraints
+    pub fn edges(&self) -> &[ConstraintEdge<'help>] {
+        &self.edges
+    }
+
+    /// The constraints originating from the argument or group with this name
+    pub fn edges_from<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a ConstraintEdge<'help>> {
+        self.edges.iter().filter(move |e| e.source.name() == name)
+    }
+
+    /// The constraints pointing at the argument or group with this name
+    pub fn edges_to<'a>(
+        &'a self,
+        name: &'a str,
+    ) -> impl Iterator<Item = &'a ConstraintEdge<'help>> {
+        self.edges.iter().filter(move |e| e.target.name() == name)
+    }
+
+    /// Renders the graph in DOT format for graphviz
+    ///
+    /// Arguments are drawn as ellipses and groups as boxes; conflicts are
+    /// dashed, group membership is dotted, and conditional requirements carry
+    /// the triggering value in their label.
+    pub fn to_dot(&self) -> String {
+        let mut dot = String::new();
+        let _ = writeln!(dot, "digraph \"{}\" {{", escape(&self.name));
+
+        for node in &self.nodes {
+            let shape = match node {
+                ConstraintNode::Arg(_) => "ellipse",
+                ConstraintNode::Group(_) => "box",
+            };
+            let _ = writeln!(dot, "    \"{}\" [shape={}];", escape(node.name()), shape);
+        }
+
+        for edge in &self.edges {
+            let (label, style) = match edge.kind {
+                ConstraintKind::Requires => ("requires".to_string(), "solid"),
+                ConstraintKind::RequiresIfValue(val) => {
+                    (format!("requires if ={}", val.to_string_lossy()), "solid")
+                }
+                ConstraintKind::RequiredIfValue(val) => {
+                    (format!("required if ={}", val.to_string_lossy()), "solid")
+                }
+                ConstraintKind::RequiredUnlessPresent => {
+                    ("required unless".to_string(), "solid")
+                }
+                ConstraintKind::Conflicts => ("conflicts".to_string(), "dashed"),
+                ConstraintKind::GroupMember => ("member".to_string(), "dotted"),
+            };
+            let _ = writeln!(
+                dot,
+                "    \"{}\" -> \"{}\" [label=\"{}\", style={}];",
+                escape(edge.source.name()),
+                escape(edge.target.name()),
+                escape(&label),
+                style
+            );
+        }
+
+        dot.push_str("}\n");
+        dot
+    }
+}
+
+fn escape(s: &str) -> String {
+    s.replace('\\', "\\\\").replace('"', "\\\"")
+}
@@ -52,6 +52,27 @@ pub(crate) fn assert_app(app: &App) {
         }
     }
 
+    // Subcommand names colliding with a positional's possible values
+    if app.subcommand_value_policy.is_none() {
+        for arg in app.args.args().filter(|a| a.is_positional()) {
+            for pv in &arg.possible_vals {
+                if let Some(sc) = app.subcommands.iter().find(|sc| {
+                    sc.name == pv.get_name() || sc.aliases.iter().any(|(a, _)| *a == pv.get_name())
+                }) {
+                    panic!(
+                        "App {}: Possible value '{}' of positional argument '{}' collides with \
+                        the subcommand '{}'; this is ambiguous at parse time\n\n\t\
+                        Use App::subcommand_value_policy to choose how to resolve it",
+                        app.get_name(),
+                        pv.get_name(),
+                        arg.name,
+                        sc.name,
+                    )
+                }
+            }
+        }
+    }
+
     for arg in app.args.args() {
         assert_arg(arg);
 
@@ -276,6 +297,15 @@ pub(crate) fn assert_app(app: &App) {
 
     _verify_positionals(app);
 
+    for req in &app.subcommand_required_unless {
+        assert!(
+            app.id_exists(req),
+            "App {}: Argument or group '{:?}' specified in 'subcommand_required_unless_present' does not exist",
+            app.get_name(),
+            req,
+        );
+    }
+
     if let Some(help_template) = app.template {
         assert!(
             !help_template.contains("{flags}"),
@@ -407,7 +437,6 @@ fn assert_app_flags(app: &App) {
     }
 
     checker!(is_allow_invalid_utf8_for_external_subcommands_set requires is_allow_external_subcommands_set);
-    #[cfg(feature = "unstable-multicall")]
     checker!(is_multicall_set conflicts is_no_binary_name_set);
 }
 
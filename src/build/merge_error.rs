@@ -0,0 +1,74 @@
+use std::error::Error;
+use std::fmt;
+
+/// A conflict found while combining two `App` fragments with [`App::try_merge`].
+///
+/// [`App::try_merge`]: crate::App::try_merge
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum MergeError {
+    /// Both fragments define an argument or group with the given id.
+    DuplicateArgId {
+        /// The conflicting id
+        id: String,
+    },
+
+    /// Two arguments share a long flag.
+    DuplicateLong {
+        /// The conflicting long flag (without leading `--`)
+        long: String,
+        /// Name of the argument already present
+        existing: String,
+        /// Name of the argument being merged in
+        new: String,
+    },
+
+    /// Two arguments share a short flag.
+    DuplicateShort {
+        /// The conflicting short flag (without leading `-`)
+        short: char,
+        /// Name of the argument already present
+        existing: String,
+        /// Name of the argument being merged in
+        new: String,
+    },
+
+    /// Both fragments define a subcommand with the given name (or alias).
+    DuplicateSubcommand {
+        /// The conflicting subcommand name
+        name: String,
+    },
+}
+
+impl fmt::Display for MergeError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::DuplicateArgId { id } => {
+                write!(f, "argument or group '{}' is defined in both fragments", id)
+            }
+            Self::DuplicateLong {
+                long,
+                existing,
+                new,
+            } => write!(
+                f,
+                "'--{}' is in use by both '{}' and '{}'",
+                long, existing, new
+            ),
+            Self::DuplicateShort {
+                short,
+                existing,
+                new,
+            } => write!(
+                f,
+                "'-{}' is in use by both '{}' and '{}'",
+                short, existing, new
+            ),
+            Self::DuplicateSubcommand { name } => {
+                write!(f, "subcommand '{}' is defined in both fragments", name)
+            }
+        }
+    }
+}
+
+impl Error for MergeError {}
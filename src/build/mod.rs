@@ -8,9 +8,16 @@ mod app_settings;
 mod arg_group;
 mod arg_predicate;
 mod arg_settings;
+mod constraint_graph;
+mod merge_error;
 mod possible_value;
+mod subcommand_value_policy;
 mod usage_parser;
+mod validation;
 mod value_hint;
+mod value_name_casing;
+mod value_parser;
+mod value_transform;
 
 #[cfg(feature = "regex")]
 mod regex;
@@ -25,12 +32,19 @@ mod arg_tests;
 
 pub use app::App;
 pub use app_settings::{AppFlags, AppSettings};
-pub use arg::Arg;
+pub use arg::{Arg, ValueRange};
 pub use arg_group::ArgGroup;
 pub(crate) use arg_predicate::ArgPredicate;
 pub use arg_settings::{ArgFlags, ArgSettings};
+pub use constraint_graph::{ConstraintEdge, ConstraintGraph, ConstraintKind, ConstraintNode};
+pub use merge_error::MergeError;
 pub use possible_value::PossibleValue;
+pub use subcommand_value_policy::SubcommandValuePolicy;
+pub use validation::DefinitionProblem;
 pub use value_hint::ValueHint;
+pub use value_name_casing::ValueNameCasing;
+pub use value_parser::ValueParser;
+pub use value_transform::ValueTransform;
 
 #[cfg(feature = "regex")]
 pub use self::regex::RegexRef;
@@ -28,6 +28,7 @@ use crate::util::eq_ignore_case;
 pub struct PossibleValue<'help> {
     name: &'help str,
     help: Option<&'help str>,
+    long_help: Option<&'help str>,
     aliases: Vec<&'help str>, // (name, visible)
     hide: bool,
 }
@@ -77,6 +78,28 @@ impl<'help> PossibleValue<'help> {
         self
     }
 
+    /// Sets the long help description of the value.
+    ///
+    /// In long help (`--help`), values carrying a help description are rendered as an
+    /// indented list under the argument, and this text is used instead of
+    /// [`PossibleValue::help`]; it may span multiple lines.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::PossibleValue;
+    /// PossibleValue::new("slow")
+    ///     .help("not fast")
+    ///     .long_help("Processes the input the thorough way, trading speed for accuracy")
+    /// # ;
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn long_help(mut self, long_help: &'help str) -> Self {
+        self.long_help = Some(long_help);
+        self
+    }
+
     /// Hides this value from help and shell completions.
     ///
     /// This is an alternative to hiding through [`Arg::hide_possible_values(true)`], if you only
@@ -148,6 +171,17 @@ impl<'help> PossibleValue<'help> {
         self.help
     }
 
+    /// Get the long help specified for this argument, if any
+    #[inline]
+    pub fn get_long_help(&self) -> Option<&'help str> {
+        self.long_help
+    }
+
+    /// Should this value be listed with its help in long help output?
+    pub(crate) fn should_show_help_long(&self) -> bool {
+        !self.hide && (self.help.is_some() || self.long_help.is_some())
+    }
+
     /// Deprecated, replaced with [`PossibleValue::is_hide_set`]
     #[inline]
     #[deprecated(since = "3.1.0", note = "Replaced with `PossibleValue::is_hide_set`")]
@@ -0,0 +1,21 @@
+/// Policy for tokens that match both a subcommand name and a positional's possible value.
+///
+/// When a positional argument restricts its input with
+/// [`Arg::possible_values`][crate::Arg::possible_values] and one of those values is also the
+/// name (or alias) of a subcommand, a matching token on the command line is ambiguous.
+/// Historically clap silently dispatched to the subcommand; this policy makes the choice
+/// explicit.
+///
+/// See [`App::subcommand_value_policy`][crate::App::subcommand_value_policy].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum SubcommandValuePolicy {
+    /// Dispatch to the subcommand (clap's historical behavior).
+    PreferSubcommand,
+
+    /// Consume the token as the positional's value.
+    PreferPositional,
+
+    /// Error out, explaining the ambiguity to the user.
+    Error,
+}
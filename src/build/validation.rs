@@ -0,0 +1,285 @@
+// Std
+use std::fmt;
+
+// Internal
+use crate::build::App;
+use crate::util::Id;
+
+/// A problem in an [`App`] definition, found by [`App::validate`].
+///
+/// Each variant names the offending pieces of the definition so tests can
+/// assert on exactly what went wrong instead of matching a panic message.
+///
+/// [`App::validate`]: crate::App::validate()
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DefinitionProblem {
+    /// Two arguments (or an argument and a subcommand) use the same short flag.
+    DuplicateShort {
+        /// The contested short flag.
+        short: char,
+        /// What defined the flag first.
+        first: String,
+        /// What defined it again.
+        second: String,
+    },
+
+    /// Two arguments (or an argument and a subcommand) use the same long flag
+    /// or alias.
+    DuplicateLong {
+        /// The contested long flag.
+        long: String,
+        /// What defined the flag first.
+        first: String,
+        /// What defined it again.
+        second: String,
+    },
+
+    /// Two arguments or groups share the same id.
+    DuplicateId {
+        /// The contested id.
+        id: String,
+    },
+
+    /// Two positional arguments claim the same index.
+    DuplicateIndex {
+        /// The contested index.
+        index: usize,
+        /// The positional that claimed the index first.
+        first: String,
+        /// The positional that claimed it again.
+        second: String,
+    },
+
+    /// A group names a member that isn't a defined argument.
+    UnknownGroupMember {
+        /// The group with the dangling member.
+        group: String,
+        /// The undefined member.
+        member: String,
+    },
+
+    /// A requirement, conflict, or override names an id that isn't a defined
+    /// argument or group.
+    UnknownReference {
+        /// The argument or group holding the dangling reference.
+        from: String,
+        /// The undefined id.
+        reference: String,
+    },
+}
+
+impl fmt::Display for DefinitionProblem {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            DefinitionProblem::DuplicateShort {
+                short,
+                first,
+                second,
+            } => write!(
+                f,
+                "short flag `-{}` is used by both `{}` and `{}`",
+                short, first, second
+            ),
+            DefinitionProblem::DuplicateLong {
+                long,
+                first,
+                second,
+            } => write!(
+                f,
+                "long flag `--{}` is used by both `{}` and `{}`",
+                long, first, second
+            ),
+            DefinitionProblem::DuplicateId { id } => {
+                write!(f, "id `{}` is used more than once", id)
+            }
+            DefinitionProblem::DuplicateIndex {
+                index,
+                first,
+                second,
+            } => write!(
+                f,
+                "positional index {} is used by both `{}` and `{}`",
+                index, first, second
+            ),
+            DefinitionProblem::UnknownGroupMember { group, member } => write!(
+                f,
+                "group `{}` names `{}`, which isn't a defined argument",
+                group, member
+            ),
+            DefinitionProblem::UnknownReference { from, reference } => write!(
+                f,
+                "`{}` references `{}`, which isn't a defined argument or group",
+                from, reference
+            ),
+        }
+    }
+}
+
+/// Check one level of a built `App` for definition problems. [`App::validate`]
+/// drives this over the whole command tree.
+///
+/// [`App::validate`]: crate::App::validate()
+pub(crate) fn check_app(app: &App) -> Vec<DefinitionProblem> {
+    let mut problems = Vec::new();
+
+    check_flags(app, &mut problems);
+    check_ids(app, &mut problems);
+    check_indexes(app, &mut problems);
+    check_groups(app, &mut problems);
+    check_references(app, &mut problems);
+
+    problems
+}
+
+fn check_flags(app: &App, problems: &mut Vec<DefinitionProblem>) {
+    let mut shorts: Vec<(char, String)> = Vec::new();
+    let mut longs: Vec<(String, String)> = Vec::new();
+
+    for arg in app.get_arguments() {
+        let owner = || format!("argument '{}'", arg.name);
+        for short in arg.short.iter().chain(arg.short_aliases.iter().map(|(s, _)| s)) {
+            shorts.push((*short, owner()));
+        }
+        for long in arg.long.iter().chain(arg.aliases.iter().map(|(l, _)| l)) {
+            longs.push(((*long).to_string(), owner()));
+        }
+    }
+    for sc in app.get_subcommands() {
+        let owner = || format!("subcommand '{}'", sc.get_name());
+        for short in sc
+            .short_flag
+            .iter()
+            .chain(sc.short_flag_aliases.iter().map(|(s, _)| s))
+        {
+            shorts.push((*short, owner()));
+        }
+        for long in sc
+            .long_flag
+            .iter()
+            .chain(sc.long_flag_aliases.iter().map(|(l, _)| l))
+        {
+            longs.push(((*long).to_string(), owner()));
+        }
+    }
+
+    for (i, (short, first)) in shorts.iter().enumerate() {
+        for (other, second) in &shorts[i + 1..] {
+            if short == other && first != second {
+                problems.push(DefinitionProblem::DuplicateShort {
+                    short: *short,
+                    first: first.clone(),
+                    second: second.clone(),
+                });
+            }
+        }
+    }
+    for (i, (long, first)) in longs.iter().enumerate() {
+        for (other, second) in &longs[i + 1..] {
+            if long == other && first != second {
+                problems.push(DefinitionProblem::DuplicateLong {
+                    long: long.clone(),
+                    first: first.clone(),
+                    second: second.clone(),
+                });
+            }
+        }
+    }
+}
+
+fn check_ids(app: &App, problems: &mut Vec<DefinitionProblem>) {
+    let args: Vec<_> = app.get_arguments().collect();
+    for (i, arg) in args.iter().enumerate() {
+        if args[i + 1..].iter().any(|other| other.id == arg.id) {
+            problems.push(DefinitionProblem::DuplicateId {
+                id: arg.name.to_string(),
+            });
+        }
+        if app.groups.iter().any(|g| g.id == arg.id) {
+            problems.push(DefinitionProblem::DuplicateId {
+                id: arg.name.to_string(),
+            });
+        }
+    }
+    for (i, group) in app.groups.iter().enumerate() {
+        if app.groups[i + 1..].iter().any(|other| other.id == group.id) {
+            problems.push(DefinitionProblem::DuplicateId {
+                id: group.name.to_string(),
+            });
+        }
+    }
+}
+
+fn check_indexes(app: &App, problems: &mut Vec<DefinitionProblem>) {
+    let positionals: Vec<_> = app
+        .get_arguments()
+        .filter(|arg| arg.is_positional())
+        .collect();
+    for (i, arg) in positionals.iter().enumerate() {
+        for other in &positionals[i + 1..] {
+            if arg.index.is_some() && arg.index == other.index {
+                problems.push(DefinitionProblem::DuplicateIndex {
+                    index: arg.index.expect("just checked"),
+                    first: arg.name.to_string(),
+                    second: other.name.to_string(),
+                });
+            }
+        }
+    }
+}
+
+fn check_groups(app: &App, problems: &mut Vec<DefinitionProblem>) {
+    for group in &app.groups {
+        for member in &group.args {
+            if !id_is_arg(app, member) {
+                problems.push(DefinitionProblem::UnknownGroupMember {
+                    group: group.name.to_string(),
+                    member: format!("{:?}", member),
+                });
+            }
+        }
+        for reference in group.requires.iter().chain(group.conflicts.iter()) {
+            if !id_is_defined(app, reference) {
+                problems.push(DefinitionProblem::UnknownReference {
+                    from: format!("group '{}'", group.name),
+                    reference: format!("{:?}", reference),
+                });
+            }
+        }
+    }
+}
+
+fn check_references(app: &App, problems: &mut Vec<DefinitionProblem>) {
+    for arg in app.get_arguments() {
+        let references = arg
+            .blacklist
+            .iter()
+            .chain(arg.overrides.iter())
+            .chain(arg.requires.iter().map(|(_, id)| id))
+            .chain(arg.r_ifs.iter().map(|(id, _)| id))
+            .chain(arg.r_ifs_all.iter().map(|(id, _)| id))
+            .chain(arg.r_unless.iter())
+            .chain(arg.r_unless_all.iter())
+            .chain(arg.default_vals_ifs.iter().map(|(id, _, _)| id));
+        for reference in references {
+            // Self-references come from `AllArgsOverrideSelf` and the like;
+            // they are always defined, but skip them for clarity.
+            if *reference == arg.id {
+                continue;
+            }
+            if !id_is_defined(app, reference) {
+                problems.push(DefinitionProblem::UnknownReference {
+                    from: format!("argument '{}'", arg.name),
+                    reference: format!("{:?}", reference),
+                });
+            }
+        }
+    }
+}
+
+fn id_is_arg(app: &App, id: &Id) -> bool {
+    app.get_arguments().any(|arg| arg.id == *id)
+}
+
+fn id_is_defined(app: &App, id: &Id) -> bool {
+    id_is_arg(app, id) || app.groups.iter().any(|group| group.id == *id)
+}
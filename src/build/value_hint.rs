@@ -13,6 +13,9 @@ use std::str::FromStr;
 /// | `AnyPath`              | Yes | Yes     |
 /// | `FilePath`             | Yes | Yes     |
 /// | `DirPath`              | Yes | Yes     |
+/// | `ExistingFile`         | Yes | Yes     |
+/// | `ExistingDir`          | Yes | Yes     |
+/// | `CreatableFile`        | Yes | Yes     |
 /// | `ExecutablePath`       | Yes | Partial |
 /// | `CommandName`          | Yes | Yes     |
 /// | `CommandString`        | Yes | Partial |
@@ -38,6 +41,23 @@ pub enum ValueHint {
     FilePath,
     /// Path to a directory.
     DirPath,
+    /// Path to an existing file.
+    ///
+    /// Unlike [`FilePath`][ValueHint::FilePath] this can also be validated at
+    /// parse time: with [`Arg::validate_hint(true)`][crate::Arg::validate_hint]
+    /// a value that doesn't name an existing file is rejected with an
+    /// [`ErrorKind::ValueValidation`][crate::ErrorKind::ValueValidation] error.
+    ExistingFile,
+    /// Path to an existing directory.
+    ///
+    /// Can be validated at parse time like [`ExistingFile`][ValueHint::ExistingFile].
+    ExistingDir,
+    /// Path to a file that may not exist yet, but whose parent directory must
+    /// exist and be writable.
+    ///
+    /// Can be validated at parse time like [`ExistingFile`][ValueHint::ExistingFile];
+    /// useful for output files.
+    CreatableFile,
     /// Path to an executable file.
     ExecutablePath,
     /// Name of a command, without arguments. May be relative to PATH, or full path to executable.
@@ -81,6 +101,9 @@ impl FromStr for ValueHint {
             "anypath" => ValueHint::AnyPath,
             "filepath" => ValueHint::FilePath,
             "dirpath" => ValueHint::DirPath,
+            "existingfile" => ValueHint::ExistingFile,
+            "existingdir" => ValueHint::ExistingDir,
+            "creatablefile" => ValueHint::CreatableFile,
             "executablepath" => ValueHint::ExecutablePath,
             "commandname" => ValueHint::CommandName,
             "commandstring" => ValueHint::CommandString,
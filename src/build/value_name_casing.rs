@@ -0,0 +1,77 @@
+use std::borrow::Cow;
+
+/// How a value name is derived from the argument id when [`Arg::value_name`] isn't set.
+///
+/// The chosen casing is applied consistently across help, usage, and error messages.
+/// Set it application-wide with [`App::value_name_casing`]; explicitly set value names
+/// are never transformed.
+///
+/// [`Arg::value_name`]: crate::Arg::value_name()
+/// [`App::value_name_casing`]: crate::App::value_name_casing()
+#[derive(Copy, Clone, Debug)]
+#[non_exhaustive]
+pub enum ValueNameCasing {
+    /// Use the argument id verbatim, e.g. `out_file` renders as `<out_file>`
+    AsIs,
+    /// `SCREAMING_SNAKE_CASE`, e.g. `out-file` renders as `<OUT_FILE>`
+    ScreamingSnake,
+    /// `kebab-case`, e.g. `OUT_FILE` renders as `<out-file>`
+    Kebab,
+    /// A custom transformation applied to the argument id
+    Custom(fn(&str) -> String),
+}
+
+// Function pointer comparisons aren't meaningful, so `Custom` follows the same rule as
+// validators: two custom transformations are never considered equal
+impl PartialEq for ValueNameCasing {
+    fn eq(&self, other: &Self) -> bool {
+        matches!(
+            (self, other),
+            (Self::AsIs, Self::AsIs)
+                | (Self::ScreamingSnake, Self::ScreamingSnake)
+                | (Self::Kebab, Self::Kebab)
+        )
+    }
+}
+
+// Not reflexive for `Custom`, but required by containers that derive `Eq`; the named
+// variants, which are the ones worth comparing, behave normally
+impl Eq for ValueNameCasing {}
+
+impl ValueNameCasing {
+    /// Derives the displayed value name from the argument id
+    pub(crate) fn apply<'a>(self, name: &'a str) -> Cow<'a, str> {
+        match self {
+            Self::AsIs => Cow::Borrowed(name),
+            Self::ScreamingSnake => Cow::Owned(name.replace('-', "_").to_uppercase()),
+            Self::Kebab => Cow::Owned(name.replace('_', "-").to_lowercase()),
+            Self::Custom(transform) => Cow::Owned(transform(name)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn screaming_snake() {
+        assert_eq!(
+            ValueNameCasing::ScreamingSnake.apply("out-file"),
+            "OUT_FILE"
+        );
+    }
+
+    #[test]
+    fn kebab() {
+        assert_eq!(ValueNameCasing::Kebab.apply("OUT_FILE"), "out-file");
+    }
+
+    #[test]
+    fn as_is_borrows() {
+        assert!(matches!(
+            ValueNameCasing::AsIs.apply("out_file"),
+            Cow::Borrowed("out_file")
+        ));
+    }
+}
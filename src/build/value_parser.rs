@@ -0,0 +1,183 @@
+use std::any::Any;
+use std::error::Error as StdError;
+use std::str::FromStr;
+use std::sync::Arc;
+
+use crate::util::AnyValue;
+
+/// Spellings [`ValueParser::bool`] accepts as `true`, case-insensitively
+const TRUE_LITERALS: [&str; 6] = ["y", "yes", "t", "true", "on", "1"];
+
+/// Spellings [`ValueParser::bool`] accepts as `false`, case-insensitively
+const FALSE_LITERALS: [&str; 6] = ["n", "no", "f", "false", "off", "0"];
+
+/// Parses raw argument values into a typed value at parse time.
+///
+/// Set on an argument with [`Arg::value_parser`], the parser runs while matching, so
+/// malformed values surface as [`ErrorKind::ValueValidation`] errors with the usual
+/// formatting, and [`ArgMatches::get_one`] hands back the typed result without
+/// re-parsing the string.
+///
+/// [`Arg::value_parser`]: crate::Arg::value_parser()
+/// [`ErrorKind::ValueValidation`]: crate::ErrorKind::ValueValidation
+/// [`ArgMatches::get_one`]: crate::ArgMatches::get_one()
+#[derive(Clone)]
+pub struct ValueParser {
+    parse: Arc<dyn Fn(&str) -> Result<AnyValue, Box<dyn StdError + Send + Sync>> + Send + Sync>,
+    type_name: &'static str,
+}
+
+impl ValueParser {
+    /// A parser backed by the type's [`FromStr`] implementation
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ValueParser};
+    /// let m = App::new("prog")
+    ///     .arg(
+    ///         Arg::new("port")
+    ///             .takes_value(true)
+    ///             .value_parser(ValueParser::new::<u16>()),
+    ///     )
+    ///     .get_matches_from(["prog", "8080"]);
+    ///
+    /// assert_eq!(m.get_one::<u16>("port"), Some(&8080));
+    /// ```
+    pub fn new<T>() -> Self
+    where
+        T: FromStr + Any + Send + Sync,
+        T::Err: StdError + Send + Sync + 'static,
+    {
+        Self::custom(T::from_str)
+    }
+
+    /// A parser backed by a custom function
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ValueParser};
+    /// let m = App::new("prog")
+    ///     .arg(
+    ///         Arg::new("percent")
+    ///             .takes_value(true)
+    ///             .value_parser(ValueParser::custom(|s: &str| -> Result<u8, String> {
+    ///                 let v: u8 = s.parse().map_err(|e| format!("{}", e))?;
+    ///                 if v <= 100 {
+    ///                     Ok(v)
+    ///                 } else {
+    ///                     Err(format!("{} is over 100", v))
+    ///                 }
+    ///             })),
+    ///     )
+    ///     .get_matches_from(["prog", "42"]);
+    ///
+    /// assert_eq!(m.get_one::<u8>("percent"), Some(&42));
+    /// ```
+    pub fn custom<T, E, F>(parse: F) -> Self
+    where
+        T: Any + Send + Sync,
+        E: Into<Box<dyn StdError + Send + Sync>>,
+        F: Fn(&str) -> Result<T, E> + Send + Sync + 'static,
+    {
+        ValueParser {
+            parse: Arc::new(move |raw| {
+                parse(raw).map(AnyValue::new).map_err(Into::into)
+            }),
+            type_name: std::any::type_name::<T>(),
+        }
+    }
+
+    /// A lenient boolean parser
+    ///
+    /// Unlike `ValueParser::new::<bool>()`, which only accepts `true` and `false`, this
+    /// accepts the common spellings `y`/`yes`/`t`/`true`/`on`/`1` and
+    /// `n`/`no`/`f`/`false`/`off`/`0` case-insensitively, normalizing them to a `bool`.
+    /// Anything else is rejected with an error that lists the accepted spellings.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ValueParser};
+    /// let m = App::new("prog")
+    ///     .arg(
+    ///         Arg::new("color")
+    ///             .long("color")
+    ///             .takes_value(true)
+    ///             .value_parser(ValueParser::bool()),
+    ///     )
+    ///     .get_matches_from(["prog", "--color=no"]);
+    ///
+    /// assert_eq!(m.get_one::<bool>("color"), Some(&false));
+    /// ```
+    pub fn bool() -> Self {
+        Self::custom(|raw: &str| -> Result<bool, String> {
+            let lower = raw.to_lowercase();
+            if TRUE_LITERALS.contains(&lower.as_str()) {
+                Ok(true)
+            } else if FALSE_LITERALS.contains(&lower.as_str()) {
+                Ok(false)
+            } else {
+                Err(format!(
+                    "'{}' isn't a valid boolean: use one of {} or {}",
+                    raw,
+                    TRUE_LITERALS.join(", "),
+                    FALSE_LITERALS.join(", ")
+                ))
+            }
+        })
+    }
+
+    pub(crate) fn parse(
+        &self,
+        raw: &str,
+    ) -> Result<AnyValue, Box<dyn StdError + Send + Sync>> {
+        (self.parse)(raw)
+    }
+}
+
+impl std::fmt::Debug for ValueParser {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("ValueParser")
+            .field("type", &self.type_name)
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn from_str_based() {
+        let parser = ValueParser::new::<i64>();
+        assert_eq!(
+            parser.parse("-42").unwrap().downcast_ref::<i64>(),
+            Some(&-42)
+        );
+        assert!(parser.parse("four").is_err());
+    }
+
+    #[test]
+    fn bool_spellings() {
+        let parser = ValueParser::bool();
+        assert_eq!(parser.parse("Yes").unwrap().downcast_ref::<bool>(), Some(&true));
+        assert_eq!(parser.parse("off").unwrap().downcast_ref::<bool>(), Some(&false));
+        let err = parser.parse("maybe").unwrap_err().to_string();
+        assert!(err.contains("true"), "{}", err);
+        assert!(err.contains("no"), "{}", err);
+    }
+
+    #[test]
+    fn custom_closure() {
+        let parser = ValueParser::custom(|s: &str| -> Result<usize, String> {
+            s.len().checked_sub(1).ok_or_else(|| "empty".to_string())
+        });
+        assert_eq!(
+            parser.parse("ab").unwrap().downcast_ref::<usize>(),
+            Some(&1)
+        );
+        assert_eq!(parser.parse("").unwrap_err().to_string(), "empty");
+    }
+}
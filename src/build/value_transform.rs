@@ -0,0 +1,108 @@
+use std::ffi::{OsStr, OsString};
+use std::fmt;
+use std::sync::{Arc, Mutex};
+
+type TransformFn = dyn FnMut(&OsStr) -> OsString + Send;
+
+/// A transformation applied to an argument's values before validation and storage.
+///
+/// See [`Arg::value_transform`][crate::Arg::value_transform] to set this on an argument.
+///
+/// Transforms run in the order they were added to the [`Arg`][crate::Arg]. Validation
+/// (`possible_values`, validators) sees the transformed value, while error messages still
+/// show the value as the user typed it.
+#[derive(Clone)]
+#[non_exhaustive]
+pub enum ValueTransform {
+    /// Remove leading and trailing whitespace.
+    TrimWhitespace,
+    /// Convert the value to lowercase.
+    Lowercase,
+    /// Expand a leading `~` or `~/` to the user's home directory.
+    ///
+    /// Only a tilde referring to the current user is expanded; `~other/...` is left
+    /// untouched. Values that are not valid UTF-8 are passed through unchanged.
+    #[cfg(feature = "env")]
+    ExpandTilde,
+    /// A user-provided transformation, created with [`ValueTransform::custom`].
+    Custom(Arc<Mutex<TransformFn>>),
+}
+
+impl ValueTransform {
+    /// Create a transform from a closure.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use std::ffi::OsString;
+    /// # use clap::{App, Arg, ValueTransform};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("name")
+    ///         .value_transform(ValueTransform::custom(|s| {
+    ///             let mut v = OsString::from("user-");
+    ///             v.push(s);
+    ///             v
+    ///         })))
+    ///     .get_matches_from(vec!["prog", "alice"]);
+    /// assert_eq!(m.value_of("name"), Some("user-alice"));
+    /// ```
+    pub fn custom<F>(mut f: F) -> Self
+    where
+        F: FnMut(&OsStr) -> OsString + Send + 'static,
+    {
+        Self::Custom(Arc::new(Mutex::new(move |s: &OsStr| f(s))))
+    }
+
+    pub(crate) fn apply(&self, val: OsString) -> OsString {
+        match self {
+            Self::TrimWhitespace => match val.into_string() {
+                Ok(s) => s.trim().to_string().into(),
+                Err(os) => os,
+            },
+            Self::Lowercase => match val.into_string() {
+                Ok(s) => s.to_lowercase().into(),
+                Err(os) => os,
+            },
+            #[cfg(feature = "env")]
+            Self::ExpandTilde => expand_tilde(val),
+            Self::Custom(f) => (f.lock().unwrap())(&val),
+        }
+    }
+}
+
+impl fmt::Debug for ValueTransform {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::TrimWhitespace => f.write_str("TrimWhitespace"),
+            Self::Lowercase => f.write_str("Lowercase"),
+            #[cfg(feature = "env")]
+            Self::ExpandTilde => f.write_str("ExpandTilde"),
+            Self::Custom(_) => f.write_str("Custom(FnMut)"),
+        }
+    }
+}
+
+#[cfg(feature = "env")]
+fn expand_tilde(val: OsString) -> OsString {
+    let s = match val.to_str() {
+        Some(s) => s,
+        None => return val,
+    };
+    let home = || {
+        #[cfg(windows)]
+        let home = std::env::var_os("USERPROFILE");
+        #[cfg(not(windows))]
+        let home = std::env::var_os("HOME");
+        home
+    };
+    if s == "~" {
+        if let Some(home) = home() {
+            return home;
+        }
+    } else if let Some(rest) = s.strip_prefix("~/") {
+        if let Some(home) = home() {
+            return std::path::Path::new(&home).join(rest).into_os_string();
+        }
+    }
+    val
+}
@@ -0,0 +1,134 @@
+//! Command-line length estimation and splitting.
+//!
+//! Tools that re-exec themselves or spawn child processes with user-supplied operand
+//! lists (compilers, VCS porcelain, batch runners) can exceed the platform's
+//! command-line length limit.  This module estimates that limit and splits a long
+//! operand list into multiple invocations, xargs-style.
+//!
+//! # Examples
+//!
+//! ```
+//! use clap::cmdline;
+//! use std::ffi::OsString;
+//!
+//! let prefix: Vec<OsString> = vec!["grep".into(), "-l".into(), "pattern".into()];
+//! let files: Vec<OsString> = (0..1000).map(|i| format!("file{}.txt", i).into()).collect();
+//!
+//! for chunk in cmdline::split_invocations(&prefix, &files, cmdline::platform_limit()) {
+//!     // std::process::Command::new(&prefix[0]).args(&prefix[1..]).args(chunk)...
+//!     assert!(!chunk.is_empty());
+//! }
+//! ```
+
+use std::ffi::OsString;
+
+/// A conservative estimate of the platform's maximum command-line length, in bytes.
+///
+/// This is not the exact kernel limit (which can depend on the environment size and
+/// stack limits); it is a safe value comparable to what `xargs` uses by default.
+pub fn platform_limit() -> usize {
+    if cfg!(windows) {
+        // CreateProcess is limited to 32767 UTF-16 units; leave headroom for quoting
+        32_000
+    } else {
+        // ARG_MAX is commonly 2 MiB or more on modern unixes, but it also covers the
+        // environment; 128 KiB is the conservative chunk size used by xargs
+        128 * 1024
+    }
+}
+
+/// The number of bytes `argv` occupies on the command line.
+///
+/// Each element is counted with one extra byte for its separator/terminator, matching
+/// how the kernel accounts for `argv` strings.
+pub fn cmdline_len<I, S>(argv: I) -> usize
+where
+    I: IntoIterator<Item = S>,
+    S: Into<OsString>,
+{
+    argv.into_iter().map(|arg| arg.into().len() + 1).sum()
+}
+
+/// Split `operands` into chunks such that `prefix` plus each chunk fits within `limit`.
+///
+/// Every returned chunk forms one invocation: `prefix + chunk`.  At least one operand is
+/// placed in each chunk even if it alone exceeds `limit`; such an invocation will likely
+/// fail to spawn, but silently dropping the operand would be worse.
+///
+/// Returns an empty `Vec` when `operands` is empty.
+pub fn split_invocations(
+    prefix: &[OsString],
+    operands: &[OsString],
+    limit: usize,
+) -> Vec<Vec<OsString>> {
+    let fixed = cmdline_len(prefix.iter().cloned());
+
+    let mut chunks = Vec::new();
+    let mut current = Vec::new();
+    let mut current_len = fixed;
+
+    for operand in operands {
+        let operand_len = operand.len() + 1;
+        if !current.is_empty() && current_len + operand_len > limit {
+            chunks.push(std::mem::take(&mut current));
+            current_len = fixed;
+        }
+        current_len += operand_len;
+        current.push(operand.clone());
+    }
+    if !current.is_empty() {
+        chunks.push(current);
+    }
+
+    chunks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn operands(n: usize, len: usize) -> Vec<OsString> {
+        (0..n).map(|_| "x".repeat(len).into()).collect()
+    }
+
+    #[test]
+    fn cmdline_len_counts_separators() {
+        assert_eq!(cmdline_len(["ab", "c"]), 3 + 2);
+        assert_eq!(cmdline_len(Vec::<OsString>::new()), 0);
+    }
+
+    #[test]
+    fn everything_fits_in_one_chunk() {
+        let prefix: Vec<OsString> = vec!["prog".into()];
+        let ops = operands(10, 4);
+        let chunks = split_invocations(&prefix, &ops, 1024);
+        assert_eq!(chunks.len(), 1);
+        assert_eq!(chunks[0].len(), 10);
+    }
+
+    #[test]
+    fn splits_at_the_limit() {
+        let prefix: Vec<OsString> = vec!["prog".into()]; // 5 bytes
+        let ops = operands(4, 9); // 10 bytes each
+                                  // 5 + 2 * 10 = 25 <= 28, a third operand would exceed it
+        let chunks = split_invocations(&prefix, &ops, 28);
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].len(), 2);
+        assert_eq!(chunks[1].len(), 2);
+    }
+
+    #[test]
+    fn oversized_operand_gets_its_own_chunk() {
+        let prefix: Vec<OsString> = vec!["prog".into()];
+        let ops = operands(2, 100);
+        let chunks = split_invocations(&prefix, &ops, 50);
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks[0].len(), 1);
+    }
+
+    #[test]
+    fn no_operands_no_chunks() {
+        let prefix: Vec<OsString> = vec!["prog".into()];
+        assert!(split_invocations(&prefix, &[], 1024).is_empty());
+    }
+}
@@ -10,6 +10,8 @@ pub enum ContextKind {
     PriorArg,
     /// Accepted values
     ValidValue,
+    /// Arguments an ambiguous abbreviation could resolve to
+    ValidArg,
     /// Rejected values
     InvalidValue,
     /// Number of values present
@@ -18,10 +20,14 @@ pub enum ContextKind {
     ExpectedNumValues,
     /// Minimum number of allowed values
     MinValues,
+    /// Maximum number of allowed values
+    MaxValues,
     /// Number of occurrences present
     ActualNumOccurrences,
     /// Maximum number of allowed occurrences
     MaxOccurrences,
+    /// Required multiple for the number of occurrences
+    ExpectedOccurrenceMultiple,
     /// Potential fix for the user
     SuggestedCommand,
     /// Potential fix for the user
@@ -30,8 +36,14 @@ pub enum ContextKind {
     SuggestedArg,
     /// Potential fix for the user
     SuggestedValue,
+    /// Arguments that waive the requirement behind the error
+    ExemptingArgs,
     /// Trailing argument
     TrailingArg,
+    /// The echoed command line being parsed
+    CommandLine,
+    /// Byte range of the offending token within the echoed command line
+    Span,
     /// A usage string
     Usage,
     /// An opaque message to the user
@@ -52,4 +64,6 @@ pub enum ContextValue {
     Strings(Vec<String>),
     /// A single value
     Number(isize),
+    /// A byte range
+    Range(std::ops::Range<usize>),
 }
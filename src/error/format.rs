@@ -0,0 +1,77 @@
+//! Pluggable rendering of parse errors.
+
+use crate::error::{ContextKind, ContextValue, Error};
+
+/// Renders an [`Error`] into the text shown to the user.
+///
+/// Implement this to fully control how [`ErrorKind`] and the error's
+/// [`ContextKind`]/[`ContextValue`] pairs become text — replacing the default
+/// `error:` prefix, localizing messages, or collapsing everything onto one
+/// line for logging pipelines. Install an implementation with
+/// [`App::error_formatter`].
+///
+/// Build the output from [`Error::kind`] and [`Error::context`]; calling
+/// [`Error::to_string`] from inside `format_error` would recurse back into the
+/// formatter.
+///
+/// [`ErrorKind`]: crate::ErrorKind
+/// [`App::error_formatter`]: crate::App::error_formatter()
+/// [`Error::to_string`]: std::string::ToString::to_string()
+pub trait ErrorFormatter {
+    /// Renders the error, including any trailing newline
+    fn format_error(error: &Error) -> String;
+}
+
+/// Renders only the error cause on a single line.
+///
+/// The output is `error: <cause>\n` with no usage, suggestions, or color,
+/// suited to logging pipelines:
+///
+/// ```rust
+/// # use clap::{App, Arg};
+/// use clap::error::KindFormatter;
+///
+/// let err = App::new("prog")
+///     .error_formatter::<KindFormatter>()
+///     .arg(Arg::new("debug").long("debug"))
+///     .try_get_matches_from(["prog", "--bogus"])
+///     .unwrap_err();
+/// assert_eq!(
+///     err.to_string(),
+///     "error: Found an argument which wasn't expected or isn't valid in this context\n"
+/// );
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct KindFormatter;
+
+impl ErrorFormatter for KindFormatter {
+    fn format_error(error: &Error) -> String {
+        let mut text = String::from("error: ");
+        if let Some(description) = error.kind().as_str() {
+            text.push_str(description);
+        } else if let Some(ContextValue::String(message)) =
+            error.get_context(ContextKind::Custom)
+        {
+            text.push_str(message);
+        } else {
+            text.push_str("unknown cause");
+        }
+        text.push('\n');
+        text
+    }
+}
+
+/// The hook an [`ErrorFormatter`] is stored as inside `App`.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct FormatterHook(pub(crate) fn(&Error) -> String);
+
+// Comparing function pointers isn't meaningful, so hooks follow the same rule
+// as validators: two installed formatters are never considered equal
+impl PartialEq for FormatterHook {
+    fn eq(&self, _other: &Self) -> bool {
+        false
+    }
+}
+
+// Not reflexive, but required by containers that derive `Eq`
+impl Eq for FormatterHook {}
@@ -1,5 +1,5 @@
 /// Command line argument parser kind of error
-#[derive(Debug, Copy, Clone, PartialEq)]
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
 #[non_exhaustive]
 pub enum ErrorKind {
     /// Occurs when an [`Arg`][crate::Arg] has a set of possible values,
@@ -86,6 +86,50 @@ pub enum ErrorKind {
     /// [`UnknownArgument`]: ErrorKind::UnknownArgument
     UnrecognizedSubcommand,
 
+    /// Occurs when a token matches both a [`Subcommand`] name and a possible value of a
+    /// positional argument, and [`App::subcommand_value_policy`] is set to
+    /// [`SubcommandValuePolicy::Error`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind, SubcommandValuePolicy};
+    /// let result = App::new("prog")
+    ///     .subcommand_value_policy(SubcommandValuePolicy::Error)
+    ///     .subcommand(App::new("list"))
+    ///     .arg(Arg::new("mode")
+    ///         .possible_values(["list", "tree"]))
+    ///     .try_get_matches_from(vec!["prog", "list"]);
+    /// assert!(result.is_err());
+    /// assert_eq!(result.unwrap_err().kind(), ErrorKind::AmbiguousSubcommand);
+    /// ```
+    ///
+    /// [`Subcommand`]: crate::Subcommand
+    /// [`App::subcommand_value_policy`]: crate::App::subcommand_value_policy
+    /// [`SubcommandValuePolicy::Error`]: crate::SubcommandValuePolicy::Error
+    AmbiguousSubcommand,
+
+    /// Occurs when an abbreviated long flag or inferred subcommand prefix matches more
+    /// than one candidate.
+    ///
+    /// The candidates are listed in the error's
+    /// [context][crate::Error::get_context] under
+    /// [`ContextKind::ValidArg`][crate::error::ContextKind::ValidArg].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, AppSettings, Arg, ErrorKind};
+    /// let res = App::new("prog")
+    ///     .setting(AppSettings::InferLongArgs)
+    ///     .arg(Arg::new("config").long("config"))
+    ///     .arg(Arg::new("count").long("count"))
+    ///     .try_get_matches_from(vec!["prog", "--co"]);
+    /// assert!(res.is_err());
+    /// assert_eq!(res.unwrap_err().kind(), ErrorKind::AmbiguousPrefix);
+    /// ```
+    AmbiguousPrefix,
+
     /// Occurs when the user provides an empty value for an option that does not allow empty
     /// values.
     ///
@@ -196,6 +240,66 @@ pub enum ErrorKind {
     /// [`Arg::max_occurrences`]: crate::Arg::max_occurrences()
     TooManyOccurrences,
 
+    /// Occurs when an argument's occurrences don't satisfy an occurrence-pattern rule set with
+    /// [`Arg::occurrences_multiple_of`] or [`Arg::paired_with`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let result = App::new("prog")
+    ///     .arg(Arg::new("map")
+    ///         .long("map")
+    ///         .occurrences_multiple_of(2))
+    ///     .try_get_matches_from(vec!["prog", "--map", "--map", "--map"]);
+    /// assert!(result.is_err());
+    /// assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidOccurrencePattern);
+    /// ```
+    /// [`Arg::occurrences_multiple_of`]: crate::Arg::occurrences_multiple_of()
+    /// [`Arg::paired_with`]: crate::Arg::paired_with()
+    InvalidOccurrencePattern,
+
+    /// Occurs when the number of arguments used from an [`ArgGroup`] doesn't satisfy the
+    /// group's cardinality set with [`ArgGroup::required_count`], [`ArgGroup::at_least`], or
+    /// [`ArgGroup::at_most`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ArgGroup, ErrorKind};
+    /// let result = App::new("prog")
+    ///     .arg(Arg::new("major").long("major"))
+    ///     .arg(Arg::new("minor").long("minor"))
+    ///     .arg(Arg::new("patch").long("patch"))
+    ///     .group(ArgGroup::new("vers")
+    ///         .args(&["major", "minor", "patch"])
+    ///         .required_count(2))
+    ///     .try_get_matches_from(vec!["prog", "--major"]);
+    /// assert!(result.is_err());
+    /// assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidGroupCount);
+    /// ```
+    /// [`ArgGroup`]: crate::ArgGroup
+    /// [`ArgGroup::required_count`]: crate::ArgGroup::required_count()
+    /// [`ArgGroup::at_least`]: crate::ArgGroup::at_least()
+    /// [`ArgGroup::at_most`]: crate::ArgGroup::at_most()
+    InvalidGroupCount,
+
+    /// Occurs when a line passed to [`App::try_get_matches_from_line`] can't be split into
+    /// words, because of an unclosed quote or a trailing backslash.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ErrorKind};
+    /// let result = App::new("prog")
+    ///     .arg(Arg::new("message").long("message").takes_value(true))
+    ///     .try_get_matches_from_line("prog --message 'hello");
+    /// assert!(result.is_err());
+    /// assert_eq!(result.unwrap_err().kind(), ErrorKind::InvalidLineSyntax);
+    /// ```
+    /// [`App::try_get_matches_from_line`]: crate::App::try_get_matches_from_line()
+    InvalidLineSyntax,
+
     /// Occurs when the user provides a different number of values for an argument than what's
     /// been defined by setting [`Arg::number_of_values`] or than was implicitly set by
     /// [`Arg::value_names`].
@@ -384,6 +488,28 @@ pub enum ErrorKind {
     /// [`ArgMatches::value_of_t`]: crate::ArgMatches::value_of_t()
     ArgumentNotFound,
 
+    /// Several problems were collected into one error because
+    /// [`AppSettings::CollectAllErrors`] is set.
+    ///
+    /// The individual problems are listed in the message and their context is preserved
+    /// via [`Error::context`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, AppSettings, Arg, ErrorKind};
+    /// let result = App::new("prog")
+    ///     .setting(AppSettings::CollectAllErrors)
+    ///     .arg(Arg::new("input").required(true))
+    ///     .try_get_matches_from(vec!["prog", "--bogus"]);
+    /// assert!(result.is_err());
+    /// assert_eq!(result.unwrap_err().kind(), ErrorKind::MultipleErrors);
+    /// ```
+    ///
+    /// [`AppSettings::CollectAllErrors`]: crate::AppSettings::CollectAllErrors
+    /// [`Error::context`]: crate::Error::context()
+    MultipleErrors,
+
     /// Represents an [I/O error].
     /// Can occur when writing to `stderr` or `stdout` or reading a configuration file.
     ///
@@ -408,12 +534,27 @@ impl ErrorKind {
             }
             Self::InvalidSubcommand => Some("A subcommand wasn't recognized"),
             Self::UnrecognizedSubcommand => Some("A subcommand wasn't recognized"),
+            Self::AmbiguousSubcommand => {
+                Some("A subcommand name collided with a positional's possible value")
+            }
+            Self::AmbiguousPrefix => {
+                Some("An abbreviation matched more than one argument or subcommand")
+            }
             Self::EmptyValue => Some("An argument requires a value but none was supplied"),
             Self::NoEquals => Some("Equal is needed when assigning values to one of the arguments"),
             Self::ValueValidation => Some("Invalid for for one of the arguments"),
             Self::TooManyValues => Some("An argument received an unexpected value"),
             Self::TooFewValues => Some("An argument requires more values"),
             Self::TooManyOccurrences => Some("An argument occurred too many times"),
+            Self::InvalidOccurrencePattern => {
+                Some("An argument's occurrences didn't match the required pattern")
+            }
+            Self::InvalidGroupCount => {
+                Some("An argument group was used with the wrong number of its arguments")
+            }
+            Self::InvalidLineSyntax => {
+                Some("A line couldn't be split into words before parsing")
+            }
             Self::WrongNumberOfValues => Some("An argument received too many or too few values"),
             Self::ArgumentConflict => {
                 Some("An argument cannot be used with one or more of the other specified arguments")
@@ -430,10 +571,28 @@ impl ErrorKind {
             Self::DisplayHelpOnMissingArgumentOrSubcommand => None,
             Self::DisplayVersion => None,
             Self::ArgumentNotFound => Some("An argument wasn't found"),
+            Self::MultipleErrors => Some("Multiple problems were found with the arguments"),
             Self::Io => None,
             Self::Format => None,
         }
     }
+
+    /// The [`log::Level`] this error case should be recorded at
+    ///
+    /// Help and version requests are ordinary program flow and map to
+    /// [`log::Level::Info`]; problems inside clap itself ([`ErrorKind::Io`],
+    /// [`ErrorKind::Format`]) map to [`log::Level::Error`]; everything else is a
+    /// usage error on the part of the end user and maps to [`log::Level::Warn`].
+    #[cfg(feature = "log")]
+    pub fn log_level(self) -> log::Level {
+        match self {
+            Self::DisplayHelp
+            | Self::DisplayHelpOnMissingArgumentOrSubcommand
+            | Self::DisplayVersion => log::Level::Info,
+            Self::Io | Self::Format => log::Level::Error,
+            _ => log::Level::Warn,
+        }
+    }
 }
 
 impl std::fmt::Display for ErrorKind {
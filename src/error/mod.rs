@@ -6,7 +6,8 @@ use std::{
     borrow::Cow,
     convert::From,
     error,
-    fmt::{self, Debug, Display, Formatter},
+    ffi::OsString,
+    fmt::{self, Debug, Display, Formatter, Write as _},
     io::{self, BufRead},
     result::Result as StdResult,
 };
@@ -14,19 +15,25 @@ use std::{
 // Internal
 use crate::{
     build::Arg,
-    output::fmt::Colorizer,
+    output::fmt::{Colorizer, Theme},
+    output::localizer::{localize, DefaultText, Localization},
     parse::features::suggestions,
     util::{color::ColorChoice, safe_exit, SUCCESS_CODE, USAGE_CODE},
     App, AppSettings,
 };
 
 mod context;
+mod format;
 mod kind;
 
 pub use context::ContextKind;
 pub use context::ContextValue;
+pub use format::ErrorFormatter;
+pub use format::KindFormatter;
 pub use kind::ErrorKind;
 
+pub(crate) use format::FormatterHook;
+
 /// Short hand for [`Result`] type
 ///
 /// [`Result`]: std::result::Result
@@ -56,7 +63,15 @@ struct ErrorInner {
     source: Option<Box<dyn error::Error + Send + Sync>>,
     help_flag: Option<&'static str>,
     color_when: ColorChoice,
+    theme: Option<Theme>,
     wait_on_exit: bool,
+    strip_ansi: bool,
+    page_help: bool,
+    suppress_usage: bool,
+    after_help: Option<String>,
+    help_search: Option<String>,
+    formatter: Option<fn(&Error) -> String>,
+    localization: Option<Localization>,
     backtrace: Option<Backtrace>,
 }
 
@@ -94,6 +109,36 @@ impl Error {
         self.inner.context.iter().map(|(k, v)| (*k, v))
     }
 
+    /// The exit code [`Error::exit`] would terminate the process with.
+    ///
+    /// Returns `2` for actual parse errors and `0` for `--help`/`--version`,
+    /// which are surfaced as errors but are not failures.  Useful together
+    /// with [`Error::print`] or [`Error::print_to`] when the application wants
+    /// to call [`std::process::exit`] itself.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use clap::App;
+    ///
+    /// match App::new("App").try_get_matches() {
+    ///     Ok(matches) => {
+    ///         // do_something
+    ///     },
+    ///     Err(err) => {
+    ///         err.print().expect("Error writing Error");
+    ///         std::process::exit(err.exit_code());
+    ///     },
+    /// };
+    /// ```
+    #[inline]
+    pub fn exit_code(&self) -> i32 {
+        if self.use_stderr() {
+            USAGE_CODE
+        } else {
+            SUCCESS_CODE
+        }
+    }
+
     /// Should the message be written to `stdout` or not?
     #[inline]
     pub fn use_stderr(&self) -> bool {
@@ -144,7 +189,179 @@ impl Error {
     /// };
     /// ```
     pub fn print(&self) -> io::Result<()> {
-        self.formatted().print()
+        if let Some(formatter) = self.formatter() {
+            let mut c = Colorizer::new(self.use_stderr(), self.inner.color_when)
+                .with_theme(self.inner.theme);
+            c.none(formatter(self));
+            return c.print();
+        }
+        if self.should_page() {
+            let content = self.formatted().to_string();
+            // Short help fits on screen; only page when scrolling would be needed,
+            // like `git --help`
+            if exceeds_terminal_height(&content) {
+                return crate::output::pager::page(&content, self.inner.help_search.as_deref());
+            }
+        }
+        if self.inner.strip_ansi {
+            self.formatted()
+                .into_owned()
+                .strip_ansi_on_redirect(true)
+                .print()
+        } else {
+            self.formatted().print()
+        }
+    }
+
+    /// Writes the formatted error to an arbitrary writer, without color
+    ///
+    /// This is the message [`Error::print`] would write, but routed into the
+    /// application's own writer (a log file, a test buffer, ...) instead of the
+    /// terminal, so callers of [`App::try_get_matches`] can report the error
+    /// themselves and exit with [`Error::exit_code`].
+    ///
+    /// # Example
+    /// ```no_run
+    /// use clap::App;
+    ///
+    /// match App::new("App").try_get_matches() {
+    ///     Ok(matches) => {
+    ///         // do_something
+    ///     },
+    ///     Err(err) => {
+    ///         let mut buf = Vec::new();
+    ///         err.print_to(&mut buf).expect("Error writing Error");
+    ///         // route `buf` into logging, then...
+    ///         std::process::exit(err.exit_code());
+    ///     },
+    /// };
+    /// ```
+    /// [`App::try_get_matches`]: crate::App::try_get_matches()
+    pub fn print_to(&self, w: &mut dyn io::Write) -> io::Result<()> {
+        if let Some(formatter) = self.formatter() {
+            return w.write_all(formatter(self).as_bytes());
+        }
+        write!(w, "{}", self.formatted())
+    }
+
+    /// Emits the error as a [`log`] record instead of writing it to the terminal
+    ///
+    /// The record's level is derived from the error kind via
+    /// [`ErrorKind::log_level`] and its target is `clap`, so daemons can route
+    /// or filter clap's usage errors like any other log output. The message is
+    /// the same text [`Error::print`] would write, without color codes.
+    ///
+    /// # Example
+    /// ```no_run
+    /// use clap::App;
+    ///
+    /// match App::new("App").try_get_matches() {
+    ///     Ok(matches) => {
+    ///         // do_something
+    ///     },
+    ///     Err(err) => {
+    ///         err.log();
+    ///     },
+    /// };
+    /// ```
+    #[cfg(feature = "log")]
+    pub fn log(&self) {
+        log::log!(target: "clap", self.kind().log_level(), "{}", self.formatted());
+    }
+
+    /// Renders the error as a JSON object for consumption by wrapper tools
+    ///
+    /// The object carries the error `kind`, its end-user `description` where
+    /// one exists, and every context pair (including usage and suggestions)
+    /// under `context`, keyed by the [`ContextKind`] name. Raw messages from
+    /// [`Error::raw`] are carried under `message`. None of the values contain
+    /// color codes, so the output can be parsed instead of scraping the
+    /// rendered text.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let err = App::new("prog")
+    ///     .arg(Arg::new("debug").long("debug"))
+    ///     .try_get_matches_from(["prog", "--bogus"])
+    ///     .unwrap_err();
+    ///
+    /// let json = err.render_json();
+    /// assert!(json.contains("\"kind\": \"UnknownArgument\""));
+    /// assert!(json.contains("\"InvalidArg\": \"--bogus\""));
+    /// ```
+    pub fn render_json(&self) -> String {
+        let mut json = String::from("{");
+
+        json.push_str("\n  \"kind\": ");
+        push_json_string(&mut json, &format!("{:?}", self.kind()));
+
+        if let Some(description) = self.kind().as_str() {
+            json.push_str(",\n  \"description\": ");
+            push_json_string(&mut json, description);
+        }
+
+        if let Some(Message::Raw(message)) = self.inner.message.as_ref() {
+            json.push_str(",\n  \"message\": ");
+            push_json_string(&mut json, message);
+        }
+
+        json.push_str(",\n  \"context\": {");
+        for (i, (kind, value)) in self.context().enumerate() {
+            if i != 0 {
+                json.push(',');
+            }
+            json.push_str("\n    ");
+            push_json_string(&mut json, &format!("{:?}", kind));
+            json.push_str(": ");
+            match value {
+                ContextValue::None => json.push_str("null"),
+                ContextValue::Bool(b) => {
+                    let _ = write!(json, "{}", b);
+                }
+                ContextValue::String(s) => push_json_string(&mut json, s),
+                ContextValue::Strings(values) => {
+                    json.push('[');
+                    for (j, s) in values.iter().enumerate() {
+                        if j != 0 {
+                            json.push_str(", ");
+                        }
+                        push_json_string(&mut json, s);
+                    }
+                    json.push(']');
+                }
+                ContextValue::Number(n) => {
+                    let _ = write!(json, "{}", n);
+                }
+                ContextValue::Range(range) => {
+                    let _ = write!(json, "[{}, {}]", range.start, range.end);
+                }
+            }
+        }
+        if self.inner.context.is_empty() {
+            json.push('}');
+        } else {
+            json.push_str("\n  }");
+        }
+
+        json.push_str("\n}");
+        json
+    }
+
+    /// The installed formatter, if any; help and version output keep the default rendering
+    fn formatter(&self) -> Option<fn(&Error) -> String> {
+        match self.kind() {
+            ErrorKind::DisplayHelp
+            | ErrorKind::DisplayHelpOnMissingArgumentOrSubcommand
+            | ErrorKind::DisplayVersion => None,
+            _ => self.inner.formatter,
+        }
+    }
+
+    /// Should the help message be piped through a pager?
+    fn should_page(&self) -> bool {
+        self.inner.page_help && self.kind() == ErrorKind::DisplayHelp && is_stdout_a_tty()
     }
 
     /// Deprecated, replaced with [`App::error`]
@@ -156,7 +373,14 @@ impl Error {
         Error::raw(kind, description)
     }
 
-    fn new(kind: ErrorKind) -> Self {
+    /// Create an error with no message or context, rendered from `kind` alone.
+    ///
+    /// Attach context with [`Error::insert_context`] or [`Error::with_context`] to get
+    /// the same rendering as clap's internal errors.  Prefer [`App::error`] when an
+    /// `App` is at hand; it fills in the usage line and help flag automatically.
+    ///
+    /// [`App::error`]: crate::App::error
+    pub fn new(kind: ErrorKind) -> Self {
         Self {
             inner: Box::new(ErrorInner {
                 kind,
@@ -165,7 +389,15 @@ impl Error {
                 source: None,
                 help_flag: None,
                 color_when: ColorChoice::Never,
+                theme: None,
                 wait_on_exit: false,
+                strip_ansi: false,
+                page_help: false,
+                suppress_usage: false,
+                after_help: None,
+                help_search: None,
+                formatter: None,
+                localization: None,
                 backtrace: Backtrace::new(),
             }),
             kind,
@@ -182,9 +414,18 @@ impl Error {
     }
 
     pub(crate) fn with_app(self, app: &App) -> Self {
+        let suppress_usage = app.is_usage_suppressed_for(self.kind());
+        let after_help = app.after_error_help_for(self.kind()).map(str::to_owned);
         self.set_wait_on_exit(app.settings.is_set(AppSettings::WaitOnError))
             .set_color(app.get_color())
+            .set_theme(app.theme)
             .set_help_flag(get_help_flag(app))
+            .set_strip_ansi(app.is_strip_ansi_on_redirect_set())
+            .set_page_help(app.settings.is_set(AppSettings::PageHelp))
+            .set_suppress_usage(suppress_usage)
+            .set_after_help(after_help)
+            .set_formatter(app.error_formatter.map(|hook| hook.0))
+            .set_localization(app.localizer.clone())
     }
 
     pub(crate) fn set_message(mut self, message: impl Into<Message>) -> Self {
@@ -207,6 +448,11 @@ impl Error {
         self
     }
 
+    pub(crate) fn set_theme(mut self, theme: Option<Theme>) -> Self {
+        self.inner.theme = theme;
+        self
+    }
+
     pub(crate) fn set_help_flag(mut self, help_flag: Option<&'static str>) -> Self {
         self.inner.help_flag = help_flag;
         self
@@ -217,6 +463,105 @@ impl Error {
         self
     }
 
+    pub(crate) fn set_strip_ansi(mut self, yes: bool) -> Self {
+        self.inner.strip_ansi = yes;
+        self
+    }
+
+    pub(crate) fn set_page_help(mut self, yes: bool) -> Self {
+        self.inner.page_help = yes;
+        self
+    }
+
+    pub(crate) fn set_suppress_usage(mut self, yes: bool) -> Self {
+        self.inner.suppress_usage = yes;
+        self
+    }
+
+    pub(crate) fn set_after_help(mut self, text: Option<String>) -> Self {
+        self.inner.after_help = text;
+        self
+    }
+
+    pub(crate) fn set_help_search(mut self, pattern: impl Into<String>) -> Self {
+        self.inner.help_search = Some(pattern.into());
+        self
+    }
+
+    pub(crate) fn set_formatter(mut self, formatter: Option<fn(&Error) -> String>) -> Self {
+        self.inner.formatter = formatter;
+        self
+    }
+
+    pub(crate) fn set_localization(mut self, localization: Option<Localization>) -> Self {
+        self.inner.localization = localization;
+        self
+    }
+
+    /// Attach a piece of context, replacing any existing value of the same kind.
+    ///
+    /// The default formatter renders recognized context the same way it does for
+    /// clap's internal errors, so errors built in higher layers (e.g. from validators)
+    /// blend in with the rest of the output.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{Error, ErrorKind};
+    /// use clap::error::{ContextKind, ContextValue};
+    ///
+    /// let err = Error::new(ErrorKind::UnknownArgument)
+    ///     .insert_context(
+    ///         ContextKind::InvalidArg,
+    ///         ContextValue::String("--unknwon".to_string()),
+    ///     )
+    ///     .insert_context(
+    ///         ContextKind::SuggestedArg,
+    ///         ContextValue::String("--unknown".to_string()),
+    ///     );
+    ///
+    /// let rendered = err.to_string();
+    /// assert!(rendered.contains("Found argument '--unknwon'"));
+    /// assert!(rendered.contains("Did you mean '--unknown'?"));
+    /// ```
+    #[must_use]
+    pub fn insert_context(mut self, kind: ContextKind, value: ContextValue) -> Self {
+        if let Some(existing) = self.inner.context.iter_mut().find(|(k, _)| *k == kind) {
+            existing.1 = value;
+        } else {
+            self.inner.context.push((kind, value));
+        }
+        self
+    }
+
+    /// Attach several pieces of context at once.
+    ///
+    /// Equivalent to calling [`Error::insert_context`] for each pair.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{Error, ErrorKind};
+    /// use clap::error::{ContextKind, ContextValue};
+    ///
+    /// let err = Error::new(ErrorKind::MissingRequiredArgument).with_context([(
+    ///     ContextKind::InvalidArg,
+    ///     ContextValue::Strings(vec!["<input>".to_string()]),
+    /// )]);
+    ///
+    /// assert!(err.to_string().contains("<input>"));
+    /// ```
+    #[must_use]
+    pub fn with_context(
+        mut self,
+        context: impl IntoIterator<Item = (ContextKind, ContextValue)>,
+    ) -> Self {
+        for (kind, value) in context {
+            self = self.insert_context(kind, value);
+        }
+        self
+    }
+
     /// Does not verify if `ContextKind` is already present
     #[inline(never)]
     pub(crate) fn insert_context_unchecked(
@@ -238,8 +583,52 @@ impl Error {
         self
     }
 
+    /// Record where in the original command line the offending token sits, so
+    /// the rendered message can echo the line with a caret under the token.
+    /// Only called when [`AppSettings::EchoCommandLine`][crate::AppSettings::EchoCommandLine]
+    /// is set.
+    ///
+    /// Only applies when the error names an invalid argument that appears
+    /// verbatim in `argv` (possibly with an attached `=value`); errors about
+    /// rendered argument descriptions or grouped short flags are left alone.
+    pub(crate) fn with_command_line(mut self, argv: &[OsString]) -> Self {
+        let invalid_arg = match self.get_context(ContextKind::InvalidArg) {
+            Some(ContextValue::String(invalid_arg)) => invalid_arg.clone(),
+            _ => return self,
+        };
+        if self.get_context(ContextKind::CommandLine).is_some() {
+            return self;
+        }
+
+        let mut line = String::new();
+        let mut span = None;
+        for (index, item) in argv.iter().enumerate() {
+            let token = item.to_string_lossy();
+            if index != 0 {
+                line.push(' ');
+            }
+            // `argv[0]` is the binary name, never the offending token
+            if span.is_none() && index != 0 {
+                let matches = *token == invalid_arg
+                    || (token.starts_with(&*invalid_arg)
+                        && token[invalid_arg.len()..].starts_with('='));
+                if matches {
+                    span = Some(line.len()..line.len() + invalid_arg.len());
+                }
+            }
+            line.push_str(&token);
+        }
+
+        if let Some(span) = span {
+            self = self
+                .insert_context_unchecked(ContextKind::CommandLine, ContextValue::String(line))
+                .insert_context_unchecked(ContextKind::Span, ContextValue::Range(span));
+        }
+        self
+    }
+
     #[inline(never)]
-    fn get_context(&self, kind: ContextKind) -> Option<&ContextValue> {
+    pub(crate) fn get_context(&self, kind: ContextKind) -> Option<&ContextValue> {
         self.inner
             .context
             .iter()
@@ -330,7 +719,12 @@ impl Error {
         let mut info = vec![arg.to_string(), bad_val.clone()];
         info.extend(good_vals.iter().map(|s| (*s).to_owned()));
 
-        let suggestion = suggestions::did_you_mean(&bad_val, good_vals.iter()).pop();
+        let suggestion = suggestions::did_you_mean(
+            &bad_val,
+            good_vals.iter(),
+            app.get_suggestion_confidence(),
+        )
+        .pop();
         let mut err = Self::new(ErrorKind::InvalidValue)
             .with_app(app)
             .set_info(info)
@@ -383,7 +777,11 @@ impl Error {
 
     pub(crate) fn unrecognized_subcommand(app: &App, subcmd: String, name: String) -> Self {
         let info = vec![subcmd.clone()];
-        let usage = format!("USAGE:\n    {} <subcommands>", name);
+        let usage = format!(
+            "{}\n    {} <subcommands>",
+            localize(app.localizer.as_ref(), DefaultText::UsageHeading, "USAGE:"),
+            name
+        );
         Self::new(ErrorKind::UnrecognizedSubcommand)
             .with_app(app)
             .set_info(info)
@@ -393,6 +791,40 @@ impl Error {
             ])
     }
 
+    pub(crate) fn ambiguous_subcommand(
+        app: &App,
+        subcmd: String,
+        arg: String,
+        usage: String,
+    ) -> Self {
+        let info = vec![subcmd.clone(), arg.clone()];
+        Self::new(ErrorKind::AmbiguousSubcommand)
+            .with_app(app)
+            .set_info(info)
+            .extend_context_unchecked([
+                (ContextKind::InvalidSubcommand, ContextValue::String(subcmd)),
+                (ContextKind::InvalidArg, ContextValue::String(arg)),
+                (ContextKind::Usage, ContextValue::String(usage)),
+            ])
+    }
+
+    pub(crate) fn ambiguous_prefix(
+        app: &App,
+        arg: String,
+        candidates: Vec<String>,
+        usage: String,
+    ) -> Self {
+        let info = vec![arg.clone()];
+        Self::new(ErrorKind::AmbiguousPrefix)
+            .with_app(app)
+            .set_info(info)
+            .extend_context_unchecked([
+                (ContextKind::InvalidArg, ContextValue::String(arg)),
+                (ContextKind::ValidArg, ContextValue::Strings(candidates)),
+                (ContextKind::Usage, ContextValue::String(usage)),
+            ])
+    }
+
     pub(crate) fn missing_required_argument(
         app: &App,
         required: Vec<String>,
@@ -408,15 +840,27 @@ impl Error {
             ])
     }
 
-    pub(crate) fn missing_subcommand(app: &App, name: String, usage: String) -> Self {
+    pub(crate) fn missing_subcommand(
+        app: &App,
+        name: String,
+        exempting_args: Vec<String>,
+        usage: String,
+    ) -> Self {
         let info = vec![];
-        Self::new(ErrorKind::MissingSubcommand)
+        let mut err = Self::new(ErrorKind::MissingSubcommand)
             .with_app(app)
             .set_info(info)
             .extend_context_unchecked([
                 (ContextKind::InvalidSubcommand, ContextValue::String(name)),
                 (ContextKind::Usage, ContextValue::String(usage)),
-            ])
+            ]);
+        if !exempting_args.is_empty() {
+            err = err.insert_context_unchecked(
+                ContextKind::ExemptingArgs,
+                ContextValue::Strings(exempting_args),
+            );
+        }
+        err
     }
 
     pub(crate) fn invalid_utf8(app: &App, usage: String) -> Self {
@@ -459,6 +903,96 @@ impl Error {
             ])
     }
 
+    pub(crate) fn occurrence_not_multiple_of(
+        app: &App,
+        arg: &Arg,
+        multiple: usize,
+        curr_occurs: usize,
+        usage: String,
+    ) -> Self {
+        let info = vec![
+            arg.to_string(),
+            curr_occurs.to_string(),
+            multiple.to_string(),
+        ];
+        Self::new(ErrorKind::InvalidOccurrencePattern)
+            .with_app(app)
+            .set_info(info)
+            .extend_context_unchecked([
+                (
+                    ContextKind::InvalidArg,
+                    ContextValue::String(arg.to_string()),
+                ),
+                (
+                    ContextKind::ExpectedOccurrenceMultiple,
+                    ContextValue::Number(multiple as isize),
+                ),
+                (
+                    ContextKind::ActualNumOccurrences,
+                    ContextValue::Number(curr_occurs as isize),
+                ),
+                (ContextKind::Usage, ContextValue::String(usage)),
+            ])
+    }
+
+    pub(crate) fn unpaired_occurrence(
+        app: &App,
+        arg: &Arg,
+        partner: String,
+        usage: String,
+    ) -> Self {
+        let info = vec![arg.to_string(), partner.clone()];
+        Self::new(ErrorKind::InvalidOccurrencePattern)
+            .with_app(app)
+            .set_info(info)
+            .extend_context_unchecked([
+                (
+                    ContextKind::InvalidArg,
+                    ContextValue::String(arg.to_string()),
+                ),
+                (ContextKind::PriorArg, ContextValue::String(partner)),
+                (ContextKind::Usage, ContextValue::String(usage)),
+            ])
+    }
+
+    pub(crate) fn group_count(
+        app: &App,
+        group: String,
+        members: Vec<String>,
+        at_least: Option<usize>,
+        at_most: Option<usize>,
+        actual: usize,
+        usage: String,
+    ) -> Self {
+        let mut info = vec![group.clone(), actual.to_string()];
+        info.extend(members.iter().cloned());
+        let mut err = Self::new(ErrorKind::InvalidGroupCount)
+            .with_app(app)
+            .set_info(info)
+            .extend_context_unchecked([
+                (ContextKind::InvalidArg, ContextValue::String(group)),
+                (ContextKind::PriorArg, ContextValue::Strings(members)),
+                (
+                    ContextKind::ActualNumOccurrences,
+                    ContextValue::Number(actual as isize),
+                ),
+                (ContextKind::Usage, ContextValue::String(usage)),
+            ]);
+        if let Some(at_least) = at_least {
+            err = err.insert_context_unchecked(
+                ContextKind::MinValues,
+                ContextValue::Number(at_least as isize),
+            );
+        }
+        if let Some(at_most) = at_most {
+            err = err.insert_context_unchecked(
+                ContextKind::MaxValues,
+                ContextValue::Number(at_most as isize),
+            );
+        }
+        err
+    }
+
     pub(crate) fn too_many_values(app: &App, val: String, arg: String, usage: String) -> Self {
         let info = vec![arg.to_string(), val.clone()];
         Self::new(ErrorKind::TooManyValues)
@@ -597,6 +1131,57 @@ impl Error {
             ])
     }
 
+    pub(crate) fn multiple(app: &App, mut errors: Vec<Error>, usage: String) -> Self {
+        debug_assert!(!errors.is_empty());
+        if errors.len() == 1 {
+            return errors.pop().unwrap();
+        }
+
+        let mut c = Colorizer::new(true, app.get_color()).with_theme(app.theme);
+        let mut info = Vec::new();
+        let mut context = Vec::new();
+        for (i, err) in errors.iter().enumerate() {
+            if i > 0 {
+                c.none("\n");
+            }
+            start_error(&mut c, app.localizer.as_ref());
+            if !err.write_dynamic_context(&mut c) {
+                if let Some(Message::Raw(msg)) = err.inner.message.as_ref() {
+                    c.none(msg.trim().to_owned());
+                } else if let Some(msg) = err.kind().as_str() {
+                    c.none(msg.to_owned());
+                } else if let Some(source) = err.inner.source.as_ref() {
+                    c.none(source.to_string());
+                } else {
+                    c.none("Unknown cause");
+                }
+            }
+            info.extend(err.info.iter().cloned());
+            // Each collected error carries its own usage copy; one shared copy is
+            // appended below instead.
+            context.extend(
+                err.inner
+                    .context
+                    .iter()
+                    .filter(|(kind, _)| *kind != ContextKind::Usage)
+                    .cloned(),
+            );
+        }
+        context.push((ContextKind::Usage, ContextValue::String(usage.clone())));
+
+        if !app.is_usage_suppressed_for(ErrorKind::MultipleErrors) {
+            put_usage(&mut c, usage);
+        }
+        try_help(&mut c, app.localizer.as_ref(), get_help_flag(app));
+
+        let mut err = Self::new(ErrorKind::MultipleErrors)
+            .with_app(app)
+            .set_message(c)
+            .set_info(info);
+        err.inner.context = context;
+        err
+    }
+
     pub(crate) fn argument_not_found_auto(arg: String) -> Self {
         let info = vec![arg.to_string()];
         Self::new(ErrorKind::ArgumentNotFound)
@@ -608,9 +1193,10 @@ impl Error {
         if let Some(message) = self.inner.message.as_ref() {
             message.formatted()
         } else {
-            let mut c = Colorizer::new(self.use_stderr(), self.inner.color_when);
+            let mut c = Colorizer::new(self.use_stderr(), self.inner.color_when)
+                .with_theme(self.inner.theme);
 
-            start_error(&mut c);
+            start_error(&mut c, self.inner.localization.as_ref());
 
             if !self.write_dynamic_context(&mut c) {
                 if let Some(msg) = self.kind().as_str() {
@@ -622,12 +1208,30 @@ impl Error {
                 }
             }
 
+            if let (Some(ContextValue::String(line)), Some(ContextValue::Range(span))) = (
+                self.get_context(ContextKind::CommandLine),
+                self.get_context(ContextKind::Span),
+            ) {
+                c.none("\n\n    ");
+                c.none(line.clone());
+                c.none("\n    ");
+                c.none(" ".repeat(line[..span.start].chars().count()));
+                c.warning("^".repeat(line[span.clone()].chars().count().max(1)));
+            }
+
             let usage = self.get_context(ContextKind::Usage);
             if let Some(ContextValue::String(usage)) = usage {
-                put_usage(&mut c, usage);
+                if !self.inner.suppress_usage {
+                    put_usage(&mut c, usage);
+                }
             }
 
-            try_help(&mut c, self.inner.help_flag);
+            if let Some(after_help) = self.inner.after_help.as_ref() {
+                c.none("\n\n");
+                c.none(after_help.clone());
+            }
+
+            try_help(&mut c, self.inner.localization.as_ref(), self.inner.help_flag);
 
             Cow::Owned(c)
         }
@@ -778,10 +1382,54 @@ impl Error {
                     false
                 }
             }
+            ErrorKind::AmbiguousSubcommand => {
+                let invalid_sub = self.get_context(ContextKind::InvalidSubcommand);
+                let invalid_arg = self.get_context(ContextKind::InvalidArg);
+                if let (
+                    Some(ContextValue::String(invalid_sub)),
+                    Some(ContextValue::String(invalid_arg)),
+                ) = (invalid_sub, invalid_arg)
+                {
+                    c.none("The value '");
+                    c.warning(invalid_sub);
+                    c.none("' is both a subcommand and a possible value of '");
+                    c.warning(invalid_arg);
+                    c.none("'\n\n\tIf the value was intended, pass it after '--'");
+                    true
+                } else {
+                    false
+                }
+            }
+            ErrorKind::AmbiguousPrefix => {
+                let invalid_arg = self.get_context(ContextKind::InvalidArg);
+                if let Some(ContextValue::String(invalid_arg)) = invalid_arg {
+                    c.none("The abbreviation '");
+                    c.warning(invalid_arg);
+                    c.none("' is ambiguous and could match:");
+                    if let Some(ContextValue::Strings(candidates)) =
+                        self.get_context(ContextKind::ValidArg)
+                    {
+                        for candidate in candidates {
+                            c.none("\n    ");
+                            c.good(&**candidate);
+                        }
+                    }
+                    true
+                } else {
+                    false
+                }
+            }
             ErrorKind::MissingRequiredArgument => {
                 let invalid_arg = self.get_context(ContextKind::InvalidArg);
                 if let Some(ContextValue::Strings(invalid_arg)) = invalid_arg {
-                    c.none("The following required arguments were not provided:");
+                    c.none(
+                        localize(
+                            self.inner.localization.as_ref(),
+                            DefaultText::MissingRequiredArguments,
+                            "The following required arguments were not provided:",
+                        )
+                        .into_owned(),
+                    );
                     for v in invalid_arg {
                         c.none("\n    ");
                         c.good(&**v);
@@ -797,6 +1445,17 @@ impl Error {
                     c.none("'");
                     c.warning(invalid_sub);
                     c.none("' requires a subcommand but one was not provided");
+                    if let Some(ContextValue::Strings(exempting_args)) =
+                        self.get_context(ContextKind::ExemptingArgs)
+                    {
+                        c.none("\n\n\tunless one of the following arguments is used: ");
+                        for (i, arg) in exempting_args.iter().enumerate() {
+                            if i != 0 {
+                                c.none(", ");
+                            }
+                            c.good(&**arg);
+                        }
+                    }
                     true
                 } else {
                     false
@@ -826,6 +1485,80 @@ impl Error {
                     false
                 }
             }
+            ErrorKind::InvalidOccurrencePattern => {
+                let invalid_arg = self.get_context(ContextKind::InvalidArg);
+                if let Some(ContextValue::String(invalid_arg)) = invalid_arg {
+                    let multiple = self.get_context(ContextKind::ExpectedOccurrenceMultiple);
+                    let actual_num_occurs = self.get_context(ContextKind::ActualNumOccurrences);
+                    let partner = self.get_context(ContextKind::PriorArg);
+                    if let (
+                        Some(ContextValue::Number(multiple)),
+                        Some(ContextValue::Number(actual_num_occurs)),
+                    ) = (multiple, actual_num_occurs)
+                    {
+                        let were_provided = Error::singular_or_plural(*actual_num_occurs as usize);
+                        c.none("The argument '");
+                        c.warning(invalid_arg);
+                        c.none("' must be provided a multiple of ");
+                        c.warning(multiple.to_string());
+                        c.none(" times, but ");
+                        c.warning(actual_num_occurs.to_string());
+                        c.none(were_provided);
+                        true
+                    } else if let Some(ContextValue::String(partner)) = partner {
+                        c.none("Each occurrence of the argument '");
+                        c.warning(invalid_arg);
+                        c.none("' must be followed by an occurrence of '");
+                        c.warning(partner);
+                        c.none("'");
+                        true
+                    } else {
+                        false
+                    }
+                } else {
+                    false
+                }
+            }
+            ErrorKind::InvalidGroupCount => {
+                let invalid_group = self.get_context(ContextKind::InvalidArg);
+                let members = self.get_context(ContextKind::PriorArg);
+                let actual = self.get_context(ContextKind::ActualNumOccurrences);
+                if let (
+                    Some(ContextValue::String(invalid_group)),
+                    Some(ContextValue::Strings(members)),
+                    Some(ContextValue::Number(actual)),
+                ) = (invalid_group, members, actual)
+                {
+                    let at_least = match self.get_context(ContextKind::MinValues) {
+                        Some(ContextValue::Number(n)) => Some(*n),
+                        _ => None,
+                    };
+                    let at_most = match self.get_context(ContextKind::MaxValues) {
+                        Some(ContextValue::Number(n)) => Some(*n),
+                        _ => None,
+                    };
+                    let allowed = match (at_least, at_most) {
+                        (Some(min), Some(max)) if min == max => format!("exactly {}", min),
+                        (Some(min), Some(max)) => format!("between {} and {}", min, max),
+                        (Some(min), None) => format!("at least {}", min),
+                        (None, Some(max)) => format!("at most {}", max),
+                        (None, None) => return false,
+                    };
+                    let were_provided = Error::singular_or_plural(*actual as usize);
+                    c.none("The argument group '");
+                    c.warning(invalid_group);
+                    c.none("' requires ");
+                    c.warning(allowed);
+                    c.none(" of the arguments: ");
+                    c.warning(members.join(", "));
+                    c.none(", but ");
+                    c.warning(actual.to_string());
+                    c.none(were_provided);
+                    true
+                } else {
+                    false
+                }
+            }
             ErrorKind::TooManyValues => {
                 let invalid_arg = self.get_context(ContextKind::InvalidArg);
                 let invalid_value = self.get_context(ContextKind::InvalidValue);
@@ -953,6 +1686,13 @@ impl Error {
                         (_, _) => {}
                     }
 
+                    let suggestion = self.get_context(ContextKind::SuggestedCommand);
+                    if let Some(ContextValue::String(suggestion)) = suggestion {
+                        c.none("\n\n\tA different level of the command tree accepts it; try `");
+                        c.good(suggestion);
+                        c.none("`");
+                    }
+
                     let invalid_arg = self.get_context(ContextKind::InvalidArg);
                     if let Some(ContextValue::String(invalid_arg)) = invalid_arg {
                         if invalid_arg.starts_with('-') {
@@ -989,6 +1729,8 @@ impl Error {
             ErrorKind::DisplayHelp
             | ErrorKind::DisplayHelpOnMissingArgumentOrSubcommand
             | ErrorKind::DisplayVersion
+            | ErrorKind::MultipleErrors
+            | ErrorKind::InvalidLineSyntax
             | ErrorKind::Io
             | ErrorKind::Format => false,
         }
@@ -1025,6 +1767,9 @@ impl error::Error for Error {
 
 impl Display for Error {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        if let Some(formatter) = self.formatter() {
+            return f.write_str(&formatter(self));
+        }
         // Assuming `self.message` already has a trailing newline, from `try_help` or similar
         write!(f, "{}", self.formatted())?;
         if let Some(backtrace) = self.inner.backtrace.as_ref() {
@@ -1036,8 +1781,8 @@ impl Display for Error {
     }
 }
 
-fn start_error(c: &mut Colorizer) {
-    c.error("error:");
+fn start_error(c: &mut Colorizer, localization: Option<&Localization>) {
+    c.error(localize(localization, DefaultText::ErrorPrefix, "error:").into_owned());
     c.none(" ");
 }
 
@@ -1046,6 +1791,25 @@ fn put_usage(c: &mut Colorizer, usage: impl Into<String>) {
     c.none(usage);
 }
 
+/// Appends `s` as a JSON string literal, escaping quotes, backslashes, and control characters
+fn push_json_string(json: &mut String, s: &str) {
+    json.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => json.push_str("\\\""),
+            '\\' => json.push_str("\\\\"),
+            '\n' => json.push_str("\\n"),
+            '\r' => json.push_str("\\r"),
+            '\t' => json.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(json, "\\u{:04x}", c as u32);
+            }
+            c => json.push(c),
+        }
+    }
+    json.push('"');
+}
+
 fn get_help_flag(app: &App) -> Option<&'static str> {
     if !app.settings.is_set(AppSettings::DisableHelpFlag) {
         Some("--help")
@@ -1056,9 +1820,33 @@ fn get_help_flag(app: &App) -> Option<&'static str> {
     }
 }
 
-fn try_help(c: &mut Colorizer, help: Option<&str>) {
+#[cfg(feature = "color")]
+fn is_stdout_a_tty() -> bool {
+    atty::is(atty::Stream::Stdout)
+}
+
+#[cfg(not(feature = "color"))]
+fn is_stdout_a_tty() -> bool {
+    false
+}
+
+/// Whether `content` has more lines than the terminal has rows.
+///
+/// When the terminal size can't be determined the content is assumed not to fit,
+/// preserving paging on terminals that don't report their size.
+fn exceeds_terminal_height(content: &str) -> bool {
+    match crate::output::dimensions() {
+        Some((_, height)) => content.lines().count() >= height,
+        None => true,
+    }
+}
+
+fn try_help(c: &mut Colorizer, localization: Option<&Localization>, help: Option<&str>) {
     if let Some(help) = help {
-        c.none("\n\nFor more information try ");
+        c.none(format!(
+            "\n\n{}",
+            localize(localization, DefaultText::TryHelp, "For more information try ")
+        ));
         c.good(help);
         c.none("\n");
     } else {
@@ -1090,14 +1878,14 @@ impl Message {
     fn format(&mut self, app: &App, usage: String) {
         match self {
             Message::Raw(s) => {
-                let mut c = Colorizer::new(true, app.get_color());
+                let mut c = Colorizer::new(true, app.get_color()).with_theme(app.theme);
 
                 let mut message = String::new();
                 std::mem::swap(s, &mut message);
-                start_error(&mut c);
+                start_error(&mut c, app.localizer.as_ref());
                 c.none(message);
                 put_usage(&mut c, usage);
-                try_help(&mut c, get_help_flag(app));
+                try_help(&mut c, app.localizer.as_ref(), get_help_flag(app));
                 *self = Self::Formatted(c);
             }
             Message::Formatted(_) => {}
@@ -1108,7 +1896,7 @@ impl Message {
         match self {
             Message::Raw(s) => {
                 let mut c = Colorizer::new(true, ColorChoice::Never);
-                start_error(&mut c);
+                start_error(&mut c, None);
                 c.none(s);
                 Cow::Owned(c)
             }
@@ -27,10 +27,16 @@
 compile_error!("`std` feature is currently required to build `clap`");
 
 pub use crate::build::{
-    App, AppFlags, AppSettings, Arg, ArgFlags, ArgGroup, ArgSettings, PossibleValue, ValueHint,
+    App, AppFlags, AppSettings, Arg, ArgFlags, ArgGroup, ArgSettings, ConstraintEdge,
+    ConstraintGraph, ConstraintKind, ConstraintNode, DefinitionProblem, MergeError, PossibleValue,
+    SubcommandValuePolicy, ValueHint, ValueNameCasing, ValueParser, ValueRange, ValueTransform,
 };
 pub use crate::error::Error;
-pub use crate::parse::{ArgMatches, Indices, OsValues, ValueSource, Values};
+pub use crate::output::fmt::{Theme, ThemeColor, ThemeStyle};
+pub use crate::output::localizer::{DefaultText, Localizer};
+pub use crate::parse::{
+    ArgMatches, ArgsInOrder, GroupedValues, Indices, OsValues, ValueSource, Values,
+};
 #[cfg(feature = "color")]
 pub use crate::util::color::ColorChoice;
 
@@ -69,6 +75,7 @@ mod derive;
 #[cfg(feature = "regex")]
 pub use crate::build::RegexRef;
 
+pub mod cmdline;
 pub mod error;
 
 mod build;
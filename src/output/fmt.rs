@@ -3,13 +3,214 @@ use crate::util::color::ColorChoice;
 use std::{
     fmt::{self, Display, Formatter},
     io::{self, Write},
+    sync::{Arc, Mutex},
 };
 
+/// Terminal colors available to a [`Theme`].
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ThemeColor {
+    /// Black
+    Black,
+    /// Red
+    Red,
+    /// Green
+    Green,
+    /// Yellow
+    Yellow,
+    /// Blue
+    Blue,
+    /// Magenta
+    Magenta,
+    /// Cyan
+    Cyan,
+    /// White
+    White,
+}
+
+impl ThemeColor {
+    #[cfg(feature = "color")]
+    fn to_termcolor(self) -> termcolor::Color {
+        match self {
+            Self::Black => termcolor::Color::Black,
+            Self::Red => termcolor::Color::Red,
+            Self::Green => termcolor::Color::Green,
+            Self::Yellow => termcolor::Color::Yellow,
+            Self::Blue => termcolor::Color::Blue,
+            Self::Magenta => termcolor::Color::Magenta,
+            Self::Cyan => termcolor::Color::Cyan,
+            Self::White => termcolor::Color::White,
+        }
+    }
+}
+
+/// How one section of a [`Theme`] is rendered.
+///
+/// The default is plain text, so assigning `ThemeStyle::new()` to a section
+/// disables its styling entirely.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub struct ThemeStyle {
+    color: Option<ThemeColor>,
+    bold: bool,
+    dimmed: bool,
+}
+
+impl ThemeStyle {
+    /// A plain, unstyled section.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the foreground color.
+    #[must_use]
+    pub fn color(mut self, color: ThemeColor) -> Self {
+        self.color = Some(color);
+        self
+    }
+
+    /// Renders the section in bold.
+    #[must_use]
+    pub fn bold(mut self, yes: bool) -> Self {
+        self.bold = yes;
+        self
+    }
+
+    /// Renders the section dimmed.
+    #[must_use]
+    pub fn dimmed(mut self, yes: bool) -> Self {
+        self.dimmed = yes;
+        self
+    }
+}
+
+/// The colors used for each section of clap's output, plus an optional hook
+/// deciding whether the destination supports color at all.
+///
+/// The default theme matches clap's historical palette: literals green, help
+/// headings and warnings yellow, errors bold red, hints dimmed.
+///
+/// # Examples
+///
+/// ```rust
+/// # use clap::{App, Theme, ThemeStyle, ThemeColor};
+/// let app = App::new("prog")
+///     .theme(
+///         Theme::default()
+///             .error(ThemeStyle::new().color(ThemeColor::Magenta).bold(true))
+///             // Disable heading styling entirely
+///             .heading(ThemeStyle::new()),
+///     );
+/// ```
+#[derive(Copy, Clone, Debug)]
+pub struct Theme {
+    good: ThemeStyle,
+    warning: ThemeStyle,
+    error: ThemeStyle,
+    hint: ThemeStyle,
+    heading: ThemeStyle,
+    color_support: Option<fn(bool) -> bool>,
+}
+
+// Comparing function pointers isn't meaningful, so themes follow the same rule as
+// error formatters: two themes with detection callbacks are never considered equal
+impl PartialEq for Theme {
+    fn eq(&self, other: &Self) -> bool {
+        self.good == other.good
+            && self.warning == other.warning
+            && self.error == other.error
+            && self.hint == other.hint
+            && self.heading == other.heading
+            && self.color_support.is_none()
+            && other.color_support.is_none()
+    }
+}
+
+// Not reflexive, but required by containers that derive `Eq`
+impl Eq for Theme {}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme {
+            good: ThemeStyle::new().color(ThemeColor::Green),
+            warning: ThemeStyle::new().color(ThemeColor::Yellow),
+            error: ThemeStyle::new().color(ThemeColor::Red).bold(true),
+            hint: ThemeStyle::new().dimmed(true),
+            heading: ThemeStyle::new().color(ThemeColor::Yellow),
+            color_support: None,
+        }
+    }
+}
+
+impl Theme {
+    /// Style for "good" segments, e.g. flag and value literals.
+    #[must_use]
+    pub fn good(mut self, style: ThemeStyle) -> Self {
+        self.good = style;
+        self
+    }
+
+    /// Style for warning segments.
+    #[must_use]
+    pub fn warning(mut self, style: ThemeStyle) -> Self {
+        self.warning = style;
+        self
+    }
+
+    /// Style for error segments.
+    #[must_use]
+    pub fn error(mut self, style: ThemeStyle) -> Self {
+        self.error = style;
+        self
+    }
+
+    /// Style for hint segments, e.g. "Did you mean" suggestions.
+    #[must_use]
+    pub fn hint(mut self, style: ThemeStyle) -> Self {
+        self.hint = style;
+        self
+    }
+
+    /// Style for help headings, e.g. `OPTIONS:`.
+    #[must_use]
+    pub fn heading(mut self, style: ThemeStyle) -> Self {
+        self.heading = style;
+        self
+    }
+
+    /// Replaces terminal detection with the given callback when deciding
+    /// whether the destination supports color under [`ColorChoice::Auto`].
+    ///
+    /// The callback receives `true` when output goes to stderr; returning
+    /// `false` disables color for that stream.
+    ///
+    /// [`ColorChoice::Auto`]: crate::ColorChoice::Auto
+    #[must_use]
+    pub fn color_support(mut self, detect: fn(bool) -> bool) -> Self {
+        self.color_support = Some(detect);
+        self
+    }
+
+    fn style_of(&self, style: Style) -> ThemeStyle {
+        match style {
+            Style::Good => self.good,
+            Style::Warning => self.warning,
+            Style::Error => self.error,
+            Style::Hint => self.hint,
+            Style::Heading => self.heading,
+            Style::Default => ThemeStyle::default(),
+        }
+    }
+}
+
 #[derive(Clone, Debug)]
 pub(crate) struct Colorizer {
     use_stderr: bool,
     #[allow(unused)]
     color_when: ColorChoice,
+    #[allow(unused)]
+    strip_ansi_on_redirect: bool,
+    #[allow(unused)]
+    theme: Theme,
     pieces: Vec<(String, Style)>,
 }
 
@@ -19,10 +220,27 @@ impl Colorizer {
         Colorizer {
             use_stderr,
             color_when,
+            strip_ansi_on_redirect: false,
+            theme: Theme::default(),
             pieces: vec![],
         }
     }
 
+    /// Applies an application's [`Theme`], when it set one.
+    pub(crate) fn with_theme(mut self, theme: Option<Theme>) -> Self {
+        if let Some(theme) = theme {
+            self.theme = theme;
+        }
+        self
+    }
+
+    /// Re-check the destination at print time, even for [`ColorChoice::Always`], and
+    /// strip color when it is not a terminal (e.g. redirected to a log file).
+    pub(crate) fn strip_ansi_on_redirect(mut self, yes: bool) -> Self {
+        self.strip_ansi_on_redirect = yes;
+        self
+    }
+
     #[inline(never)]
     pub(crate) fn good(&mut self, msg: impl Into<String>) {
         self.pieces.push((msg.into(), Style::Good));
@@ -44,6 +262,11 @@ impl Colorizer {
         self.pieces.push((msg.into(), Style::Hint));
     }
 
+    #[inline(never)]
+    pub(crate) fn heading(&mut self, msg: impl Into<String>) {
+        self.pieces.push((msg.into(), Style::Heading));
+    }
+
     #[inline(never)]
     pub(crate) fn none(&mut self, msg: impl Into<String>) {
         self.pieces.push((msg.into(), Style::Default));
@@ -56,9 +279,16 @@ impl Colorizer {
     pub(crate) fn print(&self) -> io::Result<()> {
         use termcolor::{BufferWriter, ColorChoice as DepColorChoice, ColorSpec, WriteColor};
 
+        let supports_color = match self.theme.color_support {
+            Some(detect) => detect(self.use_stderr),
+            None => is_a_tty(self.use_stderr),
+        };
         let color_when = match self.color_when {
+            ColorChoice::Always if self.strip_ansi_on_redirect && !supports_color => {
+                DepColorChoice::Never
+            }
             ColorChoice::Always => DepColorChoice::Always,
-            ColorChoice::Auto if is_a_tty(self.use_stderr) => DepColorChoice::Auto,
+            ColorChoice::Auto if supports_color => DepColorChoice::Auto,
             _ => DepColorChoice::Never,
         };
 
@@ -71,23 +301,11 @@ impl Colorizer {
         let mut buffer = writer.buffer();
 
         for piece in &self.pieces {
+            let style = self.theme.style_of(piece.1);
             let mut color = ColorSpec::new();
-            match piece.1 {
-                Style::Good => {
-                    color.set_fg(Some(termcolor::Color::Green));
-                }
-                Style::Warning => {
-                    color.set_fg(Some(termcolor::Color::Yellow));
-                }
-                Style::Error => {
-                    color.set_fg(Some(termcolor::Color::Red));
-                    color.set_bold(true);
-                }
-                Style::Hint => {
-                    color.set_dimmed(true);
-                }
-                Style::Default => {}
-            }
+            color.set_fg(style.color.map(ThemeColor::to_termcolor));
+            color.set_bold(style.bold);
+            color.set_dimmed(style.dimmed);
 
             buffer.set_color(&color)?;
             buffer.write_all(piece.0.as_bytes())?;
@@ -124,12 +342,45 @@ impl Display for Colorizer {
     }
 }
 
+/// Destination for parser warnings (e.g. deprecated aliases) when an application
+/// captures them with [`App::warning_writer`][crate::App::warning_writer] instead of
+/// letting them go to stderr.
+#[derive(Clone)]
+pub(crate) struct WarningWriter(Arc<Mutex<dyn Write + Send>>);
+
+impl WarningWriter {
+    pub(crate) fn new(writer: Arc<Mutex<dyn Write + Send>>) -> Self {
+        WarningWriter(writer)
+    }
+
+    pub(crate) fn write(&self, message: &str) {
+        if let Ok(mut writer) = self.0.lock() {
+            let _ = writer.write_all(message.as_bytes());
+        }
+    }
+}
+
+impl fmt::Debug for WarningWriter {
+    fn fmt(&self, f: &mut Formatter) -> fmt::Result {
+        f.debug_struct("WarningWriter").finish()
+    }
+}
+
+impl PartialEq for WarningWriter {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for WarningWriter {}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq)]
 pub enum Style {
     Good,
     Warning,
     Error,
     Hint,
+    Heading,
     Default,
 }
 
@@ -10,7 +10,11 @@ use std::{
 // Internal
 use crate::{
     build::{arg::display_arg_val, App, Arg},
-    output::{fmt::Colorizer, Usage},
+    output::{
+        fmt::Colorizer,
+        localizer::{localize, DefaultText},
+        Usage,
+    },
 };
 
 // Third party
@@ -27,6 +31,7 @@ pub(crate) struct Help<'help, 'app, 'writer> {
     next_line_help: bool,
     term_w: usize,
     use_long: bool,
+    verbose: bool,
 }
 
 // Public Functions
@@ -36,13 +41,13 @@ impl<'help, 'app, 'writer> Help<'help, 'app, 'writer> {
         {author-with-newline}{about-with-newline}\n\
         {usage-heading}\n    {usage}\n\
         \n\
-        {all-args}{after-help}\
+        {all-args}{sections}{after-help}\
     ";
 
     const DEFAULT_NO_ARGS_TEMPLATE: &'static str = "\
         {before-help}{bin} {version}\n\
         {author-with-newline}{about-with-newline}\n\
-        {usage-heading}\n    {usage}{after-help}\
+        {usage-heading}\n    {usage}{sections}{after-help}\
     ";
 
     /// Create a new `Help` instance.
@@ -73,9 +78,16 @@ impl<'help, 'app, 'writer> Help<'help, 'app, 'writer> {
             next_line_help,
             term_w,
             use_long,
+            verbose: false,
         }
     }
 
+    /// Render the verbose help tier, revealing arguments normally held back for it.
+    pub(crate) fn verbose(mut self, yes: bool) -> Self {
+        self.verbose = yes;
+        self
+    }
+
     /// Writes the parser help to the wrapped stream.
     pub(crate) fn write_help(&mut self) -> io::Result<()> {
         debug!("Help::write_help");
@@ -88,11 +100,11 @@ impl<'help, 'app, 'writer> Help<'help, 'app, 'writer> {
             let pos = self
                 .app
                 .get_positionals()
-                .any(|arg| should_show_arg(self.use_long, arg));
+                .any(|arg| should_show_arg(self.use_long, self.verbose, arg));
             let non_pos = self
                 .app
                 .get_non_positionals()
-                .any(|arg| should_show_arg(self.use_long, arg));
+                .any(|arg| should_show_arg(self.use_long, self.verbose, arg));
             let subcmds = self.app.has_visible_subcommands();
 
             if non_pos || pos || subcmds {
@@ -128,8 +140,8 @@ impl<'help, 'app, 'writer> Help<'help, 'app, 'writer> {
     }
 
     #[inline(never)]
-    fn warning<T: Into<String> + AsRef<[u8]>>(&mut self, msg: T) -> io::Result<()> {
-        write_method!(self, msg, warning)
+    fn heading<T: Into<String> + AsRef<[u8]>>(&mut self, msg: T) -> io::Result<()> {
+        write_method!(self, msg, heading)
     }
 
     #[inline(never)]
@@ -158,7 +170,7 @@ impl<'help, 'app, 'writer> Help<'help, 'app, 'writer> {
 
         for &arg in args
             .iter()
-            .filter(|arg| should_show_arg(self.use_long, *arg))
+            .filter(|arg| should_show_arg(self.use_long, self.verbose, *arg))
         {
             if arg.longest_filter() {
                 longest = longest.max(display_width(arg.to_string().as_str()));
@@ -187,7 +199,7 @@ impl<'help, 'app, 'writer> Help<'help, 'app, 'writer> {
             // If it's NextLineHelp we don't care to compute how long it is because it may be
             // NextLineHelp on purpose simply *because* it's so long and would throw off all other
             // args alignment
-            should_show_arg(self.use_long, *arg)
+            should_show_arg(self.use_long, self.verbose, *arg)
         }) {
             if arg.longest_filter() {
                 debug!("Help::write_args: Current Longest...{}", longest);
@@ -270,7 +282,11 @@ impl<'help, 'app, 'writer> Help<'help, 'app, 'writer> {
             if arg.short.is_some() {
                 self.none(", ")?;
             }
-            self.good(format!("--{}", long))?;
+            if arg.is_negatable_set() {
+                self.good(format!("--[no-]{}", long))?;
+            } else {
+                self.good(format!("--{}", long))?;
+            }
         }
         Ok(())
     }
@@ -350,6 +366,18 @@ impl<'help, 'app, 'writer> Help<'help, 'app, 'writer> {
         Ok(())
     }
 
+    /// Wraps free-form text (about, before/after help) honoring
+    /// [`AppSettings::SmartWrap`].
+    ///
+    /// [`AppSettings::SmartWrap`]: crate::AppSettings::SmartWrap
+    fn wrap_free_text(&self, text: &str) -> String {
+        if self.app.is_smart_wrap_set() {
+            smart_text_wrapper(text, self.term_w)
+        } else {
+            text_wrapper(text, self.term_w)
+        }
+    }
+
     fn write_before_help(&mut self) -> io::Result<()> {
         debug!("Help::write_before_help");
         let before_help = if self.use_long {
@@ -358,7 +386,8 @@ impl<'help, 'app, 'writer> Help<'help, 'app, 'writer> {
             self.app.before_help
         };
         if let Some(output) = before_help {
-            self.none(text_wrapper(&output.replace("{n}", "\n"), self.term_w))?;
+            let wrapped = self.wrap_free_text(&output.replace("{n}", "\n"));
+            self.none(wrapped)?;
             self.none("\n\n")?;
         }
         Ok(())
@@ -373,7 +402,8 @@ impl<'help, 'app, 'writer> Help<'help, 'app, 'writer> {
         };
         if let Some(output) = after_help {
             self.none("\n\n")?;
-            self.none(text_wrapper(&output.replace("{n}", "\n"), self.term_w))?;
+            let wrapped = self.wrap_free_text(&output.replace("{n}", "\n"));
+            self.none(wrapped)?;
         }
         Ok(())
     }
@@ -459,13 +489,48 @@ impl<'help, 'app, 'writer> Help<'help, 'app, 'writer> {
             next_line_help,
             longest,
         )?;
+        if self.use_long_pv(arg) {
+            self.write_possible_vals(arg)?;
+        }
+        Ok(())
+    }
+
+    /// Should the argument's possible values be listed with their help under it?
+    fn use_long_pv(&self, arg: &Arg) -> bool {
+        self.use_long
+            && !arg.is_hide_possible_values_set()
+            && arg
+                .possible_vals
+                .iter()
+                .any(|pv| pv.should_show_help_long())
+    }
+
+    /// Writes an argument's possible values as an indented list with their help.
+    fn write_possible_vals(&mut self, arg: &Arg<'help>) -> io::Result<()> {
+        debug!("Help::write_possible_vals: arg={}", arg.name);
+        self.none(format!("\n\n{}{}{}Possible values:", TAB, TAB, TAB))?;
+        for pv in arg.possible_vals.iter().filter(|pv| !pv.is_hide_set()) {
+            self.none(format!("\n{}{}{}- ", TAB, TAB, TAB))?;
+            self.good(pv.get_name())?;
+            if let Some(help) = pv.get_long_help().or_else(|| pv.get_help()) {
+                self.none(": ")?;
+                let mut lines = help.lines();
+                if let Some(line) = lines.next() {
+                    self.none(line)?;
+                }
+                for line in lines {
+                    self.none(format!("\n{}{}{}{}", TAB, TAB, TAB, TAB))?;
+                    self.none(line)?;
+                }
+            }
+        }
         Ok(())
     }
 
     /// Will use next line help on writing args.
     fn will_args_wrap(&self, args: &[&Arg<'help>], longest: usize) -> bool {
         args.iter()
-            .filter(|arg| should_show_arg(self.use_long, *arg))
+            .filter(|arg| should_show_arg(self.use_long, self.verbose, *arg))
             .any(|arg| {
                 let spec_vals = &self.spec_vals(arg);
                 self.arg_next_line_help(arg, spec_vals, longest)
@@ -568,7 +633,8 @@ impl<'help, 'app, 'writer> Help<'help, 'app, 'writer> {
             }
         }
 
-        if !a.is_hide_possible_values_set() && !a.possible_vals.is_empty() {
+        if !a.is_hide_possible_values_set() && !a.possible_vals.is_empty() && !self.use_long_pv(a)
+        {
             debug!(
                 "Help::spec_vals: Found possible vals...{:?}",
                 a.possible_vals
@@ -591,6 +657,11 @@ impl<'help, 'app, 'writer> Help<'help, 'app, 'writer> {
 
             spec_vals.push(format!("[possible values: {}]", pvs));
         }
+
+        if let Some(range) = a.value_range {
+            debug!("Help::spec_vals: Found value range...{}", range);
+            spec_vals.push(format!("[range: {}]", range));
+        }
         let connector = if self.use_long { "\n" } else { " " };
         let prefix = if !spec_vals.is_empty() && !a.get_help().unwrap_or("").is_empty() {
             if self.use_long {
@@ -614,7 +685,8 @@ impl<'help, 'app, 'writer> Help<'help, 'app, 'writer> {
             if before_new_line {
                 self.none("\n")?;
             }
-            self.none(text_wrapper(output, self.term_w))?;
+            let wrapped = self.wrap_free_text(output);
+            self.none(wrapped)?;
             if after_new_line {
                 self.none("\n")?;
             }
@@ -678,9 +750,12 @@ impl<'help, 'app, 'writer> Help<'help, 'app, 'writer> {
                 .map(|a| format!("-{}", a))
                 .collect::<Vec<_>>();
 
-            let als = a.get_visible_aliases().map(|s| s.to_string());
+            if !self.app.is_list_subcommand_aliases_set() {
+                // Otherwise visible aliases get their own rows
+                let als = a.get_visible_aliases().map(|s| s.to_string());
 
-            short_als.extend(als);
+                short_als.extend(als);
+            }
 
             let all_als = short_als.join(", ");
 
@@ -727,12 +802,12 @@ impl<'help, 'app, 'writer> Help<'help, 'app, 'writer> {
         let pos = self
             .app
             .get_positionals_with_no_heading()
-            .filter(|arg| should_show_arg(self.use_long, arg))
+            .filter(|arg| should_show_arg(self.use_long, self.verbose, arg))
             .collect::<Vec<_>>();
         let non_pos = self
             .app
             .get_non_positionals_with_no_heading()
-            .filter(|arg| should_show_arg(self.use_long, arg))
+            .filter(|arg| should_show_arg(self.use_long, self.verbose, arg))
             .collect::<Vec<_>>();
         let subcmds = self.app.has_visible_subcommands();
 
@@ -745,7 +820,10 @@ impl<'help, 'app, 'writer> Help<'help, 'app, 'writer> {
 
         let mut first = if !pos.is_empty() {
             // Write positional args if any
-            self.warning("ARGS:\n")?;
+            self.heading(format!(
+                "{}\n",
+                localize(self.app.localizer.as_ref(), DefaultText::ArgsHeading, "ARGS:")
+            ))?;
             self.write_args_unsorted(&pos)?;
             false
         } else {
@@ -756,7 +834,14 @@ impl<'help, 'app, 'writer> Help<'help, 'app, 'writer> {
             if !first {
                 self.none("\n\n")?;
             }
-            self.warning("OPTIONS:\n")?;
+            self.heading(format!(
+                "{}\n",
+                localize(
+                    self.app.localizer.as_ref(),
+                    DefaultText::OptionsHeading,
+                    "OPTIONS:"
+                )
+            ))?;
             self.write_args(&non_pos)?;
             first = false;
         }
@@ -772,14 +857,14 @@ impl<'help, 'app, 'writer> Help<'help, 'app, 'writer> {
                         }
                         false
                     })
-                    .filter(|arg| should_show_arg(self.use_long, arg))
+                    .filter(|arg| should_show_arg(self.use_long, self.verbose, arg))
                     .collect::<Vec<_>>();
 
                 if !args.is_empty() {
                     if !first {
                         self.none("\n\n")?;
                     }
-                    self.warning(format!("{}:\n", heading))?;
+                    self.heading(format!("{}:\n", heading))?;
                     self.write_args(&args)?;
                     first = false
                 }
@@ -791,8 +876,18 @@ impl<'help, 'app, 'writer> Help<'help, 'app, 'writer> {
                 self.none("\n\n")?;
             }
 
-            self.warning(self.app.subcommand_heading.unwrap_or("SUBCOMMANDS"))?;
-            self.warning(":\n")?;
+            match self.app.subcommand_heading {
+                Some(heading) => self.heading(heading)?,
+                None => self.heading(
+                    localize(
+                        self.app.localizer.as_ref(),
+                        DefaultText::SubcommandsHeading,
+                        "SUBCOMMANDS",
+                    )
+                    .into_owned(),
+                )?,
+            }
+            self.heading(":\n")?;
 
             self.write_subcommands(self.app)?;
         }
@@ -831,7 +926,19 @@ impl<'help, 'app, 'writer> Help<'help, 'app, 'writer> {
             }
             sc_str.push_str(&subcommand.name);
             longest = longest.max(display_width(&sc_str));
-            ord_v.push((subcommand.get_display_order(), sc_str, subcommand));
+            ord_v.push((subcommand.get_display_order(), sc_str, subcommand, false));
+
+            if app.is_list_subcommand_aliases_set() {
+                for alias in subcommand.get_visible_aliases() {
+                    longest = longest.max(display_width(alias));
+                    ord_v.push((
+                        subcommand.get_display_order(),
+                        alias.to_string(),
+                        subcommand,
+                        true,
+                    ));
+                }
+            }
         }
         ord_v.sort_by(|a, b| (a.0, &a.1).cmp(&(b.0, &b.1)));
 
@@ -840,13 +947,31 @@ impl<'help, 'app, 'writer> Help<'help, 'app, 'writer> {
         let next_line_help = self.will_subcommands_wrap(&app.subcommands, longest);
 
         let mut first = true;
-        for (_, sc_str, sc) in &ord_v {
+        for (_, sc_str, sc, is_alias) in &ord_v {
             if first {
                 first = false;
             } else {
                 self.none("\n")?;
             }
-            self.write_subcommand(sc_str, sc, next_line_help, longest)?;
+            if *is_alias {
+                let about = format!("→ {}", sc.name);
+                self.subcmd(sc_str, next_line_help, longest)?;
+                self.help(false, &about, "", next_line_help, longest)?;
+            } else {
+                self.write_subcommand(sc_str, sc, next_line_help, longest)?;
+            }
+        }
+
+        // Plugin-style external subcommands discovered on PATH are listed by
+        // name only; their help text lives with the plugin itself.
+        for plugin in app._discover_external_subcommands() {
+            if first {
+                first = false;
+            } else {
+                self.none("
+")?;
+            }
+            self.subcmd(&plugin, next_line_help, longest)?;
         }
         Ok(())
     }
@@ -901,10 +1026,10 @@ impl<'help, 'app, 'writer> Help<'help, 'app, 'writer> {
                         }
                     )*
 
-                    // Unknown tag, write it back.
+                    // Unknown tag, maybe a section registered via `App::help_section`;
+                    // otherwise write it back.
                     part => {
-                        self.none("{")?;
-                        self.none(part)?;
+                        self.write_custom_tag(part)?;
                     }
                 }
             };
@@ -943,7 +1068,14 @@ impl<'help, 'app, 'writer> Help<'help, 'app, 'writer> {
                         self.write_about(true, true)?;
                     }
                     "usage-heading" => {
-                        self.warning("USAGE:")?;
+                        self.heading(
+                            localize(
+                                self.app.localizer.as_ref(),
+                                DefaultText::UsageHeading,
+                                "USAGE:",
+                            )
+                            .into_owned(),
+                        )?;
                     }
                     "usage" => {
                         self.none(self.usage.create_usage_no_title(&[]))?;
@@ -962,6 +1094,9 @@ impl<'help, 'app, 'writer> Help<'help, 'app, 'writer> {
                     "subcommands" => {
                         self.write_subcommands(self.app)?;
                     }
+                    "sections" => {
+                        self.write_all_help_sections()?;
+                    }
                     "after-help" => {
                         self.write_after_help()?;
                     }
@@ -974,6 +1109,90 @@ impl<'help, 'app, 'writer> Help<'help, 'app, 'writer> {
 
         Ok(())
     }
+
+    /// Resolves an unknown template tag against the sections registered via
+    /// [`App::help_section`][crate::App::help_section], writing the tag back
+    /// verbatim when none matches.
+    fn write_custom_tag(&mut self, part: &str) -> io::Result<()> {
+        if let Some((tag, rest)) = part.split_once('}') {
+            let section = self
+                .app
+                .help_sections
+                .iter()
+                .find(|(heading, _)| section_tag(heading) == tag)
+                .copied();
+            if let Some((heading, text)) = section {
+                self.write_help_section(heading, text)?;
+                return self.none(rest);
+            }
+        }
+        self.none("{")?;
+        self.none(part)
+    }
+
+    /// Writes every registered help section in registration order, followed by
+    /// the `EXAMPLES` section when any examples are registered.
+    fn write_all_help_sections(&mut self) -> io::Result<()> {
+        let sections = self.app.help_sections.clone();
+        for (heading, text) in sections {
+            self.none("\n\n")?;
+            self.write_help_section(heading, text)?;
+        }
+        if !self.app.examples.is_empty() {
+            self.none("\n\n")?;
+            self.write_examples_section()?;
+        }
+        Ok(())
+    }
+
+    /// Writes the examples registered via [`App::example`][crate::App::example]
+    /// as invocation lines each followed by an indented description.
+    fn write_examples_section(&mut self) -> io::Result<()> {
+        let examples = self.app.examples.clone();
+        self.heading("EXAMPLES:\n")?;
+        let mut first = true;
+        for (invocation, description) in examples {
+            if !first {
+                self.none("\n")?;
+            }
+            first = false;
+            self.none(TAB)?;
+            self.good(invocation)?;
+            self.none("\n")?;
+            self.none(TAB)?;
+            self.none(TAB)?;
+            self.none(description)?;
+        }
+        Ok(())
+    }
+
+    /// Writes one custom section as its heading followed by the indented text.
+    fn write_help_section(&mut self, heading: &str, text: &str) -> io::Result<()> {
+        self.heading(format!("{}:\n", heading))?;
+        let wrapped = if self.app.is_smart_wrap_set() {
+            smart_text_wrapper(text, self.term_w.saturating_sub(TAB.len()))
+        } else {
+            text_wrapper(text, self.term_w.saturating_sub(TAB.len()))
+        };
+        let mut first = true;
+        for line in wrapped.lines() {
+            if !first {
+                self.none("\n")?;
+            }
+            first = false;
+            if !line.is_empty() {
+                self.none(TAB)?;
+                self.none(line)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+/// The template tag matching a custom section heading: lowercased, with spaces
+/// replaced by dashes, e.g. `EXIT CODES` -> `exit-codes`
+fn section_tag(heading: &str) -> String {
+    heading.to_lowercase().replace(' ', "-")
 }
 
 pub(crate) fn dimensions() -> Option<(usize, usize)> {
@@ -991,11 +1210,20 @@ pub(crate) enum HelpWriter<'writer> {
     Buffer(&'writer mut Colorizer),
 }
 
-fn should_show_arg(use_long: bool, arg: &Arg) -> bool {
-    debug!("should_show_arg: use_long={:?}, arg={}", use_long, arg.name);
+fn should_show_arg(use_long: bool, verbose: bool, arg: &Arg) -> bool {
+    debug!(
+        "should_show_arg: use_long={:?}, verbose={:?}, arg={}",
+        use_long, verbose, arg.name
+    );
     if arg.is_hide_set() {
         return false;
     }
+    if verbose {
+        return true;
+    }
+    if arg.is_hide_unless_verbose_set() {
+        return false;
+    }
     (!arg.is_hide_long_help_set() && use_long)
         || (!arg.is_hide_short_help_set() && !use_long)
         || arg.is_next_line_help_set()
@@ -1013,6 +1241,75 @@ fn text_wrapper(help: &str, width: usize) -> String {
         .join("\n")
 }
 
+/// Paragraph-aware wrapping for [`AppSettings::SmartWrap`]: consecutive plain lines
+/// reflow as one paragraph, bullet list items keep a hanging indent, and lines
+/// indented by four or more spaces (code blocks) are left untouched.
+///
+/// [`AppSettings::SmartWrap`]: crate::AppSettings::SmartWrap
+fn smart_text_wrapper(help: &str, width: usize) -> String {
+    /// Width of the list marker when `line` starts a bullet or numbered item,
+    /// e.g. 2 for `- item` and 3 for `1. item`
+    fn list_marker_width(line: &str) -> Option<usize> {
+        if let Some(rest) = line.strip_prefix("- ").or_else(|| line.strip_prefix("* ")) {
+            return Some(line.len() - rest.trim_start().len());
+        }
+        let digits = line.chars().take_while(char::is_ascii_digit).count();
+        if digits > 0 {
+            if let Some(rest) = line[digits..]
+                .strip_prefix(". ")
+                .or_else(|| line[digits..].strip_prefix(") "))
+            {
+                return Some(line.len() - rest.trim_start().len());
+            }
+        }
+        None
+    }
+
+    fn flush(paragraph: &mut Vec<&str>, hanging: &mut String, out: &mut Vec<String>, width: usize) {
+        if paragraph.is_empty() {
+            return;
+        }
+        let text = paragraph
+            .iter()
+            .enumerate()
+            .map(|(i, line)| if i == 0 { *line } else { line.trim_start() })
+            .collect::<Vec<_>>()
+            .join(" ");
+        let wrapper = textwrap::Options::new(width)
+            .break_words(false)
+            .subsequent_indent(hanging);
+        out.push(textwrap::fill(&text, &wrapper));
+        paragraph.clear();
+        hanging.clear();
+    }
+
+    let mut out: Vec<String> = Vec::new();
+    let mut paragraph: Vec<&str> = Vec::new();
+    let mut hanging = String::new();
+
+    for line in help.lines() {
+        let trimmed = line.trim_start();
+        let indent = line.len() - trimmed.len();
+        if trimmed.is_empty() {
+            flush(&mut paragraph, &mut hanging, &mut out, width);
+            out.push(String::new());
+        } else if indent >= 4 {
+            // Code block; keep source formatting
+            flush(&mut paragraph, &mut hanging, &mut out, width);
+            out.push(line.trim_end().to_string());
+        } else if let Some(marker) = list_marker_width(trimmed) {
+            // Each list item wraps on its own, continuations aligned past the marker
+            flush(&mut paragraph, &mut hanging, &mut out, width);
+            hanging = " ".repeat(indent + marker);
+            paragraph.push(line.trim_end());
+        } else {
+            paragraph.push(line.trim_end());
+        }
+    }
+    flush(&mut paragraph, &mut hanging, &mut out, width);
+    out.join("\n")
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -1023,6 +1320,45 @@ mod test {
         assert_eq!(text_wrapper(&help, 5), "foo\nbar\nbaz");
     }
 
+    #[test]
+    fn smart_wrap_reflows_paragraphs() {
+        let help = "foo bar\nbaz qux";
+        assert_eq!(smart_text_wrapper(help, 11), "foo bar baz\nqux");
+    }
+
+    #[test]
+    fn smart_wrap_keeps_paragraph_breaks() {
+        let help = "foo bar\n\nbaz qux";
+        assert_eq!(smart_text_wrapper(help, 11), "foo bar\n\nbaz qux");
+    }
+
+    #[test]
+    fn smart_wrap_hangs_bullet_items() {
+        let help = "- one two three\n- four";
+        assert_eq!(smart_text_wrapper(help, 10), "- one two\n  three\n- four");
+    }
+
+    #[test]
+    fn smart_wrap_hangs_numbered_items() {
+        let help = "1. one two three";
+        assert_eq!(smart_text_wrapper(help, 10), "1. one two\n   three");
+    }
+
+    #[test]
+    fn smart_wrap_joins_bullet_continuations() {
+        let help = "- one two\n  three four";
+        assert_eq!(smart_text_wrapper(help, 14), "- one two\n  three four");
+    }
+
+    #[test]
+    fn smart_wrap_leaves_code_blocks_alone() {
+        let help = "intro\n\n    let x = some_very_long_expression();\n\noutro";
+        assert_eq!(
+            smart_text_wrapper(help, 10),
+            "intro\n\n    let x = some_very_long_expression();\n\noutro"
+        );
+    }
+
     #[test]
     fn display_width_handles_non_ascii() {
         // Popular Danish tongue-twister, the name of a fruit dessert.
@@ -1033,6 +1369,28 @@ mod test {
         assert_eq!(text.len(), 20);
     }
 
+    #[test]
+    fn display_width_handles_cjk_double_width() {
+        // Each CJK character occupies two terminal columns.
+        assert_eq!(display_width("コンパイル"), 10);
+        assert_eq!(display_width("编译选项"), 8);
+    }
+
+    #[test]
+    fn display_width_ignores_combining_characters() {
+        // "e" followed by U+0301 (combining acute) renders as one column.
+        let text = "cafe\u{301}";
+        assert_eq!(text.chars().count(), 5);
+        assert_eq!(display_width(text), 4);
+    }
+
+    #[test]
+    fn display_width_ignores_zero_width_joiner() {
+        // Man + ZWJ + Red Hair: the ZWJ itself takes no column.
+        let text = "\u{1f468}\u{200d}\u{1f9b0}";
+        assert_eq!(display_width(text), 4);
+    }
+
     #[test]
     fn display_width_handles_emojis() {
         let text = "😂";
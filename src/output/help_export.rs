@@ -0,0 +1,212 @@
+//! Exporting help for the full subcommand tree as Markdown or HTML.
+
+use crate::build::App;
+use crate::output::Usage;
+use crate::parse::Parser;
+
+/// The sink a document is written into; the walker drives one of these per format
+trait DocWriter {
+    fn heading(&mut self, level: usize, text: &str);
+    fn paragraph(&mut self, text: &str);
+    fn usage(&mut self, usage: &str);
+    fn item(&mut self, term: &str, description: Option<&str>);
+}
+
+#[derive(Default)]
+struct Markdown(String);
+
+impl DocWriter for Markdown {
+    fn heading(&mut self, level: usize, text: &str) {
+        self.0
+            .push_str(&format!("{} {}\n\n", "#".repeat(level), text));
+    }
+
+    fn paragraph(&mut self, text: &str) {
+        self.0.push_str(&format!("{}\n\n", text));
+    }
+
+    fn usage(&mut self, usage: &str) {
+        self.0.push_str(&format!("**Usage:** `{}`\n\n", usage));
+    }
+
+    fn item(&mut self, term: &str, description: Option<&str>) {
+        match description {
+            Some(description) => self
+                .0
+                .push_str(&format!("* `{}` — {}\n", term, description)),
+            None => self.0.push_str(&format!("* `{}`\n", term)),
+        }
+    }
+}
+
+#[derive(Default)]
+struct Html(String);
+
+impl DocWriter for Html {
+    fn heading(&mut self, level: usize, text: &str) {
+        self.0
+            .push_str(&format!("<h{0}>{1}</h{0}>\n", level, escape_html(text)));
+    }
+
+    fn paragraph(&mut self, text: &str) {
+        self.0.push_str(&format!("<p>{}</p>\n", escape_html(text)));
+    }
+
+    fn usage(&mut self, usage: &str) {
+        self.0.push_str(&format!(
+            "<p><b>Usage:</b> <code>{}</code></p>\n",
+            escape_html(usage)
+        ));
+    }
+
+    fn item(&mut self, term: &str, description: Option<&str>) {
+        self.0
+            .push_str(&format!("<dt><code>{}</code></dt>\n", escape_html(term)));
+        if let Some(description) = description {
+            self.0
+                .push_str(&format!("<dd>{}</dd>\n", escape_html(description)));
+        }
+    }
+}
+
+pub(crate) fn render_markdown(app: &mut App) -> String {
+    let mut doc = Markdown::default();
+    let path = app.get_name().to_string();
+    write_command(&mut doc, app, &path, 1, |_| {}, |d| d.0.push('\n'));
+    doc.0
+}
+
+pub(crate) fn render_html(app: &mut App) -> String {
+    let mut doc = Html::default();
+    let path = app.get_name().to_string();
+    write_command(
+        &mut doc,
+        app,
+        &path,
+        1,
+        |d| d.0.push_str("<dl>\n"),
+        |d| d.0.push_str("</dl>\n"),
+    );
+    doc.0
+}
+
+/// Writes one command and recurses into its visible subcommands
+///
+/// `open_list`/`close_list` bracket each run of items, which Markdown leaves bare
+/// and HTML wraps in a definition list.
+fn write_command<W: DocWriter>(
+    doc: &mut W,
+    app: &mut App,
+    path: &str,
+    level: usize,
+    open_list: fn(&mut W),
+    close_list: fn(&mut W),
+) {
+    // Seed the bin name so subcommand usage lines show the full path, as they
+    // would after parsing descended into the subcommand
+    if app.bin_name.is_none() {
+        app.bin_name = Some(path.to_string());
+    }
+    app._build();
+
+    doc.heading(level, path);
+    if let Some(about) = app.get_long_about().or_else(|| app.get_about()) {
+        doc.paragraph(about);
+    }
+
+    let usage = {
+        let parser = Parser::new(app);
+        Usage::new(parser.app, &parser.required).create_usage_no_title(&[])
+    };
+    doc.usage(&usage);
+
+    let positionals: Vec<_> = app
+        .get_positionals()
+        .filter(|a| !a.is_hide_set())
+        .collect();
+    if !positionals.is_empty() {
+        doc.heading(level + 1, "Arguments");
+        open_list(doc);
+        for arg in positionals {
+            let name = arg
+                .get_value_names()
+                .and_then(|names| names.first().copied())
+                .unwrap_or_else(|| arg.get_id());
+            let term = if arg.is_required_set() {
+                format!("<{}>", name)
+            } else {
+                format!("[{}]", name)
+            };
+            doc.item(&term, arg.get_long_help().or_else(|| arg.get_help()));
+        }
+        close_list(doc);
+    }
+
+    let options: Vec<_> = app
+        .get_arguments()
+        .filter(|a| !a.is_positional() && !a.is_hide_set())
+        .collect();
+    if !options.is_empty() {
+        doc.heading(level + 1, "Options");
+        open_list(doc);
+        for arg in options {
+            let mut term = String::new();
+            if let Some(short) = arg.get_short() {
+                term.push_str(&format!("-{}", short));
+            }
+            if let Some(long) = arg.get_long() {
+                if !term.is_empty() {
+                    term.push_str(", ");
+                }
+                term.push_str(&format!("--{}", long));
+            }
+            if arg.is_takes_value_set() {
+                let name = arg
+                    .get_value_names()
+                    .and_then(|names| names.first().copied())
+                    .unwrap_or_else(|| arg.get_id());
+                term.push_str(&format!(" <{}>", name));
+            }
+            doc.item(&term, arg.get_long_help().or_else(|| arg.get_help()));
+        }
+        close_list(doc);
+    }
+
+    let examples: Vec<_> = app.get_examples().collect();
+    if !examples.is_empty() {
+        doc.heading(level + 1, "Examples");
+        open_list(doc);
+        for (invocation, description) in examples {
+            doc.item(invocation, Some(description));
+        }
+        close_list(doc);
+    }
+
+    let subcommand_paths: Vec<(String, String)> = app
+        .get_subcommands()
+        .filter(|sc| !sc.is_hide_set())
+        .map(|sc| (sc.get_name().to_string(), format!("{} {}", path, sc.get_name())))
+        .collect();
+    if !subcommand_paths.is_empty() {
+        doc.heading(level + 1, "Subcommands");
+        open_list(doc);
+        for (name, _) in &subcommand_paths {
+            let about = app
+                .find_subcommand(name)
+                .and_then(|sc| sc.get_about());
+            doc.item(name, about);
+        }
+        close_list(doc);
+
+        for (name, sc_path) in &subcommand_paths {
+            let sc = app
+                .find_subcommand_mut(name)
+                .expect("listed from this app's subcommands");
+            write_command(doc, sc, sc_path, level + 1, open_list, close_list);
+        }
+    }
+}
+
+fn escape_html(s: &str) -> String {
+    s.replace('&', "&amp;").replace('<', "&lt;").replace('>', "&gt;")
+}
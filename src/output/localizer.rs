@@ -0,0 +1,86 @@
+//! Translation hooks for clap's built-in strings.
+
+use std::borrow::Cow;
+use std::fmt;
+use std::sync::Arc;
+
+/// A built-in string clap renders on its own, identified for translation.
+///
+/// Passed to [`Localizer::translate`].  New variants may be added as more of
+/// the output becomes localizable, so matches should include a `_` arm.
+#[non_exhaustive]
+#[derive(Copy, Clone, Debug, PartialEq, Eq, Hash)]
+pub enum DefaultText {
+    /// The `error:` prefix in front of parse errors.
+    ErrorPrefix,
+    /// The `USAGE:` heading, in help output and in errors.
+    UsageHeading,
+    /// The `OPTIONS:` heading in help output.
+    OptionsHeading,
+    /// The `ARGS:` heading in help output.
+    ArgsHeading,
+    /// The `SUBCOMMANDS` heading in help output (rendered without the colon,
+    /// matching [`App::subcommand_heading`]).
+    ///
+    /// [`App::subcommand_heading`]: crate::App::subcommand_heading()
+    SubcommandsHeading,
+    /// The `For more information try ` phrase before the help flag suggestion.
+    TryHelp,
+    /// The `The following required arguments were not provided:` message.
+    MissingRequiredArguments,
+}
+
+/// Supplies translations for clap's built-in strings.
+///
+/// Registered via [`App::localizer`], after which every built-in string in
+/// error rendering and help generation is looked up before being written.
+/// Returning `None` keeps the English default, so a localizer only needs to
+/// cover the strings it actually translates.
+///
+/// [`App::localizer`]: crate::App::localizer()
+pub trait Localizer {
+    /// Return the translation for `text`, or `None` to keep the default.
+    fn translate(&self, text: DefaultText) -> Option<Cow<'static, str>>;
+}
+
+/// Shared handle threaded from the `App` into help and error rendering.
+#[derive(Clone)]
+pub(crate) struct Localization(Arc<dyn Localizer + Send + Sync>);
+
+impl Localization {
+    pub(crate) fn new(localizer: impl Localizer + Send + Sync + 'static) -> Self {
+        Self(Arc::new(localizer))
+    }
+
+    pub(crate) fn text(&self, key: DefaultText, default: &'static str) -> Cow<'static, str> {
+        self.0
+            .translate(key)
+            .unwrap_or(Cow::Borrowed(default))
+    }
+}
+
+impl fmt::Debug for Localization {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Localization").finish()
+    }
+}
+
+impl PartialEq for Localization {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl Eq for Localization {}
+
+/// Look up `key`, falling back to `default` when no localizer is registered.
+pub(crate) fn localize(
+    localization: Option<&Localization>,
+    key: DefaultText,
+    default: &'static str,
+) -> Cow<'static, str> {
+    match localization {
+        Some(localization) => localization.text(key, default),
+        None => Cow::Borrowed(default),
+    }
+}
@@ -1,7 +1,12 @@
 mod help;
+mod help_export;
 mod usage;
 
 pub(crate) mod fmt;
+pub(crate) mod localizer;
+pub(crate) mod pager;
+pub(crate) mod prompt;
 
-pub(crate) use self::help::{Help, HelpWriter};
+pub(crate) use self::help::{dimensions, Help, HelpWriter};
+pub(crate) use self::help_export::{render_html, render_markdown};
 pub(crate) use self::usage::Usage;
@@ -0,0 +1,76 @@
+//! Piping help output through the user's pager.
+
+use std::env;
+use std::io::{self, Write};
+use std::process::{Command, Stdio};
+
+/// Pipe `content` through the user's pager, falling back to plain printing if the
+/// pager cannot be spawned.
+///
+/// When `search` is given and the pager is `less`, the pager is opened pre-searched
+/// to the first occurrence of the pattern (via `+/pattern`).
+pub(crate) fn page(content: &str, search: Option<&str>) -> io::Result<()> {
+    let pager = env::var("PAGER").unwrap_or_else(|_| "less".to_owned());
+
+    let mut cmd = Command::new(&pager);
+    cmd.args(pager_args(&pager, search));
+    cmd.stdin(Stdio::piped());
+
+    let mut child = match cmd.spawn() {
+        Ok(child) => child,
+        Err(_) => {
+            // No usable pager; print the content as-is.
+            let stdout = io::stdout();
+            let mut stdout = stdout.lock();
+            return stdout.write_all(content.as_bytes());
+        }
+    };
+
+    if let Some(stdin) = child.stdin.as_mut() {
+        // Ignore broken pipe; the user may quit the pager before reading everything.
+        let _ = stdin.write_all(content.as_bytes());
+    }
+    child.wait()?;
+
+    Ok(())
+}
+
+/// Build the arguments to pass to the pager.
+fn pager_args(pager: &str, search: Option<&str>) -> Vec<String> {
+    let is_less = std::path::Path::new(pager)
+        .file_stem()
+        .map_or(false, |stem| stem == "less");
+
+    let mut args = Vec::new();
+    if is_less {
+        // Pass ANSI color sequences through unchanged.
+        args.push("-R".to_owned());
+        if let Some(pattern) = search {
+            args.push(format!("+/{}", pattern));
+        }
+    }
+    args
+}
+
+#[cfg(test)]
+mod tests {
+    use super::pager_args;
+
+    #[test]
+    fn less_gets_raw_control_chars() {
+        assert_eq!(pager_args("less", None), vec!["-R".to_owned()]);
+    }
+
+    #[test]
+    fn less_search_pattern() {
+        assert_eq!(
+            pager_args("/usr/bin/less", Some("--config")),
+            vec!["-R".to_owned(), "+/--config".to_owned()]
+        );
+    }
+
+    #[test]
+    fn unknown_pager_gets_no_args() {
+        assert!(pager_args("more", Some("--config")).is_empty());
+    }
+}
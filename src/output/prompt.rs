@@ -0,0 +1,37 @@
+//! Interactive prompting for missing argument values.
+
+use std::io::{self, BufRead, Write};
+
+/// Whether stdin is connected to a terminal.
+///
+/// Prompting only makes sense interactively; when stdin is a pipe or file the
+/// caller should fall back to the usual missing-argument error.
+pub(crate) fn stdin_is_tty() -> bool {
+    #[cfg(feature = "color")]
+    {
+        atty::is(atty::Stream::Stdin)
+    }
+    #[cfg(not(feature = "color"))]
+    {
+        false
+    }
+}
+
+/// Write `prompt` to stderr and read one line from stdin.
+///
+/// The typed value is echoed by the terminal as usual; prompting is not
+/// suitable for passwords or other secrets.
+pub(crate) fn read_value(prompt: &str) -> io::Result<String> {
+    let stderr = io::stderr();
+    let mut stderr = stderr.lock();
+    stderr.write_all(prompt.as_bytes())?;
+    stderr.flush()?;
+
+    let mut value = String::new();
+    io::stdin().lock().read_line(&mut value)?;
+
+    while value.ends_with('\n') || value.ends_with('\r') {
+        value.pop();
+    }
+    Ok(value)
+}
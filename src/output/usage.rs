@@ -4,6 +4,7 @@ use indexmap::IndexSet;
 use crate::{
     build::AppSettings as AS,
     build::{App, Arg, ArgPredicate},
+    output::localizer::{localize, DefaultText},
     parse::ArgMatcher,
     util::{ChildGraph, Id},
     INTERNAL_ERROR_MSG,
@@ -24,7 +25,12 @@ impl<'help, 'app> Usage<'help, 'app> {
     pub(crate) fn create_usage_with_title(&self, used: &[Id]) -> String {
         debug!("Usage::create_usage_with_title");
         let mut usage = String::with_capacity(75);
-        usage.push_str("USAGE:\n    ");
+        usage.push_str(&localize(
+            self.app.localizer.as_ref(),
+            DefaultText::UsageHeading,
+            "USAGE:",
+        ));
+        usage.push_str("\n    ");
         usage.push_str(&*self.create_usage_no_title(used));
         usage
     }
@@ -113,6 +113,27 @@ impl ArgMatcher {
         self.0.subcommand = Some(Box::new(sc));
     }
 
+    /// Records where the trailing capture began; only the first capture counts
+    pub(crate) fn set_consumed_len(&mut self, len: usize) {
+        self.0.consumed_len.get_or_insert(len);
+    }
+
+    pub(crate) fn record_flag_prefix(&mut self, arg: &Id, prefix: char) {
+        self.0.flag_prefixes.insert(arg.clone(), prefix);
+    }
+
+    pub(crate) fn clear_flag_prefix(&mut self, arg: &Id) {
+        self.0.flag_prefixes.swap_remove(arg);
+    }
+
+    pub(crate) fn push_trailing(&mut self, val: OsString) {
+        self.0.trailing.push(val);
+    }
+
+    pub(crate) fn set_remaining(&mut self, vals: Vec<OsString>) {
+        self.0.remaining = vals;
+    }
+
     pub(crate) fn subcommand_name(&self) -> Option<&str> {
         self.0.subcommand_name()
     }
@@ -132,6 +153,15 @@ impl ArgMatcher {
         ma.update_ty(ValueSource::CommandLine);
         ma.set_ignore_case(arg.is_ignore_case_set());
         ma.invalid_utf8_allowed(arg.is_allow_invalid_utf8_set());
+        ma.set_source_name(arg.name);
+        if arg.is_saturating_occurrences_set() {
+            if let Some(max) = arg.max_occurs {
+                if ma.get_occurrences() as usize >= max {
+                    debug!("ArgMatcher::inc_occurrence_of_arg: saturated at {}", max);
+                    return;
+                }
+            }
+        }
         ma.inc_occurrences();
     }
 
@@ -165,6 +195,12 @@ impl ArgMatcher {
         ma.append_val(val);
     }
 
+    pub(crate) fn add_raw_val_to(&mut self, arg: &Id, val: OsString) {
+        let ma = self.entry(arg).or_default();
+        let index = ma.num_vals();
+        ma.push_raw_val(index, val);
+    }
+
     pub(crate) fn new_val_group(&mut self, arg: &Id) {
         let ma = self.entry(arg).or_default();
         ma.new_val_group();
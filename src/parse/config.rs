@@ -0,0 +1,128 @@
+use yaml_rust::{Yaml, YamlLoader};
+
+/// A value read from a config file, before it is matched against an argument.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ConfigValue {
+    Bool(bool),
+    String(String),
+    List(Vec<String>),
+}
+
+/// Parses a YAML config document into key/value pairs.
+///
+/// The document is parsed with [`yaml_rust`], then restricted to the flat
+/// subset that maps onto arguments: a single top-level mapping from string
+/// keys to booleans, scalars, or lists of scalars.  Nested mappings and lists
+/// are rejected since they have no natural mapping onto arguments.
+pub(crate) fn parse_config(text: &str) -> Result<Vec<(String, ConfigValue)>, String> {
+    let mut docs = YamlLoader::load_from_str(text).map_err(|e| e.to_string())?;
+    let doc = match docs.len() {
+        0 => return Ok(Vec::new()),
+        1 => docs.remove(0),
+        _ => return Err("expected a single YAML document".to_string()),
+    };
+    let hash = match doc {
+        Yaml::Hash(hash) => hash,
+        Yaml::Null => return Ok(Vec::new()),
+        _ => return Err("expected a mapping of keys to values".to_string()),
+    };
+
+    let mut pairs = Vec::new();
+    for (key, value) in hash {
+        let key = match key {
+            Yaml::String(key) => key,
+            other => return Err(format!("key `{:?}` is not a string", other)),
+        };
+        let value = match value {
+            Yaml::Array(items) => {
+                let items = items
+                    .into_iter()
+                    .map(|item| {
+                        scalar_string(item).map_err(|e| format!("key `{}`: list {}", key, e))
+                    })
+                    .collect::<Result<Vec<_>, String>>()?;
+                ConfigValue::List(items)
+            }
+            Yaml::Boolean(b) => ConfigValue::Bool(b),
+            Yaml::Hash(_) => {
+                return Err(format!(
+                    "key `{}`: nested mappings are not supported; use top-level keys",
+                    key
+                ));
+            }
+            other => scalar_string(other)
+                .map(ConfigValue::String)
+                .map_err(|e| format!("key `{}`: {}", key, e))?,
+        };
+        pairs.push((key, value));
+    }
+    Ok(pairs)
+}
+
+fn scalar_string(value: Yaml) -> Result<String, String> {
+    match value {
+        Yaml::String(s) => Ok(s),
+        Yaml::Integer(i) => Ok(i.to_string()),
+        Yaml::Real(s) => Ok(s),
+        Yaml::Boolean(b) => Ok(b.to_string()),
+        Yaml::Null => Err("null values are not supported".to_string()),
+        Yaml::Array(_) | Yaml::Hash(_) => {
+            Err("values must be scalars; nested collections are not supported".to_string())
+        }
+        other => Err(format!("unsupported value `{:?}`", other)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scalars_and_lists() {
+        let pairs = parse_config(
+            "# settings\nport: 8080\nname: \"srv\" # inline\nverbose: true\nfiles: [a, \"b\"]\nmore:\n  - c\n  - d\n",
+        )
+        .unwrap();
+        assert_eq!(
+            pairs,
+            vec![
+                ("port".into(), ConfigValue::String("8080".into())),
+                ("name".into(), ConfigValue::String("srv".into())),
+                ("verbose".into(), ConfigValue::Bool(true)),
+                (
+                    "files".into(),
+                    ConfigValue::List(vec!["a".into(), "b".into()])
+                ),
+                (
+                    "more".into(),
+                    ConfigValue::List(vec!["c".into(), "d".into()])
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn empty_document_is_empty() {
+        assert_eq!(parse_config("").unwrap(), vec![]);
+        assert_eq!(parse_config("# only comments\n").unwrap(), vec![]);
+    }
+
+    #[test]
+    fn rejects_nested_mappings() {
+        let err = parse_config("server:\n  port: 1\n").unwrap_err();
+        assert!(err.contains("server"), "{}", err);
+        assert!(err.contains("nested"), "{}", err);
+    }
+
+    #[test]
+    fn rejects_nested_lists() {
+        let err = parse_config("files:\n  - [a, b]\n").unwrap_err();
+        assert!(err.contains("files"), "{}", err);
+        assert!(err.contains("scalars"), "{}", err);
+    }
+
+    #[test]
+    fn rejects_invalid_yaml() {
+        assert!(parse_config("port: [unterminated\n").is_err());
+    }
+}
@@ -4,12 +4,31 @@ use std::cmp::Ordering;
 // Internal
 use crate::build::App;
 
+/// The minimum Jaro-Winkler confidence a candidate needs before it is suggested,
+/// unless overridden with [`App::suggestion_threshold`][crate::App::suggestion_threshold].
+pub(crate) const DEFAULT_CONFIDENCE: f64 = 0.8;
+
+/// An [`App::suggestion_threshold`][crate::App::suggestion_threshold] override.
+///
+/// Wraps the `f64` so `App` can keep deriving `PartialEq`/`Eq`; thresholds compare by
+/// bit pattern, which is exact for the literals users pass in.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Confidence(pub(crate) f64);
+
+impl PartialEq for Confidence {
+    fn eq(&self, other: &Self) -> bool {
+        self.0.to_bits() == other.0.to_bits()
+    }
+}
+
+impl Eq for Confidence {}
+
 /// Produces multiple strings from a given list of possible values which are similar
 /// to the passed in value `v` within a certain confidence by least confidence.
 /// Thus in a list of possible values like ["foo", "bar"], the value "fop" will yield
 /// `Some("foo")`, whereas "blark" would yield `None`.
 #[cfg(feature = "suggestions")]
-pub(crate) fn did_you_mean<T, I>(v: &str, possible_values: I) -> Vec<String>
+pub(crate) fn did_you_mean<T, I>(v: &str, possible_values: I, min_confidence: f64) -> Vec<String>
 where
     T: AsRef<str>,
     I: IntoIterator<Item = T>,
@@ -17,14 +36,14 @@ where
     let mut candidates: Vec<(f64, String)> = possible_values
         .into_iter()
         .map(|pv| (strsim::jaro_winkler(v, pv.as_ref()), pv.as_ref().to_owned()))
-        .filter(|(confidence, _)| *confidence > 0.8)
+        .filter(|(confidence, _)| *confidence > min_confidence)
         .collect();
     candidates.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(Ordering::Equal));
     candidates.into_iter().map(|(_, pv)| pv).collect()
 }
 
 #[cfg(not(feature = "suggestions"))]
-pub(crate) fn did_you_mean<T, I>(_: &str, _: I) -> Vec<String>
+pub(crate) fn did_you_mean<T, I>(_: &str, _: I, _: f64) -> Vec<String>
 where
     T: AsRef<str>,
     I: IntoIterator<Item = T>,
@@ -38,6 +57,7 @@ pub(crate) fn did_you_mean_flag<I, T>(
     remaining_args: &[&str],
     longs: I,
     subcommands: &mut [App],
+    min_confidence: f64,
 ) -> Option<(String, Option<String>)>
 where
     T: AsRef<str>,
@@ -45,7 +65,7 @@ where
 {
     use crate::mkeymap::KeyType;
 
-    match did_you_mean(arg, longs).pop() {
+    match did_you_mean(arg, longs, min_confidence).pop() {
         Some(candidate) => Some((candidate, None)),
         None => subcommands
             .iter_mut()
@@ -62,7 +82,7 @@ where
 
                 let subcommand_name = subcommand.get_name();
 
-                let candidate = did_you_mean(arg, longs).pop()?;
+                let candidate = did_you_mean(arg, longs, min_confidence).pop()?;
                 let score = remaining_args.iter().position(|x| *x == subcommand_name)?;
                 Some((score, (candidate, Some(subcommand_name.to_string()))))
             })
@@ -71,6 +91,36 @@ where
     }
 }
 
+/// Searches the entire subcommand tree for a command that accepts `--arg` verbatim,
+/// returning the names of the subcommands leading to it.
+///
+/// Candidates closer to the root win, so the rendered path stays as short as possible.
+pub(crate) fn flag_subcommand_path(arg: &str, subcommands: &mut [App]) -> Option<Vec<String>> {
+    use crate::mkeymap::KeyType;
+
+    for subcommand in subcommands.iter_mut() {
+        subcommand._build();
+
+        let accepts = subcommand.args.keys().any(|a| match a {
+            KeyType::Long(l) => l.to_string_lossy() == arg,
+            _ => false,
+        });
+        if accepts {
+            return Some(vec![subcommand.get_name().to_string()]);
+        }
+    }
+
+    for subcommand in subcommands.iter_mut() {
+        subcommand._materialize_all_lazy_subcommands();
+        if let Some(mut path) = flag_subcommand_path(arg, subcommand.subcommands.as_mut_slice()) {
+            path.insert(0, subcommand.get_name().to_string());
+            return Some(path);
+        }
+    }
+
+    None
+}
+
 #[cfg(all(test, features = "suggestions"))]
 mod test {
     use super::*;
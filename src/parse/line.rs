@@ -0,0 +1,90 @@
+// Internal
+use crate::{Error, ErrorKind};
+
+/// Split a command line into words with POSIX-ish shell rules, for
+/// [`App::try_get_matches_from_line`].
+///
+/// Whitespace separates words; single quotes keep their contents verbatim;
+/// double quotes keep whitespace but let `\` escape `"`, `\`, `$` and
+/// `` ` ``; a backslash outside quotes escapes the next character. Unclosed
+/// quotes and a trailing backslash are reported as
+/// [`ErrorKind::InvalidLineSyntax`] pointing at the offending column.
+///
+/// [`App::try_get_matches_from_line`]: crate::App::try_get_matches_from_line()
+pub(crate) fn split_line(line: &str) -> Result<Vec<String>, Error> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut in_word = false;
+    let mut chars = line.char_indices();
+
+    while let Some((index, c)) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {
+                if in_word {
+                    words.push(std::mem::take(&mut current));
+                    in_word = false;
+                }
+            }
+            '\'' => {
+                in_word = true;
+                loop {
+                    match chars.next() {
+                        Some((_, '\'')) => break,
+                        Some((_, c)) => current.push(c),
+                        None => return Err(syntax_error(line, index, "unclosed single quote")),
+                    }
+                }
+            }
+            '"' => {
+                in_word = true;
+                loop {
+                    match chars.next() {
+                        Some((_, '"')) => break,
+                        Some((_, '\\')) => match chars.next() {
+                            Some((_, c @ ('"' | '\\' | '$' | '`'))) => current.push(c),
+                            Some((_, c)) => {
+                                current.push('\\');
+                                current.push(c);
+                            }
+                            None => {
+                                return Err(syntax_error(line, index, "unclosed double quote"))
+                            }
+                        },
+                        Some((_, c)) => current.push(c),
+                        None => return Err(syntax_error(line, index, "unclosed double quote")),
+                    }
+                }
+            }
+            '\\' => {
+                in_word = true;
+                match chars.next() {
+                    Some((_, c)) => current.push(c),
+                    None => return Err(syntax_error(line, index, "dangling escape")),
+                }
+            }
+            c => {
+                in_word = true;
+                current.push(c);
+            }
+        }
+    }
+    if in_word {
+        words.push(current);
+    }
+
+    Ok(words)
+}
+
+fn syntax_error(line: &str, index: usize, what: &str) -> Error {
+    let column = line[..index].chars().count();
+    Error::raw(
+        ErrorKind::InvalidLineSyntax,
+        format!(
+            "{} at column {}\n\n  {}\n  {}^\n",
+            what,
+            column + 1,
+            line,
+            " ".repeat(column)
+        ),
+    )
+}
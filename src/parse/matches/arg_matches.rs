@@ -1,9 +1,11 @@
 // Std
 use std::{
+    any::Any,
     borrow::Cow,
     ffi::{OsStr, OsString},
-    fmt::{Debug, Display},
+    fmt::{Debug, Display, Write as _},
     iter::{Cloned, Flatten, Map},
+    path::Path,
     slice::Iter,
     str::FromStr,
 };
@@ -14,7 +16,7 @@ use indexmap::IndexMap;
 // Internal
 use crate::parse::MatchedArg;
 use crate::parse::ValueSource;
-use crate::util::{Id, Key};
+use crate::util::{AnyValue, Id, Key};
 use crate::{Error, INVALID_UTF8};
 
 /// Container for parse results.
@@ -75,7 +77,11 @@ pub struct ArgMatches {
     #[cfg(debug_assertions)]
     pub(crate) disable_asserts: bool,
     pub(crate) args: IndexMap<Id, MatchedArg>,
+    pub(crate) flag_prefixes: IndexMap<Id, char>,
     pub(crate) subcommand: Option<Box<SubCommand>>,
+    pub(crate) consumed_len: Option<usize>,
+    pub(crate) trailing: Vec<OsString>,
+    pub(crate) remaining: Vec<OsString>,
 }
 
 impl ArgMatches {
@@ -247,6 +253,101 @@ impl ArgMatches {
         Some(v.as_os_str())
     }
 
+    /// Gets the canonical, absolute path resolved from the value of a specific argument.
+    ///
+    /// Only populated for args with [`Arg::canonicalize(true)`]; for those, the regular
+    /// accessors keep returning the path as the user typed it.
+    ///
+    /// Returns `None` if the option wasn't present or wasn't set up for canonicalization.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("config")
+    ///         .long("config")
+    ///         .canonicalize(true))
+    ///     .get_matches_from(vec!["prog", "--config", "Cargo.toml"]);
+    /// assert!(m.canonical_value_of("config").unwrap().is_absolute());
+    /// ```
+    /// [`Arg::canonicalize(true)`]: crate::Arg::canonicalize()
+    pub fn canonical_value_of<T: Key>(&self, id: T) -> Option<&Path> {
+        let id = Id::from(id);
+        let arg = self.get_arg(&id)?;
+        let v = arg.first_canonical()?;
+        Some(Path::new(v))
+    }
+
+    /// Gets the canonical, absolute paths resolved from the values of a specific argument.
+    ///
+    /// Like [`ArgMatches::canonical_value_of`] but for args that take multiple values.
+    ///
+    /// Returns `None` if the option wasn't present or wasn't set up for canonicalization.
+    ///
+    /// [`ArgMatches::canonical_value_of`]: ArgMatches::canonical_value_of()
+    pub fn canonical_values_of<T: Key>(&self, id: T) -> Option<Vec<&Path>> {
+        let id = Id::from(id);
+        let arg = self.get_arg(&id)?;
+        if arg.canonical_vals().len() == 0 {
+            return None;
+        }
+        Some(arg.canonical_vals().map(Path::new).collect())
+    }
+
+    /// Gets the typed value produced by the argument's [`ValueParser`].
+    ///
+    /// Only populated for args with [`Arg::value_parser`]; the value was parsed
+    /// while matching, so no string re-parsing happens here.
+    ///
+    /// Returns `None` if the option wasn't present or has no value parser.
+    ///
+    /// # Panics
+    ///
+    /// If `T` is not the type the argument's parser produces.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg, ValueParser};
+    /// let m = App::new("prog")
+    ///     .arg(Arg::new("port")
+    ///         .long("port")
+    ///         .takes_value(true)
+    ///         .value_parser(ValueParser::new::<u16>()))
+    ///     .get_matches_from(vec!["prog", "--port", "8080"]);
+    /// assert_eq!(m.get_one::<u16>("port"), Some(&8080));
+    /// ```
+    /// [`ValueParser`]: crate::ValueParser
+    /// [`Arg::value_parser`]: crate::Arg::value_parser()
+    pub fn get_one<T: Any>(&self, id: &str) -> Option<&T> {
+        let id = Id::from(id);
+        let arg = self.get_arg(&id)?;
+        let v = arg.first_typed()?;
+        Some(expect_type(v, &id))
+    }
+
+    /// Gets the typed values produced by the argument's [`ValueParser`].
+    ///
+    /// Like [`ArgMatches::get_one`] but for args that take multiple values.
+    ///
+    /// Returns `None` if the option wasn't present or has no value parser.
+    ///
+    /// # Panics
+    ///
+    /// If `T` is not the type the argument's parser produces.
+    ///
+    /// [`ValueParser`]: crate::ValueParser
+    /// [`ArgMatches::get_one`]: ArgMatches::get_one()
+    pub fn get_many<T: Any>(&self, id: &str) -> Option<impl Iterator<Item = &T>> {
+        let id = Id::from(id);
+        let arg = self.get_arg(&id)?;
+        if arg.typed_vals().len() == 0 {
+            return None;
+        }
+        Some(arg.typed_vals().map(move |v| expect_type(v, &id)))
+    }
+
     /// Get an [`Iterator`] over [values] of a specific option or positional argument.
     ///
     /// i.e. an argument that takes multiple values at runtime.
@@ -291,8 +392,35 @@ impl ArgMatches {
         Some(v)
     }
 
-    /// Placeholder documentation.
-    #[cfg(feature = "unstable-grouped")]
+    /// Get the values of an argument grouped by occurrence.
+    ///
+    /// Where [`ArgMatches::values_of`] flattens everything into one sequence,
+    /// this keeps the values of each occurrence together, so `-p a b -p c` can
+    /// be told apart from `-p a b c` without index arithmetic.
+    ///
+    /// # Panics
+    ///
+    /// If the value is invalid UTF-8.
+    ///
+    /// If `id` is is not a valid argument or group name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myprog")
+    ///     .arg(Arg::new("exec")
+    ///         .short('p')
+    ///         .takes_value(true)
+    ///         .multiple_values(true)
+    ///         .multiple_occurrences(true))
+    ///     .get_matches_from(vec![
+    ///         "myprog", "-p", "a", "b", "-p", "c"
+    ///     ]);
+    /// let groups: Vec<Vec<&str>> = m.grouped_values_of("exec").unwrap().collect();
+    /// assert_eq!(groups, vec![vec!["a", "b"], vec!["c"]]);
+    /// ```
+    /// [`ArgMatches::values_of`]: ArgMatches::values_of()
     pub fn grouped_values_of<T: Key>(&self, id: T) -> Option<GroupedValues> {
         let id = Id::from(id);
         let arg = self.get_arg(&id)?;
@@ -626,10 +754,12 @@ impl ArgMatches {
         self.args.contains_key(&id)
     }
 
-    /// Check if an argument was present at runtime.
+    /// Report where the argument's value came from.
     ///
-    /// *NOTE:* This will always return `true` if [`default_value`] has been set.
-    /// [`occurrences_of`] can be used to check if a value is present at runtime.
+    /// Unlike [`is_present`], this distinguishes values the user passed on the
+    /// command-line from those filled in by [`default_value`] or [`env`], which is
+    /// essential for config-precedence logic.  Returns `None` if the argument wasn't
+    /// present at all.
     ///
     /// # Panics
     ///
@@ -638,19 +768,25 @@ impl ArgMatches {
     /// # Examples
     ///
     /// ```rust
-    /// # use clap::{App, Arg};
-    /// let m = App::new("myprog")
-    ///     .arg(Arg::new("debug")
-    ///         .short('d'))
-    ///     .get_matches_from(vec![
-    ///         "myprog", "-d"
-    ///     ]);
-    ///
-    /// assert!(m.is_present("debug"));
+    /// # use clap::{App, Arg, ValueSource};
+    /// let app = || {
+    ///     App::new("myprog")
+    ///         .arg(Arg::new("port")
+    ///             .long("port")
+    ///             .takes_value(true)
+    ///             .default_value("80"))
+    /// };
+    ///
+    /// let m = app().get_matches_from(vec!["myprog", "--port", "8080"]);
+    /// assert_eq!(m.value_source("port"), Some(ValueSource::CommandLine));
+    ///
+    /// let m = app().get_matches_from(vec!["myprog"]);
+    /// assert_eq!(m.value_source("port"), Some(ValueSource::DefaultValue));
     /// ```
     ///
+    /// [`is_present`]: ArgMatches::is_present()
     /// [`default_value`]: crate::Arg::default_value()
-    /// [`occurrences_of`]: ArgMatches::occurrences_of()
+    /// [`env`]: crate::Arg::env()
     pub fn value_source<T: Key>(&self, id: T) -> Option<ValueSource> {
         let id = Id::from(id);
 
@@ -659,6 +795,143 @@ impl ArgMatches {
         value.and_then(MatchedArg::source)
     }
 
+    /// The alternative prefix character the argument was last used with.
+    ///
+    /// Prefixes other than `-` are registered with
+    /// [`App::flag_prefix`][crate::App::flag_prefix]; this reports which one
+    /// introduced the argument's most recent occurrence, so `+x`/`-x` pairs can
+    /// mean enable/disable. Returns `None` when the argument was absent or only
+    /// used with the standard `-`/`--` prefixes.
+    ///
+    /// # Panics
+    ///
+    /// If `id` is is not a valid argument or group name.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let app = || {
+    ///     App::new("sh")
+    ///         .flag_prefix('+')
+    ///         .arg(Arg::new("xtrace").short('x'))
+    /// };
+    ///
+    /// let m = app().get_matches_from(vec!["sh", "+x"]);
+    /// assert_eq!(m.flag_prefix("xtrace"), Some('+'));
+    ///
+    /// let m = app().get_matches_from(vec!["sh", "-x"]);
+    /// assert_eq!(m.flag_prefix("xtrace"), None);
+    /// ```
+    pub fn flag_prefix<T: Key>(&self, id: T) -> Option<char> {
+        let id = Id::from(id);
+
+        #[cfg(debug_assertions)]
+        self.get_arg(&id);
+
+        self.flag_prefixes.get(&id).copied()
+    }
+
+    /// Render a human-readable report of every argument, its final value, and
+    /// which source supplied it.
+    ///
+    /// One padded row per argument present in the matches, with a section per
+    /// subcommand in the chain. Intended for `--explain-config` style
+    /// debugging flags in large CLIs, so users can see whether a value came
+    /// from the command line, the environment, a config file, or a default.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myapp")
+    ///     .arg(Arg::new("port")
+    ///         .long("port")
+    ///         .takes_value(true)
+    ///         .default_value("80"))
+    ///     .arg(Arg::new("verbose").short('v'))
+    ///     .get_matches_from(vec!["myapp", "-v"]);
+    /// let report = m.explain();
+    /// assert!(report.contains("port"));
+    /// assert!(report.contains("default"));
+    /// assert!(report.contains("command line"));
+    /// ```
+    pub fn explain(&self) -> String {
+        let mut rows = Vec::new();
+        self.explain_rows(&mut Vec::new(), &mut rows);
+
+        let name_width = rows
+            .iter()
+            .map(|(_, name, ..)| name.len())
+            .fold("ARGUMENT".len(), usize::max);
+        let value_width = rows
+            .iter()
+            .map(|(_, _, value, _)| value.len())
+            .fold("VALUE".len(), usize::max);
+
+        let mut out = String::new();
+        let _ = writeln!(
+            out,
+            "{:<name_w$}  {:<value_w$}  SOURCE",
+            "ARGUMENT",
+            "VALUE",
+            name_w = name_width,
+            value_w = value_width,
+        );
+        let mut section = String::new();
+        for (path, name, value, source) in rows {
+            if path != section {
+                let _ = writeln!(out, "\n[{}]", path);
+                section = path;
+            }
+            let _ = writeln!(
+                out,
+                "{:<name_w$}  {:<value_w$}  {}",
+                name,
+                value,
+                source,
+                name_w = name_width,
+                value_w = value_width,
+            );
+        }
+        out
+    }
+
+    fn explain_rows(
+        &self,
+        path: &mut Vec<String>,
+        rows: &mut Vec<(String, String, String, String)>,
+    ) {
+        for (id, matched) in &self.args {
+            let name = matched
+                .source_name()
+                .map(str::to_owned)
+                .unwrap_or_else(|| format!("{:?}", id));
+            let value = if matched.first().is_none() {
+                "set".to_owned()
+            } else {
+                matched
+                    .vals_flatten()
+                    .map(|v| v.to_string_lossy())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            };
+            let source = match matched.source() {
+                Some(ValueSource::CommandLine) => "command line",
+                Some(ValueSource::EnvVariable) => "environment",
+                Some(ValueSource::ConfigFile) => "config file",
+                Some(ValueSource::DefaultValue) => "default",
+                None => "unknown",
+            };
+            rows.push((path.join(" "), name, value, source.to_owned()));
+        }
+        if let Some(ref sc) = self.subcommand {
+            path.push(sc.name.clone());
+            sc.matches.explain_rows(path, rows);
+            path.pop();
+        }
+    }
+
     /// The number of times an argument was used at runtime.
     ///
     /// If an argument isn't present it will return `0`.
@@ -708,6 +981,166 @@ impl ArgMatches {
             .map_or(0, |a| a.get_occurrences())
     }
 
+    /// The number of times an argument was used as a small integer.
+    ///
+    /// This is a convenience over [`ArgMatches::occurrences_of`] for counted
+    /// flags such as `-vvv`, avoiding a cast at every call site.  Counts larger
+    /// than [`u8::MAX`] saturate; use [`Arg::max_occurrences`] together with
+    /// [`Arg::saturating_occurrences`] to enforce a smaller cap at parse time.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myprog")
+    ///     .arg(Arg::new("verbose")
+    ///         .short('v')
+    ///         .multiple_occurrences(true))
+    ///     .get_matches_from(vec![
+    ///         "myprog", "-vvv"
+    ///     ]);
+    ///
+    /// assert_eq!(m.count_of("verbose"), 3);
+    /// ```
+    /// [`Arg::max_occurrences`]: crate::Arg::max_occurrences()
+    /// [`Arg::saturating_occurrences`]: crate::Arg::saturating_occurrences()
+    pub fn count_of<T: Key>(&self, id: T) -> u8 {
+        self.occurrences_of(id).min(u64::from(u8::MAX)) as u8
+    }
+
+    /// Resolve a [negatable] flag to a boolean.
+    ///
+    /// Returns `None` if the flag was never given, `Some(true)` if the last
+    /// occurrence used the positive spelling (`--color` or a short flag), and
+    /// `Some(false)` if the last occurrence was the `--no-` form.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let app = App::new("myprog")
+    ///     .arg(Arg::new("color")
+    ///         .long("color")
+    ///         .negatable(true));
+    ///
+    /// let m = app.clone().get_matches_from(vec!["myprog", "--no-color"]);
+    /// assert_eq!(m.flag_of("color"), Some(false));
+    ///
+    /// let m = app.clone().get_matches_from(vec!["myprog", "--no-color", "--color"]);
+    /// assert_eq!(m.flag_of("color"), Some(true));
+    ///
+    /// let m = app.get_matches_from(vec!["myprog"]);
+    /// assert_eq!(m.flag_of("color"), None);
+    /// ```
+    /// [negatable]: crate::Arg::negatable()
+    pub fn flag_of<T: Key>(&self, id: T) -> Option<bool> {
+        self.get_arg(&Id::from(id)).map(|a| !a.is_negated())
+    }
+
+    /// The `KEY=VAL` pairs of a [map argument], in command-line order.
+    ///
+    /// Pairs are returned as an insertion-ordered map, so iterating yields the
+    /// keys in the order the user supplied them.  Returns `None` if the
+    /// argument was never given.  Parsing has already rejected malformed pairs
+    /// and duplicate keys, so every value splits cleanly.
+    ///
+    /// # Panics
+    ///
+    /// If any value contains invalid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myprog")
+    ///     .arg(Arg::new("define")
+    ///         .short('D')
+    ///         .key_value_map(true))
+    ///     .get_matches_from(vec![
+    ///         "myprog", "-D", "opt=3", "-D", "debug=false"
+    ///     ]);
+    ///
+    /// let map = m.get_map("define").unwrap();
+    /// assert_eq!(map.get("opt"), Some(&"3"));
+    /// assert_eq!(map.get("debug"), Some(&"false"));
+    /// assert_eq!(map.keys().collect::<Vec<_>>(), [&"opt", &"debug"]);
+    /// ```
+    /// [map argument]: crate::Arg::key_value_map()
+    pub fn get_map<T: Key>(&self, id: T) -> Option<IndexMap<&str, &str>> {
+        let arg = self.get_arg(&Id::from(id))?;
+        let map = arg
+            .vals_flatten()
+            .filter_map(|val| val.to_str().expect(INVALID_UTF8).split_once('='))
+            .collect();
+        Some(map)
+    }
+
+    /// Iterate over all matched arguments in their original command-line order.
+    ///
+    /// Yields `(arg name, value, index)` tuples across *all* arguments,
+    /// interleaving flags and values exactly as the user typed them.  Flags
+    /// yield one tuple per occurrence with a `None` value; options and
+    /// positionals yield one tuple per value.  This is intended for tools such
+    /// as `find`-style expression languages where the relative order of
+    /// different arguments is semantic; for a single argument,
+    /// [`ArgMatches::indices_of`] is usually enough.
+    ///
+    /// Default and environment values are not included since they have no
+    /// position on the command line.
+    ///
+    /// # Panics
+    ///
+    /// If any included value is invalid UTF-8.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("myprog")
+    ///     .arg(Arg::new("name").short('n').takes_value(true)
+    ///         .multiple_occurrences(true))
+    ///     .arg(Arg::new("print").short('p')
+    ///         .multiple_occurrences(true))
+    ///     .get_matches_from(vec![
+    ///         "myprog", "-n", "a", "-p", "-n", "b"
+    ///     ]);
+    ///
+    /// let order: Vec<_> = m.args_in_order().collect();
+    /// assert_eq!(order, [
+    ///     ("name", Some("a"), 2),
+    ///     ("print", None, 3),
+    ///     ("name", Some("b"), 5),
+    /// ]);
+    /// ```
+    /// [`ArgMatches::indices_of`]: ArgMatches::indices_of()
+    pub fn args_in_order(&self) -> ArgsInOrder {
+        let mut items: Vec<(&str, Option<&str>, usize)> = Vec::new();
+        for ma in self.args.values() {
+            let name = match ma.source_name() {
+                Some(name) => name,
+                None => continue,
+            };
+            if !matches!(ma.source(), Some(ValueSource::CommandLine)) {
+                continue;
+            }
+            if ma.num_vals() > 0 {
+                for (i, val) in ma.vals_flatten().enumerate() {
+                    if let Some(idx) = ma.get_index(i) {
+                        items.push((name, Some(val.to_str().expect(INVALID_UTF8)), idx));
+                    }
+                }
+            } else {
+                for idx in ma.indices() {
+                    items.push((name, None, idx));
+                }
+            }
+        }
+        items.sort_by_key(|&(_, _, idx)| idx);
+        ArgsInOrder {
+            iter: items.into_iter(),
+        }
+    }
+
     /// The first index of that an argument showed up.
     ///
     /// Indices are similar to argv indices, but are not exactly 1:1.
@@ -933,6 +1366,92 @@ impl ArgMatches {
         Some(i)
     }
 
+    /// How many leading argv elements clap parsed before a trailing capture began
+    ///
+    /// When parsing stops at `--`, a [`trailing_var_arg`] positional or an
+    /// [external subcommand], the returned count is the index into the original
+    /// argv (including the program name) where the captured remainder starts:
+    /// `argv[..len]` was consumed by clap and `argv[len..]` is the capture. For
+    /// external subcommands, the subcommand name itself counts as part of the
+    /// remainder. Wrapper tools can re-exec `argv[len..]` without re-deriving
+    /// it from [`ArgMatches::indices_of`].
+    ///
+    /// Returns `None` if parsing never entered a trailing capture. The count is
+    /// unreliable if argv was rewritten via [`App::replace`].
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, Arg};
+    /// let m = App::new("wrapper")
+    ///     .arg(Arg::new("verbose").short('v'))
+    ///     .arg(Arg::new("cmd").multiple_values(true))
+    ///     .get_matches_from(vec!["wrapper", "-v", "--", "make", "-j4"]);
+    /// // "wrapper", "-v" and the "--" separator were consumed by clap
+    /// assert_eq!(m.consumed_len(), Some(3));
+    /// ```
+    /// [`trailing_var_arg`]: crate::App::trailing_var_arg()
+    /// [external subcommand]: crate::App::allow_external_subcommands()
+    /// [`App::replace`]: crate::App::replace()
+    pub fn consumed_len(&self) -> Option<usize> {
+        self.consumed_len
+    }
+
+    /// Everything after the first `--`, in original order and [`OsStr`] form.
+    ///
+    /// The values are captured independent of any positional definition, and a
+    /// positional may still consume the same tokens as values. Tokens that no
+    /// positional claims are an [`ErrorKind::UnknownArgument`][crate::ErrorKind::UnknownArgument]
+    /// error as usual;
+    /// [`AppSettings::AllowUnclaimedTrailingArgs`][crate::AppSettings::AllowUnclaimedTrailingArgs]
+    /// accepts them so wrappers (`cargo run -- ...`) don't need a catch-all
+    /// positional to forward them.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, AppSettings};
+    /// # use std::ffi::OsStr;
+    /// let m = App::new("wrapper")
+    ///     .setting(AppSettings::AllowUnclaimedTrailingArgs)
+    ///     .get_matches_from(vec!["wrapper", "--", "make", "-j4"]);
+    /// assert_eq!(
+    ///     m.trailing_args().collect::<Vec<_>>(),
+    ///     [OsStr::new("make"), OsStr::new("-j4")]
+    /// );
+    /// ```
+    pub fn trailing_args(&self) -> impl Iterator<Item = &OsStr> {
+        self.trailing.iter().map(OsString::as_os_str)
+    }
+
+    /// The first unrecognized token and everything after it, in original order and
+    /// [`OsStr`] form.
+    ///
+    /// Only populated under [`AppSettings::StopAtUnknown`], which halts parsing at the
+    /// first token clap doesn't recognize instead of erroring. Wrapper programs
+    /// (`time`, `env`-style commands) can parse their own flags and forward the tail
+    /// to the wrapped program.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// # use clap::{App, AppSettings, Arg};
+    /// # use std::ffi::OsStr;
+    /// let m = App::new("timer")
+    ///     .setting(AppSettings::StopAtUnknown)
+    ///     .arg(Arg::new("verbose").short('v'))
+    ///     .get_matches_from(vec!["timer", "-v", "make", "-j4"]);
+    /// assert!(m.is_present("verbose"));
+    /// assert_eq!(
+    ///     m.remaining().collect::<Vec<_>>(),
+    ///     [OsStr::new("make"), OsStr::new("-j4")]
+    /// );
+    /// ```
+    /// [`AppSettings::StopAtUnknown`]: crate::AppSettings::StopAtUnknown
+    pub fn remaining(&self) -> impl Iterator<Item = &OsStr> {
+        self.remaining.iter().map(OsString::as_os_str)
+    }
+
     /// The name and `ArgMatches` of the current [subcommand].
     ///
     /// Subcommand values are put in a child [`ArgMatches`]
@@ -1157,6 +1676,71 @@ pub(crate) struct SubCommand {
     pub(crate) matches: ArgMatches,
 }
 
+#[cfg(feature = "serde")]
+mod ser {
+    use serde::ser::{Serialize, SerializeMap, SerializeStruct, Serializer};
+
+    use super::{ArgMatches, MatchedArg, SubCommand};
+
+    /// Serializes the matches as `{ "args": { <name>: { "occurrences", "values" } },
+    /// "subcommand": { "name", "matches" } | null }` so invocations can be
+    /// logged, replayed, or forwarded over RPC.
+    ///
+    /// Keys are the argument (or group) names as declared on the `App`,
+    /// recorded when the entry was matched, so they are stable across debug
+    /// and release builds; an external subcommand's captured arguments appear
+    /// under the empty name. Values are converted to strings lossily.
+    impl Serialize for ArgMatches {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut state = serializer.serialize_struct("ArgMatches", 2)?;
+            state.serialize_field("args", &Args(self))?;
+            state.serialize_field("subcommand", &self.subcommand.as_deref())?;
+            state.end()
+        }
+    }
+
+    struct Args<'a>(&'a ArgMatches);
+
+    impl Serialize for Args<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut map = serializer.serialize_map(Some(self.0.args.len()))?;
+            for (id, matched) in &self.0.args {
+                let name = matched
+                    .source_name()
+                    .map(str::to_owned)
+                    .unwrap_or_else(|| format!("{:?}", id));
+                map.serialize_entry(&name, &Matched(matched))?;
+            }
+            map.end()
+        }
+    }
+
+    struct Matched<'a>(&'a MatchedArg);
+
+    impl Serialize for Matched<'_> {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let values: Vec<String> = self
+                .0
+                .vals_flatten()
+                .map(|v| v.to_string_lossy().into_owned())
+                .collect();
+            let mut state = serializer.serialize_struct("MatchedArg", 2)?;
+            state.serialize_field("occurrences", &self.0.get_occurrences())?;
+            state.serialize_field("values", &values)?;
+            state.end()
+        }
+    }
+
+    impl Serialize for SubCommand {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut state = serializer.serialize_struct("SubCommand", 2)?;
+            state.serialize_field("name", &self.name)?;
+            state.serialize_field("matches", &self.matches)?;
+            state.end()
+        }
+    }
+}
+
 // The following were taken and adapted from vec_map source
 // repo: https://github.com/contain-rs/vec-map
 // commit: be5e1fa3c26e351761b33010ddbdaf5f05dbcc33
@@ -1220,6 +1804,9 @@ impl<'a> Default for Values<'a> {
     }
 }
 
+/// Iterator over an argument's values, grouped by occurrence.
+///
+/// Created by [`ArgMatches::grouped_values_of`].
 #[derive(Clone)]
 #[allow(missing_debug_implementations)]
 pub struct GroupedValues<'a> {
@@ -1247,6 +1834,43 @@ impl<'a> DoubleEndedIterator for GroupedValues<'a> {
 
 impl<'a> ExactSizeIterator for GroupedValues<'a> {}
 
+/// Iterator over all matched arguments in command-line order.
+///
+/// Created by [`ArgMatches::args_in_order`]; yields `(arg name, value, index)`
+/// tuples.
+#[derive(Clone, Debug)]
+pub struct ArgsInOrder<'a> {
+    iter: std::vec::IntoIter<(&'a str, Option<&'a str>, usize)>,
+}
+
+impl<'a> Iterator for ArgsInOrder<'a> {
+    type Item = (&'a str, Option<&'a str>, usize);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next()
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a> DoubleEndedIterator for ArgsInOrder<'a> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back()
+    }
+}
+
+impl<'a> ExactSizeIterator for ArgsInOrder<'a> {}
+
+/// Creates an empty iterator. Used for `unwrap_or_default()`.
+impl<'a> Default for ArgsInOrder<'a> {
+    fn default() -> Self {
+        ArgsInOrder {
+            iter: Vec::new().into_iter(),
+        }
+    }
+}
+
 /// Creates an empty iterator. Used for `unwrap_or_default()`.
 impl<'a> Default for GroupedValues<'a> {
     fn default() -> Self {
@@ -1373,6 +1997,17 @@ impl<'a> Default for Indices<'a> {
     }
 }
 
+#[track_caller]
+fn expect_type<'a, T: Any>(value: &'a AnyValue, id: &Id) -> &'a T {
+    value.downcast_ref().unwrap_or_else(|| {
+        panic!(
+            "Mismatch between definition and access of `{:?}`: the value parser produced `{}`, not the requested type",
+            id,
+            value.type_name()
+        )
+    })
+}
+
 #[cfg_attr(debug_assertions, track_caller)]
 #[inline]
 fn assert_utf8_validation(arg: &MatchedArg, id: &Id) {
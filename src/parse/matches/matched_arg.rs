@@ -7,7 +7,7 @@ use std::{
 
 use crate::build::ArgPredicate;
 use crate::parse::ValueSource;
-use crate::util::eq_ignore_case;
+use crate::util::{eq_ignore_case, AnyValue};
 use crate::INTERNAL_ERROR_MSG;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
@@ -16,8 +16,23 @@ pub(crate) struct MatchedArg {
     ty: Option<ValueSource>,
     indices: Vec<usize>,
     vals: Vec<Vec<OsString>>,
+    // Original (pre-transform) spellings, keyed by the flattened value index.  Only
+    // populated when a `ValueTransform` changed the value.
+    raw_vals: Vec<(usize, OsString)>,
+    // Canonicalized paths, parallel to the flattened values.  Only populated for args
+    // with `Arg::canonicalize(true)`.
+    canonical_vals: Vec<OsString>,
+    // Values produced by the arg's `ValueParser`, parallel to the flattened values.
+    // Only populated for args with `Arg::value_parser`.
+    typed_vals: Vec<AnyValue>,
     ignore_case: bool,
     invalid_utf8_allowed: Option<bool>,
+    // Whether the last occurrence used the `--no-<long>` spelling of a
+    // negatable flag.
+    negated: bool,
+    // Name of the `Arg` this entry was matched from.  `None` for group entries,
+    // which only mirror their members' values.
+    source_name: Option<String>,
 }
 
 impl MatchedArg {
@@ -27,8 +42,13 @@ impl MatchedArg {
             ty: None,
             indices: Vec::new(),
             vals: Vec::new(),
+            raw_vals: Vec::new(),
+            canonical_vals: Vec::new(),
+            typed_vals: Vec::new(),
             ignore_case: false,
             invalid_utf8_allowed: None,
+            negated: false,
+            source_name: None,
         }
     }
 
@@ -40,6 +60,24 @@ impl MatchedArg {
         self.occurs
     }
 
+    pub(crate) fn set_negated(&mut self, negated: bool) {
+        self.negated = negated;
+    }
+
+    pub(crate) fn is_negated(&self) -> bool {
+        self.negated
+    }
+
+    pub(crate) fn set_source_name(&mut self, name: &str) {
+        if self.source_name.is_none() {
+            self.source_name = Some(name.to_owned());
+        }
+    }
+
+    pub(crate) fn source_name(&self) -> Option<&str> {
+        self.source_name.as_deref()
+    }
+
     pub(crate) fn indices(&self) -> Cloned<Iter<'_, usize>> {
         self.indices.iter().cloned()
     }
@@ -77,6 +115,53 @@ impl MatchedArg {
         self.vals.last_mut().expect(INTERNAL_ERROR_MSG).push(val)
     }
 
+    pub(crate) fn set_canonical_vals(&mut self, vals: Vec<OsString>) {
+        self.canonical_vals = vals;
+    }
+
+    pub(crate) fn canonical_vals(&self) -> Iter<OsString> {
+        self.canonical_vals.iter()
+    }
+
+    pub(crate) fn first_canonical(&self) -> Option<&OsString> {
+        self.canonical_vals.first()
+    }
+
+    pub(crate) fn set_typed_vals(&mut self, vals: Vec<AnyValue>) {
+        self.typed_vals = vals;
+    }
+
+    pub(crate) fn typed_vals(&self) -> Iter<'_, AnyValue> {
+        self.typed_vals.iter()
+    }
+
+    pub(crate) fn first_typed(&self) -> Option<&AnyValue> {
+        self.typed_vals.first()
+    }
+
+    /// Rewrites the flattened value at `index` in place, preserving grouping.
+    pub(crate) fn replace_val(&mut self, index: usize, val: OsString) {
+        let mut remaining = index;
+        for group in self.vals.iter_mut() {
+            if remaining < group.len() {
+                group[remaining] = val;
+                return;
+            }
+            remaining -= group.len();
+        }
+    }
+
+    pub(crate) fn push_raw_val(&mut self, index: usize, val: OsString) {
+        self.raw_vals.push((index, val))
+    }
+
+    /// The value as the user typed it, if a transform changed it.
+    pub(crate) fn raw_val(&self, index: usize) -> Option<&OsString> {
+        self.raw_vals
+            .iter()
+            .find_map(|(i, v)| (*i == index).then(|| v))
+    }
+
     pub(crate) fn num_vals(&self) -> usize {
         self.vals.iter().flatten().count()
     }
@@ -2,7 +2,7 @@ mod arg_matches;
 mod matched_arg;
 mod value_source;
 
-pub use arg_matches::{ArgMatches, Indices, OsValues, Values};
+pub use arg_matches::{ArgMatches, ArgsInOrder, GroupedValues, Indices, OsValues, Values};
 pub use value_source::ValueSource;
 
 pub(crate) use arg_matches::SubCommand;
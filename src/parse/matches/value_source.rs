@@ -4,6 +4,9 @@
 pub enum ValueSource {
     /// Value came [`Arg::default_value`][crate::Arg::default_value]
     DefaultValue,
+    /// Value came from a config file registered with
+    /// [`App::config_file`][crate::App::config_file]
+    ConfigFile,
     /// Value came [`Arg::env`][crate::Arg::env]
     EnvVariable,
     /// Value was passed in on the command-line
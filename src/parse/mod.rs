@@ -1,13 +1,21 @@
 pub mod features;
 
 mod arg_matcher;
+#[cfg(feature = "yaml")]
+mod config;
+mod line;
 pub mod matches;
 mod parser;
 mod validator;
 
 pub(crate) use self::arg_matcher::ArgMatcher;
+#[cfg(feature = "yaml")]
+pub(crate) use self::config::{parse_config, ConfigValue};
+pub(crate) use self::line::split_line;
 pub(crate) use self::matches::{MatchedArg, SubCommand};
 pub(crate) use self::parser::{Input, ParseState, Parser};
 pub(crate) use self::validator::Validator;
 
-pub use self::matches::{ArgMatches, Indices, OsValues, ValueSource, Values};
+pub use self::matches::{
+    ArgMatches, ArgsInOrder, GroupedValues, Indices, OsValues, ValueSource, Values,
+};
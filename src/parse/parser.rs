@@ -6,18 +6,22 @@ use std::{
 };
 
 // Third Party
-use os_str_bytes::RawOsStr;
+use os_str_bytes::{RawOsStr, RawOsString};
 
 // Internal
 use crate::build::AppSettings as AS;
-use crate::build::{App, Arg};
+use crate::build::{App, Arg, SubcommandValuePolicy};
 use crate::error::Error as ClapError;
 use crate::error::Result as ClapResult;
+use crate::error::{ContextKind, ContextValue};
 use crate::mkeymap::KeyType;
 use crate::output::{fmt::Colorizer, Help, HelpWriter, Usage};
 use crate::parse::features::suggestions;
+#[cfg(feature = "yaml")]
+use crate::parse::ConfigValue;
 use crate::parse::{ArgMatcher, SubCommand};
 use crate::parse::{Validator, ValueSource};
+use crate::ErrorKind;
 use crate::util::{color::ColorChoice, ChildGraph, Id};
 use crate::{INTERNAL_ERROR_MSG, INVALID_UTF8};
 
@@ -32,6 +36,16 @@ pub(crate) struct Parser<'help, 'app> {
     /// Counter indicating the number of items to skip
     /// when revisiting the group of flags which includes the flag subcommand.
     flag_subcmd_skip: usize,
+    /// Recoverable errors held back for aggregation under
+    /// [`AppSettings::CollectAllErrors`][crate::AppSettings::CollectAllErrors].
+    pub(crate) pending_errors: Vec<ClapError>,
+    /// Whether the verbose help tier was requested (help flag passed twice or
+    /// the configured [`App::help_all_flag`][crate::App::help_all_flag] seen).
+    verbose_help: Cell<bool>,
+    /// The [`App::flag_prefix`][crate::App::flag_prefix] character the token
+    /// currently being parsed was spelled with, consumed when the occurrence
+    /// is recorded.
+    pending_flag_prefix: RefCell<Option<(Id, char)>>,
 }
 
 // Initializing Methods
@@ -58,6 +72,9 @@ impl<'help, 'app> Parser<'help, 'app> {
             cur_idx: Cell::new(0),
             flag_subcmd_at: None,
             flag_subcmd_skip: 0,
+            pending_errors: Vec::new(),
+            verbose_help: Cell::new(false),
+            pending_flag_prefix: Default::default(),
         }
     }
 
@@ -93,13 +110,27 @@ impl<'help, 'app> Parser<'help, 'app> {
         let mut valid_arg_found = false;
         // If the user already passed '--'. Meaning only positional args follow.
         let mut trailing_values = false;
+        // Set only by a literal '--' (not `trailing_var_arg`), so every later token can
+        // be captured for `ArgMatches::trailing_args`
+        let mut double_dash_seen = false;
+
+        // Number of `@file` tokens expanded so far, so response files referencing each
+        // other can't recurse forever
+        let mut response_files_expanded = 0;
 
         // Count of positional args
         let positional_count = self.app.args.keys().filter(|x| x.is_position()).count();
         // If any arg sets .last(true)
         let contains_last = self.app.args.args().any(|x| x.is_last_set());
 
-        while let Some((arg_os, remaining_args)) = it.next() {
+        loop {
+            // Index of the current token in the original argv, so trailing captures can
+            // report where the remainder started
+            let consumed = it.cursor();
+            let (arg_os, remaining_args) = match it.next() {
+                Some(next) => next,
+                None => break,
+            };
             // Recover the replaced items if any.
             if let Some((_replacer, replaced_items)) = self
                 .app
@@ -115,7 +146,55 @@ impl<'help, 'app> Parser<'help, 'app> {
                 continue;
             }
 
+            // Expand `@file` response files, re-running the loop over the file's tokens.
+            // Anything after `--` is taken literally.
+            if !trailing_values && self.app.is_allow_response_files_set() {
+                if let Some(path) = arg_os
+                    .to_str()
+                    .and_then(|s| s.strip_prefix('@'))
+                    .filter(|path| !path.is_empty())
+                {
+                    if response_files_expanded >= RESPONSE_FILE_LIMIT {
+                        return Err(ClapError::raw(
+                            ErrorKind::Io,
+                            format!(
+                                "response file `{}` exceeds the expansion limit of {}\n",
+                                path, RESPONSE_FILE_LIMIT
+                            ),
+                        )
+                        .with_app(self.app));
+                    }
+                    response_files_expanded += 1;
+                    let text = std::fs::read_to_string(path).map_err(|e| {
+                        ClapError::raw(
+                            ErrorKind::Io,
+                            format!("could not read response file `{}`: {}\n", path, e),
+                        )
+                        .with_app(self.app)
+                    })?;
+                    let tokens = tokenize_response_file(&text);
+                    debug!(
+                        "Parser::get_matches_with: expanded response file {:?}: {:?}",
+                        path, tokens
+                    );
+                    it.insert(&tokens.iter().map(String::as_str).collect::<Vec<_>>());
+                    continue;
+                }
+            }
+
             let arg_os = RawOsStr::new(arg_os);
+            // Everything after '--' is a literal value, so slash flags no longer apply
+            let arg_os = match self.slash_flag_translation(&arg_os) {
+                Some(translated) if !trailing_values => std::borrow::Cow::Owned(translated),
+                _ => arg_os,
+            };
+            let arg_os = match self.alternate_prefix_translation(&arg_os) {
+                Some((id, prefix, translated)) if !trailing_values => {
+                    *self.pending_flag_prefix.borrow_mut() = Some((id, prefix));
+                    std::borrow::Cow::Owned(translated)
+                }
+                _ => arg_os,
+            };
             debug!(
                 "Parser::get_matches_with: Begin parsing '{:?}' ({:?})",
                 arg_os,
@@ -192,6 +271,22 @@ impl<'help, 'app> Parser<'help, 'app> {
                 }
             };
 
+            // After the first '--', a further '--' is normally captured as a literal
+            // positional value; optionally treat it as another separator
+            if trailing_values
+                && &*arg_os == RawOsStr::from_str("--")
+                && self.app.is_repeated_double_dash_as_separator_set()
+            {
+                debug!("Parser::get_matches_with: ignoring repeated '--'");
+                continue;
+            }
+
+            // Capture everything after the first '--' verbatim, whether or not a
+            // positional also claims it
+            if double_dash_seen {
+                matcher.push_trailing(arg_os.to_os_str().into_owned());
+            }
+
             // Has the user already passed '--'? Meaning only positional args follow
             if !trailing_values {
                 if self.app.is_subcommand_precedence_over_arg_set()
@@ -201,14 +296,16 @@ impl<'help, 'app> Parser<'help, 'app> {
                     let sc_name = self.possible_subcommand(&arg_os, valid_arg_found);
                     debug!("Parser::get_matches_with: sc={:?}", sc_name);
                     if let Some(sc_name) = sc_name {
-                        if sc_name == "help"
-                            && !self.is_set(AS::NoAutoHelp)
-                            && !self.app.is_disable_help_subcommand_set()
-                        {
-                            self.parse_help_subcommand(remaining_args)?;
+                        if self.take_as_subcommand(&arg_os, pos_counter)? {
+                            if sc_name == "help"
+                                && !self.is_set(AS::NoAutoHelp)
+                                && !self.app.is_disable_help_subcommand_set()
+                            {
+                                self.parse_help_subcommand(it.remaining_full())?;
+                            }
+                            subcmd_name = Some(sc_name.to_owned());
+                            break;
                         }
-                        subcmd_name = Some(sc_name.to_owned());
-                        break;
                     }
                 }
 
@@ -228,6 +325,8 @@ impl<'help, 'app> Parser<'help, 'app> {
                         ParseResult::NoArg => {
                             debug!("Parser::get_matches_with: setting TrailingVals=true");
                             trailing_values = true;
+                            double_dash_seen = true;
+                            matcher.set_consumed_len(consumed + 1);
                             continue;
                         }
                         ParseResult::ValuesDone => {
@@ -254,11 +353,30 @@ impl<'help, 'app> Parser<'help, 'app> {
                             ));
                         }
                         ParseResult::NoMatchingArg { arg } => {
-                            let remaining_args: Vec<_> = remaining_args
+                            if self.app.is_stop_at_unknown_set() {
+                                let first = arg_os.to_os_str().into_owned();
+                                self.capture_remaining(first, matcher, it);
+                                break;
+                            }
+                            let remaining_args: Vec<_> = it
+                                .remaining_full()
                                 .iter()
                                 .map(|x| x.to_str().expect(INVALID_UTF8))
                                 .collect();
-                            return Err(self.did_you_mean_error(&arg, matcher, &remaining_args));
+                            let err = self.did_you_mean_error(&arg, matcher, &remaining_args);
+                            if self.app.is_collect_all_errors_set() {
+                                self.pending_errors.push(err);
+                                continue;
+                            }
+                            return Err(err);
+                        }
+                        ParseResult::AmbiguousArg { arg, candidates } => {
+                            return Err(ClapError::ambiguous_prefix(
+                                self.app,
+                                arg,
+                                candidates,
+                                Usage::new(self.app, &self.required).create_usage_with_title(&[]),
+                            ));
                         }
                         ParseResult::UnneededAttachedValue { rest, used, arg } => {
                             return Err(ClapError::too_many_values(
@@ -269,6 +387,10 @@ impl<'help, 'app> Parser<'help, 'app> {
                             ))
                         }
                         ParseResult::HelpFlag => {
+                            self.app._materialize_all_lazy_subcommands();
+                            if self.remaining_requests_verbose_help(it) {
+                                self.verbose_help.set(true);
+                            }
                             return Err(self.help_err(true));
                         }
                         ParseResult::VersionFlag => {
@@ -343,14 +465,28 @@ impl<'help, 'app> Parser<'help, 'app> {
                             ))
                         }
                         ParseResult::NoMatchingArg { arg } => {
-                            return Err(ClapError::unknown_argument(
+                            if self.app.is_stop_at_unknown_set() {
+                                let first = arg_os.to_os_str().into_owned();
+                                self.capture_remaining(first, matcher, it);
+                                break;
+                            }
+                            let err = ClapError::unknown_argument(
                                 self.app,
                                 arg,
                                 None,
                                 Usage::new(self.app, &self.required).create_usage_with_title(&[]),
-                            ));
+                            );
+                            if self.app.is_collect_all_errors_set() {
+                                self.pending_errors.push(err);
+                                continue;
+                            }
+                            return Err(err);
                         }
                         ParseResult::HelpFlag => {
+                            self.app._materialize_all_lazy_subcommands();
+                            if self.remaining_requests_verbose_help(it) {
+                                self.verbose_help.set(true);
+                            }
                             return Err(self.help_err(false));
                         }
                         ParseResult::VersionFlag => {
@@ -360,6 +496,7 @@ impl<'help, 'app> Parser<'help, 'app> {
                             // Maybe a hyphen value, do nothing.
                         }
                         ParseResult::UnneededAttachedValue { .. }
+                        | ParseResult::AmbiguousArg { .. }
                         | ParseResult::AttachedValueNotConsumed => unreachable!(),
                     }
                 }
@@ -398,6 +535,7 @@ impl<'help, 'app> Parser<'help, 'app> {
 
                 if self.app.is_trailing_var_arg_set() && pos_counter == positional_count {
                     trailing_values = true;
+                    matcher.set_consumed_len(consumed);
                 }
 
                 self.seen.push(p.id.clone());
@@ -426,7 +564,11 @@ impl<'help, 'app> Parser<'help, 'app> {
                     parse_state = ParseState::Pos(p.id.clone());
                 }
                 valid_arg_found = true;
-            } else if self.app.is_allow_external_subcommands_set() {
+            } else if self.app.is_allow_external_subcommands_set()
+                && arg_os
+                    .to_str()
+                    .map_or(true, |s| self.app._external_subcommand_exists(s))
+            {
                 // Get external subcommand name
                 let sc_name = match arg_os.to_str() {
                     Some(s) => s.to_string(),
@@ -438,6 +580,9 @@ impl<'help, 'app> Parser<'help, 'app> {
                     }
                 };
 
+                // The external subcommand name itself is part of the re-executable remainder
+                matcher.set_consumed_len(consumed);
+
                 // Collect the external subcommand args
                 let mut sc_m = ArgMatcher::new(self.app);
 
@@ -457,9 +602,9 @@ impl<'help, 'app> Parser<'help, 'app> {
                         ValueSource::CommandLine,
                         false,
                     );
-                    sc_m.get_mut(&Id::empty_hash())
-                        .expect("just inserted")
-                        .invalid_utf8_allowed(allow_invalid_utf8);
+                    let ma = sc_m.get_mut(&Id::empty_hash()).expect("just inserted");
+                    ma.invalid_utf8_allowed(allow_invalid_utf8);
+                    ma.set_source_name("");
                 }
 
                 matcher.subcommand(SubCommand {
@@ -469,25 +614,203 @@ impl<'help, 'app> Parser<'help, 'app> {
                 });
 
                 return Validator::new(self).validate(parse_state, matcher, trailing_values);
+            } else if double_dash_seen && self.app.is_allow_unclaimed_trailing_args_set() {
+                // No positional claimed it, but it was already captured for
+                // `ArgMatches::trailing_args`
+                debug!("Parser::get_matches_with: trailing arg with no positional to match");
             } else {
                 // Start error processing
-                return Err(self.match_arg_error(&arg_os, valid_arg_found, trailing_values));
+                if self.app.is_auto_correct_subcommands_set() {
+                    if let Some(sc_name) = self.auto_correct_subcommand(&arg_os) {
+                        subcmd_name = Some(sc_name);
+                        break;
+                    }
+                }
+                if self.app.is_stop_at_unknown_set() {
+                    let first = arg_os.to_os_str().into_owned();
+                    self.capture_remaining(first, matcher, it);
+                    break;
+                }
+                let err = self.match_arg_error(&arg_os, valid_arg_found, trailing_values);
+                if self.app.is_collect_all_errors_set() {
+                    self.pending_errors.push(err);
+                    continue;
+                }
+                return Err(err);
             }
         }
 
         if let Some(ref pos_sc_name) = subcmd_name {
+            self.app._materialize_lazy_subcommand(pos_sc_name);
             let sc_name = self
                 .app
                 .find_subcommand(pos_sc_name)
                 .expect(INTERNAL_ERROR_MSG)
                 .name
                 .clone();
-            self.parse_subcommand(&sc_name, matcher, it, keep_state)?;
+            self.parse_subcommand(&sc_name, matcher, it, keep_state)
+                .map_err(|error| self.suggest_flag_elsewhere(error))?;
         }
 
         Validator::new(self).validate(parse_state, matcher, trailing_values)
     }
 
+    /// Records the first unrecognized token and everything after it for
+    /// [`ArgMatches::remaining`] ([`AppSettings::StopAtUnknown`]).
+    fn capture_remaining(&self, first: OsString, matcher: &mut ArgMatcher, it: &mut Input) {
+        let mut remaining = vec![first];
+        remaining.extend(it.remaining_full().iter().cloned());
+        debug!("Parser::capture_remaining: {:?}", remaining);
+        matcher.set_remaining(remaining);
+    }
+
+    /// The subcommand to dispatch to for an unrecognized token under
+    /// [`AppSettings::AutoCorrectSubcommands`][crate::AppSettings::AutoCorrectSubcommands]:
+    /// the single candidate clearing the suggestion confidence threshold, if any
+    fn auto_correct_subcommand(&self, arg_os: &RawOsStr) -> Option<String> {
+        let token = arg_os.to_str()?;
+        if token.starts_with('-') {
+            return None;
+        }
+        let candidates = suggestions::did_you_mean(
+            token,
+            self.app.all_subcommand_names(),
+            self.app.get_suggestion_confidence(),
+        );
+        // Only an unambiguous match may dispatch
+        if candidates.len() != 1 {
+            return None;
+        }
+        let subcommand = self.app.find_subcommand(&candidates[0])?;
+        if subcommand.is_no_auto_correct_set() {
+            return None;
+        }
+        let name = subcommand.name.clone();
+        self.warn_auto_correct(token, &name);
+        Some(name)
+    }
+
+    /// The notice accompanying an auto-corrected subcommand; goes to
+    /// `App::warning_writer` when set, styled stderr otherwise
+    fn warn_auto_correct(&self, used: &str, corrected: &str) {
+        debug!("Parser::warn_auto_correct: {} -> {}", used, corrected);
+        if let Some(writer) = &self.app.warning_writer {
+            writer.write(&format!(
+                "warning: unknown subcommand '{}'; assuming you meant '{}'\n",
+                used, corrected
+            ));
+        } else {
+            let mut c = Colorizer::new(true, self.app.get_color()).with_theme(self.app.theme);
+            c.warning("warning:");
+            c.none(format!(
+                " unknown subcommand '{}'; assuming you meant '{}'\n",
+                used, corrected
+            ));
+            let _ = c.print();
+        }
+    }
+
+    /// The full invocation to suggest when an unrecognized token exists on disk
+    /// and the [`App::file_subcommand_hint`][crate::App::file_subcommand_hint]
+    /// hook names a subcommand for it, e.g. `` `myapp run ./script.sh` ``
+    fn file_subcommand_suggestion(&self, arg_os: &RawOsStr) -> Option<String> {
+        let hint = self.app.file_subcommand_hint.as_ref()?;
+        let token = arg_os.to_str()?;
+        let path = std::path::Path::new(token);
+        if !path.exists() {
+            return None;
+        }
+        let subcommand = hint.suggest(path)?;
+        if self.app.find_subcommand(&subcommand).is_none() {
+            return None;
+        }
+        let bin_name = self.app.bin_name.as_ref().unwrap_or(&self.app.name);
+        Some(format!("`{} {} {}`", bin_name, subcommand, token))
+    }
+
+    /// The `-`/`--` spelling of a Windows-style `/flag` token under
+    /// [`AppSettings::AllowSlashFlags`][crate::AppSettings::AllowSlashFlags].
+    ///
+    /// Only tokens whose name (before a `:` or `=` value separator) matches a
+    /// defined long or single-character short are translated; anything else,
+    /// such as a Unix path, is left for positional handling.
+    fn slash_flag_translation(&self, arg_os: &RawOsStr) -> Option<RawOsString> {
+        if !self.app.is_allow_slash_flags_set() {
+            return None;
+        }
+        let token = arg_os.to_str()?;
+        let rest = token.strip_prefix('/')?;
+        let (name, value) = match rest.split_once(|c| c == ':' || c == '=') {
+            Some((name, value)) => (name, Some(value)),
+            None => (rest, None),
+        };
+        if name.is_empty() {
+            return None;
+        }
+        let mut translated = if self.app.args.get(&name).is_some() {
+            format!("--{}", name)
+        } else if name.chars().count() == 1
+            && self
+                .app
+                .contains_short(name.chars().next().expect(INTERNAL_ERROR_MSG))
+        {
+            format!("-{}", name)
+        } else {
+            return None;
+        };
+        if let Some(value) = value {
+            translated.push('=');
+            translated.push_str(value);
+        }
+        debug!(
+            "Parser::slash_flag_translation: {:?} -> {:?}",
+            token, translated
+        );
+        Some(RawOsString::new(translated.into()))
+    }
+
+    /// The `-`/`--` spelling of a token using a prefix registered with
+    /// [`App::flag_prefix`][crate::App::flag_prefix], along with the matched
+    /// argument's id and the prefix itself so the matcher can record which
+    /// prefix introduced the occurrence (e.g. `+x` vs `-x`).
+    fn alternate_prefix_translation(&self, arg_os: &RawOsStr) -> Option<(Id, char, RawOsString)> {
+        if self.app.flag_prefixes.is_empty() {
+            return None;
+        }
+        let token = arg_os.to_str()?;
+        let mut chars = token.chars();
+        let prefix = chars.next()?;
+        if !self.app.flag_prefixes.contains(&prefix) {
+            return None;
+        }
+        let rest = chars.as_str();
+        let (name, value) = match rest.split_once('=') {
+            Some((name, value)) => (name, Some(value)),
+            None => (rest, None),
+        };
+        if name.is_empty() {
+            return None;
+        }
+        let (id, mut translated) = if let Some(opt) = self.app.args.get(&name) {
+            (opt.id.clone(), format!("--{}", name))
+        } else if name.chars().count() == 1 {
+            let c = name.chars().next().expect(INTERNAL_ERROR_MSG);
+            let opt = self.app.args.args().find(|a| a.short == Some(c))?;
+            (opt.id.clone(), format!("-{}", name))
+        } else {
+            return None;
+        };
+        if let Some(value) = value {
+            translated.push('=');
+            translated.push_str(value);
+        }
+        debug!(
+            "Parser::alternate_prefix_translation: {:?} -> {:?}",
+            token, translated
+        );
+        Some((id, prefix, RawOsString::new(translated.into())))
+    }
+
     fn match_arg_error(
         &self,
         arg_os: &RawOsStr,
@@ -505,8 +828,46 @@ impl<'help, 'app> Parser<'help, 'app> {
                 );
             }
         }
-        let candidates =
-            suggestions::did_you_mean(&arg_os.to_str_lossy(), self.app.all_subcommand_names());
+        // If the argument names an existing file or directory, the configured
+        // hook can point at the subcommand meant to receive it.
+        if let Some(suggestion) = self.file_subcommand_suggestion(arg_os) {
+            return ClapError::invalid_subcommand(
+                self.app,
+                arg_os.to_str_lossy().into_owned(),
+                suggestion,
+                self.app
+                    .bin_name
+                    .as_ref()
+                    .unwrap_or(&self.app.name)
+                    .to_string(),
+                Usage::new(self.app, &self.required).create_usage_with_title(&[]),
+            );
+        }
+        // An ambiguous subcommand prefix lists its candidates rather than guessing.
+        if self.app.is_infer_subcommands_set() {
+            let arg_str = arg_os.to_str_lossy();
+            let candidates: Vec<String> = self
+                .app
+                .all_subcommand_names()
+                .filter(|s| s.starts_with(&*arg_str))
+                .map(|s| s.to_string())
+                .collect();
+            if candidates.len() > 1 {
+                return ClapError::ambiguous_prefix(
+                    self.app,
+                    arg_os.to_str_lossy().into_owned(),
+                    candidates,
+                    Usage::new(self.app, &self.required).create_usage_with_title(&[]),
+                );
+            }
+        }
+        let candidates = suggestions::did_you_mean(
+            &arg_os.to_str_lossy(),
+            self.app
+                .all_subcommand_names()
+                .chain(self.app.suggestion_words.iter().copied()),
+            self.app.get_suggestion_confidence(),
+        );
         // If the argument looks like a subcommand.
         if !candidates.is_empty() {
             let candidates: Vec<_> = candidates
@@ -570,6 +931,14 @@ impl<'help, 'app> Parser<'help, 'app> {
             if let Some(sc) = self.app.find_subcommand(arg_os) {
                 return Some(&sc.name);
             }
+            if let Some(lazy) = self
+                .app
+                .lazy_subcommands
+                .iter()
+                .find(|lazy| *arg_os == *lazy.name())
+            {
+                return Some(lazy.name());
+            }
         }
         None
     }
@@ -608,15 +977,71 @@ impl<'help, 'app> Parser<'help, 'app> {
         None
     }
 
+    /// Whether a token that matched a subcommand should actually be dispatched as one.
+    ///
+    /// When the token is also a possible value of the positional currently being filled,
+    /// the answer depends on `App::subcommand_value_policy`.
+    fn take_as_subcommand(&self, arg_os: &RawOsStr, pos_counter: usize) -> ClapResult<bool> {
+        let pos = match self
+            .app
+            .get_positionals()
+            .find(|p| p.index == Some(pos_counter))
+        {
+            Some(pos) => pos,
+            None => return Ok(true),
+        };
+        let token = arg_os.to_str_lossy();
+        if !pos
+            .possible_vals
+            .iter()
+            .any(|pv| pv.matches(&token, pos.is_ignore_case_set()))
+        {
+            return Ok(true);
+        }
+        match self.app.get_subcommand_value_policy() {
+            None | Some(SubcommandValuePolicy::PreferSubcommand) => Ok(true),
+            Some(SubcommandValuePolicy::PreferPositional) => {
+                debug!(
+                    "Parser::take_as_subcommand: '{}' taken as value of {}",
+                    token, pos.name
+                );
+                Ok(false)
+            }
+            Some(SubcommandValuePolicy::Error) => Err(ClapError::ambiguous_subcommand(
+                self.app,
+                token.into_owned(),
+                pos.to_string(),
+                Usage::new(self.app, &self.required).create_usage_with_title(&[]),
+            )),
+        }
+    }
+
     fn parse_help_subcommand(&self, cmds: &[OsString]) -> ClapResult<ParseResult> {
         debug!("Parser::parse_help_subcommand");
 
         let mut bin_name = self.app.bin_name.as_ref().unwrap_or(&self.app.name).clone();
 
+        let mut help_search = None;
+        let mut dump_all = false;
         let mut sc = {
             let mut sc = self.app.clone();
 
             for cmd in cmds.iter() {
+                if cmd == "--all" {
+                    // `help --all` (optionally after a path) dumps the reached
+                    // command and every subcommand below it.
+                    dump_all = true;
+                    break;
+                }
+
+                if let Some(pattern) = flag_search_pattern(&sc, cmd) {
+                    // `help` was given a flag of the (sub)command; show that command's help,
+                    // pre-searched to the flag's entry when paging.
+                    help_search = Some(pattern);
+                    break;
+                }
+
+                sc._materialize_lazy_subcommand(&cmd.to_string_lossy());
                 sc = if let Some(c) = sc.find_subcommand(cmd) {
                     c
                 } else if let Some(c) = sc.find_subcommand(&cmd.to_string_lossy()) {
@@ -642,10 +1067,61 @@ impl<'help, 'app> Parser<'help, 'app> {
             sc
         };
         sc = sc.bin_name(bin_name);
+        sc._materialize_all_lazy_subcommands();
+
+        if dump_all {
+            let mut c = Colorizer::new(false, self.color_help()).with_theme(self.app.theme);
+            Self::write_help_recursively(&mut sc, &mut c, true)?;
+            return Err(ClapError::display_help(self.app, c));
+        }
 
         let parser = Parser::new(&mut sc);
 
-        Err(parser.help_err(true))
+        let mut err = parser.help_err(true);
+        if let Some(pattern) = help_search {
+            err = err.set_help_search(pattern);
+        }
+        Err(err)
+    }
+
+    /// Renders the long help of `app` followed by that of every visible subcommand
+    /// below it, for `help --all`.
+    fn write_help_recursively(
+        app: &mut App<'help>,
+        c: &mut Colorizer,
+        first: bool,
+    ) -> ClapResult<()> {
+        app._build();
+        app._materialize_all_lazy_subcommands();
+        if !first {
+            c.none("\n\n");
+        }
+        {
+            let parser = Parser::new(app);
+            let usage = Usage::new(parser.app, &parser.required);
+            Help::new(HelpWriter::Buffer(c), parser.app, &usage, true).write_help()?;
+        }
+        app._build_bin_names();
+        for sc in app
+            .subcommands
+            .iter_mut()
+            .filter(|sc| !sc.is_hide_set() && sc.name != "help")
+        {
+            Self::write_help_recursively(sc, c, false)?;
+        }
+        Ok(())
+    }
+
+    /// Whether the pending argument may still consume a leading-hyphen token. With
+    /// [`Arg::allow_hyphen_values_once`], only the first value of an occurrence may
+    /// start with a hyphen; later hyphenated tokens parse as flags again.
+    fn pending_arg_accepts_hyphen(&self, opt: &Id, matcher: &ArgMatcher) -> bool {
+        let arg = &self.app[opt];
+        arg.is_allow_hyphen_values_set()
+            && (!arg.is_allow_hyphen_values_once_set()
+                || matcher
+                    .get(opt)
+                    .map_or(true, |m| m.num_vals_last_group() == 0))
     }
 
     fn is_new_arg(&self, next: &RawOsStr, current_positional: &Arg) -> bool {
@@ -658,6 +1134,7 @@ impl<'help, 'app> Parser<'help, 'app> {
             || self.app[&current_positional.id].is_allow_hyphen_values_set()
             || (self.app.is_allow_negative_numbers_set()
                 && next.to_str_lossy().parse::<f64>().is_ok())
+            || self.app.token_looks_like_value(&next.to_str_lossy())
         {
             // If allow hyphen, this isn't a new arg.
             debug!("Parser::is_new_arg: Allow hyphen");
@@ -701,6 +1178,8 @@ impl<'help, 'app> Parser<'help, 'app> {
 
         let partial_parsing_enabled = self.app.is_ignore_errors_set();
 
+        self.app._materialize_lazy_subcommand(sc_name);
+
         if let Some(sc) = self.app.subcommands.iter_mut().find(|s| s.name == sc_name) {
             // Display subcommand name, short and long in usage
             let mut sc_names = sc.name.clone();
@@ -790,6 +1269,17 @@ impl<'help, 'app> Parser<'help, 'app> {
             }
         }
 
+        if let Some(help_all) = self.app.help_all_flag {
+            if arg == help_all
+                && !self.is_set(AS::NoAutoHelp)
+                && !self.app.is_disable_help_flag_set()
+            {
+                debug!("Verbose help");
+                self.verbose_help.set(true);
+                return Some(ParseResult::HelpFlag);
+            }
+        }
+
         if let Some(version) = self.app.find(&Id::version_hash()) {
             if let Some(v) = version.long {
                 if arg == v
@@ -806,6 +1296,26 @@ impl<'help, 'app> Parser<'help, 'app> {
         None
     }
 
+    /// Whether a remaining token repeats the help flag or names the configured
+    /// help-all flag, requesting the verbose help tier
+    fn remaining_requests_verbose_help(&self, it: &mut Input) -> bool {
+        let help = self.app.find(&Id::help_hash());
+        let long_help = help.and_then(|a| a.long);
+        let short_help = help.and_then(|a| a.short);
+        it.remaining_full().iter().any(|os| {
+            os.to_str().map_or(false, |token| {
+                if let Some(long) = token.strip_prefix("--") {
+                    Some(long) == long_help || Some(long) == self.app.help_all_flag
+                } else if let Some(short) = token.strip_prefix('-') {
+                    let mut chars = short.chars();
+                    chars.next() == short_help && chars.next().is_none()
+                } else {
+                    false
+                }
+            })
+        })
+    }
+
     fn check_for_help_and_version_char(&self, arg: char) -> Option<ParseResult> {
         debug!("Parser::check_for_help_and_version_char");
         debug!(
@@ -846,7 +1356,10 @@ impl<'help, 'app> Parser<'help, 'app> {
         // specified by the user is sent through. If hide_short_help is not included,
         // then items specified with hidden_short_help will also be hidden.
         let should_long = |v: &Arg| {
-            v.long_help.is_some() || v.is_hide_long_help_set() || v.is_hide_short_help_set()
+            v.long_help.is_some()
+                || v.is_hide_long_help_set()
+                || v.is_hide_short_help_set()
+                || v.possible_vals.iter().any(|pv| pv.should_show_help_long())
         };
 
         // Subcommands aren't checked because we prefer short help for them, deferring to
@@ -869,7 +1382,7 @@ impl<'help, 'app> Parser<'help, 'app> {
         debug!("Parser::parse_long_arg");
 
         if matches!(parse_state, ParseState::Opt(opt) | ParseState::Pos(opt) if
-            self.app[opt].is_allow_hyphen_values_set())
+            self.pending_arg_accepts_hyphen(opt, matcher))
         {
             return ParseResult::MaybeHyphenValue;
         }
@@ -891,20 +1404,49 @@ impl<'help, 'app> Parser<'help, 'app> {
             (long_arg, None)
         };
 
+        let mut negated = false;
         let opt = if let Some(opt) = self.app.args.get(&*arg.to_os_str()) {
             debug!(
                 "Parser::parse_long_arg: Found valid opt or flag '{}'",
                 opt.to_string()
             );
             Some(opt)
+        } else if let Some(opt) = self.app.args.args().find(|a| {
+            a.is_negatable_set()
+                && a.long
+                    .map_or(false, |long| arg.to_str_lossy().strip_prefix("no-") == Some(long))
+        }) {
+            debug!(
+                "Parser::parse_long_arg: Found negated form of '{}'",
+                opt.to_string()
+            );
+            negated = true;
+            Some(opt)
         } else if self.app.is_infer_long_args_set() {
             let arg_str = arg.to_str_lossy();
-            self.app.args.args().find(|a| {
-                a.long.map_or(false, |long| long.starts_with(&*arg_str))
-                    || a.aliases
-                        .iter()
-                        .any(|(alias, _)| alias.starts_with(&*arg_str))
-            })
+            let mut candidates = self.app.args.args().filter(|a| {
+                !a.is_no_abbreviation_set()
+                    && (a.long.map_or(false, |long| long.starts_with(&*arg_str))
+                        || a.aliases
+                            .iter()
+                            .any(|(alias, _)| alias.starts_with(&*arg_str)))
+            });
+            match (candidates.next(), candidates.next()) {
+                (Some(first), Some(second)) => {
+                    let mut matches: Vec<String> = vec![first, second]
+                        .into_iter()
+                        .chain(candidates)
+                        .map(|a| format!("--{}", a.long.unwrap_or(a.name)))
+                        .collect();
+                    matches.sort_unstable();
+                    return ParseResult::AmbiguousArg {
+                        arg: format!("--{}", arg_str),
+                        candidates: matches,
+                    };
+                }
+                (first, None) => first,
+                (None, Some(_)) => unreachable!(),
+            }
         } else {
             None
         };
@@ -912,6 +1454,10 @@ impl<'help, 'app> Parser<'help, 'app> {
         if let Some(opt) = opt {
             *valid_arg_found = true;
             self.seen.push(opt.id.clone());
+            let used = arg.to_str_lossy();
+            if opt.deprecated_aliases.iter().any(|alias| *alias == used) {
+                self.warn_deprecated_alias(opt, &used);
+            }
             if opt.is_takes_value_set() {
                 debug!(
                     "Parser::parse_long_arg: Found an opt with value '{:?}'",
@@ -939,10 +1485,13 @@ impl<'help, 'app> Parser<'help, 'app> {
                 parse_result
             } else {
                 debug!("Parser::parse_long_arg: Presence validated");
-                self.parse_flag(opt, matcher)
+                self.parse_flag(opt, matcher, negated)
             }
         } else if let Some(sc_name) = self.possible_long_flag_subcommand(arg) {
             ParseResult::FlagSubCommand(sc_name.to_string())
+        } else if let Some(parse_result) = self.check_for_help_and_version_str(arg) {
+            // Catches the configured help-all flag, which is not a defined arg.
+            parse_result
         } else if self.app.is_allow_hyphen_values_set() {
             ParseResult::MaybeHyphenValue
         } else {
@@ -952,6 +1501,27 @@ impl<'help, 'app> Parser<'help, 'app> {
         }
     }
 
+    // The alias still parses; the note goes to `App::warning_writer` when set, styled
+    // stderr otherwise, so renames are handled centrally instead of ad-hoc in every app.
+    fn warn_deprecated_alias(&self, opt: &Arg, used: &str) {
+        debug!("Parser::warn_deprecated_alias: {}", used);
+        let canonical = opt.long.unwrap_or(opt.name);
+        if let Some(writer) = &self.app.warning_writer {
+            writer.write(&format!(
+                "warning: '--{}' is deprecated; use '--{}' instead\n",
+                used, canonical
+            ));
+        } else {
+            let mut c = Colorizer::new(true, self.app.get_color()).with_theme(self.app.theme);
+            c.warning("warning:");
+            c.none(format!(
+                " '--{}' is deprecated; use '--{}' instead\n",
+                used, canonical
+            ));
+            let _ = c.print();
+        }
+    }
+
     fn parse_short_arg(
         &mut self,
         matcher: &mut ArgMatcher,
@@ -969,13 +1539,18 @@ impl<'help, 'app> Parser<'help, 'app> {
         if self.app.is_allow_negative_numbers_set() && arg.parse::<f64>().is_ok() {
             debug!("Parser::parse_short_arg: negative number");
             return ParseResult::MaybeHyphenValue;
+        } else if arg.chars().any(|c| !self.app.contains_short(c))
+            && self.app.token_looks_like_value(&format!("-{}", arg))
+        {
+            debug!("Parser::parse_short_arg: value detection matched");
+            return ParseResult::MaybeHyphenValue;
         } else if self.app.is_allow_hyphen_values_set()
             && arg.chars().any(|c| !self.app.contains_short(c))
         {
             debug!("Parser::parse_short_args: contains non-short flag");
             return ParseResult::MaybeHyphenValue;
         } else if matches!(parse_state, ParseState::Opt(opt) | ParseState::Pos(opt)
-                if self.app[opt].is_allow_hyphen_values_set())
+                if self.pending_arg_accepts_hyphen(opt, matcher))
         {
             debug!("Parser::parse_short_args: prior arg accepts hyphenated values",);
             return ParseResult::MaybeHyphenValue;
@@ -1019,7 +1594,7 @@ impl<'help, 'app> Parser<'help, 'app> {
                     if let Some(parse_result) = self.check_for_help_and_version_char(c) {
                         return parse_result;
                     }
-                    ret = self.parse_flag(opt, matcher);
+                    ret = self.parse_flag(opt, matcher, false);
                     continue;
                 }
 
@@ -1222,6 +1797,17 @@ impl<'help, 'app> Parser<'help, 'app> {
     ) {
         debug!("Parser::add_single_val_to_arg: adding val...{:?}", val);
 
+        let val = if arg.value_transforms.is_empty() {
+            val
+        } else {
+            let transformed = arg.transform_value(val.clone());
+            if transformed != val {
+                // Remember the original spelling so errors can show what the user typed
+                matcher.add_raw_val_to(&arg.id, val);
+            }
+            transformed
+        };
+
         // update the current index because each value is a distinct index to clap
         self.cur_idx.set(self.cur_idx.get() + 1);
         debug!(
@@ -1232,20 +1818,32 @@ impl<'help, 'app> Parser<'help, 'app> {
         // Increment or create the group "args"
         for group in self.app.groups_for_arg(&arg.id) {
             matcher.add_val_to(&group, val.clone(), ty, append);
+            self.record_group_source_name(matcher, &group);
         }
 
         matcher.add_val_to(&arg.id, val, ty, append);
         matcher.add_index_to(&arg.id, self.cur_idx.get(), ty);
+        if let Some(ma) = matcher.get_mut(&arg.id) {
+            ma.set_source_name(arg.name);
+        }
     }
 
     fn has_val_groups(&self, matcher: &mut ArgMatcher, arg: &Arg<'help>) -> bool {
         matcher.has_val_groups(&arg.id)
     }
 
-    fn parse_flag(&self, flag: &Arg<'help>, matcher: &mut ArgMatcher) -> ParseResult {
+    fn parse_flag(
+        &self,
+        flag: &Arg<'help>,
+        matcher: &mut ArgMatcher,
+        negated: bool,
+    ) -> ParseResult {
         debug!("Parser::parse_flag");
 
         self.inc_occurrence_of_arg(matcher, flag);
+        if let Some(ma) = matcher.get_mut(&flag.id) {
+            ma.set_negated(negated);
+        }
         matcher.add_index_to(&flag.id, self.cur_idx.get(), ValueSource::CommandLine);
 
         ParseResult::ValuesDone
@@ -1330,6 +1928,25 @@ impl<'help, 'app> Parser<'help, 'app> {
             debug!("Parser::add_value: doesn't have conditional defaults");
         }
 
+        if let Some(ref default_fn) = arg.default_value_fn {
+            debug!("Parser::add_value: has a default value fn");
+            if matcher.get(&arg.id).is_none() {
+                if let Some(val) = default_fn(matcher) {
+                    debug!("Parser::add_value: default value fn returned {:?}", val);
+                    self.add_val_to_arg(
+                        arg,
+                        &RawOsStr::new(&val),
+                        matcher,
+                        ty,
+                        false,
+                        trailing_values,
+                    );
+                    return;
+                }
+                debug!("Parser::add_value: default value fn returned None");
+            }
+        }
+
         fn process_default_vals(arg: &Arg<'_>, default_vals: &[&OsStr]) -> Vec<OsString> {
             if let Some(delim) = arg.val_delim {
                 let mut vals = vec![];
@@ -1416,19 +2033,34 @@ impl<'help, 'app> Parser<'help, 'app> {
     ) -> ClapResult<()> {
         use crate::util::str_to_bool;
 
-        self.app.args.args().try_for_each(|a| {
+        let env_file_vals = self.env_file_values(matcher)?;
+
+        // Rendering help needs `&mut self.app` to materialize lazy subcommands,
+        // which can't happen while `args` is borrowed, so it is deferred until
+        // after the scan.
+        let mut parse_result = None;
+        for a in self.app.args.args() {
             // Use env only if the arg was absent among command line args,
-            // early return if this is not the case.
+            // skip the arg if this is not the case.
             if matcher
                 .get(&a.id)
                 .map_or(false, |a| a.get_occurrences() != 0)
             {
                 debug!("Parser::add_env: Skipping existing arg `{}`", a);
-                return Ok(());
+                continue;
             }
 
             debug!("Parser::add_env: Checking arg `{}`", a);
-            if let Some((_, Some(ref val))) = a.env {
+            let val = if let Some((name, ref val)) = a.env {
+                // Pairs from the env file shadow the process environment.
+                env_file_vals
+                    .as_ref()
+                    .and_then(|vals| vals.get(name.to_str()?).cloned())
+                    .or_else(|| val.clone())
+            } else {
+                self.derived_env_value(a, env_file_vals.as_ref())
+            };
+            if let Some(ref val) = val {
                 let val = RawOsStr::new(val);
 
                 if a.is_takes_value_set() {
@@ -1444,17 +2076,15 @@ impl<'help, 'app> Parser<'help, 'app> {
                         false,
                         trailing_values,
                     );
-                    return Ok(());
+                    continue;
                 }
 
                 debug!("Parser::add_env: Checking for help and version");
-                // Early return on `HelpFlag` or `VersionFlag`.
+                // Early break on `HelpFlag` or `VersionFlag`.
                 match self.check_for_help_and_version_str(&val) {
-                    Some(ParseResult::HelpFlag) => {
-                        return Err(self.help_err(true));
-                    }
-                    Some(ParseResult::VersionFlag) => {
-                        return Err(self.version_err(true));
+                    result @ Some(ParseResult::HelpFlag | ParseResult::VersionFlag) => {
+                        parse_result = result;
+                        break;
                     }
                     _ => (),
                 }
@@ -1466,9 +2096,216 @@ impl<'help, 'app> Parser<'help, 'app> {
                     matcher.add_index_to(&a.id, self.cur_idx.get(), ValueSource::EnvVariable);
                 }
             }
+        }
 
-            Ok(())
-        })
+        match parse_result {
+            Some(ParseResult::HelpFlag) => {
+                self.app._materialize_all_lazy_subcommands();
+                Err(self.help_err(true))
+            }
+            Some(ParseResult::VersionFlag) => Err(self.version_err(true)),
+            _ => Ok(()),
+        }
+    }
+
+    /// Value of the env var derived from [`App::env_prefix`], e.g. `MYAPP_PORT` for a
+    /// `port` arg. Only consulted when the arg has no explicit [`Arg::env`].
+    #[cfg(feature = "env")]
+    fn derived_env_value(
+        &self,
+        arg: &Arg,
+        env_file_vals: Option<&std::collections::HashMap<String, OsString>>,
+    ) -> Option<OsString> {
+        let prefix = self.app.env_prefix.as_ref()?;
+        // The generated help/version flags shouldn't spring to life from the environment
+        if arg.id == Id::help_hash() || arg.id == Id::version_hash() {
+            return None;
+        }
+        let name = format!("{}_{}", prefix, crate::build::app::env_component(arg.name));
+        debug!(
+            "Parser::derived_env_value: arg={}, env={}",
+            arg.name, name
+        );
+        env_file_vals
+            .and_then(|vals| vals.get(&name).cloned())
+            .or_else(|| std::env::var_os(name))
+    }
+
+    /// The `KEY=VAL` pairs loaded from the file named by the
+    /// [`App::env_file_arg`][crate::App::env_file_arg] option, if one was given
+    /// on the command line. Blank lines and `#` comments are skipped.
+    #[cfg(feature = "env")]
+    fn env_file_values(
+        &self,
+        matcher: &ArgMatcher,
+    ) -> ClapResult<Option<std::collections::HashMap<String, OsString>>> {
+        let name = match self.app.env_file_arg {
+            Some(name) => name,
+            None => return Ok(None),
+        };
+        let path = match matcher.get(&Id::from(name)).and_then(|ma| ma.first()) {
+            Some(path) => path.clone(),
+            None => return Ok(None),
+        };
+        let text = std::fs::read_to_string(&path).map_err(|e| {
+            ClapError::raw(
+                ErrorKind::Io,
+                format!(
+                    "could not read env file `{}`: {}\n",
+                    path.to_string_lossy(),
+                    e
+                ),
+            )
+            .with_app(self.app)
+        })?;
+        let mut vals = std::collections::HashMap::new();
+        for line in text.lines() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+            if let Some((key, val)) = line.split_once('=') {
+                vals.insert(key.trim().to_owned(), OsString::from(val.trim()));
+            }
+        }
+        debug!("Parser::env_file_values: loaded {} pairs", vals.len());
+        Ok(Some(vals))
+    }
+
+    #[cfg(feature = "yaml")]
+    pub(crate) fn add_config(
+        &self,
+        matcher: &mut ArgMatcher,
+        trailing_values: bool,
+    ) -> ClapResult<()> {
+        for (key, value) in &self.app.config_values {
+            let id = Id::from(key.as_str());
+            let arg = match self.app.find(&id) {
+                Some(arg) => arg,
+                None => {
+                    return Err(ClapError::raw(
+                        ErrorKind::UnknownArgument,
+                        format!("config file key `{}` does not match any argument\n", key),
+                    ));
+                }
+            };
+
+            // Config values sit beneath both command-line and env sources, so skip any
+            // arg that already picked up a value.
+            if matcher.get(&arg.id).map_or(false, |m| m.source().is_some()) {
+                debug!("Parser::add_config: Skipping existing arg `{}`", arg);
+                continue;
+            }
+
+            debug!("Parser::add_config: Adding `{}`={:?}", arg, value);
+            if arg.is_takes_value_set() {
+                match value {
+                    ConfigValue::Bool(b) => {
+                        self.add_val_to_arg(
+                            arg,
+                            RawOsStr::from_str(if *b { "true" } else { "false" }),
+                            matcher,
+                            ValueSource::ConfigFile,
+                            false,
+                            trailing_values,
+                        );
+                    }
+                    ConfigValue::String(s) => {
+                        self.add_val_to_arg(
+                            arg,
+                            RawOsStr::from_str(s),
+                            matcher,
+                            ValueSource::ConfigFile,
+                            false,
+                            trailing_values,
+                        );
+                    }
+                    ConfigValue::List(items) => {
+                        for item in items {
+                            self.add_val_to_arg(
+                                arg,
+                                RawOsStr::from_str(item),
+                                matcher,
+                                ValueSource::ConfigFile,
+                                false,
+                                trailing_values,
+                            );
+                        }
+                    }
+                }
+            } else {
+                match value {
+                    ConfigValue::Bool(true) => {
+                        matcher.add_index_to(&arg.id, self.cur_idx.get(), ValueSource::ConfigFile);
+                    }
+                    ConfigValue::Bool(false) => {}
+                    _ => {
+                        return Err(ClapError::raw(
+                            ErrorKind::ValueValidation,
+                            format!(
+                                "config file key `{}` corresponds to a flag and must be `true` or `false`\n",
+                                key
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    pub(crate) fn add_prompts(&mut self, matcher: &mut ArgMatcher) -> ClapResult<()> {
+        use crate::output::prompt;
+
+        let prompt_all = self.app.is_prompt_missing_set();
+        if !prompt_all && self.app.args.args().all(|a| a.prompt.is_none()) {
+            return Ok(());
+        }
+        if !prompt::stdin_is_tty() {
+            // Scripts and pipes should fail fast with the usual error rather
+            // than hang waiting for input.
+            debug!("Parser::add_prompts: stdin is not a terminal, skipping");
+            return Ok(());
+        }
+
+        let missing: Vec<Id> = self
+            .app
+            .args
+            .args()
+            .filter(|a| {
+                a.is_takes_value_set()
+                    && (a.prompt.is_some() || (prompt_all && a.is_required_set()))
+                    && !matcher.contains(&a.id)
+            })
+            .map(|a| a.id.clone())
+            .collect();
+
+        for id in missing {
+            let arg = self.app.find(&id).expect(INTERNAL_ERROR_MSG);
+            let text = arg
+                .prompt
+                .map(str::to_owned)
+                .unwrap_or_else(|| format!("{}: ", arg.name));
+            debug!("Parser::add_prompts: Prompting for `{}`", arg);
+            let value = prompt::read_value(&text).map_err(|e| {
+                ClapError::raw(
+                    ErrorKind::Io,
+                    format!("could not prompt for '{}': {}\n", arg.name, e),
+                )
+                .with_app(self.app)
+            })?;
+            self.add_val_to_arg(
+                arg,
+                RawOsStr::from_str(&value),
+                matcher,
+                ValueSource::CommandLine,
+                false,
+                false,
+            );
+        }
+
+        Ok(())
     }
 
     /// Increase occurrence of specific argument and the grouped arg it's in.
@@ -1477,9 +2314,24 @@ impl<'help, 'app> Parser<'help, 'app> {
         self.remove_overrides(arg, matcher);
 
         matcher.inc_occurrence_of_arg(arg);
+        // Track which prefix character spelled this occurrence, so that in
+        // `+x ... -x` the standard spelling wins again.
+        match self.pending_flag_prefix.borrow_mut().take() {
+            Some((id, prefix)) if id == arg.id => matcher.record_flag_prefix(&arg.id, prefix),
+            _ => matcher.clear_flag_prefix(&arg.id),
+        }
         // Increment or create the group "args"
         for group in self.app.groups_for_arg(&arg.id) {
             matcher.inc_occurrence_of_group(&group);
+            self.record_group_source_name(matcher, &group);
+        }
+    }
+
+    /// Stamp a group's entry with the group name, so lookups that need the
+    /// declared name (such as serialization) work in release builds too
+    fn record_group_source_name(&self, matcher: &mut ArgMatcher, group: &Id) {
+        if let (Some(g), Some(ma)) = (self.app.find_group(group), matcher.get_mut(group)) {
+            ma.set_source_name(g.name);
         }
     }
 }
@@ -1495,7 +2347,7 @@ impl<'help, 'app> Parser<'help, 'app> {
     ) -> ClapError {
         debug!("Parser::did_you_mean_error: arg={}", arg);
         // Didn't match a flag or option
-        let longs = self
+        let mut longs = self
             .app
             .args
             .keys()
@@ -1504,13 +2356,16 @@ impl<'help, 'app> Parser<'help, 'app> {
                 _ => None,
             })
             .collect::<Vec<_>>();
+        longs.extend(self.app.suggestion_words.iter().map(|w| w.to_string()));
         debug!("Parser::did_you_mean_error: longs={:?}", longs);
 
+        let min_confidence = self.app.get_suggestion_confidence();
         let did_you_mean = suggestions::did_you_mean_flag(
             arg,
             remaining_args,
             longs.iter().map(|x| &x[..]),
             self.app.subcommands.as_mut_slice(),
+            min_confidence,
         );
 
         // Add the arg to the matches to build a proper usage string
@@ -1530,17 +2385,66 @@ impl<'help, 'app> Parser<'help, 'app> {
             .cloned()
             .collect();
 
-        ClapError::unknown_argument(
+        let err = ClapError::unknown_argument(
             self.app,
             format!("--{}", arg),
             did_you_mean,
             Usage::new(self.app, &self.required).create_usage_with_title(&*used),
-        )
+        );
+        self.suggest_flag_elsewhere(err)
+    }
+
+    /// Attaches a "try `myapp sub --flag`" hint to an unknown argument error when the
+    /// flag exists verbatim somewhere else in the command tree. Propagating the error
+    /// through the parent parsers gives every level a chance to find it, so flags
+    /// misplaced in either direction get the exact path.
+    fn suggest_flag_elsewhere(&mut self, error: ClapError) -> ClapError {
+        if error.kind() != ErrorKind::UnknownArgument
+            || error.get_context(ContextKind::SuggestedCommand).is_some()
+        {
+            return error;
+        }
+        let long = match error.get_context(ContextKind::InvalidArg) {
+            Some(ContextValue::String(arg)) => match arg.strip_prefix("--") {
+                Some(long) => long.split('=').next().unwrap_or(long).to_string(),
+                None => return error,
+            },
+            _ => return error,
+        };
+
+        let accepted_here = self.app.args.keys().any(|a| match a {
+            KeyType::Long(l) => l.to_string_lossy() == long,
+            _ => false,
+        });
+        let path = if accepted_here {
+            Some(Vec::new())
+        } else {
+            suggestions::flag_subcommand_path(&long, self.app.subcommands.as_mut_slice())
+        };
+
+        match path {
+            Some(path) => {
+                let mut cmd = self
+                    .app
+                    .bin_name
+                    .clone()
+                    .unwrap_or_else(|| self.app.name.clone());
+                for name in path {
+                    cmd.push(' ');
+                    cmd.push_str(&name);
+                }
+                error.insert_context_unchecked(
+                    ContextKind::SuggestedCommand,
+                    ContextValue::String(format!("{} --{}", cmd, long)),
+                )
+            }
+            None => error,
+        }
     }
 
     pub(crate) fn write_help_err(&self) -> ClapResult<Colorizer> {
         let usage = Usage::new(self.app, &self.required);
-        let mut c = Colorizer::new(true, self.color_help());
+        let mut c = Colorizer::new(true, self.color_help()).with_theme(self.app.theme);
         Help::new(HelpWriter::Buffer(&mut c), self.app, &usage, false).write_help()?;
         Ok(c)
     }
@@ -1551,11 +2455,16 @@ impl<'help, 'app> Parser<'help, 'app> {
             use_long && self.use_long_help()
         );
 
-        use_long = use_long && self.use_long_help();
+        let verbose = self.verbose_help.get();
+        // The verbose tier always renders the long help format.
+        use_long = (use_long && self.use_long_help()) || verbose;
         let usage = Usage::new(self.app, &self.required);
-        let mut c = Colorizer::new(false, self.color_help());
+        let mut c = Colorizer::new(false, self.color_help()).with_theme(self.app.theme);
 
-        match Help::new(HelpWriter::Buffer(&mut c), self.app, &usage, use_long).write_help() {
+        match Help::new(HelpWriter::Buffer(&mut c), self.app, &usage, use_long)
+            .verbose(verbose)
+            .write_help()
+        {
             Err(e) => e.into(),
             _ => ClapError::display_help(self.app, c),
         }
@@ -1565,7 +2474,7 @@ impl<'help, 'app> Parser<'help, 'app> {
         debug!("Parser::version_err");
 
         let msg = self.app._render_version(use_long);
-        let mut c = Colorizer::new(false, self.color_help());
+        let mut c = Colorizer::new(false, self.color_help()).with_theme(self.app.theme);
         c.none(msg);
         ClapError::display_version(self.app, c)
     }
@@ -1578,10 +2487,104 @@ impl<'help, 'app> Parser<'help, 'app> {
     }
 }
 
-#[derive(Debug)]
+/// If `token` looks like a flag and matches one of `app`'s arguments, the pattern to search
+/// for in the help output (the flag as it appears in help, preferring the long form).
+fn flag_search_pattern(app: &App, token: &OsString) -> Option<String> {
+    let token = token.to_str()?;
+    if let Some(long) = token.strip_prefix("--") {
+        app.args
+            .args()
+            .find(|a| a.long == Some(long) || a.aliases.iter().any(|(als, _)| *als == long))
+            .map(|a| format!("--{}", a.long.unwrap_or(long)))
+    } else if let Some(short) = token.strip_prefix('-') {
+        let mut chars = short.chars();
+        let c = chars.next()?;
+        if chars.next().is_some() {
+            return None;
+        }
+        app.args
+            .args()
+            .find(|a| a.short == Some(c))
+            .map(|a| match a.long {
+                Some(long) => format!("--{}", long),
+                None => format!("-{}", c),
+            })
+    } else {
+        None
+    }
+}
+
+/// Upper bound on `@file` expansions in one parse, guarding against response files
+/// that reference each other in a cycle
+const RESPONSE_FILE_LIMIT: usize = 25;
+
+/// Splits a response file into arguments following MSVC/javac conventions:
+/// arguments are separated by whitespace (including newlines), single or double
+/// quotes group an argument containing whitespace, and a backslash escapes the
+/// next character inside double quotes.
+fn tokenize_response_file(text: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut current = String::new();
+    let mut in_token = false;
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            _ if c.is_whitespace() => {
+                if in_token {
+                    tokens.push(std::mem::take(&mut current));
+                    in_token = false;
+                }
+            }
+            '\'' => {
+                in_token = true;
+                for c in chars.by_ref() {
+                    if c == '\'' {
+                        break;
+                    }
+                    current.push(c);
+                }
+            }
+            '"' => {
+                in_token = true;
+                while let Some(c) = chars.next() {
+                    match c {
+                        '"' => break,
+                        '\\' => {
+                            if let Some(escaped) = chars.next() {
+                                current.push(escaped);
+                            }
+                        }
+                        _ => current.push(c),
+                    }
+                }
+            }
+            _ => {
+                in_token = true;
+                current.push(c);
+            }
+        }
+    }
+    if in_token {
+        tokens.push(current);
+    }
+    tokens
+}
+
 pub(crate) struct Input {
     items: Vec<OsString>,
     cursor: usize,
+    // Lazily pulled source for `App::try_parse_iter`; tokens are moved into `items` as
+    // parsing advances instead of being collected upfront
+    source: Option<Box<dyn Iterator<Item = OsString>>>,
+}
+
+impl std::fmt::Debug for Input {
+    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+        f.debug_struct("Input")
+            .field("items", &self.items)
+            .field("cursor", &self.cursor)
+            .finish()
+    }
 }
 
 impl<I, T> From<I> for Input
@@ -1593,12 +2596,45 @@ where
         Self {
             items: val.map(|x| x.into()).collect(),
             cursor: 0,
+            source: None,
         }
     }
 }
 
 impl Input {
+    // Every token pulled so far, including already-consumed ones; used to
+    // echo the command line in error messages
+    pub(crate) fn items(&self) -> &[OsString] {
+        &self.items
+    }
+
+    pub(crate) fn from_lazy(source: impl Iterator<Item = OsString> + 'static) -> Self {
+        Self {
+            items: Vec::new(),
+            cursor: 0,
+            source: Some(Box::new(source)),
+        }
+    }
+
+    // Moves tokens out of the source until `len` items are buffered or it runs dry
+    fn fill_to(&mut self, len: usize) {
+        if let Some(source) = self.source.as_mut() {
+            while self.items.len() < len {
+                match source.next() {
+                    Some(item) => self.items.push(item),
+                    None => {
+                        self.source = None;
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
     pub(crate) fn next(&mut self) -> Option<(&OsStr, &[OsString])> {
+        // One token of lookahead is enough for the common paths; the rare
+        // full-remainder consumers go through `remaining_full`
+        self.fill_to(self.cursor + 2);
         if self.cursor >= self.items.len() {
             None
         } else {
@@ -1609,6 +2645,17 @@ impl Input {
         }
     }
 
+    /// Everything not yet consumed, buffering the rest of a lazy source first
+    pub(crate) fn remaining_full(&mut self) -> &[OsString] {
+        self.fill_to(usize::MAX);
+        &self.items[self.cursor..]
+    }
+
+    /// Index of the next item to be returned, i.e. the number of items consumed so far.
+    pub(crate) fn cursor(&self) -> usize {
+        self.cursor
+    }
+
     /// Insert some items to the Input items just after current parsing cursor.
     /// Usually used by replaced items recovering.
     pub(crate) fn insert(&mut self, insert_items: &[&str]) {
@@ -1653,6 +2700,11 @@ enum ParseResult {
     NoMatchingArg {
         arg: String,
     },
+    /// An abbreviated long flag matched more than one argument.
+    AmbiguousArg {
+        arg: String,
+        candidates: Vec<String>,
+    },
     /// No argument found e.g. parser is given `-` when parsing a flag.
     NoArg,
     /// This is a Help flag.
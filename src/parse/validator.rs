@@ -27,8 +27,19 @@ impl<'help, 'app, 'parser> Validator<'help, 'app, 'parser> {
         #[cfg(feature = "env")]
         self.p.add_env(matcher, trailing_values)?;
 
+        #[cfg(feature = "yaml")]
+        self.p.add_config(matcher, trailing_values)?;
+
         self.p.add_defaults(matcher, trailing_values);
 
+        self.p.add_prompts(matcher)?;
+
+        // Under `AppSettings::CollectAllErrors` these checks must not return
+        // early, or errors already collected while parsing would be dropped;
+        // their failures join the aggregation below instead.
+        let collect_all = self.p.app.is_collect_all_errors_set();
+        let mut early_errors = Vec::new();
+
         if let ParseState::Opt(a) = parse_state {
             debug!("Validator::validate: needs_val_of={:?}", a);
 
@@ -39,7 +50,7 @@ impl<'help, 'app, 'parser> Validator<'help, 'app, 'parser> {
                 true
             };
             if should_err {
-                return Err(Error::empty_value(
+                let err = Error::empty_value(
                     self.p.app,
                     &o.possible_vals
                         .iter()
@@ -47,11 +58,18 @@ impl<'help, 'app, 'parser> Validator<'help, 'app, 'parser> {
                         .collect::<Vec<_>>(),
                     o,
                     Usage::new(self.p.app, &self.p.required).create_usage_with_title(&[]),
-                ));
+                );
+                if collect_all {
+                    early_errors.push(err);
+                } else {
+                    return Err(err);
+                }
             }
         }
 
-        if !has_subcmd && self.p.app.is_arg_required_else_help_set() {
+        let has_errors = !early_errors.is_empty() || !self.p.pending_errors.is_empty();
+
+        if !has_errors && !has_subcmd && self.p.app.is_arg_required_else_help_set() {
             let num_user_values = matcher
                 .arg_names()
                 .filter(|arg_id| matcher.check_explicit(arg_id, ArgPredicate::IsPresent))
@@ -62,28 +80,98 @@ impl<'help, 'app, 'parser> Validator<'help, 'app, 'parser> {
             }
         }
         #[allow(deprecated)]
-        if !has_subcmd && self.p.app.is_subcommand_required_set() {
+        if !has_subcmd
+            && self.p.app.is_subcommand_required_set()
+            && !self
+                .p
+                .app
+                .subcommand_required_unless
+                .iter()
+                .any(|id| matcher.check_explicit(id, ArgPredicate::IsPresent))
+        {
             let bn = self.p.app.bin_name.as_ref().unwrap_or(&self.p.app.name);
-            return Err(Error::missing_subcommand(
+            let err = Error::missing_subcommand(
                 self.p.app,
                 bn.to_string(),
+                self.p
+                    .app
+                    .subcommand_required_unless
+                    .iter()
+                    .map(|id| self.p.app[id].to_string())
+                    .collect(),
                 Usage::new(self.p.app, &self.p.required).create_usage_with_title(&[]),
-            ));
-        } else if !has_subcmd && self.p.app.is_set(AppSettings::SubcommandRequiredElseHelp) {
+            );
+            if collect_all {
+                early_errors.push(err);
+            } else {
+                return Err(err);
+            }
+        } else if !has_errors
+            && !has_subcmd
+            && self.p.app.is_set(AppSettings::SubcommandRequiredElseHelp)
+        {
             debug!("Validator::new::get_matches_with: SubcommandRequiredElseHelp=true");
             let message = self.p.write_help_err()?;
             return Err(Error::display_help_error(self.p.app, message));
         }
 
-        self.validate_conflicts(matcher)?;
-        if !(self.p.app.is_subcommand_negates_reqs_set() && has_subcmd) {
-            self.validate_required(matcher)?;
+        self.canonicalize_paths(matcher)?;
+        self.canonicalize_value_casing(matcher);
+
+        if collect_all {
+            let mut errors = std::mem::take(&mut self.p.pending_errors);
+            errors.append(&mut early_errors);
+            if let Err(err) = self.validate_conflicts(matcher) {
+                errors.push(err);
+            }
+            if !(self.p.app.is_subcommand_negates_reqs_set() && has_subcmd) {
+                if let Err(err) = self.validate_required(matcher) {
+                    errors.push(err);
+                }
+                if let Err(err) = self.validate_group_counts(matcher) {
+                    errors.push(err);
+                }
+            }
+            if let Err(err) = self.validate_matched_args(matcher) {
+                errors.push(err);
+            }
+            if let Err(err) = self.parse_typed_values(matcher) {
+                errors.push(err);
+            }
+            if !errors.is_empty() {
+                let usage = Usage::new(self.p.app, &self.p.required).create_usage_with_title(&[]);
+                return Err(Error::multiple(self.p.app, errors, usage));
+            }
+        } else {
+            self.validate_conflicts(matcher)?;
+            if !(self.p.app.is_subcommand_negates_reqs_set() && has_subcmd) {
+                self.validate_required(matcher)?;
+                self.validate_group_counts(matcher)?;
+            }
+            self.validate_matched_args(matcher)?;
+            self.parse_typed_values(matcher)?;
         }
-        self.validate_matched_args(matcher)?;
+
+        self.validate_matches_hook(matcher)?;
 
         Ok(())
     }
 
+    /// Run the [`App::validator`] hook against the fully parsed matches.
+    fn validate_matches_hook(&self, matcher: &ArgMatcher) -> ClapResult<()> {
+        if let Some(ref validator) = self.p.app.matches_validator {
+            debug!("Validator::validate_matches_hook");
+            validator.validate(matcher).map_err(|e| {
+                Error::raw(
+                    crate::error::ErrorKind::ValueValidation,
+                    format!("{}\n", e),
+                )
+                .with_app(self.p.app)
+            })?;
+        }
+        Ok(())
+    }
+
     fn validate_arg_values(
         &self,
         arg: &Arg,
@@ -91,7 +179,9 @@ impl<'help, 'app, 'parser> Validator<'help, 'app, 'parser> {
         matcher: &ArgMatcher,
     ) -> ClapResult<()> {
         debug!("Validator::validate_arg_values: arg={:?}", arg.name);
-        for val in ma.vals_flatten() {
+        for (val_idx, val) in ma.vals_flatten().enumerate() {
+            // Errors display the value as the user typed it, not as transformed
+            let typed_val = ma.raw_val(val_idx).unwrap_or(val);
             if !arg.is_allow_invalid_utf8_set() && val.to_str().is_none() {
                 debug!(
                     "Validator::validate_arg_values: invalid UTF-8 found in val {:?}",
@@ -125,7 +215,7 @@ impl<'help, 'app, 'parser> Validator<'help, 'app, 'parser> {
                         .collect();
                     return Err(Error::invalid_value(
                         self.p.app,
-                        val_str.into_owned(),
+                        typed_val.to_string_lossy().into_owned(),
                         &arg.possible_vals
                             .iter()
                             .filter_map(PossibleValue::get_visible_name)
@@ -135,6 +225,33 @@ impl<'help, 'app, 'parser> Validator<'help, 'app, 'parser> {
                     ));
                 }
             }
+            if let Some(range) = arg.value_range {
+                debug!("Validator::validate_arg_values: value_range={}", range);
+                let ok = val
+                    .to_str()
+                    .and_then(|s| s.parse::<i64>().ok())
+                    .map_or(false, |n| range.contains(n));
+                if !ok {
+                    return Err(Error::value_validation(
+                        arg.to_string(),
+                        typed_val.to_string_lossy().into_owned(),
+                        format!("value must be {}", range.describe()).into(),
+                    )
+                    .with_app(self.p.app));
+                }
+            }
+            if let Some(msg) = Self::validate_path_hint(arg, val) {
+                debug!(
+                    "Validator::validate_arg_values: path hint {:?} rejected {:?}",
+                    arg.value_hint, val
+                );
+                return Err(Error::value_validation(
+                    arg.to_string(),
+                    typed_val.to_string_lossy().into_owned(),
+                    msg.into(),
+                )
+                .with_app(self.p.app));
+            }
             if arg.is_forbid_empty_values_set() && val.is_empty() && matcher.contains(&arg.id) {
                 debug!("Validator::validate_arg_values: illegal empty val found");
                 return Err(Error::empty_value(
@@ -155,7 +272,7 @@ impl<'help, 'app, 'parser> Validator<'help, 'app, 'parser> {
                     debug!("error");
                     return Err(Error::value_validation(
                         arg.to_string(),
-                        val.to_string_lossy().into_owned(),
+                        typed_val.to_string_lossy().into_owned(),
                         e,
                     )
                     .with_app(self.p.app));
@@ -170,7 +287,7 @@ impl<'help, 'app, 'parser> Validator<'help, 'app, 'parser> {
                     debug!("error");
                     return Err(Error::value_validation(
                         arg.to_string(),
-                        val.to_string_lossy().into(),
+                        typed_val.to_string_lossy().into(),
                         e,
                     )
                     .with_app(self.p.app));
@@ -182,6 +299,202 @@ impl<'help, 'app, 'parser> Validator<'help, 'app, 'parser> {
         Ok(())
     }
 
+    /// Check `val` against a path-validating [`ValueHint`][crate::ValueHint],
+    /// returning the error message when the expectation doesn't hold.
+    ///
+    /// Hints only inform shell completion unless the arg opted into
+    /// [`Arg::validate_hint`][crate::Arg::validate_hint].
+    fn validate_path_hint(arg: &Arg, val: &std::ffi::OsStr) -> Option<String> {
+        use crate::ValueHint;
+
+        if !arg.is_validate_hint_set() {
+            return None;
+        }
+        let path = std::path::Path::new(val);
+        match arg.value_hint {
+            ValueHint::ExistingFile if !path.is_file() => Some("file does not exist".to_owned()),
+            ValueHint::ExistingDir if !path.is_dir() => {
+                Some("directory does not exist".to_owned())
+            }
+            ValueHint::CreatableFile => {
+                let parent = match path.parent() {
+                    Some(parent) if parent.as_os_str().is_empty() => std::path::Path::new("."),
+                    Some(parent) => parent,
+                    None => return Some("cannot create a file at this path".to_owned()),
+                };
+                if !parent.is_dir() {
+                    Some(format!(
+                        "parent directory `{}` does not exist",
+                        parent.display()
+                    ))
+                } else if std::fs::metadata(parent).map_or(false, |m| m.permissions().readonly()) {
+                    Some(format!(
+                        "parent directory `{}` is not writable",
+                        parent.display()
+                    ))
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        }
+    }
+
+    fn canonicalize_paths(&self, matcher: &mut ArgMatcher) -> ClapResult<()> {
+        debug!("Validator::canonicalize_paths");
+        let ids: Vec<Id> = matcher
+            .arg_names()
+            .filter(|id| {
+                self.p
+                    .app
+                    .find(id)
+                    .map_or(false, |a| a.is_canonicalize_set())
+            })
+            .cloned()
+            .collect();
+        for id in ids {
+            let arg = &self.p.app[&id];
+            let mut canonical = Vec::new();
+            for val in matcher.get(&id).expect(INTERNAL_ERROR_MSG).vals_flatten() {
+                match std::path::Path::new(val).canonicalize() {
+                    Ok(path) => canonical.push(path.into_os_string()),
+                    Err(e) => {
+                        return Err(Error::value_validation(
+                            arg.to_string(),
+                            val.to_string_lossy().into_owned(),
+                            Box::new(e),
+                        )
+                        .with_app(self.p.app));
+                    }
+                }
+            }
+            matcher
+                .get_mut(&id)
+                .expect(INTERNAL_ERROR_MSG)
+                .set_canonical_vals(canonical);
+        }
+        Ok(())
+    }
+
+    // With `Arg::ignore_case`, `--format JSON` should behave exactly like `--format json`:
+    // downstream consumers (`value_of_t`, `ArgEnum::from_str`, value parsers) see the
+    // canonical spelling while errors keep the value as the user typed it.
+    fn canonicalize_value_casing(&self, matcher: &mut ArgMatcher) {
+        debug!("Validator::canonicalize_value_casing");
+        let ids: Vec<Id> = matcher
+            .arg_names()
+            .filter(|id| {
+                self.p.app.find(id).map_or(false, |a| {
+                    a.is_ignore_case_set() && !a.possible_vals.is_empty()
+                })
+            })
+            .cloned()
+            .collect();
+        for id in ids {
+            let arg = &self.p.app[&id];
+            let ma = matcher.get_mut(&id).expect(INTERNAL_ERROR_MSG);
+            let replacements: Vec<(usize, std::ffi::OsString, std::ffi::OsString)> = ma
+                .vals_flatten()
+                .enumerate()
+                .filter_map(|(index, val)| {
+                    let val_str = val.to_str()?;
+                    // An exact match (including aliases) is already canonical enough
+                    if arg.possible_vals.iter().any(|pv| pv.matches(val_str, false)) {
+                        return None;
+                    }
+                    let canonical = arg
+                        .possible_vals
+                        .iter()
+                        .find(|pv| pv.matches(val_str, true))
+                        .map(PossibleValue::get_name)?;
+                    Some((index, canonical.into(), val.clone()))
+                })
+                .collect();
+            for (index, canonical, raw) in replacements {
+                if ma.raw_val(index).is_none() {
+                    ma.push_raw_val(index, raw);
+                }
+                ma.replace_val(index, canonical);
+            }
+        }
+    }
+
+    fn parse_typed_values(&self, matcher: &mut ArgMatcher) -> ClapResult<()> {
+        debug!("Validator::parse_typed_values");
+        let ids: Vec<Id> = matcher
+            .arg_names()
+            .filter(|id| {
+                self.p
+                    .app
+                    .find(id)
+                    .map_or(false, |a| a.value_parser.is_some())
+            })
+            .cloned()
+            .collect();
+        for id in ids {
+            let arg = &self.p.app[&id];
+            let parser = arg.value_parser.as_ref().expect(INTERNAL_ERROR_MSG);
+            let mut typed = Vec::new();
+            for val in matcher.get(&id).expect(INTERNAL_ERROR_MSG).vals_flatten() {
+                match parser.parse(&val.to_string_lossy()) {
+                    Ok(value) => typed.push(value),
+                    Err(e) => {
+                        return Err(Error::value_validation(
+                            arg.to_string(),
+                            val.to_string_lossy().into_owned(),
+                            e,
+                        )
+                        .with_app(self.p.app));
+                    }
+                }
+            }
+            matcher
+                .get_mut(&id)
+                .expect(INTERNAL_ERROR_MSG)
+                .set_typed_vals(typed);
+        }
+        Ok(())
+    }
+
+    /// Enforce [`ArgGroup::at_least`]/[`ArgGroup::at_most`] cardinality rules.
+    ///
+    /// Runs after `validate_required`, so a required group with none of its
+    /// arguments present is still reported as a missing required argument.
+    fn validate_group_counts(&self, matcher: &ArgMatcher) -> ClapResult<()> {
+        debug!("Validator::validate_group_counts");
+        for group in self
+            .p
+            .app
+            .groups
+            .iter()
+            .filter(|g| g.at_least.is_some() || g.at_most.is_some())
+        {
+            let args = self.p.app.unroll_args_in_group(&group.id);
+            let count = args
+                .iter()
+                .filter(|arg| matcher.check_explicit(arg, ArgPredicate::IsPresent))
+                .count();
+            let too_few = group.at_least.map_or(false, |min| count < min);
+            let too_many = group.at_most.map_or(false, |max| count > max);
+            if too_few || too_many {
+                debug!(
+                    "Validator::validate_group_counts: group={:?} count={} at_least={:?} at_most={:?}",
+                    group.name, count, group.at_least, group.at_most
+                );
+                return Err(Error::group_count(
+                    self.p.app,
+                    group.name.to_string(),
+                    args.iter().map(|id| self.p.app[id].to_string()).collect(),
+                    group.at_least,
+                    group.at_most,
+                    count,
+                    Usage::new(self.p.app, &self.p.required).create_usage_with_title(&[]),
+                ));
+            }
+        }
+        Ok(())
+    }
+
     fn validate_conflicts(&self, matcher: &ArgMatcher) -> ClapResult<()> {
         debug!("Validator::validate_conflicts");
 
@@ -317,6 +630,8 @@ impl<'help, 'app, 'parser> Validator<'help, 'app, 'parser> {
                 self.validate_arg_num_vals(arg, ma)?;
                 self.validate_arg_values(arg, ma, matcher)?;
                 self.validate_arg_num_occurs(arg, ma)?;
+                self.validate_occurrence_patterns(arg, ma, matcher)?;
+                self.validate_key_value_map(arg, ma)?;
             }
             Ok(())
         })
@@ -358,6 +673,88 @@ impl<'help, 'app, 'parser> Validator<'help, 'app, 'parser> {
         Ok(())
     }
 
+    fn validate_occurrence_patterns(
+        &self,
+        a: &Arg,
+        ma: &MatchedArg,
+        matcher: &ArgMatcher,
+    ) -> ClapResult<()> {
+        if a.occurs_multiple_of.is_none() && a.paired_with.is_empty() {
+            return Ok(());
+        }
+        debug!("Validator::validate_occurrence_patterns: a={:?}", a.name);
+        // Default and env values don't participate in occurrence patterns
+        if !ma.check_explicit(ArgPredicate::IsPresent) {
+            return Ok(());
+        }
+        if let Some(multiple) = a.occurs_multiple_of {
+            let occurs = ma.get_occurrences() as usize;
+            if multiple > 0 && occurs % multiple != 0 {
+                return Err(Error::occurrence_not_multiple_of(
+                    self.p.app,
+                    a,
+                    multiple,
+                    occurs,
+                    Usage::new(self.p.app, &self.p.required).create_usage_with_title(&[]),
+                ));
+            }
+        }
+        for partner_id in &a.paired_with {
+            let partner = self.p.app.find(partner_id).expect(INTERNAL_ERROR_MSG);
+            let occurrences = occurrence_start_indices(ma);
+            let partner_indices: Vec<usize> = matcher
+                .get(partner_id)
+                .map(|pma| pma.indices().collect())
+                .unwrap_or_default();
+            for (i, start) in occurrences.iter().enumerate() {
+                let end = occurrences.get(i + 1).copied().unwrap_or(usize::MAX);
+                let paired = partner_indices.iter().any(|pi| *start < *pi && *pi < end);
+                if !paired {
+                    return Err(Error::unpaired_occurrence(
+                        self.p.app,
+                        a,
+                        partner.to_string(),
+                        Usage::new(self.p.app, &self.p.required).create_usage_with_title(&[]),
+                    ));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    fn validate_key_value_map(&self, a: &Arg, ma: &MatchedArg) -> ClapResult<()> {
+        if !a.is_key_value_map_set() {
+            return Ok(());
+        }
+        debug!("Validator::validate_key_value_map: a={:?}", a.name);
+        let mut seen: Vec<String> = Vec::new();
+        for val in ma.vals_flatten() {
+            let val_str = val.to_string_lossy();
+            match val_str.split_once('=') {
+                Some((key, _)) if !key.is_empty() => {
+                    if seen.iter().any(|k| k == key) {
+                        return Err(Error::value_validation(
+                            a.to_string(),
+                            val_str.clone().into_owned(),
+                            format!("duplicate key `{}`", key).into(),
+                        )
+                        .with_app(self.p.app));
+                    }
+                    seen.push(key.to_string());
+                }
+                _ => {
+                    return Err(Error::value_validation(
+                        a.to_string(),
+                        val_str.clone().into_owned(),
+                        "expected a `KEY=VAL` pair".into(),
+                    )
+                    .with_app(self.p.app));
+                }
+            }
+        }
+        Ok(())
+    }
+
     fn validate_arg_num_vals(&self, a: &Arg, ma: &MatchedArg) -> ClapResult<()> {
         debug!("Validator::validate_arg_num_vals");
         if let Some(num) = a.num_vals {
@@ -568,6 +965,24 @@ impl<'help, 'app, 'parser> Validator<'help, 'app, 'parser> {
     }
 }
 
+/// The index each occurrence started at.  Flags record one index per occurrence; for args
+/// taking values, the index of the first value in each group marks the occurrence.
+fn occurrence_start_indices(ma: &MatchedArg) -> Vec<usize> {
+    if ma.has_val_groups() {
+        let mut starts = Vec::new();
+        let mut val_idx = 0;
+        for group in ma.vals() {
+            if let Some(index) = ma.get_index(val_idx) {
+                starts.push(index);
+            }
+            val_idx += group.len();
+        }
+        starts
+    } else {
+        ma.indices().collect()
+    }
+}
+
 #[derive(Default, Clone, Debug)]
 struct Conflicts {
     potential: std::collections::HashMap<Id, Vec<Id>>,
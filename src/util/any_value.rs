@@ -0,0 +1,46 @@
+use std::any::Any;
+use std::fmt;
+use std::sync::Arc;
+
+/// A type-erased value produced by a [`ValueParser`][crate::ValueParser]
+#[derive(Clone)]
+pub(crate) struct AnyValue {
+    inner: Arc<dyn Any + Send + Sync>,
+    // Kept for downcast panic messages, since `Any` loses the name
+    type_name: &'static str,
+}
+
+impl AnyValue {
+    pub(crate) fn new<T: Any + Send + Sync>(value: T) -> Self {
+        AnyValue {
+            inner: Arc::new(value),
+            type_name: std::any::type_name::<T>(),
+        }
+    }
+
+    pub(crate) fn downcast_ref<T: Any>(&self) -> Option<&T> {
+        self.inner.downcast_ref()
+    }
+
+    pub(crate) fn type_name(&self) -> &'static str {
+        self.type_name
+    }
+}
+
+impl fmt::Debug for AnyValue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("AnyValue")
+            .field("type", &self.type_name)
+            .finish()
+    }
+}
+
+// The erased values can't be compared, so equality falls back to identity:
+// clones of the same parse result are equal, anything else is not
+impl PartialEq for AnyValue {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.inner, &other.inner)
+    }
+}
+
+impl Eq for AnyValue {}
@@ -1,15 +1,19 @@
 #![allow(clippy::single_component_path_imports)]
 
+mod any_value;
 mod fnv;
 mod graph;
 mod id;
 #[cfg(feature = "env")]
 mod str_to_bool;
+#[cfg(any(windows, test))]
+pub(crate) mod wide;
 
 pub use self::fnv::Key;
 
 #[cfg(feature = "env")]
 pub(crate) use self::str_to_bool::str_to_bool;
+pub(crate) use self::any_value::AnyValue;
 pub(crate) use self::{graph::ChildGraph, id::Id};
 
 pub(crate) mod color;
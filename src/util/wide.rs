@@ -0,0 +1,153 @@
+//! Splitting a raw Windows command line into arguments.
+//!
+//! Implements the same rules as `CommandLineToArgvW` (post-2008 semantics),
+//! operating directly on UTF-16 units so no lossy round trip through UTF-8 is
+//! needed.
+
+const SPACE: u16 = b' ' as u16;
+const TAB: u16 = b'\t' as u16;
+const QUOTE: u16 = b'"' as u16;
+const BACKSLASH: u16 = b'\\' as u16;
+
+/// Splits a raw UTF-16 command line into individual arguments.
+///
+/// The first argument is the program name and follows simpler rules: it is
+/// delimited by the closing quote when quoted, otherwise by the first space or
+/// tab, with no backslash escaping. The remaining arguments follow
+/// `CommandLineToArgvW` escaping: `2n` backslashes before a quote collapse to
+/// `n` backslashes and toggle quoting, `2n + 1` backslashes before a quote
+/// yield `n` backslashes and a literal quote, backslashes not followed by a
+/// quote are literal, and `""` inside a quoted span is a literal quote.
+///
+/// An empty command line yields no arguments, matching the Windows behavior of
+/// leaving the caller to substitute the current executable's path.
+pub(crate) fn split_wide(cmdline: &[u16]) -> Vec<Vec<u16>> {
+    let mut args = Vec::new();
+    let mut i = 0;
+
+    if cmdline.is_empty() {
+        return args;
+    }
+
+    // Program name: no escaping, a leading quote is matched by the next quote
+    let mut arg0 = Vec::new();
+    if cmdline[0] == QUOTE {
+        i = 1;
+        while i < cmdline.len() && cmdline[i] != QUOTE {
+            arg0.push(cmdline[i]);
+            i += 1;
+        }
+        i = (i + 1).min(cmdline.len());
+    } else {
+        while i < cmdline.len() && cmdline[i] != SPACE && cmdline[i] != TAB {
+            arg0.push(cmdline[i]);
+            i += 1;
+        }
+    }
+    args.push(arg0);
+
+    loop {
+        while i < cmdline.len() && (cmdline[i] == SPACE || cmdline[i] == TAB) {
+            i += 1;
+        }
+        if i == cmdline.len() {
+            break;
+        }
+
+        let mut arg = Vec::new();
+        let mut in_quotes = false;
+        while i < cmdline.len() {
+            match cmdline[i] {
+                SPACE | TAB if !in_quotes => break,
+                BACKSLASH => {
+                    let mut backslashes = 0;
+                    while i < cmdline.len() && cmdline[i] == BACKSLASH {
+                        backslashes += 1;
+                        i += 1;
+                    }
+                    if i < cmdline.len() && cmdline[i] == QUOTE {
+                        arg.resize(arg.len() + backslashes / 2, BACKSLASH);
+                        if backslashes % 2 == 1 {
+                            arg.push(QUOTE);
+                            i += 1;
+                        }
+                        // An even count leaves the quote for the next iteration
+                    } else {
+                        arg.resize(arg.len() + backslashes, BACKSLASH);
+                    }
+                }
+                QUOTE => {
+                    if in_quotes && cmdline.get(i + 1) == Some(&QUOTE) {
+                        // `""` inside a quoted span is a literal quote
+                        arg.push(QUOTE);
+                        i += 2;
+                    } else {
+                        in_quotes = !in_quotes;
+                        i += 1;
+                    }
+                }
+                unit => {
+                    arg.push(unit);
+                    i += 1;
+                }
+            }
+        }
+        args.push(arg);
+    }
+
+    args
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(cmdline: &str) -> Vec<String> {
+        let wide: Vec<u16> = cmdline.encode_utf16().collect();
+        split_wide(&wide)
+            .iter()
+            .map(|arg| String::from_utf16(arg).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn empty_command_line() {
+        assert!(split("").is_empty());
+    }
+
+    #[test]
+    fn whitespace_separated() {
+        assert_eq!(split("prog a  b\tc"), ["prog", "a", "b", "c"]);
+    }
+
+    #[test]
+    fn quoted_spans() {
+        assert_eq!(split(r#"prog "a b" c"#), ["prog", "a b", "c"]);
+        assert_eq!(
+            split(r#"prog mid"dle quo"ting"#),
+            ["prog", "middle quoting"]
+        );
+    }
+
+    #[test]
+    fn backslash_rules() {
+        assert_eq!(split(r#"prog a\\b"#), ["prog", r"a\\b"]);
+        assert_eq!(split(r#"prog \"quoted\""#), ["prog", r#""quoted""#]);
+        assert_eq!(split(r#"prog "dir\\" next"#), ["prog", r"dir\", "next"]);
+        assert_eq!(split(r#"prog a\\\"b"#), ["prog", r#"a\"b"#]);
+    }
+
+    #[test]
+    fn double_quote_inside_quotes() {
+        assert_eq!(split(r#"prog "say ""hi""""#), ["prog", r#"say "hi""#]);
+    }
+
+    #[test]
+    fn program_name_is_not_escaped() {
+        assert_eq!(
+            split(r#""C:\Program Files\prog.exe" arg"#),
+            [r"C:\Program Files\prog.exe", "arg"]
+        );
+        assert_eq!(split(r"C:\prog.exe arg"), [r"C:\prog.exe", "arg"]);
+    }
+}
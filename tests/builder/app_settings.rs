@@ -1,5 +1,7 @@
 use crate::utils;
 
+use std::ffi::OsStr;
+
 use clap::{arg, error::ErrorKind, App, AppSettings, Arg};
 
 static ALLOW_EXT_SC: &str = "clap-test v1.4.8
@@ -178,6 +180,41 @@ fn sub_command_required() {
     assert_eq!(err.kind(), ErrorKind::MissingSubcommand);
 }
 
+#[test]
+fn sub_command_required_unless_present() {
+    let m = App::new("sc_required")
+        .subcommand_required_unless_present("list")
+        .arg(Arg::new("list").long("list"))
+        .subcommand(App::new("sub1"))
+        .try_get_matches_from(vec!["", "--list"])
+        .unwrap();
+    assert!(m.is_present("list"));
+}
+
+#[test]
+fn sub_command_required_unless_present_normal_operation() {
+    let m = App::new("sc_required")
+        .subcommand_required_unless_present("list")
+        .arg(Arg::new("list").long("list"))
+        .subcommand(App::new("sub1"))
+        .try_get_matches_from(vec!["", "sub1"])
+        .unwrap();
+    assert_eq!(m.subcommand_name(), Some("sub1"));
+}
+
+#[test]
+fn sub_command_required_unless_present_error_mentions_exceptions() {
+    let result = App::new("sc_required")
+        .subcommand_required_unless_present("list")
+        .arg(Arg::new("list").long("list"))
+        .subcommand(App::new("sub1"))
+        .try_get_matches_from(vec![""]);
+    assert!(result.is_err());
+    let err = result.err().unwrap();
+    assert_eq!(err.kind(), ErrorKind::MissingSubcommand);
+    assert!(err.to_string().contains("--list"), "{}", err);
+}
+
 #[test]
 fn arg_required_else_help() {
     let result = App::new("arg_required")
@@ -289,7 +326,6 @@ fn subcommand_required_else_help_error_message() {
     ));
 }
 
-#[cfg(not(feature = "suggestions"))]
 #[test]
 fn infer_subcommands_fail_no_args() {
     let m = App::new("prog")
@@ -298,19 +334,11 @@ fn infer_subcommands_fail_no_args() {
         .subcommand(App::new("temp"))
         .try_get_matches_from(vec!["prog", "te"]);
     assert!(m.is_err(), "{:#?}", m.unwrap());
-    assert_eq!(m.unwrap_err().kind(), ErrorKind::UnrecognizedSubcommand);
-}
-
-#[cfg(feature = "suggestions")]
-#[test]
-fn infer_subcommands_fail_no_args() {
-    let m = App::new("prog")
-        .infer_subcommands(true)
-        .subcommand(App::new("test"))
-        .subcommand(App::new("temp"))
-        .try_get_matches_from(vec!["prog", "te"]);
-    assert!(m.is_err(), "{:#?}", m.unwrap());
-    assert_eq!(m.unwrap_err().kind(), ErrorKind::InvalidSubcommand);
+    let err = m.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::AmbiguousPrefix);
+    let out = err.to_string();
+    assert!(out.contains("test"), "{}", out);
+    assert!(out.contains("temp"), "{}", out);
 }
 
 #[test]
@@ -490,6 +518,72 @@ fn delim_values_trailingvararg() {
     );
 }
 
+#[test]
+fn second_double_dash_is_literal_by_default() {
+    let m = App::new("onlypos")
+        .arg(arg!([arg] ... "some arg"))
+        .try_get_matches_from(vec!["", "--", "a", "--", "b"])
+        .unwrap();
+    assert_eq!(
+        m.values_of("arg").unwrap().collect::<Vec<_>>(),
+        &["a", "--", "b"]
+    );
+}
+
+#[test]
+fn repeated_double_dash_as_separator() {
+    let m = App::new("onlypos")
+        .repeated_double_dash_as_separator(true)
+        .arg(arg!([arg] ... "some arg"))
+        .try_get_matches_from(vec!["", "--", "a", "--", "b", "--"])
+        .unwrap();
+    assert_eq!(m.values_of("arg").unwrap().collect::<Vec<_>>(), &["a", "b"]);
+}
+
+#[test]
+fn repeated_double_dash_as_separator_trailingvararg() {
+    let m = App::new("positional")
+        .trailing_var_arg(true)
+        .repeated_double_dash_as_separator(true)
+        .arg(arg!([opt] ... "some pos"))
+        .try_get_matches_from(vec!["", "--", "test", "--", "--foo"])
+        .unwrap();
+    assert_eq!(
+        m.values_of("opt").unwrap().collect::<Vec<_>>(),
+        &["test", "--foo"]
+    );
+}
+
+#[test]
+fn consumed_len_double_dash() {
+    let m = App::new("wrapper")
+        .arg(arg!(-v --verbose "be noisy"))
+        .arg(arg!([cmd] ... "command to run"))
+        .try_get_matches_from(vec!["wrapper", "-v", "--", "make", "-j4"])
+        .unwrap();
+    assert_eq!(m.consumed_len(), Some(3));
+}
+
+#[test]
+fn consumed_len_trailing_var_arg() {
+    let m = App::new("wrapper")
+        .trailing_var_arg(true)
+        .arg(arg!(-v --verbose "be noisy"))
+        .arg(arg!([cmd] ... "command to run"))
+        .try_get_matches_from(vec!["wrapper", "-v", "make", "-j4"])
+        .unwrap();
+    assert_eq!(m.consumed_len(), Some(2));
+}
+
+#[test]
+fn consumed_len_no_capture() {
+    let m = App::new("wrapper")
+        .arg(arg!(-v --verbose "be noisy"))
+        .try_get_matches_from(vec!["wrapper", "-v"])
+        .unwrap();
+    assert_eq!(m.consumed_len(), None);
+}
+
 #[test]
 fn delim_values_only_pos_follows_with_delim() {
     let r = App::new("onlypos")
@@ -908,6 +1002,17 @@ fn external_subcommand_looks_like_built_in() {
     }
 }
 
+#[test]
+fn consumed_len_external_subcommand() {
+    let m = App::new("cargo")
+        .allow_external_subcommands(true)
+        .allow_invalid_utf8_for_external_subcommands(true)
+        .try_get_matches_from(vec!["cargo", "install-update", "foo"])
+        .unwrap();
+    // The external subcommand name is part of the re-executable remainder
+    assert_eq!(m.consumed_len(), Some(1));
+}
+
 #[test]
 fn aaos_flags() {
     // flags
@@ -1180,3 +1285,471 @@ fn color_is_global() {
     let sub = app.get_subcommands().collect::<Vec<_>>()[0];
     assert_eq!(sub.get_color(), clap::ColorChoice::Never);
 }
+
+#[test]
+fn trailing_args_without_positional() {
+    let m = App::new("wrapper")
+        .setting(AppSettings::AllowUnclaimedTrailingArgs)
+        .arg(arg!(-v --verbose "be noisy"))
+        .try_get_matches_from(vec!["wrapper", "-v", "--", "make", "-j4"])
+        .unwrap();
+    assert!(m.is_present("verbose"));
+    assert_eq!(
+        m.trailing_args().collect::<Vec<_>>(),
+        [OsStr::new("make"), OsStr::new("-j4")]
+    );
+}
+
+#[test]
+fn unclaimed_trailing_args_error_by_default() {
+    let res = App::new("wrapper")
+        .arg(arg!(-v --verbose "be noisy"))
+        .try_get_matches_from(vec!["wrapper", "--", "make", "-j4"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::UnknownArgument);
+}
+
+#[test]
+fn trailing_args_seen_by_positional_too() {
+    let m = App::new("wrapper")
+        .arg(arg!([cmd] ... "command to run"))
+        .try_get_matches_from(vec!["wrapper", "--", "make", "-j4"])
+        .unwrap();
+    assert_eq!(
+        m.values_of("cmd").unwrap().collect::<Vec<_>>(),
+        &["make", "-j4"]
+    );
+    assert_eq!(
+        m.trailing_args().collect::<Vec<_>>(),
+        [OsStr::new("make"), OsStr::new("-j4")]
+    );
+}
+
+#[test]
+fn trailing_args_empty_without_double_dash() {
+    let m = App::new("wrapper")
+        .trailing_var_arg(true)
+        .arg(arg!([cmd] ... "command to run"))
+        .try_get_matches_from(vec!["wrapper", "make", "-j4"])
+        .unwrap();
+    assert_eq!(m.trailing_args().count(), 0);
+}
+
+#[test]
+fn prompt_missing_errors_when_stdin_is_not_a_terminal() {
+    // In tests stdin is not a terminal, so the usual error is produced instead
+    // of a prompt.
+    let res = App::new("prog")
+        .setting(AppSettings::PromptMissing)
+        .arg(Arg::new("user").long("user").takes_value(true).required(true))
+        .try_get_matches_from(vec!["prog"]);
+    assert!(res.is_err());
+    assert_eq!(
+        res.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+    );
+}
+
+#[test]
+fn arg_prompt_errors_when_stdin_is_not_a_terminal() {
+    let res = App::new("prog")
+        .arg(
+            Arg::new("user")
+                .long("user")
+                .required(true)
+                .prompt("Username: "),
+        )
+        .try_get_matches_from(vec!["prog"]);
+    assert!(res.is_err());
+    assert_eq!(
+        res.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+    );
+}
+
+#[cfg(unix)]
+fn plugin_dir(prefix: &str, names: &[&str]) -> std::path::PathBuf {
+    use std::os::unix::fs::PermissionsExt;
+
+    let dir = std::env::temp_dir().join(format!("clap-plugin-{}-{}", prefix, std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    for name in names {
+        let path = dir.join(format!("{}{}", prefix, name));
+        std::fs::write(&path, "#!/bin/sh\n").unwrap();
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o755)).unwrap();
+    }
+
+    // Prepend rather than replace, so concurrently running tests still see a sane PATH
+    let path = std::env::var_os("PATH").unwrap_or_default();
+    let paths = std::iter::once(dir.clone()).chain(std::env::split_paths(&path));
+    std::env::set_var("PATH", std::env::join_paths(paths).unwrap());
+    dir
+}
+
+#[test]
+#[cfg(unix)]
+fn external_subcommand_prefix_resolves_plugin_on_path() {
+    let dir = plugin_dir("myapp1-", &["frob"]);
+    let _ = &dir;
+
+    let res = App::new("myapp1")
+        .external_subcommand_prefix("myapp1-")
+        .allow_invalid_utf8_for_external_subcommands(true)
+        .try_get_matches_from(vec!["myapp1", "frob", "--level", "3"]);
+
+    assert!(res.is_ok(), "{}", res.unwrap_err());
+    match res.unwrap().subcommand() {
+        Some((name, args)) => {
+            assert_eq!(name, "frob");
+            assert_eq!(
+                args.values_of_lossy(""),
+                Some(vec!["--level".to_string(), "3".to_string()])
+            );
+        }
+        _ => unreachable!(),
+    }
+}
+
+#[test]
+#[cfg(unix)]
+fn external_subcommand_prefix_rejects_unknown_plugin() {
+    let dir = plugin_dir("myapp2-", &["frob"]);
+    let _ = &dir;
+
+    let res = App::new("myapp2")
+        .external_subcommand_prefix("myapp2-")
+        .try_get_matches_from(vec!["myapp2", "nope"]);
+
+    assert!(res.is_err());
+}
+
+#[test]
+#[cfg(unix)]
+fn external_subcommand_prefix_lists_plugins_in_help() {
+    let dir = plugin_dir("myapp3-", &["frob", "tidy"]);
+    let _ = &dir;
+
+    let mut app = App::new("myapp3")
+        .external_subcommand_prefix("myapp3-")
+        .subcommand(App::new("build").about("Compile the project"));
+    let mut buf = Vec::new();
+    app.write_help(&mut buf).unwrap();
+    let help = String::from_utf8(buf).unwrap();
+
+    assert!(help.contains("build"), "{}", help);
+    assert!(help.contains("frob"), "{}", help);
+    assert!(help.contains("tidy"), "{}", help);
+}
+
+#[test]
+fn value_detection_option_value() {
+    let res = App::new("tz")
+        .value_detection(|token| token[1..].chars().all(|c| c.is_ascii_digit() || c == ':'))
+        .arg(Arg::new("offset").long("offset").takes_value(true))
+        .try_get_matches_from(vec!["tz", "--offset", "-05:00"]);
+    assert!(res.is_ok(), "Error: {:?}", res.unwrap_err().kind());
+    assert_eq!(res.unwrap().value_of("offset").unwrap(), "-05:00");
+}
+
+#[test]
+fn value_detection_positional() {
+    let res = App::new("range")
+        .value_detection(|token| token.contains(".."))
+        .arg(Arg::new("span"))
+        .try_get_matches_from(vec!["range", "-10..10"]);
+    assert!(res.is_ok(), "Error: {:?}", res.unwrap_err().kind());
+    assert_eq!(res.unwrap().value_of("span").unwrap(), "-10..10");
+}
+
+#[test]
+fn value_detection_rejected_token_is_still_a_flag() {
+    let res = App::new("tz")
+        .value_detection(|token| token[1..].chars().all(|c| c.is_ascii_digit() || c == ':'))
+        .arg(Arg::new("offset").long("offset").takes_value(true))
+        .try_get_matches_from(vec!["tz", "--offset", "-bogus"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::UnknownArgument);
+}
+
+#[test]
+fn value_detection_does_not_shadow_defined_flags() {
+    let res = App::new("tz")
+        .value_detection(|_| true)
+        .arg(Arg::new("verbose").short('v'))
+        .arg(Arg::new("pos"))
+        .try_get_matches_from(vec!["tz", "-v"]);
+    assert!(res.is_ok(), "Error: {:?}", res.unwrap_err().kind());
+    assert!(res.unwrap().is_present("verbose"));
+}
+
+#[test]
+fn allow_hyphen_values_once_takes_only_next_token() {
+    let res = App::new("prog")
+        .arg(
+            Arg::new("expr")
+                .long("expr")
+                .multiple_values(true)
+                .allow_hyphen_values_once(true),
+        )
+        .arg(Arg::new("verbose").short('v'))
+        .try_get_matches_from(vec!["prog", "--expr", "-x+1", "y", "-v"]);
+    assert!(res.is_ok(), "Error: {:?}", res.unwrap_err().kind());
+    let m = res.unwrap();
+    assert_eq!(
+        m.values_of("expr").unwrap().collect::<Vec<_>>(),
+        ["-x+1", "y"]
+    );
+    assert!(m.is_present("verbose"));
+}
+
+#[test]
+fn allow_hyphen_values_once_rejects_later_hyphen_token() {
+    let res = App::new("prog")
+        .arg(
+            Arg::new("expr")
+                .long("expr")
+                .multiple_values(true)
+                .allow_hyphen_values_once(true),
+        )
+        .try_get_matches_from(vec!["prog", "--expr", "-x+1", "-y+2"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::UnknownArgument);
+}
+
+#[test]
+fn allow_hyphen_values_once_resets_per_occurrence() {
+    let res = App::new("prog")
+        .arg(
+            Arg::new("expr")
+                .long("expr")
+                .takes_value(true)
+                .multiple_occurrences(true)
+                .allow_hyphen_values_once(true),
+        )
+        .try_get_matches_from(vec!["prog", "--expr", "-x+1", "--expr", "-y+2"]);
+    assert!(res.is_ok(), "Error: {:?}", res.unwrap_err().kind());
+    assert_eq!(
+        res.unwrap().values_of("expr").unwrap().collect::<Vec<_>>(),
+        ["-x+1", "-y+2"]
+    );
+}
+
+#[test]
+fn stop_at_unknown_returns_remainder() {
+    let res = App::new("timer")
+        .setting(AppSettings::StopAtUnknown)
+        .arg(Arg::new("verbose").short('v'))
+        .try_get_matches_from(vec!["timer", "-v", "make", "-j4", "all"]);
+    assert!(res.is_ok(), "Error: {:?}", res.unwrap_err().kind());
+    let m = res.unwrap();
+    assert!(m.is_present("verbose"));
+    assert_eq!(
+        m.remaining().collect::<Vec<_>>(),
+        [OsStr::new("make"), OsStr::new("-j4"), OsStr::new("all")]
+    );
+}
+
+#[test]
+fn stop_at_unknown_long_flag() {
+    let res = App::new("wrapper")
+        .setting(AppSettings::StopAtUnknown)
+        .arg(Arg::new("opt").long("opt").takes_value(true))
+        .try_get_matches_from(vec!["wrapper", "--opt", "val", "--wrapped-flag", "x"]);
+    assert!(res.is_ok(), "Error: {:?}", res.unwrap_err().kind());
+    let m = res.unwrap();
+    assert_eq!(m.value_of("opt"), Some("val"));
+    assert_eq!(
+        m.remaining().collect::<Vec<_>>(),
+        [OsStr::new("--wrapped-flag"), OsStr::new("x")]
+    );
+}
+
+#[test]
+fn stop_at_unknown_still_validates_parsed_args() {
+    let res = App::new("wrapper")
+        .setting(AppSettings::StopAtUnknown)
+        .arg(Arg::new("input").long("input").takes_value(true).required(true))
+        .try_get_matches_from(vec!["wrapper", "unknown-tail"]);
+    assert!(res.is_err());
+    assert_eq!(
+        res.unwrap_err().kind(),
+        ErrorKind::MissingRequiredArgument
+    );
+}
+
+#[test]
+fn stop_at_unknown_empty_without_unknown_token() {
+    let res = App::new("wrapper")
+        .setting(AppSettings::StopAtUnknown)
+        .arg(Arg::new("verbose").short('v'))
+        .try_get_matches_from(vec!["wrapper", "-v"]);
+    assert!(res.is_ok(), "Error: {:?}", res.unwrap_err().kind());
+    assert_eq!(res.unwrap().remaining().count(), 0);
+}
+
+#[test]
+fn auto_correct_dispatches_to_closest_subcommand() {
+    let m = App::new("myprog")
+        .setting(AppSettings::AutoCorrectSubcommands)
+        .subcommand(App::new("status").arg(Arg::new("short").long("short")))
+        .try_get_matches_from(vec!["myprog", "stauts", "--short"])
+        .unwrap();
+    let (name, sub_m) = m.subcommand().unwrap();
+    assert_eq!(name, "status");
+    assert!(sub_m.is_present("short"));
+}
+
+#[test]
+fn auto_correct_requires_unambiguous_candidate() {
+    let res = App::new("myprog")
+        .setting(AppSettings::AutoCorrectSubcommands)
+        .subcommand(App::new("test"))
+        .subcommand(App::new("tests"))
+        .try_get_matches_from(vec!["myprog", "teste"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::InvalidSubcommand);
+}
+
+#[test]
+fn auto_correct_respects_subcommand_opt_out() {
+    let res = App::new("myprog")
+        .setting(AppSettings::AutoCorrectSubcommands)
+        .subcommand(App::new("destroy").setting(AppSettings::NoAutoCorrect))
+        .try_get_matches_from(vec!["myprog", "destory"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::InvalidSubcommand);
+}
+
+#[test]
+fn auto_correct_off_by_default() {
+    let res = App::new("myprog")
+        .subcommand(App::new("status"))
+        .try_get_matches_from(vec!["myprog", "stauts"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::InvalidSubcommand);
+}
+
+#[test]
+fn infer_long_args_ambiguous_prefix_lists_candidates() {
+    let res = App::new("prog")
+        .setting(AppSettings::InferLongArgs)
+        .arg(Arg::new("config").long("config").takes_value(true))
+        .arg(Arg::new("count").long("count"))
+        .try_get_matches_from(vec!["prog", "--co"]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::AmbiguousPrefix);
+    let out = err.to_string();
+    assert!(out.contains("--config"), "{}", out);
+    assert!(out.contains("--count"), "{}", out);
+}
+
+#[test]
+fn infer_long_args_unique_prefix_still_resolves() {
+    let m = App::new("prog")
+        .setting(AppSettings::InferLongArgs)
+        .arg(Arg::new("config").long("config").takes_value(true))
+        .arg(Arg::new("count").long("count"))
+        .try_get_matches_from(vec!["prog", "--conf", "file.toml"])
+        .unwrap();
+    assert_eq!(m.value_of("config"), Some("file.toml"));
+}
+
+#[test]
+fn no_abbreviation_opts_arg_out_of_inference() {
+    // `--for` no longer resolves, and `--force` does not make `--f` ambiguous
+    let app = || {
+        App::new("prog")
+            .setting(AppSettings::InferLongArgs)
+            .arg(Arg::new("force").long("force").no_abbreviation(true))
+            .arg(Arg::new("file").long("file").takes_value(true))
+    };
+
+    let res = app().try_get_matches_from(vec!["prog", "--for"]);
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::UnknownArgument);
+
+    let m = app().try_get_matches_from(vec!["prog", "--f", "in.txt"]).unwrap();
+    assert_eq!(m.value_of("file"), Some("in.txt"));
+
+    // Spelled out in full it still works
+    let m = app().try_get_matches_from(vec!["prog", "--force"]).unwrap();
+    assert!(m.is_present("force"));
+}
+
+#[test]
+fn slash_flags_match_defined_longs() {
+    let m = App::new("cl")
+        .setting(AppSettings::AllowSlashFlags)
+        .arg(Arg::new("out").long("out").takes_value(true))
+        .arg(Arg::new("verbose").long("verbose"))
+        .try_get_matches_from(vec!["cl", "/verbose", "/out:main.exe"])
+        .unwrap();
+    assert!(m.is_present("verbose"));
+    assert_eq!(m.value_of("out"), Some("main.exe"));
+}
+
+#[test]
+fn slash_flags_accept_equals_separator() {
+    let m = App::new("cl")
+        .setting(AppSettings::AllowSlashFlags)
+        .arg(Arg::new("out").long("out").takes_value(true))
+        .try_get_matches_from(vec!["cl", "/out=main.exe"])
+        .unwrap();
+    assert_eq!(m.value_of("out"), Some("main.exe"));
+}
+
+#[test]
+fn slash_flags_match_single_char_shorts() {
+    let m = App::new("cl")
+        .setting(AppSettings::AllowSlashFlags)
+        .arg(Arg::new("out").short('o').takes_value(true))
+        .try_get_matches_from(vec!["cl", "/o:main.exe"])
+        .unwrap();
+    assert_eq!(m.value_of("out"), Some("main.exe"));
+}
+
+#[test]
+fn slash_flags_trigger_help() {
+    let res = App::new("cl")
+        .setting(AppSettings::AllowSlashFlags)
+        .try_get_matches_from(vec!["cl", "/help"]);
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::DisplayHelp);
+}
+
+#[test]
+fn slash_flags_leave_path_positionals_alone() {
+    let m = App::new("prog")
+        .setting(AppSettings::AllowSlashFlags)
+        .arg(Arg::new("out").long("out").takes_value(true))
+        .arg(Arg::new("input"))
+        .try_get_matches_from(vec!["prog", "/usr/share/dict/words"])
+        .unwrap();
+    assert_eq!(m.value_of("input"), Some("/usr/share/dict/words"));
+    assert!(!m.is_present("out"));
+}
+
+#[test]
+fn slash_flags_are_literal_after_double_dash() {
+    let m = App::new("cl")
+        .setting(AppSettings::AllowSlashFlags)
+        .arg(Arg::new("verbose").long("verbose"))
+        .arg(Arg::new("input").multiple_values(true))
+        .try_get_matches_from(vec!["cl", "--", "/verbose", "x"])
+        .unwrap();
+    assert!(!m.is_present("verbose"));
+    assert_eq!(
+        m.values_of("input").unwrap().collect::<Vec<_>>(),
+        ["/verbose", "x"]
+    );
+}
+
+#[test]
+fn slash_flags_off_by_default() {
+    let m = App::new("prog")
+        .arg(Arg::new("out").long("out").takes_value(true))
+        .arg(Arg::new("input"))
+        .try_get_matches_from(vec!["prog", "/out:main.exe"])
+        .unwrap();
+    assert_eq!(m.value_of("input"), Some("/out:main.exe"));
+    assert!(!m.is_present("out"));
+}
@@ -204,3 +204,83 @@ fn visible_arg_aliases_help_output() {
         false
     ));
 }
+
+#[test]
+fn deprecated_alias_still_parses() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("color")
+                .long("color")
+                .deprecated_alias("colour")
+                .takes_value(true),
+        )
+        .try_get_matches_from(vec!["prog", "--colour", "auto"])
+        .unwrap();
+
+    assert_eq!(m.value_of("color"), Some("auto"));
+}
+
+#[test]
+fn deprecated_alias_warns_through_warning_writer() {
+    use std::sync::{Arc, Mutex};
+
+    let warnings = Arc::new(Mutex::new(Vec::new()));
+    let _ = App::new("prog")
+        .warning_writer(warnings.clone())
+        .arg(
+            Arg::new("color")
+                .long("color")
+                .deprecated_alias("colour")
+                .takes_value(true),
+        )
+        .try_get_matches_from(vec!["prog", "--colour", "auto"])
+        .unwrap();
+
+    let warnings = String::from_utf8(warnings.lock().unwrap().clone()).unwrap();
+    assert_eq!(
+        warnings,
+        "warning: '--colour' is deprecated; use '--color' instead\n"
+    );
+}
+
+#[test]
+fn canonical_spelling_does_not_warn() {
+    use std::sync::{Arc, Mutex};
+
+    let warnings = Arc::new(Mutex::new(Vec::new()));
+    let _ = App::new("prog")
+        .warning_writer(warnings.clone())
+        .arg(
+            Arg::new("color")
+                .long("color")
+                .alias("col")
+                .deprecated_alias("colour")
+                .takes_value(true),
+        )
+        .try_get_matches_from(vec!["prog", "--color", "auto"])
+        .unwrap();
+
+    assert!(warnings.lock().unwrap().is_empty());
+}
+
+#[test]
+fn deprecated_alias_warns_in_subcommands() {
+    use std::sync::{Arc, Mutex};
+
+    let warnings = Arc::new(Mutex::new(Vec::new()));
+    let _ = App::new("prog")
+        .warning_writer(warnings.clone())
+        .subcommand(
+            App::new("run").arg(
+                Arg::new("color")
+                    .long("color")
+                    .deprecated_alias("colour")
+                    .takes_value(true),
+            ),
+        )
+        .try_get_matches_from(vec!["prog", "run", "--colour", "auto"])
+        .unwrap();
+
+    let warnings = String::from_utf8(warnings.lock().unwrap().clone()).unwrap();
+    assert!(warnings.contains("'--colour' is deprecated"), "{}", warnings);
+}
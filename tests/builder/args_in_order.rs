@@ -0,0 +1,67 @@
+use clap::{App, Arg};
+
+#[test]
+fn flags_and_options_interleave_in_order() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("name")
+                .short('n')
+                .takes_value(true)
+                .multiple_occurrences(true),
+        )
+        .arg(Arg::new("print").short('p').multiple_occurrences(true))
+        .try_get_matches_from(vec!["prog", "-n", "a", "-p", "-n", "b", "-p"])
+        .unwrap();
+
+    let order: Vec<_> = m.args_in_order().collect();
+    assert_eq!(
+        order,
+        [
+            ("name", Some("a"), 2),
+            ("print", None, 3),
+            ("name", Some("b"), 5),
+            ("print", None, 6),
+        ]
+    );
+}
+
+#[test]
+fn positionals_interleave_with_flags() {
+    let m = App::new("prog")
+        .arg(Arg::new("path").multiple_values(true))
+        .arg(Arg::new("follow").short('L').multiple_occurrences(true))
+        .try_get_matches_from(vec!["prog", "-L", "one", "two"])
+        .unwrap();
+
+    let order: Vec<_> = m.args_in_order().collect();
+    assert_eq!(
+        order,
+        [
+            ("follow", None, 1),
+            ("path", Some("one"), 2),
+            ("path", Some("two"), 3),
+        ]
+    );
+}
+
+#[test]
+fn default_values_are_excluded() {
+    let m = App::new("prog")
+        .arg(Arg::new("opt").long("opt").default_value("def"))
+        .arg(Arg::new("flag").short('f'))
+        .try_get_matches_from(vec!["prog", "-f"])
+        .unwrap();
+
+    let order: Vec<_> = m.args_in_order().collect();
+    assert_eq!(order, [("flag", None, 1)]);
+}
+
+#[test]
+fn empty_matches_yield_nothing() {
+    let m = App::new("prog")
+        .arg(Arg::new("flag").short('f'))
+        .try_get_matches_from(vec!["prog"])
+        .unwrap();
+
+    assert_eq!(m.args_in_order().len(), 0);
+}
@@ -0,0 +1,50 @@
+use clap::{App, Arg, ErrorKind};
+
+#[test]
+fn canonical_path_stored_alongside_original() {
+    let m = App::new("prog")
+        .arg(Arg::new("config").long("config").canonicalize(true))
+        .try_get_matches_from(&["prog", "--config", "Cargo.toml"])
+        .unwrap();
+    assert_eq!(m.value_of("config"), Some("Cargo.toml"));
+    let canonical = m.canonical_value_of("config").unwrap();
+    assert!(canonical.is_absolute());
+    assert!(canonical.ends_with("Cargo.toml"));
+}
+
+#[test]
+fn canonicalize_multiple_values() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("file")
+                .long("file")
+                .multiple_occurrences(true)
+                .canonicalize(true),
+        )
+        .try_get_matches_from(&["prog", "--file", "Cargo.toml", "--file", "README.md"])
+        .unwrap();
+    let canonical = m.canonical_values_of("file").unwrap();
+    assert_eq!(canonical.len(), 2);
+    assert!(canonical.iter().all(|p| p.is_absolute()));
+}
+
+#[test]
+fn canonicalize_missing_path_errors() {
+    let res = App::new("prog")
+        .arg(Arg::new("config").long("config").canonicalize(true))
+        .try_get_matches_from(&["prog", "--config", "does-not-exist.toml"]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ValueValidation);
+    assert!(err.to_string().contains("does-not-exist.toml"), "{}", err);
+    assert!(err.to_string().contains("--config"), "{}", err);
+}
+
+#[test]
+fn canonical_value_absent_without_setting() {
+    let m = App::new("prog")
+        .arg(Arg::new("config").long("config").takes_value(true))
+        .try_get_matches_from(&["prog", "--config", "Cargo.toml"])
+        .unwrap();
+    assert_eq!(m.canonical_value_of("config"), None);
+}
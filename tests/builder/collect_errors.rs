@@ -0,0 +1,125 @@
+use clap::{App, AppSettings, Arg, ErrorKind};
+
+fn app() -> App<'static> {
+    App::new("prog")
+        .setting(AppSettings::CollectAllErrors)
+        .arg(Arg::new("input").required(true))
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .takes_value(true)
+                .possible_values(["json", "yaml"]),
+        )
+}
+
+#[test]
+fn unknown_and_missing_required_are_aggregated() {
+    let err = app()
+        .try_get_matches_from(["prog", "--bogus"])
+        .unwrap_err();
+
+    assert_eq!(err.kind(), ErrorKind::MultipleErrors);
+    let rendered = err.to_string();
+    assert!(rendered.contains("--bogus"), "{}", rendered);
+    assert!(rendered.contains("<input>"), "{}", rendered);
+}
+
+#[test]
+fn every_unknown_argument_is_listed() {
+    let err = app()
+        .try_get_matches_from(["prog", "in", "--bogus", "--wrong"])
+        .unwrap_err();
+
+    assert_eq!(err.kind(), ErrorKind::MultipleErrors);
+    let rendered = err.to_string();
+    assert!(rendered.contains("--bogus"), "{}", rendered);
+    assert!(rendered.contains("--wrong"), "{}", rendered);
+}
+
+#[test]
+fn invalid_values_are_collected_too() {
+    let err = app()
+        .try_get_matches_from(["prog", "in", "--bogus", "--format", "toml"])
+        .unwrap_err();
+
+    assert_eq!(err.kind(), ErrorKind::MultipleErrors);
+    let rendered = err.to_string();
+    assert!(rendered.contains("--bogus"), "{}", rendered);
+    assert!(rendered.contains("toml"), "{}", rendered);
+}
+
+#[test]
+fn usage_is_rendered_once() {
+    let err = app()
+        .try_get_matches_from(["prog", "--bogus", "--wrong"])
+        .unwrap_err();
+
+    let rendered = err.to_string();
+    assert_eq!(rendered.matches("USAGE:").count(), 1, "{}", rendered);
+}
+
+#[test]
+fn single_problem_keeps_its_own_kind() {
+    let err = app().try_get_matches_from(["prog"]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+}
+
+#[test]
+fn missing_subcommand_is_aggregated() {
+    let err = App::new("prog")
+        .setting(AppSettings::CollectAllErrors)
+        .setting(AppSettings::SubcommandRequired)
+        .subcommand(App::new("run"))
+        .try_get_matches_from(["prog", "--bogus"])
+        .unwrap_err();
+
+    assert_eq!(err.kind(), ErrorKind::MultipleErrors);
+    let rendered = err.to_string();
+    assert!(rendered.contains("--bogus"), "{}", rendered);
+    assert!(rendered.contains("requires a subcommand"), "{}", rendered);
+}
+
+#[test]
+fn collected_errors_win_over_arg_required_else_help() {
+    let err = App::new("prog")
+        .setting(AppSettings::CollectAllErrors)
+        .setting(AppSettings::ArgRequiredElseHelp)
+        .arg(Arg::new("input"))
+        .try_get_matches_from(["prog", "--bogus"])
+        .unwrap_err();
+
+    assert_eq!(err.kind(), ErrorKind::UnknownArgument);
+    assert!(err.to_string().contains("--bogus"), "{}", err);
+}
+
+#[test]
+fn collected_errors_win_over_subcommand_required_else_help() {
+    let err = App::new("prog")
+        .setting(AppSettings::CollectAllErrors)
+        .setting(AppSettings::SubcommandRequiredElseHelp)
+        .subcommand(App::new("run"))
+        .try_get_matches_from(["prog", "--bogus"])
+        .unwrap_err();
+
+    assert_eq!(err.kind(), ErrorKind::UnknownArgument);
+    assert!(err.to_string().contains("--bogus"), "{}", err);
+}
+
+#[test]
+fn context_of_each_problem_is_preserved() {
+    let err = app()
+        .try_get_matches_from(["prog", "in", "--bogus", "--wrong"])
+        .unwrap_err();
+
+    let invalid: Vec<_> = err
+        .context()
+        .filter_map(|(kind, value)| match (kind, value) {
+            (clap::error::ContextKind::InvalidArg, clap::error::ContextValue::String(arg)) => {
+                Some(arg.clone())
+            }
+            _ => None,
+        })
+        .collect();
+    assert!(invalid.contains(&"--bogus".to_string()), "{:?}", invalid);
+    assert!(invalid.contains(&"--wrong".to_string()), "{:?}", invalid);
+}
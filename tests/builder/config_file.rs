@@ -0,0 +1,136 @@
+#![cfg(feature = "yaml")]
+
+use std::path::PathBuf;
+
+use clap::{App, Arg, ErrorKind, ValueSource};
+
+fn write_config(name: &str, contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("clap_config_{}_{}", std::process::id(), name));
+    std::fs::write(&path, contents).unwrap();
+    path
+}
+
+fn app() -> App<'static> {
+    App::new("prog")
+        .arg(Arg::new("port").long("port").takes_value(true))
+        .arg(Arg::new("verbose").long("verbose"))
+        .arg(
+            Arg::new("file")
+                .long("file")
+                .takes_value(true)
+                .multiple_occurrences(true),
+        )
+}
+
+#[test]
+fn config_values_layer_beneath_command_line() {
+    let path = write_config("layer.yaml", "port: 8080\nverbose: true\n");
+
+    let m = app()
+        .config_file(&path)
+        .try_get_matches_from_with_config(["prog"])
+        .unwrap();
+    assert_eq!(m.value_of("port"), Some("8080"));
+    assert!(m.is_present("verbose"));
+    assert_eq!(m.value_source("port"), Some(ValueSource::ConfigFile));
+
+    let m = app()
+        .config_file(&path)
+        .try_get_matches_from_with_config(["prog", "--port", "9000"])
+        .unwrap();
+    assert_eq!(m.value_of("port"), Some("9000"));
+    assert_eq!(m.value_source("port"), Some(ValueSource::CommandLine));
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn config_lists_become_multiple_values() {
+    let path = write_config("list.yml", "file:\n  - a.txt\n  - b.txt\n");
+
+    let m = app()
+        .config_file(&path)
+        .try_get_matches_from_with_config(["prog"])
+        .unwrap();
+    let files: Vec<_> = m.values_of("file").unwrap().collect();
+    assert_eq!(files, ["a.txt", "b.txt"]);
+    assert_eq!(m.value_source("file"), Some(ValueSource::ConfigFile));
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn config_flags_take_booleans() {
+    let path = write_config("flags.yaml", "port: \"8080\"\nverbose: false\n");
+
+    let m = app()
+        .config_file(&path)
+        .try_get_matches_from_with_config(["prog"])
+        .unwrap();
+    assert_eq!(m.value_of("port"), Some("8080"));
+    assert!(!m.is_present("verbose"));
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn config_sits_above_defaults() {
+    let path = write_config("defaults.yaml", "port: 8080\n");
+
+    let m = App::new("prog")
+        .arg(
+            Arg::new("port")
+                .long("port")
+                .takes_value(true)
+                .default_value("80"),
+        )
+        .config_file(&path)
+        .try_get_matches_from_with_config(["prog"])
+        .unwrap();
+    assert_eq!(m.value_of("port"), Some("8080"));
+    assert_eq!(m.value_source("port"), Some(ValueSource::ConfigFile));
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn unknown_config_key_errors() {
+    let path = write_config("unknown.yaml", "prot: 8080\n");
+
+    let err = app()
+        .config_file(&path)
+        .try_get_matches_from_with_config(["prog"])
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnknownArgument);
+    assert!(err.to_string().contains("prot"), "{}", err);
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn missing_or_malformed_config_errors() {
+    let err = app()
+        .config_file("does-not-exist.yaml")
+        .try_get_matches_from_with_config(["prog"])
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Io);
+    assert!(err.to_string().contains("does-not-exist.yaml"), "{}", err);
+
+    let path = write_config("bad.yaml", "server:\n  port: 1\n");
+    let err = app()
+        .config_file(&path)
+        .try_get_matches_from_with_config(["prog"])
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::Io);
+    assert!(err.to_string().contains("nested"), "{}", err);
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn get_matches_without_config_never_reads_the_file() {
+    let m = app()
+        .config_file("does-not-exist.yaml")
+        .try_get_matches_from(["prog"])
+        .unwrap();
+    assert_eq!(m.value_of("port"), None);
+}
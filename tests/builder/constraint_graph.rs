@@ -0,0 +1,92 @@
+use clap::{App, Arg, ArgGroup, ConstraintKind, ConstraintNode};
+
+fn build_app() -> App<'static> {
+    App::new("graph")
+        .arg(Arg::new("input"))
+        .arg(
+            Arg::new("output")
+                .requires("input")
+                .conflicts_with("stdout"),
+        )
+        .arg(Arg::new("stdout"))
+        .arg(
+            Arg::new("format")
+                .takes_value(true)
+                .requires_if("json", "schema"),
+        )
+        .arg(Arg::new("schema").required_unless_present("format"))
+        .group(ArgGroup::new("dest").args(&["output", "stdout"]))
+}
+
+#[test]
+fn constraint_graph_edges_are_queryable() {
+    let graph = build_app().constraint_graph();
+
+    let output_edges: Vec<_> = graph.edges_from("output").collect();
+    assert_eq!(output_edges.len(), 2);
+    assert!(output_edges.iter().any(|e| {
+        e.target() == ConstraintNode::Arg("input") && e.kind() == ConstraintKind::Requires
+    }));
+    assert!(output_edges.iter().any(|e| {
+        e.target() == ConstraintNode::Arg("stdout") && e.kind() == ConstraintKind::Conflicts
+    }));
+
+    let format_edge = graph.edges_from("format").next().unwrap();
+    assert_eq!(format_edge.target(), ConstraintNode::Arg("schema"));
+    assert_eq!(
+        format_edge.kind(),
+        ConstraintKind::RequiresIfValue(std::ffi::OsStr::new("json"))
+    );
+
+    let schema_edge = graph.edges_from("schema").next().unwrap();
+    assert_eq!(schema_edge.target(), ConstraintNode::Arg("format"));
+    assert_eq!(schema_edge.kind(), ConstraintKind::RequiredUnlessPresent);
+}
+
+#[test]
+fn constraint_graph_includes_groups() {
+    let graph = build_app().constraint_graph();
+
+    let members: Vec<_> = graph
+        .edges_from("dest")
+        .filter(|e| e.kind() == ConstraintKind::GroupMember)
+        .map(|e| e.target())
+        .collect();
+    assert_eq!(
+        members,
+        [ConstraintNode::Arg("output"), ConstraintNode::Arg("stdout")]
+    );
+    assert!(graph.nodes().contains(&ConstraintNode::Group("dest")));
+
+    let pointing_at_stdout: Vec<_> = graph.edges_to("stdout").map(|e| e.source()).collect();
+    assert_eq!(
+        pointing_at_stdout,
+        [ConstraintNode::Arg("output"), ConstraintNode::Group("dest")]
+    );
+}
+
+#[test]
+fn constraint_graph_unconstrained_args_are_absent() {
+    let graph = App::new("graph")
+        .arg(Arg::new("lone"))
+        .arg(Arg::new("output").requires("input"))
+        .arg(Arg::new("input"))
+        .constraint_graph();
+
+    assert!(!graph.nodes().contains(&ConstraintNode::Arg("lone")));
+    assert_eq!(graph.edges_from("lone").count(), 0);
+}
+
+#[test]
+fn constraint_graph_dot_export() {
+    let dot = build_app().constraint_graph().to_dot();
+
+    assert!(dot.starts_with("digraph \"graph\" {\n"));
+    assert!(dot.ends_with("}\n"));
+    assert!(dot.contains("\"output\" [shape=ellipse];"));
+    assert!(dot.contains("\"dest\" [shape=box];"));
+    assert!(dot.contains("\"output\" -> \"input\" [label=\"requires\", style=solid];"));
+    assert!(dot.contains("\"output\" -> \"stdout\" [label=\"conflicts\", style=dashed];"));
+    assert!(dot.contains("\"format\" -> \"schema\" [label=\"requires if =json\", style=solid];"));
+    assert!(dot.contains("\"dest\" -> \"output\" [label=\"member\", style=dotted];"));
+}
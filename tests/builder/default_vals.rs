@@ -697,3 +697,119 @@ fn missing_with_value_delimiter() {
         ["value1", "value2", "value3", "value4", "value5"]
     );
 }
+
+#[test]
+fn value_source_distinguishes_default_from_command_line() {
+    let app = || {
+        App::new("prog").arg(
+            Arg::new("port")
+                .long("port")
+                .takes_value(true)
+                .default_value("80"),
+        )
+    };
+
+    let m = app().try_get_matches_from(vec!["prog"]).unwrap();
+    assert_eq!(
+        m.value_source("port"),
+        Some(clap::ValueSource::DefaultValue)
+    );
+
+    let m = app()
+        .try_get_matches_from(vec!["prog", "--port", "8080"])
+        .unwrap();
+    assert_eq!(m.value_source("port"), Some(clap::ValueSource::CommandLine));
+
+    let m = App::new("prog")
+        .arg(Arg::new("port").long("port").takes_value(true))
+        .try_get_matches_from(vec!["prog"])
+        .unwrap();
+    assert_eq!(m.value_source("port"), None);
+}
+
+#[test]
+fn default_value_fn_computed_from_other_arg() {
+    let m = App::new("prog")
+        .arg(Arg::new("input").required(true))
+        .arg(Arg::new("output").long("output").default_value_fn(|m| {
+            m.value_of("input")
+                .map(|input| format!("{}.out", input).into())
+        }))
+        .try_get_matches_from(vec!["prog", "in.txt"])
+        .unwrap();
+
+    assert_eq!(m.value_of("output"), Some("in.txt.out"));
+    assert_eq!(
+        m.value_source("output"),
+        Some(clap::ValueSource::DefaultValue)
+    );
+}
+
+#[test]
+fn default_value_fn_not_used_when_value_provided() {
+    let m = App::new("prog")
+        .arg(Arg::new("input").required(true))
+        .arg(Arg::new("output").long("output").default_value_fn(|m| {
+            m.value_of("input")
+                .map(|input| format!("{}.out", input).into())
+        }))
+        .try_get_matches_from(vec!["prog", "in.txt", "--output", "custom.txt"])
+        .unwrap();
+
+    assert_eq!(m.value_of("output"), Some("custom.txt"));
+    assert_eq!(
+        m.value_source("output"),
+        Some(clap::ValueSource::CommandLine)
+    );
+}
+
+#[test]
+fn default_value_fn_none_falls_back_to_default_value() {
+    let m = App::new("prog")
+        .arg(Arg::new("verbose").long("verbose"))
+        .arg(
+            Arg::new("level")
+                .long("level")
+                .default_value("warn")
+                .default_value_fn(|m| m.is_present("verbose").then(|| "debug".into())),
+        )
+        .try_get_matches_from(vec!["prog"])
+        .unwrap();
+
+    assert_eq!(m.value_of("level"), Some("warn"));
+
+    let m = App::new("prog")
+        .arg(Arg::new("verbose").long("verbose"))
+        .arg(
+            Arg::new("level")
+                .long("level")
+                .default_value("warn")
+                .default_value_fn(|m| m.is_present("verbose").then(|| "debug".into())),
+        )
+        .try_get_matches_from(vec!["prog", "--verbose"])
+        .unwrap();
+
+    assert_eq!(m.value_of("level"), Some("debug"));
+}
+
+#[test]
+fn default_value_fn_depends_on_multiple_args() {
+    let app = || {
+        App::new("prog")
+            .arg(Arg::new("host").long("host").takes_value(true))
+            .arg(Arg::new("port").long("port").takes_value(true))
+            .arg(Arg::new("url").long("url").default_value_fn(|m| {
+                let host = m.value_of("host")?;
+                let port = m.value_of("port")?;
+                Some(format!("http://{}:{}", host, port).into())
+            }))
+    };
+
+    let m = app()
+        .try_get_matches_from(vec!["prog", "--host", "localhost", "--port", "80"])
+        .unwrap();
+    assert_eq!(m.value_of("url"), Some("http://localhost:80"));
+
+    let m = app().try_get_matches_from(vec!["prog"]).unwrap();
+    assert_eq!(m.value_of("url"), None);
+}
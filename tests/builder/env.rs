@@ -350,3 +350,190 @@ fn validator_invalid() {
 
     assert!(r.is_err());
 }
+
+#[test]
+fn value_source_is_env_variable() {
+    env::set_var("CLP_TEST_ENV_SOURCE", "env");
+
+    let m = App::new("df")
+        .arg(
+            arg!([arg] "some opt")
+                .env("CLP_TEST_ENV_SOURCE")
+                .takes_value(true),
+        )
+        .try_get_matches_from(vec![""])
+        .unwrap();
+
+    assert_eq!(m.value_source("arg"), Some(clap::ValueSource::EnvVariable));
+}
+
+#[test]
+fn env_prefix_derives_fallbacks() {
+    env::set_var("CLP_PFX_ARG", "env");
+
+    let r = App::new("df")
+        .env_prefix("CLP_PFX")
+        .arg(arg!([arg] "some opt").takes_value(true))
+        .try_get_matches_from(vec![""]);
+
+    assert!(r.is_ok(), "{}", r.unwrap_err());
+    let m = r.unwrap();
+    assert!(m.is_present("arg"));
+    assert_eq!(m.occurrences_of("arg"), 0);
+    assert_eq!(m.value_of("arg").unwrap(), "env");
+}
+
+#[test]
+fn env_prefix_maps_hyphens_to_underscores() {
+    env::set_var("CLP_PFX_HYPHEN_LOG_LEVEL", "debug");
+
+    let r = App::new("df")
+        .env_prefix("CLP_PFX_HYPHEN")
+        .arg(Arg::new("log-level").long("log-level").takes_value(true))
+        .try_get_matches_from(vec![""]);
+
+    assert!(r.is_ok(), "{}", r.unwrap_err());
+    let m = r.unwrap();
+    assert_eq!(m.value_of("log-level").unwrap(), "debug");
+}
+
+#[test]
+fn env_prefix_scopes_through_subcommands() {
+    env::set_var("CLP_PFX_SC_SERVE_PORT", "8080");
+
+    let r = App::new("df")
+        .env_prefix("CLP_PFX_SC")
+        .subcommand(App::new("serve").arg(Arg::new("port").long("port").takes_value(true)))
+        .try_get_matches_from(vec!["df", "serve"]);
+
+    assert!(r.is_ok(), "{}", r.unwrap_err());
+    let m = r.unwrap();
+    let sub = m.subcommand_matches("serve").unwrap();
+    assert_eq!(sub.value_of("port").unwrap(), "8080");
+}
+
+#[test]
+fn env_prefix_loses_to_explicit_env() {
+    env::set_var("CLP_PFX_EXPLICIT_ARG", "derived");
+    env::set_var("CLP_PFX_EXPLICIT_OTHER", "explicit");
+
+    let r = App::new("df")
+        .env_prefix("CLP_PFX_EXPLICIT")
+        .arg(
+            arg!([arg] "some opt")
+                .env("CLP_PFX_EXPLICIT_OTHER")
+                .takes_value(true),
+        )
+        .try_get_matches_from(vec![""]);
+
+    assert!(r.is_ok(), "{}", r.unwrap_err());
+    let m = r.unwrap();
+    assert_eq!(m.value_of("arg").unwrap(), "explicit");
+}
+
+#[test]
+fn env_prefix_loses_to_command_line() {
+    env::set_var("CLP_PFX_CLI_ARG", "env");
+
+    let r = App::new("df")
+        .env_prefix("CLP_PFX_CLI")
+        .arg(arg!([arg] "some opt").takes_value(true))
+        .try_get_matches_from(vec!["", "cli"]);
+
+    assert!(r.is_ok(), "{}", r.unwrap_err());
+    let m = r.unwrap();
+    assert_eq!(m.occurrences_of("arg"), 1);
+    assert_eq!(m.value_of("arg").unwrap(), "cli");
+}
+
+fn write_env_file(name: &str, contents: &str) -> std::path::PathBuf {
+    let path = env::temp_dir().join(format!("clap_env_{}_{}", std::process::id(), name));
+    std::fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn env_file_provides_value() {
+    let path = write_env_file("provides", "# comment\n\nCLP_EF_PORT = 9000\n");
+
+    let m = App::new("myapp")
+        .env_file_arg("env-file")
+        .arg(
+            Arg::new("port")
+                .long("port")
+                .env("CLP_EF_PORT")
+                .takes_value(true),
+        )
+        .try_get_matches_from(vec!["myapp", "--env-file", path.to_str().unwrap()])
+        .unwrap();
+    assert_eq!(m.value_of("port"), Some("9000"));
+}
+
+#[test]
+fn env_file_overrides_process_env() {
+    env::set_var("CLP_EF_OVERRIDE", "from-env");
+    let path = write_env_file("overrides", "CLP_EF_OVERRIDE=from-file\n");
+
+    let m = App::new("myapp")
+        .env_file_arg("env-file")
+        .arg(
+            Arg::new("opt")
+                .long("opt")
+                .env("CLP_EF_OVERRIDE")
+                .takes_value(true),
+        )
+        .try_get_matches_from(vec!["myapp", "--env-file", path.to_str().unwrap()])
+        .unwrap();
+    assert_eq!(m.value_of("opt"), Some("from-file"));
+}
+
+#[test]
+fn env_file_loses_to_command_line() {
+    let path = write_env_file("cli_wins", "CLP_EF_CLI=from-file\n");
+
+    let m = App::new("myapp")
+        .env_file_arg("env-file")
+        .arg(
+            Arg::new("opt")
+                .long("opt")
+                .env("CLP_EF_CLI")
+                .takes_value(true),
+        )
+        .try_get_matches_from(vec![
+            "myapp",
+            "--env-file",
+            path.to_str().unwrap(),
+            "--opt",
+            "from-cli",
+        ])
+        .unwrap();
+    assert_eq!(m.value_of("opt"), Some("from-cli"));
+}
+
+#[test]
+fn env_file_feeds_env_prefix_lookups() {
+    let path = write_env_file("prefix", "CLPEF_PORT=7777\n");
+
+    let m = App::new("myapp")
+        .env_prefix("CLPEF")
+        .env_file_arg("env-file")
+        .arg(Arg::new("port").long("port").takes_value(true))
+        .try_get_matches_from(vec!["myapp", "--env-file", path.to_str().unwrap()])
+        .unwrap();
+    assert_eq!(m.value_of("port"), Some("7777"));
+}
+
+#[test]
+fn unreadable_env_file_is_an_io_error() {
+    let res = App::new("myapp")
+        .env_file_arg("env-file")
+        .arg(
+            Arg::new("opt")
+                .long("opt")
+                .env("CLP_EF_MISSING")
+                .takes_value(true),
+        )
+        .try_get_matches_from(vec!["myapp", "--env-file", "/no/such/file.env"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), clap::ErrorKind::Io);
+}
@@ -1,6 +1,7 @@
 use crate::utils;
 
-use clap::{arg, error::ErrorKind, App, Arg, Error};
+use clap::{arg, error::ErrorKind, App, AppSettings, Arg, Error};
+use clap::error::{ContextKind, ContextValue};
 
 fn compare_error(
     err: Error,
@@ -93,3 +94,388 @@ fn argument_not_found_auto_has_newline() {
         err.to_string()
     );
 }
+
+#[test]
+fn disable_usage_in_errors_keeps_try_help() {
+    let err = App::new("test")
+        .disable_usage_in_errors(true)
+        .arg(arg!(--flag "some flag"))
+        .try_get_matches_from(["test", "--unknown"])
+        .unwrap_err();
+
+    let rendered = err.to_string();
+    assert!(!rendered.contains("USAGE:"), "{}", rendered);
+    assert!(
+        rendered.contains("For more information try --help"),
+        "{}",
+        rendered
+    );
+}
+
+#[test]
+fn disable_usage_for_error_is_per_kind() {
+    let app = || {
+        App::new("test")
+            .disable_usage_for_error(ErrorKind::ValueValidation)
+            .arg(
+                arg!([PORT])
+                    .help("Network port to use")
+                    .validator(|s| s.parse::<u16>().map(|_| ())),
+            )
+    };
+
+    let err = app()
+        .try_get_matches_from(["test", "not-a-port"])
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ValueValidation);
+    assert!(!err.to_string().contains("USAGE:"), "{}", err);
+
+    // Other kinds keep their usage block
+    let err = app().try_get_matches_from(["test", "--bad"]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnknownArgument);
+    assert!(err.to_string().contains("USAGE:"), "{}", err);
+}
+
+#[test]
+fn render_json_carries_kind_and_context() {
+    let err = App::new("test")
+        .arg(arg!(--flag "some flag"))
+        .try_get_matches_from(["test", "--unknown"])
+        .unwrap_err();
+
+    let json = err.render_json();
+    assert!(json.contains("\"kind\": \"UnknownArgument\""), "{}", json);
+    assert!(
+        json.contains("\"description\": \"Found an argument which wasn't expected"),
+        "{}",
+        json
+    );
+    assert!(json.contains("\"InvalidArg\": \"--unknown\""), "{}", json);
+    assert!(json.contains("\"Usage\": \"USAGE:"), "{}", json);
+    // Usage is carried as data, not pre-rendered terminal output
+    assert!(!json.contains('\u{1b}'), "{}", json);
+}
+
+#[test]
+fn render_json_carries_suggestions() {
+    let err = App::new("test")
+        .arg(arg!(--flag "some flag"))
+        .try_get_matches_from(["test", "--flga"])
+        .unwrap_err();
+
+    let json = err.render_json();
+    assert!(json.contains("\"SuggestedArg\": \"--flag\""), "{}", json);
+}
+
+#[test]
+fn render_json_carries_raw_message() {
+    let err = Error::raw(ErrorKind::InvalidValue, "the \"value\" is bad");
+
+    let json = err.render_json();
+    assert!(json.contains("\"kind\": \"InvalidValue\""), "{}", json);
+    assert!(
+        json.contains("\"message\": \"the \\\"value\\\" is bad\""),
+        "{}",
+        json
+    );
+}
+
+#[test]
+fn custom_error_formatter_controls_rendering() {
+    use clap::error::{ContextKind, ContextValue, ErrorFormatter};
+
+    struct OneLine;
+
+    impl ErrorFormatter for OneLine {
+        fn format_error(error: &clap::Error) -> String {
+            let arg = error
+                .context()
+                .find_map(|(kind, value)| match (kind, value) {
+                    (ContextKind::InvalidArg, ContextValue::String(arg)) => Some(arg.as_str()),
+                    _ => None,
+                })
+                .unwrap_or("?");
+            format!("{:?} {}\n", error.kind(), arg)
+        }
+    }
+
+    let app = || {
+        App::new("test")
+            .error_formatter::<OneLine>()
+            .arg(arg!(--flag "some flag"))
+            .subcommand(App::new("sub"))
+    };
+
+    let err = app()
+        .try_get_matches_from(["test", "--unknown"])
+        .unwrap_err();
+    assert_eq!(err.to_string(), "UnknownArgument --unknown\n");
+
+    // The formatter is propagated to subcommands
+    let err = app()
+        .try_get_matches_from(["test", "sub", "--unknown"])
+        .unwrap_err();
+    assert_eq!(err.to_string(), "UnknownArgument --unknown\n");
+
+    // Help keeps the default rendering
+    let err = app().try_get_matches_from(["test", "--help"]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::DisplayHelp);
+    assert!(err.to_string().contains("USAGE:"), "{}", err);
+}
+
+#[test]
+fn custom_errors_render_like_internal_ones() {
+    let err = Error::new(ErrorKind::UnknownArgument)
+        .insert_context(
+            ContextKind::InvalidArg,
+            ContextValue::String("--unknwon".to_string()),
+        )
+        .insert_context(
+            ContextKind::SuggestedArg,
+            ContextValue::String("--unknown".to_string()),
+        )
+        .insert_context(
+            ContextKind::Usage,
+            ContextValue::String("USAGE:\n    prog [OPTIONS]".to_string()),
+        );
+
+    let rendered = err.to_string();
+    assert!(
+        rendered.contains("Found argument '--unknwon' which wasn't expected"),
+        "{}",
+        rendered
+    );
+    assert!(rendered.contains("Did you mean '--unknown'?"), "{}", rendered);
+    assert!(rendered.contains("USAGE:\n    prog [OPTIONS]"), "{}", rendered);
+}
+
+#[test]
+fn insert_context_replaces_existing_value() {
+    let err = Error::new(ErrorKind::UnknownArgument)
+        .insert_context(
+            ContextKind::InvalidArg,
+            ContextValue::String("--first".to_string()),
+        )
+        .insert_context(
+            ContextKind::InvalidArg,
+            ContextValue::String("--second".to_string()),
+        );
+
+    let invalid: Vec<_> = err
+        .context()
+        .filter(|(kind, _)| *kind == ContextKind::InvalidArg)
+        .collect();
+    assert_eq!(invalid.len(), 1);
+    assert!(err.to_string().contains("--second"), "{}", err);
+}
+
+#[cfg(feature = "suggestions")]
+#[test]
+fn suggestion_threshold_tunes_did_you_mean() {
+    let err = App::new("prog")
+        .arg(Arg::new("config").long("config"))
+        .try_get_matches_from(["prog", "--confg"])
+        .unwrap_err();
+    assert!(err.to_string().contains("Did you mean '--config'?"), "{}", err);
+
+    let err = App::new("prog")
+        .suggestion_threshold(0.99)
+        .arg(Arg::new("config").long("config"))
+        .try_get_matches_from(["prog", "--confg"])
+        .unwrap_err();
+    assert!(!err.to_string().contains("Did you mean"), "{}", err);
+}
+
+#[cfg(feature = "suggestions")]
+#[test]
+fn suggestion_words_cover_hidden_aliases() {
+    let err = App::new("prog")
+        .arg(Arg::new("color").long("color").alias("colour"))
+        .suggestion_words(["colour"])
+        .try_get_matches_from(["prog", "--colourr"])
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("Did you mean '--colour'?"),
+        "{}",
+        err
+    );
+}
+
+#[cfg(feature = "suggestions")]
+#[test]
+fn suggestion_words_cover_subcommand_like_tokens() {
+    let err = App::new("prog")
+        .subcommand(App::new("install").alias("add"))
+        .suggestion_words(["remove"])
+        .try_get_matches_from(["prog", "remvoe"])
+        .unwrap_err();
+    assert!(err.to_string().contains("remove"), "{}", err);
+}
+
+#[test]
+fn exit_code_for_parse_errors_is_usage_failure() {
+    let err = App::new("prog")
+        .arg(Arg::new("opt").long("opt"))
+        .try_get_matches_from(["prog", "--unknown"])
+        .unwrap_err();
+    assert_eq!(err.exit_code(), 2);
+}
+
+#[test]
+fn exit_code_for_help_and_version_is_success() {
+    let err = App::new("prog")
+        .version("1.0")
+        .try_get_matches_from(["prog", "--help"])
+        .unwrap_err();
+    assert_eq!(err.exit_code(), 0);
+
+    let err = App::new("prog")
+        .version("1.0")
+        .try_get_matches_from(["prog", "--version"])
+        .unwrap_err();
+    assert_eq!(err.exit_code(), 0);
+}
+
+#[test]
+fn print_to_routes_the_formatted_error() {
+    let err = App::new("prog")
+        .arg(Arg::new("opt").long("opt"))
+        .try_get_matches_from(["prog", "--unknown"])
+        .unwrap_err();
+
+    let mut buf = Vec::new();
+    err.print_to(&mut buf).unwrap();
+    let out = String::from_utf8(buf).unwrap();
+    assert!(out.contains("--unknown"), "{}", out);
+    assert_eq!(out, err.to_string());
+}
+
+#[test]
+fn error_records_command_line_span() {
+    let err = App::new("prog")
+        .setting(AppSettings::EchoCommandLine)
+        .arg(Arg::new("opt").long("opt"))
+        .arg(Arg::new("pos"))
+        .try_get_matches_from(["prog", "val", "--unknown"])
+        .unwrap_err();
+
+    let line: Vec<_> = err
+        .context()
+        .filter(|(kind, _)| *kind == ContextKind::CommandLine)
+        .collect();
+    assert_eq!(
+        line,
+        vec![(
+            ContextKind::CommandLine,
+            &ContextValue::String("prog val --unknown".to_string())
+        )]
+    );
+    let span: Vec<_> = err
+        .context()
+        .filter(|(kind, _)| *kind == ContextKind::Span)
+        .collect();
+    assert_eq!(span, vec![(ContextKind::Span, &ContextValue::Range(9..18))]);
+}
+
+#[test]
+fn error_span_underlines_the_offending_token() {
+    static MESSAGE: &str = "\
+error: Found argument '--unknown' which wasn't expected, or isn't valid in this context
+
+\tIf you tried to supply `--unknown` as a value rather than a flag, use `-- --unknown`
+
+    prog --unknown
+         ^^^^^^^^^
+
+USAGE:
+    prog [OPTIONS]
+
+For more information try --help
+";
+    let app = App::new("prog")
+        .setting(AppSettings::EchoCommandLine)
+        .arg(Arg::new("opt").long("opt"));
+    assert!(utils::compare_output(app, "prog --unknown", MESSAGE, true));
+}
+
+#[test]
+fn error_does_not_echo_command_line_by_default() {
+    let err = App::new("prog")
+        .arg(Arg::new("opt").long("opt"))
+        .try_get_matches_from(["prog", "--unknown"])
+        .unwrap_err();
+    assert!(err
+        .context()
+        .all(|(kind, _)| kind != ContextKind::CommandLine && kind != ContextKind::Span));
+    assert!(!err.to_string().contains('^'), "{}", err);
+}
+
+#[test]
+fn error_span_matches_token_with_attached_value() {
+    let err = App::new("prog")
+        .setting(AppSettings::EchoCommandLine)
+        .arg(Arg::new("opt").long("opt").takes_value(true))
+        .try_get_matches_from(["prog", "--unknwon=value"])
+        .unwrap_err();
+
+    let span: Vec<_> = err
+        .context()
+        .filter(|(kind, _)| *kind == ContextKind::Span)
+        .collect();
+    assert_eq!(span, vec![(ContextKind::Span, &ContextValue::Range(5..14))]);
+}
+
+#[test]
+fn error_without_matching_token_renders_without_span() {
+    let mut app = App::new("prog").arg(Arg::new("opt").long("opt"));
+    let err = app.error(ErrorKind::InvalidValue, "Failed for mysterious reasons");
+    assert!(err
+        .context()
+        .all(|(kind, _)| kind != ContextKind::CommandLine && kind != ContextKind::Span));
+    assert!(!err.to_string().contains('^'), "{}", err);
+}
+
+#[test]
+fn after_error_help_appended_for_matching_kind() {
+    let err = App::new("myapp")
+        .after_error_help(
+            ErrorKind::UnknownArgument,
+            "run `myapp plugins list` to see plugin-provided flags",
+        )
+        .try_get_matches_from(["myapp", "--frobnicate"])
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnknownArgument);
+    let out = err.to_string();
+    assert!(out.contains("plugins list"), "{}", out);
+    // The guidance sits between the usage block and the try-help footer
+    let guidance = out.find("plugins list").unwrap();
+    assert!(out.find("USAGE:").unwrap() < guidance, "{}", out);
+    assert!(guidance < out.find("For more information").unwrap(), "{}", out);
+}
+
+#[test]
+fn after_error_help_ignored_for_other_kinds() {
+    let err = App::new("myapp")
+        .after_error_help(ErrorKind::UnknownArgument, "plugin hint")
+        .arg(Arg::new("pos").required(true))
+        .try_get_matches_from(["myapp"])
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::MissingRequiredArgument);
+    assert!(!err.to_string().contains("plugin hint"), "{}", err);
+}
+
+#[test]
+fn after_error_help_per_kind_registrations() {
+    let app = || {
+        App::new("myapp")
+            .after_error_help(ErrorKind::UnknownArgument, "plugin hint")
+            .after_error_help(ErrorKind::MissingRequiredArgument, "required hint")
+            .arg(Arg::new("pos").required(true))
+    };
+
+    let err = app().try_get_matches_from(["myapp", "val", "--nope"]).unwrap_err();
+    assert!(err.to_string().contains("plugin hint"), "{}", err);
+
+    let err = app().try_get_matches_from(["myapp"]).unwrap_err();
+    assert!(err.to_string().contains("required hint"), "{}", err);
+}
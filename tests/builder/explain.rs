@@ -0,0 +1,65 @@
+use clap::{App, Arg};
+
+#[test]
+fn explain_reports_value_and_source_per_arg() {
+    let m = App::new("myapp")
+        .arg(
+            Arg::new("port")
+                .long("port")
+                .takes_value(true)
+                .default_value("80"),
+        )
+        .arg(Arg::new("host").long("host").takes_value(true))
+        .arg(Arg::new("verbose").short('v'))
+        .try_get_matches_from(vec!["myapp", "-v", "--host", "example.com"])
+        .unwrap();
+
+    let report = m.explain();
+    let lines: Vec<&str> = report.lines().collect();
+    assert!(lines[0].starts_with("ARGUMENT"), "{}", report);
+    assert!(lines[0].contains("VALUE"), "{}", report);
+    assert!(lines[0].contains("SOURCE"), "{}", report);
+
+    let row = |name: &str| {
+        *lines
+            .iter()
+            .find(|l| l.starts_with(name))
+            .unwrap_or_else(|| panic!("no row for {} in:\n{}", name, report))
+    };
+    assert!(row("port").contains("80"), "{}", report);
+    assert!(row("port").contains("default"), "{}", report);
+    assert!(row("host").contains("example.com"), "{}", report);
+    assert!(row("host").contains("command line"), "{}", report);
+    assert!(row("verbose").contains("set"), "{}", report);
+}
+
+#[test]
+fn explain_sections_subcommand_chain() {
+    let m = App::new("myapp")
+        .subcommand(App::new("remote").arg(Arg::new("url").takes_value(true)))
+        .try_get_matches_from(vec!["myapp", "remote", "https://example.com"])
+        .unwrap();
+
+    let report = m.explain();
+    assert!(report.contains("[remote]"), "{}", report);
+    assert!(report.contains("https://example.com"), "{}", report);
+}
+
+#[cfg(feature = "env")]
+#[test]
+fn explain_distinguishes_environment_values() {
+    std::env::set_var("CLP_EXPLAIN_HOST", "env-host");
+    let m = App::new("myapp")
+        .arg(
+            Arg::new("host")
+                .long("host")
+                .env("CLP_EXPLAIN_HOST")
+                .takes_value(true),
+        )
+        .try_get_matches_from(vec!["myapp"])
+        .unwrap();
+
+    let report = m.explain();
+    assert!(report.contains("env-host"), "{}", report);
+    assert!(report.contains("environment"), "{}", report);
+}
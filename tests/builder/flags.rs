@@ -189,3 +189,156 @@ For more information try --help
         true
     ));
 }
+
+#[test]
+fn negatable_flag_positive_and_negative_forms() {
+    let app = App::new("test").arg(Arg::new("color").long("color").negatable(true));
+
+    let m = app.clone().try_get_matches_from(vec!["test"]).unwrap();
+    assert_eq!(m.flag_of("color"), None);
+
+    let m = app
+        .clone()
+        .try_get_matches_from(vec!["test", "--color"])
+        .unwrap();
+    assert!(m.is_present("color"));
+    assert_eq!(m.flag_of("color"), Some(true));
+
+    let m = app
+        .clone()
+        .try_get_matches_from(vec!["test", "--no-color"])
+        .unwrap();
+    assert!(m.is_present("color"));
+    assert_eq!(m.flag_of("color"), Some(false));
+}
+
+#[test]
+fn negatable_flag_last_form_wins() {
+    let app = App::new("test").arg(
+        Arg::new("color")
+            .long("color")
+            .short('c')
+            .multiple_occurrences(true)
+            .negatable(true),
+    );
+
+    let m = app
+        .clone()
+        .try_get_matches_from(vec!["test", "--color", "--no-color"])
+        .unwrap();
+    assert_eq!(m.flag_of("color"), Some(false));
+
+    let m = app
+        .clone()
+        .try_get_matches_from(vec!["test", "--no-color", "-c"])
+        .unwrap();
+    assert_eq!(m.flag_of("color"), Some(true));
+}
+
+#[test]
+fn no_prefix_is_not_accepted_for_ordinary_flags() {
+    use clap::error::ErrorKind;
+    let m = App::new("test")
+        .arg(Arg::new("color").long("color"))
+        .try_get_matches_from(vec!["test", "--no-color"]);
+    assert!(m.is_err());
+    assert_eq!(m.unwrap_err().kind(), ErrorKind::UnknownArgument);
+}
+
+#[test]
+fn negatable_flag_shows_both_forms_in_help() {
+    static NEGATABLE_HELP: &str = "test 
+
+USAGE:
+    test [OPTIONS]
+
+OPTIONS:
+        --[no-]color    Use colored output
+    -h, --help          Print help information
+";
+    let app = App::new("test").arg(
+        Arg::new("color")
+            .long("color")
+            .negatable(true)
+            .help("Use colored output"),
+    );
+
+    assert!(utils::compare_output(app, "test --help", NEGATABLE_HELP, false));
+}
+
+#[test]
+fn alternate_prefix_matches_short_flag() {
+    let app = || {
+        App::new("sh")
+            .flag_prefix('+')
+            .arg(Arg::new("xtrace").short('x'))
+    };
+
+    let m = app().try_get_matches_from(vec!["sh", "+x"]).unwrap();
+    assert!(m.is_present("xtrace"));
+    assert_eq!(m.flag_prefix("xtrace"), Some('+'));
+
+    let m = app().try_get_matches_from(vec!["sh", "-x"]).unwrap();
+    assert!(m.is_present("xtrace"));
+    assert_eq!(m.flag_prefix("xtrace"), None);
+}
+
+#[test]
+fn alternate_prefix_matches_long_with_value() {
+    let m = App::new("java")
+        .flag_prefix('+')
+        .arg(Arg::new("define").long("define").takes_value(true))
+        .try_get_matches_from(vec!["java", "+define=foo"])
+        .unwrap();
+    assert_eq!(m.value_of("define"), Some("foo"));
+    assert_eq!(m.flag_prefix("define"), Some('+'));
+}
+
+#[test]
+fn alternate_prefix_last_occurrence_wins() {
+    let app = || {
+        App::new("sh")
+            .flag_prefix('+')
+            .arg(Arg::new("xtrace").short('x').multiple_occurrences(true))
+    };
+
+    let m = app().try_get_matches_from(vec!["sh", "+x", "-x"]).unwrap();
+    assert_eq!(m.flag_prefix("xtrace"), None);
+
+    let m = app().try_get_matches_from(vec!["sh", "-x", "+x"]).unwrap();
+    assert_eq!(m.flag_prefix("xtrace"), Some('+'));
+}
+
+#[test]
+fn alternate_prefix_leaves_unknown_names_alone() {
+    use clap::error::ErrorKind;
+    let res = App::new("sh")
+        .flag_prefix('+')
+        .arg(Arg::new("xtrace").short('x'))
+        .try_get_matches_from(vec!["sh", "+y"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::UnknownArgument);
+}
+
+#[test]
+fn alternate_prefix_is_literal_after_double_dash() {
+    let m = App::new("sh")
+        .flag_prefix('+')
+        .arg(Arg::new("xtrace").short('x'))
+        .arg(Arg::new("input").multiple_values(true))
+        .try_get_matches_from(vec!["sh", "--", "+x"])
+        .unwrap();
+    assert!(!m.is_present("xtrace"));
+    assert_eq!(m.values_of("input").unwrap().collect::<Vec<_>>(), ["+x"]);
+}
+
+#[test]
+fn alternate_prefix_off_when_unregistered() {
+    let m = App::new("prog")
+        .arg(Arg::new("xtrace").short('x'))
+        .arg(Arg::new("input"))
+        .try_get_matches_from(vec!["prog", "+x"])
+        .unwrap();
+    assert!(!m.is_present("xtrace"));
+    assert_eq!(m.value_of("input"), Some("+x"));
+}
@@ -0,0 +1,79 @@
+use clap::{error::ErrorKind, App, Arg};
+
+fn app() -> App<'static> {
+    App::new("prog")
+        .arg(Arg::new("message").long("message").takes_value(true))
+        .arg(Arg::new("verbose").short('v').multiple_occurrences(true))
+        .arg(Arg::new("file"))
+}
+
+#[test]
+fn line_is_split_on_whitespace() {
+    let m = app()
+        .try_get_matches_from_line("prog -vv --message hi input.txt")
+        .unwrap();
+
+    assert_eq!(m.occurrences_of("verbose"), 2);
+    assert_eq!(m.value_of("message"), Some("hi"));
+    assert_eq!(m.value_of("file"), Some("input.txt"));
+}
+
+#[test]
+fn quotes_keep_whitespace() {
+    let m = app()
+        .try_get_matches_from_line("prog --message 'hello world'")
+        .unwrap();
+    assert_eq!(m.value_of("message"), Some("hello world"));
+
+    let m = app()
+        .try_get_matches_from_line(r#"prog --message "hello \"quoted\" world""#)
+        .unwrap();
+    assert_eq!(m.value_of("message"), Some(r#"hello "quoted" world"#));
+}
+
+#[test]
+fn backslash_escapes_outside_quotes() {
+    let m = app()
+        .try_get_matches_from_line(r"prog hello\ world.txt")
+        .unwrap();
+    assert_eq!(m.value_of("file"), Some("hello world.txt"));
+}
+
+#[test]
+fn single_quotes_are_verbatim() {
+    let m = app()
+        .try_get_matches_from_line(r"prog --message 'no \escapes here'")
+        .unwrap();
+    assert_eq!(m.value_of("message"), Some(r"no \escapes here"));
+}
+
+#[test]
+fn unclosed_quote_points_at_the_column() {
+    let err = app()
+        .try_get_matches_from_line("prog --message 'hello")
+        .unwrap_err();
+
+    assert_eq!(err.kind(), ErrorKind::InvalidLineSyntax);
+    let rendered = err.to_string();
+    assert!(rendered.contains("unclosed single quote at column 16"));
+    assert!(rendered.contains("prog --message 'hello"));
+    assert!(rendered.contains("               ^"));
+}
+
+#[test]
+fn trailing_backslash_is_an_error() {
+    let err = app()
+        .try_get_matches_from_line("prog --message hi\\")
+        .unwrap_err();
+
+    assert_eq!(err.kind(), ErrorKind::InvalidLineSyntax);
+    assert!(err.to_string().contains("dangling escape at column 18"));
+}
+
+#[test]
+fn parse_errors_still_surface() {
+    let err = app()
+        .try_get_matches_from_line("prog --nonexistent")
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::UnknownArgument);
+}
@@ -1,5 +1,3 @@
-#![cfg(feature = "unstable-grouped")]
-
 use clap::{App, Arg};
 
 #[test]
@@ -320,3 +320,138 @@ fn issue_1794() {
     assert!(m.is_present("option1"));
 }
 */
+
+fn cardinality_app() -> App<'static> {
+    App::new("myprog")
+        .arg(Arg::new("major").long("major"))
+        .arg(Arg::new("minor").long("minor"))
+        .arg(Arg::new("patch").long("patch"))
+}
+
+#[test]
+fn group_required_count_satisfied() {
+    let m = cardinality_app()
+        .group(
+            ArgGroup::new("vers")
+                .args(&["major", "minor", "patch"])
+                .required_count(2),
+        )
+        .try_get_matches_from(vec!["myprog", "--major", "--patch"])
+        .unwrap();
+    assert!(m.is_present("vers"));
+}
+
+#[test]
+fn group_required_count_too_few() {
+    let res = cardinality_app()
+        .group(
+            ArgGroup::new("vers")
+                .args(&["major", "minor", "patch"])
+                .required_count(2),
+        )
+        .try_get_matches_from(vec!["myprog", "--major"]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidGroupCount);
+    assert!(err.to_string().contains("exactly 2"), "{}", err);
+    assert!(err.to_string().contains("--minor"), "{}", err);
+}
+
+#[test]
+fn group_required_count_too_many() {
+    let res = cardinality_app()
+        .group(
+            ArgGroup::new("vers")
+                .args(&["major", "minor", "patch"])
+                .required_count(2),
+        )
+        .try_get_matches_from(vec!["myprog", "--major", "--minor", "--patch"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::InvalidGroupCount);
+}
+
+#[test]
+fn group_required_count_none_reports_missing_required() {
+    let res = cardinality_app()
+        .group(
+            ArgGroup::new("vers")
+                .args(&["major", "minor", "patch"])
+                .required_count(2),
+        )
+        .try_get_matches_from(vec!["myprog"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::MissingRequiredArgument);
+}
+
+#[test]
+fn group_at_least_too_few() {
+    let res = cardinality_app()
+        .group(
+            ArgGroup::new("vers")
+                .args(&["major", "minor", "patch"])
+                .at_least(2),
+        )
+        .try_get_matches_from(vec!["myprog", "--minor"]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidGroupCount);
+    assert!(err.to_string().contains("at least 2"), "{}", err);
+}
+
+#[test]
+fn group_at_least_allows_more() {
+    let m = cardinality_app()
+        .group(
+            ArgGroup::new("vers")
+                .args(&["major", "minor", "patch"])
+                .at_least(2),
+        )
+        .try_get_matches_from(vec!["myprog", "--major", "--minor", "--patch"])
+        .unwrap();
+    assert!(m.is_present("vers"));
+}
+
+#[test]
+fn group_at_most_too_many() {
+    let res = cardinality_app()
+        .group(
+            ArgGroup::new("vers")
+                .args(&["major", "minor", "patch"])
+                .at_most(2),
+        )
+        .try_get_matches_from(vec!["myprog", "--major", "--minor", "--patch"]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidGroupCount);
+    assert!(err.to_string().contains("at most 2"), "{}", err);
+}
+
+#[test]
+fn group_at_most_allows_absence() {
+    let m = cardinality_app()
+        .group(
+            ArgGroup::new("vers")
+                .args(&["major", "minor", "patch"])
+                .at_most(2),
+        )
+        .try_get_matches_from(vec!["myprog"])
+        .unwrap();
+    assert!(!m.is_present("vers"));
+}
+
+#[test]
+fn group_at_least_and_at_most_range() {
+    let res = cardinality_app()
+        .arg(Arg::new("build").long("build"))
+        .group(
+            ArgGroup::new("vers")
+                .args(&["major", "minor", "patch", "build"])
+                .at_least(2)
+                .at_most(3),
+        )
+        .try_get_matches_from(vec!["myprog", "--major"]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidGroupCount);
+    assert!(err.to_string().contains("between 2 and 3"), "{}", err);
+}
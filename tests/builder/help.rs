@@ -655,6 +655,42 @@ fn help_subcommand() {
     assert_eq!(m.unwrap_err().kind(), ErrorKind::DisplayHelp);
 }
 
+#[test]
+fn help_subcommand_with_flag() {
+    let m = setup()
+        .arg(arg!(-v --verbose "with verbosity"))
+        .subcommand(App::new("test").about("tests things"))
+        .try_get_matches_from(vec!["myprog", "help", "--verbose"]);
+
+    assert!(m.is_err());
+    assert_eq!(m.unwrap_err().kind(), ErrorKind::DisplayHelp);
+}
+
+#[test]
+fn help_subcommand_with_nested_flag() {
+    let m = setup()
+        .subcommand(
+            App::new("test")
+                .about("tests things")
+                .arg(arg!(-v --verbose "with verbosity")),
+        )
+        .try_get_matches_from(vec!["myprog", "help", "test", "-v"]);
+
+    assert!(m.is_err());
+    assert_eq!(m.unwrap_err().kind(), ErrorKind::DisplayHelp);
+}
+
+#[test]
+fn help_subcommand_with_unknown_flag() {
+    let m = setup()
+        .arg(arg!(-v --verbose "with verbosity"))
+        .subcommand(App::new("test").about("tests things"))
+        .try_get_matches_from(vec!["myprog", "help", "--unknown"]);
+
+    assert!(m.is_err());
+    assert_eq!(m.unwrap_err().kind(), ErrorKind::UnrecognizedSubcommand);
+}
+
 #[test]
 fn req_last_arg_usage() {
     let app = App::new("example")
@@ -1565,6 +1601,43 @@ fn arg_short_conflict_with_help_mut_arg() {
         .try_get_matches_from(vec![""]);
 }
 
+#[test]
+fn arg_reclaims_help_short() {
+    let app = || {
+        App::new("reclaim").arg(
+            Arg::new("host")
+                .short('h')
+                .long("host")
+                .takes_value(true),
+        )
+    };
+
+    let m = app()
+        .try_get_matches_from(["reclaim", "-h", "example.com"])
+        .unwrap();
+    assert_eq!(m.value_of("host"), Some("example.com"));
+
+    let err = app()
+        .try_get_matches_from(["reclaim", "--help"])
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::DisplayHelp);
+}
+
+#[test]
+fn arg_reclaims_help_short_keeps_long_in_try_help() {
+    let app = App::new("reclaim").arg(
+        Arg::new("host")
+            .short('h')
+            .long("host")
+            .takes_value(true),
+    );
+
+    let err = app
+        .try_get_matches_from(["reclaim", "--bogus"])
+        .unwrap_err();
+    assert!(err.to_string().contains("For more information try --help"));
+}
+
 #[test]
 fn last_arg_mult_usage() {
     let app = App::new("last")
@@ -2774,3 +2847,359 @@ fn help_without_short() {
     let m = app.try_get_matches_from(["test", "-h", "0x100"]).unwrap();
     assert_eq!(m.value_of("hex"), Some("0x100"));
 }
+
+#[test]
+fn value_name_casing_applies_to_help_and_errors() {
+    use clap::ValueNameCasing;
+
+    let app = || {
+        App::new("myprog")
+            .value_name_casing(ValueNameCasing::ScreamingSnake)
+            .arg(
+                Arg::new("out-file")
+                    .long("out-file")
+                    .takes_value(true)
+                    .help("Where to write"),
+            )
+    };
+
+    let mut help = Vec::new();
+    app().write_help(&mut help).unwrap();
+    let help = String::from_utf8(help).unwrap();
+    assert!(help.contains("--out-file <OUT_FILE>"), "{}", help);
+
+    let err = app()
+        .try_get_matches_from(["myprog", "--out-file"])
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::EmptyValue);
+    assert!(err.to_string().contains("<OUT_FILE>"), "{}", err);
+}
+
+#[test]
+fn value_name_casing_custom_hook() {
+    use clap::ValueNameCasing;
+
+    let mut app = App::new("myprog")
+        .value_name_casing(ValueNameCasing::Custom(|name| format!("my-{}", name)))
+        .arg(Arg::new("file").takes_value(true).required(true));
+
+    let usage = app.render_usage();
+    assert!(usage.contains("<my-file>"), "{}", usage);
+}
+
+#[test]
+fn value_name_casing_does_not_override_explicit_value_name() {
+    use clap::ValueNameCasing;
+
+    let mut app = App::new("myprog")
+        .value_name_casing(ValueNameCasing::ScreamingSnake)
+        .arg(
+            Arg::new("out-file")
+                .long("out-file")
+                .value_name("path")
+                .required(true),
+        );
+
+    let usage = app.render_usage();
+    assert!(usage.contains("--out-file <path>"), "{}", usage);
+}
+
+static SMART_WRAP_LONG_ABOUT: &str = "prog 
+A tool that does a thing
+with several words
+
+USAGE:
+    prog
+
+OPTIONS:
+    -h, --help
+            Print help
+            information
+
+This tool supports:
+
+- a first point that runs
+  long
+- a second point
+
+    prog --help
+";
+
+#[test]
+fn smart_wrap_reflows_long_about() {
+    let app = App::new("prog")
+        .term_width(28)
+        .smart_wrap(true)
+        .about("A tool that does a thing with several words")
+        .after_long_help(
+            "This tool supports:\n\n\
+             - a first point that runs long\n\
+             - a second point\n\n\
+             \x20   prog --help",
+        );
+    assert!(utils::compare_output(
+        app,
+        "prog --help",
+        SMART_WRAP_LONG_ABOUT,
+        false
+    ));
+}
+
+#[test]
+fn term_width_from_env_reads_columns() {
+    std::env::set_var("COLUMNS", "28");
+    let mut app = App::new("prog")
+        .term_width_from_env()
+        .about("A tool that does a thing with several words");
+    std::env::remove_var("COLUMNS");
+
+    let mut help = Vec::new();
+    app.write_help(&mut help).unwrap();
+    let help = String::from_utf8(help).unwrap();
+    assert!(
+        help.contains("A tool that does a thing\nwith several words"),
+        "{}",
+        help
+    );
+}
+
+#[test]
+fn help_aligns_double_width_value_names() {
+    static CJK_HELP: &str = "test 
+
+USAGE:
+    test [OPTIONS]
+
+OPTIONS:
+    -h, --help            Print help information
+        --input <文件>    Input file
+        --verbose         Verbose output
+";
+    let app = App::new("test")
+        .arg(
+            Arg::new("input")
+                .long("input")
+                .value_name("文件")
+                .help("Input file"),
+        )
+        .arg(Arg::new("verbose").long("verbose").help("Verbose output"));
+
+    assert!(utils::compare_output(app, "test --help", CJK_HELP, false));
+}
+
+#[test]
+fn render_help_to_string_matches_written_help() {
+    let app = || {
+        App::new("myprog")
+            .version("1.0")
+            .arg(Arg::new("config").long("config").help("Sets a config file"))
+    };
+
+    let mut buf = Vec::new();
+    app().term_width(100).write_help(&mut buf).unwrap();
+    let written = String::from_utf8(buf).unwrap();
+
+    assert_eq!(app().render_help_to_string(false), written);
+}
+
+#[test]
+fn render_help_to_string_pins_wrapping_width() {
+    let app = || {
+        App::new("myprog").arg(Arg::new("opt").long("opt").help(
+            "A considerably long help message that would be wrapped differently \
+             depending on how wide the terminal running the tests happens to be",
+        ))
+    };
+
+    let rendered = app().render_help_to_string(true);
+    let pinned = app().term_width(100).render_help_to_string(true);
+    assert_eq!(rendered, pinned);
+}
+
+#[test]
+fn render_help_to_string_plain_strips_ansi() {
+    let mut app = App::new("myprog")
+        .arg(Arg::new("loud").long("loud").help("\x1b[31mred\x1b[0m help"));
+
+    let rendered = app.render_help_to_string(false);
+    assert!(rendered.contains("\x1b[31mred\x1b[0m help"));
+
+    let plain = app.render_help_to_string(true);
+    assert!(plain.contains("red help"));
+    assert!(!plain.contains('\x1b'));
+}
+
+#[test]
+fn render_long_help_to_string_uses_long_help() {
+    let app = || {
+        App::new("myprog").arg(
+            Arg::new("opt")
+                .long("opt")
+                .help("short about")
+                .long_help("much longer about"),
+        )
+    };
+
+    assert!(app().render_help_to_string(true).contains("short about"));
+    assert!(app()
+        .render_long_help_to_string(true)
+        .contains("much longer about"));
+}
+
+#[test]
+fn help_subcommand_all_dumps_every_subcommand() {
+    let m = App::new("myprog")
+        .subcommand(
+            App::new("remote")
+                .about("manage remotes")
+                .subcommand(App::new("add").about("add a remote")),
+        )
+        .subcommand(App::new("fetch").about("fetch objects"))
+        .try_get_matches_from(vec!["myprog", "help", "--all"]);
+
+    assert!(m.is_err());
+    let err = m.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::DisplayHelp);
+    assert!(!err.use_stderr());
+    let out = err.to_string();
+    assert!(out.contains("manage remotes"), "{}", out);
+    assert!(out.contains("add a remote"), "{}", out);
+    assert!(out.contains("fetch objects"), "{}", out);
+    assert!(out.contains("myprog remote add"), "{}", out);
+}
+
+#[test]
+fn help_subcommand_all_scoped_to_path() {
+    let m = App::new("myprog")
+        .subcommand(
+            App::new("remote")
+                .about("manage remotes")
+                .subcommand(App::new("add").about("add a remote")),
+        )
+        .subcommand(App::new("fetch").about("fetch objects"))
+        .try_get_matches_from(vec!["myprog", "help", "remote", "--all"]);
+
+    assert!(m.is_err());
+    let err = m.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::DisplayHelp);
+    let out = err.to_string();
+    assert!(out.contains("add a remote"), "{}", out);
+    assert!(!out.contains("fetch objects"), "{}", out);
+}
+
+fn verbose_help_app() -> App<'static> {
+    App::new("myprog")
+        .help_all_flag("help-all")
+        .arg(
+            Arg::new("output")
+                .long("output")
+                .help("Where to write the result"),
+        )
+        .arg(
+            Arg::new("expert-knob")
+                .long("expert-knob")
+                .hide_unless_verbose(true)
+                .help("Expert-only escape hatch"),
+        )
+        .arg(
+            Arg::new("legacy")
+                .long("legacy")
+                .hide_short_help(true)
+                .help("Kept for compatibility"),
+        )
+}
+
+#[test]
+fn hide_unless_verbose_absent_from_short_and_long_help() {
+    for flags in [vec!["myprog", "-h"], vec!["myprog", "--help"]] {
+        let m = verbose_help_app().try_get_matches_from(flags);
+        let err = m.unwrap_err();
+        assert_eq!(err.kind(), ErrorKind::DisplayHelp);
+        let out = err.to_string();
+        assert!(out.contains("--output"), "{}", out);
+        assert!(!out.contains("--expert-knob"), "{}", out);
+    }
+}
+
+#[test]
+fn double_help_flag_shows_verbose_tier() {
+    let m = verbose_help_app().try_get_matches_from(vec!["myprog", "--help", "--help"]);
+    let err = m.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::DisplayHelp);
+    let out = err.to_string();
+    assert!(out.contains("--expert-knob"), "{}", out);
+    assert!(out.contains("--legacy"), "{}", out);
+}
+
+#[test]
+fn double_short_help_flag_shows_verbose_tier() {
+    let m = verbose_help_app().try_get_matches_from(vec!["myprog", "-h", "-h"]);
+    let err = m.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::DisplayHelp);
+    let out = err.to_string();
+    assert!(out.contains("--expert-knob"), "{}", out);
+    assert!(out.contains("--legacy"), "{}", out);
+}
+
+#[test]
+fn help_all_flag_shows_verbose_tier() {
+    let m = verbose_help_app().try_get_matches_from(vec!["myprog", "--help-all"]);
+    let err = m.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::DisplayHelp);
+    let out = err.to_string();
+    assert!(out.contains("--expert-knob"), "{}", out);
+    assert!(out.contains("--legacy"), "{}", out);
+}
+
+#[test]
+fn hide_short_help_still_shows_in_plain_long_help() {
+    let m = verbose_help_app().try_get_matches_from(vec!["myprog", "--help"]);
+    let err = m.unwrap_err();
+    let out = err.to_string();
+    assert!(out.contains("--legacy"), "{}", out);
+}
+
+static EXAMPLES_HELP: &str = "myapp 
+
+USAGE:
+    myapp [OPTIONS]
+
+OPTIONS:
+    -h, --help               Print help information
+    -o, --output <output>    Where to write the result
+
+EXAMPLES:
+    myapp --output out.txt
+        Writes the result to out.txt
+    myapp
+        Prints the result to stdout
+";
+
+#[test]
+fn examples_section_in_help() {
+    let app = App::new("myapp")
+        .arg(
+            Arg::new("output")
+                .short('o')
+                .long("output")
+                .takes_value(true)
+                .help("Where to write the result"),
+        )
+        .example("myapp --output out.txt", "Writes the result to out.txt")
+        .example("myapp", "Prints the result to stdout");
+    assert!(utils::compare_output(app, "myapp -h", EXAMPLES_HELP, false));
+}
+
+#[test]
+fn examples_section_after_custom_help_sections() {
+    let m = App::new("myapp")
+        .help_section("EXIT CODES", "0 on success")
+        .example("myapp --all", "Does everything")
+        .try_get_matches_from(vec!["myapp", "--help"]);
+    let err = m.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::DisplayHelp);
+    let out = err.to_string();
+    let exit_codes = out.find("EXIT CODES:").expect(&out);
+    let examples = out.find("EXAMPLES:").expect(&out);
+    assert!(exit_codes < examples, "{}", out);
+}
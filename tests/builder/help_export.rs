@@ -0,0 +1,124 @@
+use clap::{App, Arg};
+
+fn build_app() -> App<'static> {
+    App::new("myapp")
+        .about("Does things")
+        .arg(
+            Arg::new("config")
+                .short('c')
+                .long("config")
+                .takes_value(true)
+                .value_name("FILE")
+                .help("Sets a config file"),
+        )
+        .arg(Arg::new("input").required(true).help("The input file"))
+        .subcommand(
+            App::new("test").about("tests things").arg(
+                Arg::new("case")
+                    .long("case")
+                    .takes_value(true)
+                    .help("the case to test"),
+            ),
+        )
+}
+
+static MARKDOWN: &str = "\
+# myapp
+
+Does things
+
+**Usage:** `myapp [OPTIONS] <input> [SUBCOMMAND]`
+
+## Arguments
+
+* `<input>` — The input file
+
+## Options
+
+* `-h, --help` — Print help information
+* `-c, --config <FILE>` — Sets a config file
+
+## Subcommands
+
+* `test` — tests things
+* `help` — Print this message or the help of the given subcommand(s)
+
+## myapp test
+
+tests things
+
+**Usage:** `myapp test [OPTIONS]`
+
+### Options
+
+* `--case <case>` — the case to test
+* `-h, --help` — Print help information
+
+";
+
+#[test]
+fn render_help_markdown_walks_the_tree() {
+    let markdown = build_app().render_help_markdown();
+    assert!(
+        markdown.starts_with(MARKDOWN),
+        "expected prefix:\n{}\ngot:\n{}",
+        MARKDOWN,
+        markdown
+    );
+    // The generated help subcommand gets a section too
+    assert!(markdown.contains("## myapp help"), "{}", markdown);
+}
+
+#[test]
+fn render_help_html_escapes_and_nests() {
+    let html = build_app().render_help_html();
+
+    assert!(html.starts_with("<h1>myapp</h1>\n<p>Does things</p>\n"), "{}", html);
+    assert!(
+        html.contains("<p><b>Usage:</b> <code>myapp [OPTIONS] &lt;input&gt; [SUBCOMMAND]</code></p>"),
+        "{}",
+        html
+    );
+    assert!(
+        html.contains("<dt><code>-c, --config &lt;FILE&gt;</code></dt>\n<dd>Sets a config file</dd>"),
+        "{}",
+        html
+    );
+    // Subcommands nest one heading level deeper
+    assert!(html.contains("<h2>myapp test</h2>"), "{}", html);
+    assert!(html.contains("<h3>Options</h3>"), "{}", html);
+}
+
+#[test]
+fn render_help_export_skips_hidden() {
+    let mut app = build_app()
+        .arg(Arg::new("secret").long("secret").hide(true))
+        .subcommand(App::new("internal").hide(true));
+
+    let markdown = app.render_help_markdown();
+    assert!(!markdown.contains("secret"), "{}", markdown);
+    assert!(!markdown.contains("internal"), "{}", markdown);
+}
+
+#[test]
+fn render_help_export_includes_examples() {
+    let mut app = build_app()
+        .example("myapp input.txt", "Processes input.txt")
+        .example("myapp -c my.toml input.txt", "Uses a custom config");
+
+    let markdown = app.render_help_markdown();
+    assert!(markdown.contains("## Examples"), "{}", markdown);
+    assert!(
+        markdown.contains("* `myapp input.txt` — Processes input.txt"),
+        "{}",
+        markdown
+    );
+
+    let html = app.render_help_html();
+    assert!(html.contains("<h2>Examples</h2>"), "{}", html);
+    assert!(
+        html.contains("<dt><code>myapp -c my.toml input.txt</code></dt>\n<dd>Uses a custom config</dd>"),
+        "{}",
+        html
+    );
+}
@@ -0,0 +1,104 @@
+use std::borrow::Cow;
+
+use clap::{App, Arg, DefaultText, Localizer};
+
+struct German;
+
+impl Localizer for German {
+    fn translate(&self, text: DefaultText) -> Option<Cow<'static, str>> {
+        match text {
+            DefaultText::ErrorPrefix => Some("Fehler:".into()),
+            DefaultText::UsageHeading => Some("VERWENDUNG:".into()),
+            DefaultText::OptionsHeading => Some("OPTIONEN:".into()),
+            DefaultText::ArgsHeading => Some("ARGUMENTE:".into()),
+            DefaultText::SubcommandsHeading => Some("UNTERBEFEHLE".into()),
+            DefaultText::TryHelp => Some("Weitere Informationen mit ".into()),
+            DefaultText::MissingRequiredArguments => {
+                Some("Die folgenden erforderlichen Argumente fehlen:".into())
+            }
+            _ => None,
+        }
+    }
+}
+
+#[test]
+fn localized_error_prefix_and_usage() {
+    let res = App::new("prog")
+        .localizer(German)
+        .arg(Arg::new("opt").long("opt").takes_value(true))
+        .try_get_matches_from(["prog", "--unknown"]);
+
+    assert!(res.is_err());
+    let msg = res.unwrap_err().to_string();
+    assert!(msg.contains("Fehler:"), "{}", msg);
+    assert!(msg.contains("VERWENDUNG:"), "{}", msg);
+    assert!(msg.contains("Weitere Informationen mit "), "{}", msg);
+    assert!(!msg.contains("error:"), "{}", msg);
+    assert!(!msg.contains("USAGE:"), "{}", msg);
+}
+
+#[test]
+fn localized_missing_required_arguments() {
+    let res = App::new("prog")
+        .localizer(German)
+        .arg(Arg::new("input").required(true))
+        .try_get_matches_from(["prog"]);
+
+    assert!(res.is_err());
+    let msg = res.unwrap_err().to_string();
+    assert!(
+        msg.contains("Die folgenden erforderlichen Argumente fehlen:"),
+        "{}",
+        msg
+    );
+}
+
+#[test]
+fn localized_help_headings() {
+    let mut app = App::new("prog")
+        .localizer(German)
+        .arg(Arg::new("input").help("Input file"))
+        .arg(Arg::new("opt").long("opt").help("An option"))
+        .subcommand(App::new("sub").about("A subcommand"));
+    let mut buf = Vec::new();
+    app.write_help(&mut buf).unwrap();
+    let help = String::from_utf8(buf).unwrap();
+
+    assert!(help.contains("VERWENDUNG:"), "{}", help);
+    assert!(help.contains("ARGUMENTE:"), "{}", help);
+    assert!(help.contains("OPTIONEN:"), "{}", help);
+    assert!(help.contains("UNTERBEFEHLE:"), "{}", help);
+    assert!(!help.contains("USAGE:"), "{}", help);
+}
+
+#[test]
+fn localizer_propagates_to_subcommands() {
+    let res = App::new("prog")
+        .localizer(German)
+        .subcommand(App::new("sub").arg(Arg::new("input").required(true)))
+        .try_get_matches_from(["prog", "sub"]);
+
+    assert!(res.is_err());
+    let msg = res.unwrap_err().to_string();
+    assert!(msg.contains("Fehler:"), "{}", msg);
+    assert!(msg.contains("VERWENDUNG:"), "{}", msg);
+}
+
+#[test]
+fn untranslated_keys_fall_back_to_english() {
+    struct Empty;
+    impl Localizer for Empty {
+        fn translate(&self, _: DefaultText) -> Option<Cow<'static, str>> {
+            None
+        }
+    }
+
+    let res = App::new("prog")
+        .localizer(Empty)
+        .try_get_matches_from(["prog", "--unknown"]);
+
+    assert!(res.is_err());
+    let msg = res.unwrap_err().to_string();
+    assert!(msg.contains("error:"), "{}", msg);
+    assert!(msg.contains("USAGE:"), "{}", msg);
+}
@@ -4,9 +4,14 @@ mod arg_aliases;
 mod arg_aliases_short;
 mod arg_matcher_assertions;
 mod arg_settings;
+mod args_in_order;
 mod borrowed;
+mod canonicalize;
 mod cargo;
+mod collect_errors;
+mod config_file;
 mod conflicts;
+mod constraint_graph;
 mod default_missing_vals;
 mod default_vals;
 mod delimiters;
@@ -16,32 +21,49 @@ mod double_require;
 mod empty_values;
 mod env;
 mod error;
+mod explain;
 mod flag_subcommands;
 mod flags;
+mod from_line;
 mod global_args;
 mod grouped_values;
 mod groups;
 mod help;
 mod help_env;
+mod help_export;
 mod hidden_args;
 mod ignore_errors;
 mod indices;
+mod localization;
+mod merge;
 mod multiple_occurrences;
 mod multiple_values;
+mod occurrence_patterns;
 mod opts;
 mod positionals;
 mod posix_compatible;
 mod possible_values;
+mod promote_args;
 mod propagate_globals;
 mod regex;
 mod require;
+mod response_file;
+mod serialization;
 mod subcommands;
 mod template_help;
 mod tests;
+mod theme;
+mod to_cmdline;
 mod unicode;
 mod unique_args;
 mod utf16;
 mod utf8;
 mod utils;
+mod validation;
 mod validators;
+mod value_hint;
+mod value_map;
+mod value_parser;
+mod value_range;
+mod value_transforms;
 mod version;
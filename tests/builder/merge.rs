@@ -0,0 +1,111 @@
+use clap::{App, Arg, ArgGroup, MergeError};
+
+fn base() -> App<'static> {
+    App::new("prog")
+        .arg(Arg::new("verbose").short('v').long("verbose"))
+        .subcommand(App::new("list"))
+}
+
+#[test]
+fn merge_disjoint_fragments() {
+    let extra = App::new("extra")
+        .arg(
+            Arg::new("config")
+                .short('c')
+                .long("config")
+                .takes_value(true),
+        )
+        .subcommand(App::new("fetch"));
+
+    let m = base()
+        .try_merge(extra)
+        .unwrap()
+        .try_get_matches_from(vec!["prog", "-v", "-c", "file"])
+        .unwrap();
+    assert!(m.is_present("verbose"));
+    assert_eq!(m.value_of("config"), Some("file"));
+}
+
+#[test]
+fn merged_subcommand_is_usable() {
+    let extra = App::new("extra").subcommand(App::new("fetch"));
+    let m = base()
+        .try_merge(extra)
+        .unwrap()
+        .try_get_matches_from(vec!["prog", "fetch"])
+        .unwrap();
+    assert_eq!(m.subcommand_name(), Some("fetch"));
+}
+
+#[test]
+fn merge_conflicting_id() {
+    let extra = App::new("extra").arg(Arg::new("verbose").long("louder"));
+    assert_eq!(
+        base().try_merge(extra),
+        Err(MergeError::DuplicateArgId {
+            id: "verbose".into()
+        })
+    );
+}
+
+#[test]
+fn merge_conflicting_long() {
+    let extra = App::new("extra").arg(Arg::new("chatty").long("verbose"));
+    assert_eq!(
+        base().try_merge(extra),
+        Err(MergeError::DuplicateLong {
+            long: "verbose".into(),
+            existing: "verbose".into(),
+            new: "chatty".into(),
+        })
+    );
+}
+
+#[test]
+fn merge_conflicting_short() {
+    let extra = App::new("extra").arg(Arg::new("loud").short('v'));
+    assert_eq!(
+        base().try_merge(extra),
+        Err(MergeError::DuplicateShort {
+            short: 'v',
+            existing: "verbose".into(),
+            new: "loud".into(),
+        })
+    );
+}
+
+#[test]
+fn merge_conflicting_subcommand() {
+    let extra = App::new("extra").subcommand(App::new("list"));
+    assert_eq!(
+        base().try_merge(extra),
+        Err(MergeError::DuplicateSubcommand {
+            name: "list".into()
+        })
+    );
+}
+
+#[test]
+fn merge_conflicting_group_id() {
+    let extra = App::new("extra")
+        .arg(Arg::new("loud").long("loud"))
+        .group(ArgGroup::new("verbose").arg("loud"));
+    assert_eq!(
+        base().try_merge(extra),
+        Err(MergeError::DuplicateArgId {
+            id: "verbose".into()
+        })
+    );
+}
+
+#[test]
+fn generated_args_are_not_merged() {
+    // Both fragments carry auto-generated help/version args; they must not conflict
+    let extra = App::new("extra");
+    let m = base()
+        .try_merge(extra)
+        .unwrap()
+        .try_get_matches_from(vec!["prog"])
+        .unwrap();
+    assert!(!m.is_present("verbose"));
+}
@@ -239,3 +239,65 @@ fn max_occurrences_positional() {
     assert!(m.is_err());
     assert_eq!(m.unwrap_err().kind(), ErrorKind::TooManyOccurrences);
 }
+
+#[test]
+fn count_of_counted_flag() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("verbose")
+                .short('v')
+                .multiple_occurrences(true),
+        )
+        .try_get_matches_from(vec!["prog", "-vvv"])
+        .unwrap();
+    assert_eq!(m.count_of("verbose"), 3);
+}
+
+#[test]
+fn count_of_unused_flag_is_zero() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("verbose")
+                .short('v')
+                .multiple_occurrences(true),
+        )
+        .try_get_matches_from(vec!["prog"])
+        .unwrap();
+    assert_eq!(m.count_of("verbose"), 0);
+}
+
+#[test]
+fn saturating_occurrences_caps_the_count() {
+    let app = App::new("prog").arg(
+        Arg::new("verbose")
+            .short('v')
+            .max_occurrences(3)
+            .saturating_occurrences(true),
+    );
+
+    let m = app.clone().try_get_matches_from(vec!["prog", "-vv"]);
+    assert!(m.is_ok(), "{}", m.unwrap_err());
+    assert_eq!(m.unwrap().count_of("verbose"), 2);
+
+    let m = app
+        .clone()
+        .try_get_matches_from(vec!["prog", "-vvvvv"]);
+    assert!(m.is_ok(), "{}", m.unwrap_err());
+    let m = m.unwrap();
+    assert_eq!(m.count_of("verbose"), 3);
+    assert_eq!(m.occurrences_of("verbose"), 3);
+}
+
+#[test]
+fn max_occurrences_without_saturation_still_errors() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("verbose")
+                .short('v')
+                .max_occurrences(3)
+                .saturating_occurrences(false),
+        )
+        .try_get_matches_from(vec!["prog", "-vvvv"]);
+    assert!(m.is_err());
+    assert_eq!(m.unwrap_err().kind(), ErrorKind::TooManyOccurrences);
+}
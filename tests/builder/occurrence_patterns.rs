@@ -0,0 +1,70 @@
+use clap::{App, Arg, ErrorKind};
+
+fn pair_app() -> App<'static> {
+    App::new("prog")
+        .arg(
+            Arg::new("src")
+                .long("src")
+                .takes_value(true)
+                .multiple_occurrences(true)
+                .paired_with("dst"),
+        )
+        .arg(
+            Arg::new("dst")
+                .long("dst")
+                .takes_value(true)
+                .multiple_occurrences(true),
+        )
+}
+
+#[test]
+fn occurrences_multiple_of_ok() {
+    let m = App::new("prog")
+        .arg(Arg::new("map").long("map").occurrences_multiple_of(2))
+        .try_get_matches_from(&["prog", "--map", "--map"])
+        .unwrap();
+    assert_eq!(m.occurrences_of("map"), 2);
+}
+
+#[test]
+fn occurrences_multiple_of_zero_occurrences_ok() {
+    let res = App::new("prog")
+        .arg(Arg::new("map").long("map").occurrences_multiple_of(2))
+        .try_get_matches_from(&["prog"]);
+    assert!(res.is_ok(), "{:?}", res);
+}
+
+#[test]
+fn occurrences_multiple_of_err() {
+    let res = App::new("prog")
+        .arg(Arg::new("map").long("map").occurrences_multiple_of(2))
+        .try_get_matches_from(&["prog", "--map", "--map", "--map"]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidOccurrencePattern);
+    assert!(err.to_string().contains("multiple of 2"), "{}", err);
+}
+
+#[test]
+fn paired_ok() {
+    let res = pair_app().try_get_matches_from(&[
+        "prog", "--src", "a", "--dst", "b", "--src", "c", "--dst", "d",
+    ]);
+    assert!(res.is_ok(), "{:?}", res);
+}
+
+#[test]
+fn paired_missing_partner_err() {
+    let res = pair_app().try_get_matches_from(&["prog", "--src", "a", "--src", "b", "--dst", "c"]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidOccurrencePattern);
+    assert!(err.to_string().contains("--dst"), "{}", err);
+}
+
+#[test]
+fn paired_trailing_occurrence_err() {
+    let res = pair_app().try_get_matches_from(&["prog", "--src", "a", "--dst", "b", "--src", "c"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::InvalidOccurrencePattern);
+}
@@ -308,3 +308,48 @@ fn ignore_hyphen_values_on_last() {
     let matches = app.try_get_matches_from(["test", "-n", "foo"]).unwrap();
     assert_eq!(matches.value_of("name"), Some("foo"));
 }
+
+#[test]
+fn try_parse_iter_streams_positionals() {
+    let mut app = App::new("xargs-ish")
+        .arg(Arg::new("verbose").short('v'))
+        .arg(Arg::new("input").multiple_values(true));
+    let args = ["xargs-ish", "-v", "one.txt", "two.txt", "three.txt"]
+        .iter()
+        .map(std::ffi::OsString::from);
+    let m = app.try_parse_iter(args).unwrap();
+
+    assert!(m.is_present("verbose"));
+    assert_eq!(
+        m.values_of("input").unwrap().collect::<Vec<_>>(),
+        &["one.txt", "two.txt", "three.txt"]
+    );
+}
+
+#[test]
+fn try_parse_iter_still_suggests_on_unknown_args() {
+    let mut app = App::new("prog")
+        .arg(Arg::new("config").long("config").takes_value(true))
+        .subcommand(App::new("test"));
+    let args = ["prog", "--confg", "file"]
+        .iter()
+        .map(std::ffi::OsString::from);
+    let err = app.try_parse_iter(args).unwrap_err();
+
+    assert_eq!(err.kind(), ErrorKind::UnknownArgument);
+    #[cfg(feature = "suggestions")]
+    assert!(err.to_string().contains("--config"), "{}", err);
+}
+
+#[test]
+fn try_parse_iter_handles_subcommands() {
+    let mut app = App::new("prog").subcommand(App::new("run").arg(Arg::new("job")));
+    let args = ["prog", "run", "nightly"]
+        .iter()
+        .map(std::ffi::OsString::from);
+    let m = app.try_parse_iter(args).unwrap();
+
+    let (name, sub_m) = m.subcommand().unwrap();
+    assert_eq!(name, "run");
+    assert_eq!(sub_m.value_of("job"), Some("nightly"));
+}
@@ -343,11 +343,8 @@ fn ignore_case() {
         .try_get_matches_from(vec!["pv", "--option", "TeSt123"]);
 
     assert!(m.is_ok(), "{}", m.unwrap_err());
-    assert!(m
-        .unwrap()
-        .value_of("option")
-        .unwrap()
-        .eq_ignore_ascii_case("test123"));
+    // The stored value is the canonical spelling, not the user's casing
+    assert_eq!(m.unwrap().value_of("option"), Some("test123"));
 }
 
 #[test]
@@ -385,7 +382,7 @@ fn ignore_case_multiple() {
     assert!(m.is_ok(), "{}", m.unwrap_err());
     assert_eq!(
         m.unwrap().values_of("option").unwrap().collect::<Vec<_>>(),
-        &["TeSt123", "teST123", "tESt321"]
+        &["test123", "test123", "test321"]
     );
 }
 
@@ -406,3 +403,123 @@ fn ignore_case_multiple_fail() {
     assert!(m.is_err());
     assert_eq!(m.unwrap_err().kind(), ErrorKind::InvalidValue);
 }
+
+static PV_LONG_HELP_SUB_LIST: &str = "test 
+
+USAGE:
+    test [OPTIONS]
+
+OPTIONS:
+        --format <format>
+            Output format
+
+            Possible values:
+            - json: Machine readable
+            - yaml: Human readable
+                and multi-line
+
+    -h, --help
+            Print help information
+";
+
+#[test]
+fn possible_value_help_sub_list_in_long_help() {
+    let app = App::new("test").term_width(0).arg(
+        Arg::new("format")
+            .long("format")
+            .takes_value(true)
+            .help("Output format")
+            .possible_value(PossibleValue::new("json").help("Machine readable"))
+            .possible_value(
+                PossibleValue::new("yaml")
+                    .help("Human readable")
+                    .long_help("Human readable\nand multi-line"),
+            )
+            .possible_value(PossibleValue::new("secret").hide(true)),
+    );
+    assert!(utils::compare_output(
+        app,
+        "test --help",
+        PV_LONG_HELP_SUB_LIST,
+        false
+    ));
+}
+
+#[test]
+fn possible_value_help_stays_inline_in_short_help() {
+    let m = App::new("test")
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .takes_value(true)
+                .help("Output format")
+                .possible_value(PossibleValue::new("json").help("Machine readable"))
+                .possible_value(PossibleValue::new("yaml")),
+        )
+        .try_get_matches_from(["test", "-h"]);
+    let err = m.unwrap_err();
+    let rendered = err.to_string();
+    assert!(
+        rendered.contains("[possible values: json, yaml]"),
+        "{}",
+        rendered
+    );
+    assert!(!rendered.contains("Possible values:"), "{}", rendered);
+}
+
+#[test]
+fn ignore_case_parses_the_canonical_variant() {
+    #[derive(Debug, PartialEq)]
+    enum Format {
+        Json,
+        Yaml,
+    }
+
+    impl std::str::FromStr for Format {
+        type Err = String;
+
+        // Deliberately case-sensitive: parsing relies on clap canonicalizing first
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            match s {
+                "json" => Ok(Format::Json),
+                "yaml" => Ok(Format::Yaml),
+                _ => Err(format!("unknown format: {}", s)),
+            }
+        }
+    }
+
+    let m = App::new("pv")
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .takes_value(true)
+                .possible_value("json")
+                .possible_value("yaml")
+                .ignore_case(true),
+        )
+        .try_get_matches_from(vec!["pv", "--format", "JSON"])
+        .unwrap();
+
+    assert_eq!(m.value_of_t::<Format>("format").unwrap(), Format::Json);
+}
+
+#[test]
+fn invalid_value_lists_canonical_casing() {
+    let m = App::new("pv")
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .takes_value(true)
+                .possible_value("Json")
+                .possible_value("Yaml")
+                .ignore_case(true),
+        )
+        .try_get_matches_from(vec!["pv", "--format", "toml"]);
+
+    assert!(m.is_err());
+    let err = m.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidValue);
+    let rendered = err.to_string();
+    assert!(rendered.contains("Json"), "{}", rendered);
+    assert!(rendered.contains("Yaml"), "{}", rendered);
+}
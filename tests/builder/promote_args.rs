@@ -0,0 +1,59 @@
+use clap::{App, Arg};
+
+fn common_arg() -> Arg<'static> {
+    Arg::new("verbose").long("verbose").short('v')
+}
+
+#[test]
+fn promotes_arg_shared_by_all_subcommands() {
+    let m = App::new("prog")
+        .promote_common_args(true)
+        .subcommand(App::new("add").arg(common_arg()))
+        .subcommand(App::new("remove").arg(common_arg()))
+        .try_get_matches_from(&["prog", "--verbose", "add"])
+        .unwrap();
+    assert!(m.is_present("verbose"));
+}
+
+#[test]
+fn promoted_arg_propagates_to_subcommands() {
+    let m = App::new("prog")
+        .promote_common_args(true)
+        .subcommand(App::new("add").arg(common_arg()))
+        .subcommand(App::new("remove").arg(common_arg()))
+        .try_get_matches_from(&["prog", "add", "--verbose"])
+        .unwrap();
+    let sub = m.subcommand_matches("add").unwrap();
+    assert!(sub.is_present("verbose"));
+}
+
+#[test]
+fn does_not_promote_arg_missing_from_one_subcommand() {
+    let res = App::new("prog")
+        .promote_common_args(true)
+        .subcommand(App::new("add").arg(common_arg()))
+        .subcommand(App::new("remove"))
+        .try_get_matches_from(&["prog", "--verbose", "add"]);
+    assert!(res.is_err());
+}
+
+#[test]
+fn does_not_promote_differing_definitions() {
+    let res = App::new("prog")
+        .promote_common_args(true)
+        .subcommand(App::new("add").arg(common_arg()))
+        .subcommand(App::new("remove").arg(Arg::new("verbose").long("verbose")))
+        .try_get_matches_from(&["prog", "--verbose", "add"]);
+    assert!(res.is_err());
+}
+
+#[test]
+fn subcommand_keeps_arg_when_not_promoted() {
+    let m = App::new("prog")
+        .promote_common_args(true)
+        .subcommand(App::new("add").arg(common_arg()))
+        .subcommand(App::new("remove"))
+        .try_get_matches_from(&["prog", "add", "--verbose"])
+        .unwrap();
+    assert!(m.subcommand_matches("add").unwrap().is_present("verbose"));
+}
@@ -0,0 +1,101 @@
+use std::path::PathBuf;
+
+use clap::{App, AppSettings, Arg, ErrorKind};
+
+fn write_response_file(name: &str, contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("clap_rsp_{}_{}", std::process::id(), name));
+    std::fs::write(&path, contents).unwrap();
+    path
+}
+
+fn app() -> App<'static> {
+    App::new("prog")
+        .setting(AppSettings::AllowResponseFiles)
+        .arg(Arg::new("port").long("port").takes_value(true))
+        .arg(Arg::new("verbose").long("verbose"))
+        .arg(Arg::new("input").multiple_values(true))
+}
+
+#[test]
+fn tokens_expand_in_place() {
+    let path = write_response_file("basic.txt", "--port 8080\n--verbose\n");
+
+    let m = app()
+        .try_get_matches_from(["prog", &format!("@{}", path.display()), "in.txt"])
+        .unwrap();
+    assert_eq!(m.value_of("port"), Some("8080"));
+    assert!(m.is_present("verbose"));
+    let inputs: Vec<_> = m.values_of("input").unwrap().collect();
+    assert_eq!(inputs, ["in.txt"]);
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn quotes_group_arguments_with_whitespace() {
+    let path = write_response_file(
+        "quotes.txt",
+        "\"with space\" 'also spaced' \"esc\\\"aped\"\n",
+    );
+
+    let m = app()
+        .try_get_matches_from(["prog", &format!("@{}", path.display())])
+        .unwrap();
+    let inputs: Vec<_> = m.values_of("input").unwrap().collect();
+    assert_eq!(inputs, ["with space", "also spaced", "esc\"aped"]);
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn response_files_nest() {
+    let inner = write_response_file("inner.txt", "--verbose\n");
+    let outer = write_response_file("outer.txt", &format!("--port 8080\n@{}\n", inner.display()));
+
+    let m = app()
+        .try_get_matches_from(["prog", &format!("@{}", outer.display())])
+        .unwrap();
+    assert_eq!(m.value_of("port"), Some("8080"));
+    assert!(m.is_present("verbose"));
+
+    std::fs::remove_file(outer).unwrap();
+    std::fs::remove_file(inner).unwrap();
+}
+
+#[test]
+fn cyclic_response_files_error() {
+    let path = write_response_file("cycle.txt", "");
+    std::fs::write(&path, format!("@{}\n", path.display())).unwrap();
+
+    let res = app().try_get_matches_from(["prog", &format!("@{}", path.display())]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::Io);
+
+    std::fs::remove_file(path).unwrap();
+}
+
+#[test]
+fn missing_response_file_errors() {
+    let res = app().try_get_matches_from(["prog", "@no-such-response-file"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind, ErrorKind::Io);
+}
+
+#[test]
+fn tokens_after_double_dash_are_literal() {
+    let m = app()
+        .try_get_matches_from(["prog", "--", "@literal.txt"])
+        .unwrap();
+    let inputs: Vec<_> = m.values_of("input").unwrap().collect();
+    assert_eq!(inputs, ["@literal.txt"]);
+}
+
+#[test]
+fn setting_off_treats_at_tokens_literally() {
+    let m = App::new("prog")
+        .arg(Arg::new("input").multiple_values(true))
+        .try_get_matches_from(["prog", "@literal.txt"])
+        .unwrap();
+    let inputs: Vec<_> = m.values_of("input").unwrap().collect();
+    assert_eq!(inputs, ["@literal.txt"]);
+}
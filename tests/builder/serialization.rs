@@ -0,0 +1,66 @@
+#![cfg(feature = "serde")]
+
+use clap::{App, Arg, ArgGroup};
+
+#[test]
+fn matches_serialize_args_and_values() {
+    let m = App::new("myapp")
+        .arg(Arg::new("port").long("port").takes_value(true))
+        .arg(Arg::new("verbose").short('v').multiple_occurrences(true))
+        .try_get_matches_from(vec!["myapp", "--port", "8080", "-v", "-v"])
+        .unwrap();
+
+    let json = serde_json::to_value(&m).unwrap();
+    assert_eq!(json["args"]["port"]["occurrences"], 1);
+    assert_eq!(json["args"]["port"]["values"][0], "8080");
+    assert_eq!(json["args"]["verbose"]["occurrences"], 2);
+    assert_eq!(json["subcommand"], serde_json::Value::Null);
+}
+
+#[test]
+fn matches_serialize_subcommand_chain() {
+    let m = App::new("myapp")
+        .subcommand(
+            App::new("remote").subcommand(App::new("add").arg(Arg::new("name").takes_value(true))),
+        )
+        .try_get_matches_from(vec!["myapp", "remote", "add", "origin"])
+        .unwrap();
+
+    let json = serde_json::to_value(&m).unwrap();
+    assert_eq!(json["subcommand"]["name"], "remote");
+    let inner = &json["subcommand"]["matches"]["subcommand"];
+    assert_eq!(inner["name"], "add");
+    assert_eq!(inner["matches"]["args"]["name"]["values"][0], "origin");
+}
+
+#[test]
+fn matches_serialize_group_and_default_names() {
+    let m = App::new("myapp")
+        .arg(Arg::new("json").long("json"))
+        .arg(Arg::new("yaml").long("yaml"))
+        .group(ArgGroup::new("format").args(&["json", "yaml"]))
+        .arg(
+            Arg::new("level")
+                .long("level")
+                .takes_value(true)
+                .default_value("info"),
+        )
+        .try_get_matches_from(vec!["myapp", "--json"])
+        .unwrap();
+
+    let json = serde_json::to_value(&m).unwrap();
+    assert_eq!(json["args"]["format"]["occurrences"], 1);
+    assert_eq!(json["args"]["level"]["values"][0], "info");
+}
+
+#[test]
+fn matches_serialize_multiple_values() {
+    let m = App::new("myapp")
+        .arg(Arg::new("file").multiple_values(true))
+        .try_get_matches_from(vec!["myapp", "a.txt", "b.txt"])
+        .unwrap();
+
+    let json = serde_json::to_value(&m).unwrap();
+    assert_eq!(json["args"]["file"]["values"][0], "a.txt");
+    assert_eq!(json["args"]["file"]["values"][1], "b.txt");
+}
@@ -1,6 +1,6 @@
 use crate::utils;
 
-use clap::{arg, error::ErrorKind, App, Arg};
+use clap::{arg, error::ErrorKind, App, AppSettings, Arg};
 
 static VISIBLE_ALIAS_HELP: &str = "clap-test 2.6
 
@@ -16,6 +16,22 @@ SUBCOMMANDS:
     test    Some help [aliases: dongle, done]
 ";
 
+static ALIAS_ROWS_HELP: &str = "clap-test 2.6
+
+USAGE:
+    clap-test [SUBCOMMAND]
+
+OPTIONS:
+    -h, --help       Print help information
+    -V, --version    Print version information
+
+SUBCOMMANDS:
+    done      → test
+    dongle    → test
+    help      Print this message or the help of the given subcommand(s)
+    test      Some help
+";
+
 static INVISIBLE_ALIAS_HELP: &str = "clap-test 2.6
 
 USAGE:
@@ -320,6 +336,26 @@ fn visible_aliases_help_output() {
     ));
 }
 
+#[test]
+fn visible_aliases_as_rows_help_output() {
+    let app = App::new("clap-test")
+        .version("2.6")
+        .list_subcommand_aliases(true)
+        .subcommand(
+            App::new("test")
+                .about("Some help")
+                .alias("invisible")
+                .visible_alias("dongle")
+                .visible_alias("done"),
+        );
+    assert!(utils::compare_output(
+        app,
+        "clap-test --help",
+        ALIAS_ROWS_HELP,
+        false
+    ));
+}
+
 #[test]
 fn invisible_aliases_help_output() {
     let app = App::new("clap-test")
@@ -455,6 +491,22 @@ fn subcommand_used_after_double_dash() {
     ));
 }
 
+#[test]
+fn subcommand_after_double_dash_captured_when_allowed() {
+    let m = App::new("app")
+        .setting(clap::AppSettings::AllowUnclaimedTrailingArgs)
+        .subcommand(App::new("subcmd"))
+        .try_get_matches_from(vec!["app", "--", "subcmd"])
+        .unwrap();
+
+    // Not treated as a subcommand; captured verbatim instead
+    assert_eq!(m.subcommand_name(), None);
+    assert_eq!(
+        m.trailing_args().collect::<Vec<_>>(),
+        [std::ffi::OsStr::new("subcmd")]
+    );
+}
+
 #[test]
 fn subcommand_after_argument() {
     let m = App::new("myprog")
@@ -522,7 +574,6 @@ For more information try --help
     ));
 }
 
-#[cfg(feature = "unstable-multicall")]
 #[test]
 fn busybox_like_multicall() {
     fn applet_commands() -> [App<'static>; 2] {
@@ -548,7 +599,6 @@ fn busybox_like_multicall() {
     assert_eq!(m.unwrap_err().kind(), ErrorKind::UnknownArgument);
 }
 
-#[cfg(feature = "unstable-multicall")]
 #[test]
 fn hostname_like_multicall() {
     let mut app = App::new("hostname")
@@ -577,3 +627,290 @@ fn hostname_like_multicall() {
     assert!(m.is_err());
     assert_eq!(m.unwrap_err().kind(), ErrorKind::UnknownArgument);
 }
+
+#[test]
+#[should_panic = "Possible value 'list' of positional argument 'mode' collides with the subcommand 'list'"]
+fn subcommand_value_collision_panics_without_policy() {
+    let _ = App::new("prog")
+        .subcommand(App::new("list"))
+        .arg(Arg::new("mode").possible_values(["list", "tree"]))
+        .try_get_matches_from(&["prog", "tree"]);
+}
+
+#[test]
+fn subcommand_value_collision_prefer_subcommand() {
+    let m = App::new("prog")
+        .subcommand_value_policy(clap::SubcommandValuePolicy::PreferSubcommand)
+        .subcommand(App::new("list"))
+        .arg(Arg::new("mode").possible_values(["list", "tree"]))
+        .try_get_matches_from(&["prog", "list"])
+        .unwrap();
+    assert_eq!(m.subcommand_name(), Some("list"));
+    assert!(!m.is_present("mode"));
+}
+
+#[test]
+fn subcommand_value_collision_prefer_positional() {
+    let m = App::new("prog")
+        .subcommand_value_policy(clap::SubcommandValuePolicy::PreferPositional)
+        .subcommand(App::new("list"))
+        .arg(Arg::new("mode").possible_values(["list", "tree"]))
+        .try_get_matches_from(&["prog", "list"])
+        .unwrap();
+    assert_eq!(m.value_of("mode"), Some("list"));
+    assert!(m.subcommand_name().is_none());
+}
+
+#[test]
+fn subcommand_value_collision_errors() {
+    let res = App::new("prog")
+        .subcommand_value_policy(clap::SubcommandValuePolicy::Error)
+        .subcommand(App::new("list"))
+        .arg(Arg::new("mode").possible_values(["list", "tree"]))
+        .try_get_matches_from(&["prog", "list"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::AmbiguousSubcommand);
+}
+
+#[test]
+fn subcommand_value_collision_double_dash_disambiguates() {
+    let m = App::new("prog")
+        .subcommand_value_policy(clap::SubcommandValuePolicy::Error)
+        .subcommand(App::new("list"))
+        .arg(Arg::new("mode").possible_values(["list", "tree"]))
+        .try_get_matches_from(&["prog", "--", "list"])
+        .unwrap();
+    assert_eq!(m.value_of("mode"), Some("list"));
+    assert!(m.subcommand_name().is_none());
+}
+
+#[test]
+fn subcommand_lazy_built_only_when_dispatched() {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    static BUILT: AtomicUsize = AtomicUsize::new(0);
+
+    let m = App::new("prog")
+        .subcommand_lazy("sub1", || {
+            BUILT.fetch_add(1, Ordering::SeqCst);
+            App::new("sub1").arg(Arg::new("debug").long("debug"))
+        })
+        .subcommand_lazy("sub2", || {
+            panic!("sub2 should not have been built");
+        })
+        .get_matches_from(vec!["prog", "sub1", "--debug"]);
+
+    assert_eq!(BUILT.load(Ordering::SeqCst), 1);
+    let (name, sub_m) = m.subcommand().unwrap();
+    assert_eq!(name, "sub1");
+    assert!(sub_m.is_present("debug"));
+}
+
+#[test]
+fn subcommand_lazy_listed_in_help() {
+    let app = App::new("prog")
+        .subcommand_lazy("sub", || App::new("sub").about("Does sub things"));
+
+    let mut buf = Vec::new();
+    let mut app = app;
+    app.write_help(&mut buf).unwrap();
+    let help = String::from_utf8(buf).unwrap();
+    assert!(help.contains("SUBCOMMANDS:"), "{}", help);
+    assert!(help.contains("Does sub things"), "{}", help);
+}
+
+#[test]
+fn subcommand_lazy_inferred_when_enabled() {
+    let m = App::new("prog")
+        .setting(AppSettings::InferSubcommands)
+        .subcommand_lazy("config", || App::new("config"))
+        .get_matches_from(vec!["prog", "conf"]);
+
+    assert_eq!(m.subcommand_name(), Some("config"));
+}
+
+#[test]
+fn subcommand_lazy_help_subcommand() {
+    let res = App::new("prog")
+        .subcommand_lazy("sub", || {
+            App::new("sub").arg(Arg::new("debug").long("debug"))
+        })
+        .try_get_matches_from(vec!["prog", "help", "sub"]);
+
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::DisplayHelp);
+    assert!(err.to_string().contains("--debug"), "{}", err);
+}
+
+#[test]
+fn subcommand_lazy_unknown_still_errors() {
+    let lazy = App::new("prog")
+        .subcommand_lazy("sub", || App::new("sub"))
+        .try_get_matches_from(vec!["prog", "nope"]);
+    let eager = App::new("prog")
+        .subcommand(App::new("sub"))
+        .try_get_matches_from(vec!["prog", "nope"]);
+
+    assert!(lazy.is_err());
+    assert_eq!(lazy.unwrap_err().kind(), eager.unwrap_err().kind());
+}
+
+#[test]
+fn render_usage_for_nested_subcommand() {
+    let mut app = App::new("myprog").subcommand(
+        App::new("remote").subcommand(
+            App::new("add")
+                .arg(Arg::new("name").required(true))
+                .arg(Arg::new("url").required(true)),
+        ),
+    );
+
+    assert_eq!(
+        app.render_usage_for(&["remote", "add"]).unwrap(),
+        "USAGE:\n    myprog remote add <name> <url>"
+    );
+}
+
+#[test]
+fn render_usage_for_empty_path_is_own_usage() {
+    let mut app = App::new("myprog").subcommand(App::new("sub"));
+
+    assert_eq!(app.render_usage_for(&[]).unwrap(), app.render_usage());
+}
+
+#[test]
+fn render_usage_for_unknown_path() {
+    let mut app = App::new("myprog").subcommand(App::new("sub"));
+
+    assert_eq!(app.render_usage_for(&["nope"]), None);
+    assert_eq!(app.render_usage_for(&["sub", "nested"]), None);
+}
+
+#[test]
+fn render_usage_for_lazy_subcommand() {
+    let mut app = App::new("myprog")
+        .subcommand_lazy("lazy", || App::new("lazy").arg(Arg::new("input").required(true)));
+
+    assert_eq!(
+        app.render_usage_for(&["lazy"]).unwrap(),
+        "USAGE:\n    myprog lazy <input>"
+    );
+}
+
+#[test]
+fn unknown_flag_suggests_subcommand_path() {
+    static EXPECTED: &str =
+        "error: Found argument '--force' which wasn't expected, or isn't valid in this context
+
+\tA different level of the command tree accepts it; try `myapp remote add --force`
+
+\tIf you tried to supply `--force` as a value rather than a flag, use `-- --force`
+
+USAGE:
+    myapp [SUBCOMMAND]
+
+For more information try --help
+";
+    let app = App::new("myapp")
+        .subcommand(App::new("remote").subcommand(App::new("add").arg(arg!(--force "overwrite"))));
+
+    assert!(utils::compare_output(app, "myapp --force", EXPECTED, true));
+}
+
+#[test]
+fn unknown_flag_on_subcommand_suggests_parent() {
+    let err = App::new("myapp")
+        .arg(arg!(--verbose "more output"))
+        .subcommand(App::new("sub"))
+        .try_get_matches_from(["myapp", "sub", "--verbose"])
+        .unwrap_err();
+
+    assert_eq!(err.kind(), ErrorKind::UnknownArgument);
+    assert!(
+        err.to_string().contains("try `myapp --verbose`"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn unknown_flag_on_subcommand_suggests_sibling_branch() {
+    let err = App::new("myapp")
+        .subcommand(App::new("fetch"))
+        .subcommand(App::new("push").arg(arg!(--tags "push tags")))
+        .try_get_matches_from(["myapp", "fetch", "--tags"])
+        .unwrap_err();
+
+    assert_eq!(err.kind(), ErrorKind::UnknownArgument);
+    assert!(
+        err.to_string().contains("try `myapp push --tags`"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn unknown_flag_missing_everywhere_gets_no_path_hint() {
+    let err = App::new("myapp")
+        .subcommand(App::new("sub").arg(arg!(--force "overwrite")))
+        .try_get_matches_from(["myapp", "--missing"])
+        .unwrap_err();
+
+    assert_eq!(err.kind(), ErrorKind::UnknownArgument);
+    assert!(!err.to_string().contains("try `"), "{}", err);
+}
+
+#[test]
+fn multicall_help_renders_applet_as_program() {
+    let mut app = App::new("hostname")
+        .multicall(true)
+        .subcommand(App::new("hostname").about("show the host name"))
+        .subcommand(App::new("dnsdomainname").about("show the domain name"));
+
+    let err = app
+        .try_get_matches_from_mut(&["dnsdomainname", "--help"])
+        .unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::DisplayHelp);
+    let help = err.to_string();
+    assert!(help.contains("dnsdomainname"), "{}", help);
+    assert!(!help.contains("hostname"), "{}", help);
+}
+
+#[test]
+fn file_like_positional_suggests_hooked_subcommand() {
+    // Cargo.toml exists relative to the test working directory
+    let m = App::new("myapp")
+        .subcommand(App::new("run"))
+        .file_subcommand_hint(|path| path.exists().then(|| "run".to_string()))
+        .try_get_matches_from(vec!["myapp", "Cargo.toml"]);
+
+    assert!(m.is_err());
+    let err = m.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidSubcommand);
+    let out = err.to_string();
+    assert!(out.contains("Did you mean `myapp run Cargo.toml`?"), "{}", out);
+}
+
+#[test]
+fn file_subcommand_hint_skipped_when_file_is_missing() {
+    let m = App::new("myapp")
+        .subcommand(App::new("run"))
+        .file_subcommand_hint(|_| Some("run".to_string()))
+        .try_get_matches_from(vec!["myapp", "no-such-file.xyz"]);
+
+    assert!(m.is_err());
+    let err = m.unwrap_err();
+    assert!(!err.to_string().contains("myapp run"), "{}", err);
+}
+
+#[test]
+fn file_subcommand_hint_ignores_unknown_subcommand() {
+    let m = App::new("myapp")
+        .subcommand(App::new("run"))
+        .file_subcommand_hint(|_| Some("execute".to_string()))
+        .try_get_matches_from(vec!["myapp", "Cargo.toml"]);
+
+    assert!(m.is_err());
+    let err = m.unwrap_err();
+    assert!(!err.to_string().contains("myapp execute"), "{}", err);
+}
@@ -167,3 +167,70 @@ fn get_app() -> App<'static> {
                 .arg(arg!(-l --list "lists test values")),
         )
 }
+
+static SECTIONS_DEFAULT: &str = "MyApp 1.0
+Does awesome things
+
+USAGE:
+    MyApp
+
+OPTIONS:
+    -h, --help       Print help information
+    -V, --version    Print version information
+
+EXAMPLES:
+    MyApp --all
+        Frobs every widget
+
+EXIT CODES:
+    0 on success, 1 on frobbing failure
+";
+
+static SECTIONS_TEMPLATED: &str = "MyApp
+EXIT CODES:
+    0 on success, 1 on frobbing failure
+---
+EXAMPLES:
+    MyApp --all
+        Frobs every widget
+";
+
+fn app_with_sections() -> App<'static> {
+    App::new("MyApp")
+        .version("1.0")
+        .about("Does awesome things")
+        .help_section("EXAMPLES", "MyApp --all\n    Frobs every widget")
+        .help_section("EXIT CODES", "0 on success, 1 on frobbing failure")
+}
+
+#[test]
+fn help_sections_follow_generated_args() {
+    assert!(utils::compare_output(
+        app_with_sections(),
+        "MyApp --help",
+        SECTIONS_DEFAULT,
+        false
+    ));
+}
+
+#[test]
+fn help_sections_render_where_the_template_says() {
+    let app = app_with_sections().help_template("{bin}\n{exit-codes}\n---\n{examples}");
+    assert!(utils::compare_output(
+        app,
+        "MyApp --help",
+        SECTIONS_TEMPLATED,
+        false
+    ));
+}
+
+#[test]
+fn unknown_template_tags_are_kept_verbatim() {
+    let app = App::new("MyApp").help_template("{bin}\n{not-a-section}");
+    assert!(utils::compare_output(
+        app,
+        "MyApp --help",
+        "MyApp\n{not-a-section}\n",
+        false
+    ));
+}
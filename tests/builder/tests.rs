@@ -408,3 +408,66 @@ fn mut_arg_all() {
         app = app.mut_arg(arg_name, |arg| arg.hide_possible_values(true));
     }
 }
+
+#[test]
+fn mut_subcommand_by_name() {
+    let app = App::new("foo")
+        .subcommand(App::new("bar"))
+        .mut_subcommand("bar", |sc| sc.about("Does bar things"));
+
+    let sc = app.find_subcommand("bar").unwrap();
+    assert_eq!(sc.get_about(), Some("Does bar things"));
+}
+
+#[test]
+fn mut_subcommand_by_alias() {
+    let app = App::new("foo")
+        .subcommand(App::new("bar").alias("baz"))
+        .mut_subcommand("baz", |sc| sc.about("Does bar things"));
+
+    let sc = app.find_subcommand("bar").unwrap();
+    assert_eq!(sc.get_about(), Some("Does bar things"));
+}
+
+#[test]
+fn mut_subcommand_missing_creates_it() {
+    let app = App::new("foo").mut_subcommand("bar", |sc| sc.about("Does bar things"));
+
+    let sc = app.find_subcommand("bar").unwrap();
+    assert_eq!(sc.get_about(), Some("Does bar things"));
+}
+
+#[test]
+fn mut_args_recurses_into_subcommands() {
+    let app = App::new("foo")
+        .arg(Arg::new("verbose").long("verbose"))
+        .subcommand(App::new("bar").arg(Arg::new("verbose").long("verbose")))
+        .mut_args(|a| a.get_name() == "verbose", |a| a.hide(true));
+
+    assert!(app
+        .get_arguments()
+        .find(|a| a.get_name() == "verbose")
+        .unwrap()
+        .is_hide_set());
+    assert!(app
+        .find_subcommand("bar")
+        .unwrap()
+        .get_arguments()
+        .find(|a| a.get_name() == "verbose")
+        .unwrap()
+        .is_hide_set());
+}
+
+#[test]
+fn mut_args_skips_non_matching() {
+    let app = App::new("foo")
+        .arg(Arg::new("verbose").long("verbose"))
+        .arg(Arg::new("quiet").long("quiet"))
+        .mut_args(|a| a.get_name() == "verbose", |a| a.hide(true));
+
+    assert!(!app
+        .get_arguments()
+        .find(|a| a.get_name() == "quiet")
+        .unwrap()
+        .is_hide_set());
+}
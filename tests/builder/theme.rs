@@ -0,0 +1,55 @@
+use clap::{App, Arg, Theme, ThemeColor, ThemeStyle};
+
+#[test]
+fn theme_is_reported_by_getter() {
+    let theme = Theme::default()
+        .error(ThemeStyle::new().color(ThemeColor::Magenta).bold(true))
+        .heading(ThemeStyle::new());
+    let app = App::new("prog").theme(theme);
+    assert_eq!(app.get_theme(), Some(&theme));
+}
+
+#[test]
+fn default_themes_compare_equal() {
+    assert_eq!(Theme::default(), Theme::default());
+    assert_ne!(
+        Theme::default(),
+        Theme::default().good(ThemeStyle::new().color(ThemeColor::Cyan))
+    );
+}
+
+#[test]
+fn themes_with_detection_callbacks_never_compare_equal() {
+    fn no_color(_stderr: bool) -> bool {
+        false
+    }
+    let theme = Theme::default().color_support(no_color);
+    assert_ne!(theme, theme);
+}
+
+#[test]
+fn theme_does_not_change_uncolored_help() {
+    let app = || {
+        App::new("prog").arg(
+            Arg::new("opt")
+                .long("opt")
+                .takes_value(true)
+                .help("some option"),
+        )
+    };
+
+    let mut plain = Vec::new();
+    app().write_help(&mut plain).unwrap();
+
+    let mut themed = Vec::new();
+    app()
+        .theme(
+            Theme::default()
+                .heading(ThemeStyle::new())
+                .error(ThemeStyle::new().color(ThemeColor::Magenta)),
+        )
+        .write_help(&mut themed)
+        .unwrap();
+
+    assert_eq!(plain, themed);
+}
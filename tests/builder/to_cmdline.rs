@@ -0,0 +1,83 @@
+use clap::{App, Arg};
+
+fn app() -> App<'static> {
+    App::new("prog")
+        .arg(
+            Arg::new("port")
+                .short('p')
+                .long("port")
+                .takes_value(true)
+                .multiple_occurrences(true),
+        )
+        .arg(Arg::new("verbose").short('v').multiple_occurrences(true))
+        .arg(Arg::new("input").multiple_values(true))
+}
+
+#[test]
+fn cmdline_normalizes_to_long_forms() {
+    let m = app().get_matches_from(vec!["prog", "-v", "-p", "8080"]);
+    assert_eq!(app().to_cmdline(&m, false), ["--port", "8080", "-v"]);
+}
+
+#[test]
+fn cmdline_repeats_occurrences() {
+    let m = app().get_matches_from(vec!["prog", "-vv", "--port", "80", "--port", "443"]);
+    assert_eq!(
+        app().to_cmdline(&m, false),
+        ["--port", "80", "--port", "443", "-v", "-v"]
+    );
+}
+
+#[test]
+fn cmdline_guards_flag_like_positionals() {
+    let m = app().get_matches_from(vec!["prog", "--", "-rf", "a.txt"]);
+    assert_eq!(app().to_cmdline(&m, false), ["--", "-rf", "a.txt"]);
+}
+
+#[test]
+fn cmdline_optionally_skips_defaults() {
+    let app = || {
+        App::new("prog")
+            .arg(
+                Arg::new("port")
+                    .long("port")
+                    .takes_value(true)
+                    .default_value("80"),
+            )
+            .arg(Arg::new("host").long("host").takes_value(true))
+    };
+
+    let m = app().get_matches_from(vec!["prog", "--host", "example.com"]);
+    assert_eq!(
+        app().to_cmdline(&m, false),
+        ["--port", "80", "--host", "example.com"]
+    );
+    assert_eq!(app().to_cmdline(&m, true), ["--host", "example.com"]);
+}
+
+#[test]
+fn cmdline_includes_subcommand_chain() {
+    let app = || {
+        App::new("prog")
+            .arg(Arg::new("verbose").short('v'))
+            .subcommand(
+                App::new("remote")
+                    .subcommand(App::new("add").arg(Arg::new("name").takes_value(true))),
+            )
+    };
+
+    let m = app().get_matches_from(vec!["prog", "-v", "remote", "add", "origin"]);
+    assert_eq!(
+        app().to_cmdline(&m, false),
+        ["-v", "remote", "add", "origin"]
+    );
+}
+
+#[test]
+fn cmdline_string_quotes_what_the_shell_would_split() {
+    let m = App::new("prog")
+        .arg(Arg::new("message").long("message").takes_value(true))
+        .get_matches_from(vec!["prog", "--message", "it's big"]);
+    let mut app = App::new("prog").arg(Arg::new("message").long("message").takes_value(true));
+    assert_eq!(app.to_cmdline_string(&m, false), "--message 'it'\\''s big'");
+}
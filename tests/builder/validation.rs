@@ -0,0 +1,127 @@
+use clap::{App, Arg, ArgGroup, DefinitionProblem};
+
+#[test]
+fn valid_app_has_no_problems() {
+    let problems = App::new("prog")
+        .arg(Arg::new("verbose").short('v').long("verbose"))
+        .arg(Arg::new("config").long("config").takes_value(true))
+        .subcommand(App::new("sub").arg(Arg::new("input")))
+        .validate();
+
+    assert_eq!(problems, vec![]);
+}
+
+#[test]
+fn duplicate_short_flag_is_reported() {
+    let problems = App::new("prog")
+        .arg(Arg::new("verbose").short('v'))
+        .arg(Arg::new("version_flag").short('v'))
+        .validate();
+
+    assert_eq!(
+        problems,
+        vec![DefinitionProblem::DuplicateShort {
+            short: 'v',
+            first: "argument 'verbose'".into(),
+            second: "argument 'version_flag'".into(),
+        }]
+    );
+}
+
+#[test]
+fn duplicate_long_flag_with_subcommand_is_reported() {
+    let problems = App::new("prog")
+        .arg(Arg::new("list").long("list"))
+        .subcommand(App::new("list-items").long_flag("list"))
+        .validate();
+
+    assert_eq!(
+        problems,
+        vec![DefinitionProblem::DuplicateLong {
+            long: "list".into(),
+            first: "argument 'list'".into(),
+            second: "subcommand 'list-items'".into(),
+        }]
+    );
+}
+
+#[test]
+fn duplicate_positional_index_is_reported() {
+    let problems = App::new("prog")
+        .arg(Arg::new("first").index(1))
+        .arg(Arg::new("second").index(1))
+        .validate();
+
+    assert_eq!(
+        problems,
+        vec![DefinitionProblem::DuplicateIndex {
+            index: 1,
+            first: "first".into(),
+            second: "second".into(),
+        }]
+    );
+}
+
+#[test]
+fn unknown_group_member_is_reported() {
+    let problems = App::new("prog")
+        .arg(Arg::new("json").long("json"))
+        .group(ArgGroup::new("format").args(&["json", "yaml"]))
+        .validate();
+
+    assert_eq!(
+        problems,
+        vec![DefinitionProblem::UnknownGroupMember {
+            group: "format".into(),
+            member: "yaml".into(),
+        }]
+    );
+}
+
+#[test]
+fn unknown_requires_target_is_reported() {
+    let problems = App::new("prog")
+        .arg(Arg::new("out").long("out").requires("input"))
+        .validate();
+
+    assert_eq!(
+        problems,
+        vec![DefinitionProblem::UnknownReference {
+            from: "argument 'out'".into(),
+            reference: "input".into(),
+        }]
+    );
+}
+
+#[test]
+fn problems_in_subcommands_are_reported() {
+    let problems = App::new("prog")
+        .subcommand(
+            App::new("sub")
+                .arg(Arg::new("force").short('f'))
+                .arg(Arg::new("file").short('f')),
+        )
+        .validate();
+
+    assert_eq!(
+        problems,
+        vec![DefinitionProblem::DuplicateShort {
+            short: 'f',
+            first: "argument 'force'".into(),
+            second: "argument 'file'".into(),
+        }]
+    );
+}
+
+#[test]
+fn problems_render_readable_messages() {
+    let problem = DefinitionProblem::DuplicateShort {
+        short: 'v',
+        first: "argument 'verbose'".into(),
+        second: "argument 'version'".into(),
+    };
+    assert_eq!(
+        problem.to_string(),
+        "short flag `-v` is used by both `argument 'verbose'` and `argument 'version'`"
+    );
+}
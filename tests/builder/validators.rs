@@ -64,3 +64,62 @@ fn stateful_validator() {
 
     assert!(state);
 }
+
+#[test]
+fn app_validator_passes_valid_matches() {
+    let res = App::new("test")
+        .arg(Arg::new("min").long("min").takes_value(true))
+        .arg(Arg::new("max").long("max").takes_value(true))
+        .validator(|m| -> Result<(), String> {
+            let min: u32 = m.value_of_t("min").unwrap_or(0);
+            let max: u32 = m.value_of_t("max").unwrap_or(u32::MAX);
+            if min > max {
+                return Err("--min must not exceed --max".into());
+            }
+            Ok(())
+        })
+        .try_get_matches_from(&["test", "--min", "3", "--max", "10"]);
+
+    assert!(res.is_ok(), "{}", res.unwrap_err());
+}
+
+#[test]
+fn app_validator_rejects_cross_argument_violation() {
+    let res = App::new("test")
+        .arg(Arg::new("min").long("min").takes_value(true))
+        .arg(Arg::new("max").long("max").takes_value(true))
+        .validator(|m| -> Result<(), String> {
+            let min: u32 = m.value_of_t("min").unwrap_or(0);
+            let max: u32 = m.value_of_t("max").unwrap_or(u32::MAX);
+            if min > max {
+                return Err("--min must not exceed --max".into());
+            }
+            Ok(())
+        })
+        .try_get_matches_from(&["test", "--min", "10", "--max", "3"]);
+
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind(), clap::ErrorKind::ValueValidation);
+    assert!(
+        err.to_string().contains("--min must not exceed --max"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn app_validator_runs_after_per_arg_validation() {
+    let res = App::new("test")
+        .arg(
+            Arg::new("port")
+                .long("port")
+                .takes_value(true)
+                .validator(|v| v.parse::<u16>().map_err(|e| e.to_string())),
+        )
+        .validator(|_| -> Result<(), String> { panic!("hook must not run on invalid values") })
+        .try_get_matches_from(&["test", "--port", "banana"]);
+
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), clap::ErrorKind::ValueValidation);
+}
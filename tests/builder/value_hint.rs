@@ -0,0 +1,122 @@
+use clap::{App, Arg, ErrorKind, ValueHint};
+
+use std::path::PathBuf;
+
+fn existing_file() -> PathBuf {
+    std::env::current_exe().unwrap()
+}
+
+fn existing_dir() -> PathBuf {
+    std::env::temp_dir()
+}
+
+fn missing_path() -> PathBuf {
+    std::env::temp_dir().join(format!("clap_hint_{}_does_not_exist", std::process::id()))
+}
+
+#[test]
+fn existing_file_accepts_a_file() {
+    let file = existing_file();
+    let m = App::new("prog")
+        .arg(
+            Arg::new("input")
+                .value_hint(ValueHint::ExistingFile)
+                .allow_invalid_utf8(true),
+        )
+        .try_get_matches_from(&[std::ffi::OsStr::new("prog"), file.as_os_str()])
+        .unwrap();
+    assert_eq!(m.value_of_os("input"), Some(file.as_os_str()));
+}
+
+#[test]
+fn existing_file_rejects_missing_and_dirs() {
+    let app = || {
+        App::new("prog").arg(
+            Arg::new("input")
+                .value_hint(ValueHint::ExistingFile)
+                .validate_hint(true),
+        )
+    };
+
+    let missing = missing_path();
+    let res = app().try_get_matches_from(&[std::ffi::OsStr::new("prog"), missing.as_os_str()]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ValueValidation);
+    assert!(err.to_string().contains("file does not exist"), "{}", err);
+
+    let dir = existing_dir();
+    let res = app().try_get_matches_from(&[std::ffi::OsStr::new("prog"), dir.as_os_str()]);
+    assert!(res.is_err());
+}
+
+#[test]
+fn existing_dir_rejects_missing() {
+    let app = || {
+        App::new("prog").arg(
+            Arg::new("out")
+                .value_hint(ValueHint::ExistingDir)
+                .validate_hint(true),
+        )
+    };
+
+    let dir = existing_dir();
+    let res = app().try_get_matches_from(&[std::ffi::OsStr::new("prog"), dir.as_os_str()]);
+    assert!(res.is_ok(), "{}", res.unwrap_err());
+
+    let missing = missing_path();
+    let res = app().try_get_matches_from(&[std::ffi::OsStr::new("prog"), missing.as_os_str()]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ValueValidation);
+    assert!(
+        err.to_string().contains("directory does not exist"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn creatable_file_needs_an_existing_parent() {
+    let app = || {
+        App::new("prog").arg(
+            Arg::new("out")
+                .value_hint(ValueHint::CreatableFile)
+                .validate_hint(true),
+        )
+    };
+
+    // The file itself doesn't have to exist
+    let target = existing_dir().join("clap_hint_new_file");
+    let res = app().try_get_matches_from(&[std::ffi::OsStr::new("prog"), target.as_os_str()]);
+    assert!(res.is_ok(), "{}", res.unwrap_err());
+
+    // A bare file name is created in the current directory, which exists
+    let res = app().try_get_matches_from(&["prog", "new_file"]);
+    assert!(res.is_ok(), "{}", res.unwrap_err());
+
+    let target = missing_path().join("clap_hint_new_file");
+    let res = app().try_get_matches_from(&[std::ffi::OsStr::new("prog"), target.as_os_str()]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ValueValidation);
+    assert!(err.to_string().contains("parent directory"), "{}", err);
+}
+
+#[test]
+fn non_validating_hints_accept_anything() {
+    let missing = missing_path();
+    let res = App::new("prog")
+        .arg(Arg::new("input").value_hint(ValueHint::FilePath))
+        .try_get_matches_from(&[std::ffi::OsStr::new("prog"), missing.as_os_str()]);
+    assert!(res.is_ok(), "{}", res.unwrap_err());
+}
+
+#[test]
+fn hints_do_not_validate_by_default() {
+    let missing = missing_path();
+    let res = App::new("prog")
+        .arg(Arg::new("input").value_hint(ValueHint::ExistingFile))
+        .try_get_matches_from(&[std::ffi::OsStr::new("prog"), missing.as_os_str()]);
+    assert!(res.is_ok(), "{}", res.unwrap_err());
+}
@@ -0,0 +1,67 @@
+use clap::{App, Arg, ErrorKind};
+
+fn app() -> App<'static> {
+    App::new("prog").arg(Arg::new("define").long("define").key_value_map(true))
+}
+
+#[test]
+fn pairs_are_collected_in_command_line_order() {
+    let m = app()
+        .try_get_matches_from(vec![
+            "prog", "--define", "opt=3", "--define", "debug=false", "--define", "name=x",
+        ])
+        .unwrap();
+
+    let map = m.get_map("define").unwrap();
+    assert_eq!(map.get("opt"), Some(&"3"));
+    assert_eq!(map.get("debug"), Some(&"false"));
+    assert_eq!(
+        map.keys().collect::<Vec<_>>(),
+        [&"opt", &"debug", &"name"]
+    );
+}
+
+#[test]
+fn get_map_is_none_when_absent() {
+    let m = app().try_get_matches_from(vec!["prog"]).unwrap();
+    assert!(m.get_map("define").is_none());
+}
+
+#[test]
+fn empty_value_after_equals_is_allowed() {
+    let m = app()
+        .try_get_matches_from(vec!["prog", "--define", "opt="])
+        .unwrap();
+    assert_eq!(m.get_map("define").unwrap().get("opt"), Some(&""));
+}
+
+#[test]
+fn malformed_pair_is_rejected() {
+    let res = app().try_get_matches_from(vec!["prog", "--define", "nopair"]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ValueValidation);
+    assert!(
+        err.to_string().contains("expected a `KEY=VAL` pair"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn empty_key_is_rejected() {
+    let res = app().try_get_matches_from(vec!["prog", "--define", "=val"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::ValueValidation);
+}
+
+#[test]
+fn duplicate_key_is_rejected() {
+    let res = app().try_get_matches_from(vec![
+        "prog", "--define", "opt=3", "--define", "opt=4",
+    ]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ValueValidation);
+    assert!(err.to_string().contains("duplicate key `opt`"), "{}", err);
+}
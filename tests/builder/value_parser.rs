@@ -0,0 +1,171 @@
+use std::net::IpAddr;
+use std::path::PathBuf;
+
+use clap::{App, Arg, ErrorKind, ValueParser};
+
+#[test]
+fn get_one_returns_typed_values() {
+    let m = App::new("test")
+        .arg(
+            Arg::new("port")
+                .long("port")
+                .takes_value(true)
+                .value_parser(ValueParser::new::<u16>()),
+        )
+        .arg(
+            Arg::new("bind")
+                .long("bind")
+                .takes_value(true)
+                .value_parser(ValueParser::new::<IpAddr>()),
+        )
+        .arg(
+            Arg::new("output")
+                .long("output")
+                .takes_value(true)
+                .value_parser(ValueParser::new::<PathBuf>()),
+        )
+        .try_get_matches_from(["test", "--port", "8080", "--bind", "127.0.0.1", "--output", "out.txt"])
+        .unwrap();
+
+    assert_eq!(m.get_one::<u16>("port"), Some(&8080));
+    assert_eq!(
+        m.get_one::<IpAddr>("bind"),
+        Some(&"127.0.0.1".parse().unwrap())
+    );
+    assert_eq!(m.get_one::<PathBuf>("output"), Some(&PathBuf::from("out.txt")));
+    // The raw string stays available
+    assert_eq!(m.value_of("port"), Some("8080"));
+}
+
+#[test]
+fn invalid_value_is_a_value_validation_error() {
+    let err = App::new("test")
+        .arg(
+            Arg::new("port")
+                .long("port")
+                .takes_value(true)
+                .value_parser(ValueParser::new::<u16>()),
+        )
+        .try_get_matches_from(["test", "--port", "banana"])
+        .unwrap_err();
+
+    assert_eq!(err.kind(), ErrorKind::ValueValidation);
+    assert!(err.to_string().contains("banana"), "{}", err);
+}
+
+#[test]
+fn get_many_returns_all_typed_values() {
+    let m = App::new("test")
+        .arg(
+            Arg::new("nums")
+                .long("nums")
+                .takes_value(true)
+                .multiple_values(true)
+                .value_parser(ValueParser::new::<i64>()),
+        )
+        .try_get_matches_from(["test", "--nums", "1", "2", "3"])
+        .unwrap();
+
+    let nums: Vec<i64> = m.get_many::<i64>("nums").unwrap().copied().collect();
+    assert_eq!(nums, [1, 2, 3]);
+}
+
+#[test]
+fn custom_parser_rejects_with_its_own_message() {
+    let app = || {
+        App::new("test").arg(
+            Arg::new("percent").takes_value(true).value_parser(
+                ValueParser::custom(|s: &str| -> Result<u8, String> {
+                    let v: u8 = s.parse().map_err(|e| format!("{}", e))?;
+                    if v <= 100 {
+                        Ok(v)
+                    } else {
+                        Err(format!("{} is over 100", v))
+                    }
+                }),
+            ),
+        )
+    };
+
+    let m = app().try_get_matches_from(["test", "42"]).unwrap();
+    assert_eq!(m.get_one::<u8>("percent"), Some(&42));
+
+    let err = app().try_get_matches_from(["test", "150"]).unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ValueValidation);
+    assert!(err.to_string().contains("150 is over 100"), "{}", err);
+}
+
+#[test]
+fn bool_parser_accepts_common_spellings() {
+    let app = || {
+        App::new("test").arg(
+            Arg::new("color")
+                .long("color")
+                .takes_value(true)
+                .value_parser(ValueParser::bool()),
+        )
+    };
+
+    for (raw, expected) in [
+        ("true", true),
+        ("TRUE", true),
+        ("yes", true),
+        ("on", true),
+        ("1", true),
+        ("false", false),
+        ("No", false),
+        ("off", false),
+        ("0", false),
+    ] {
+        let m = app()
+            .try_get_matches_from(["test", &format!("--color={}", raw)])
+            .unwrap();
+        assert_eq!(m.get_one::<bool>("color"), Some(&expected), "{}", raw);
+    }
+}
+
+#[test]
+fn bool_parser_error_lists_accepted_spellings() {
+    let err = App::new("test")
+        .arg(
+            Arg::new("color")
+                .long("color")
+                .takes_value(true)
+                .value_parser(ValueParser::bool()),
+        )
+        .try_get_matches_from(["test", "--color", "maybe"])
+        .unwrap_err();
+
+    assert_eq!(err.kind(), ErrorKind::ValueValidation);
+    let rendered = err.to_string();
+    assert!(rendered.contains("maybe"), "{}", rendered);
+    assert!(rendered.contains("yes"), "{}", rendered);
+    assert!(rendered.contains("off"), "{}", rendered);
+}
+
+#[test]
+fn default_values_are_parsed_too() {
+    let m = App::new("test")
+        .arg(
+            Arg::new("port")
+                .long("port")
+                .takes_value(true)
+                .default_value("80")
+                .value_parser(ValueParser::new::<u16>()),
+        )
+        .try_get_matches_from(["test"])
+        .unwrap();
+
+    assert_eq!(m.get_one::<u16>("port"), Some(&80));
+}
+
+#[test]
+fn get_one_without_parser_is_none() {
+    let m = App::new("test")
+        .arg(Arg::new("plain").takes_value(true))
+        .try_get_matches_from(["test", "value"])
+        .unwrap();
+
+    assert_eq!(m.get_one::<String>("plain"), None);
+    assert_eq!(m.value_of("plain"), Some("value"));
+}
@@ -0,0 +1,86 @@
+use clap::{App, Arg, ErrorKind};
+
+fn app() -> App<'static> {
+    App::new("range").arg(Arg::new("port").long("port").value_range(1..=65535))
+}
+
+#[test]
+fn value_inside_range_is_accepted() {
+    let m = app()
+        .try_get_matches_from(&["range", "--port", "8080"])
+        .unwrap();
+    assert_eq!(m.value_of("port"), Some("8080"));
+}
+
+#[test]
+fn bounds_are_inclusive() {
+    let m = app()
+        .try_get_matches_from(&["range", "--port", "1"])
+        .unwrap();
+    assert_eq!(m.value_of("port"), Some("1"));
+
+    let m = app()
+        .try_get_matches_from(&["range", "--port", "65535"])
+        .unwrap();
+    assert_eq!(m.value_of("port"), Some("65535"));
+}
+
+#[test]
+fn value_outside_range_is_rejected() {
+    let res = app().try_get_matches_from(&["range", "--port", "65536"]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ValueValidation);
+    assert!(
+        err.to_string()
+            .contains("value must be between 1 and 65535"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn non_integer_value_is_rejected() {
+    let res = app().try_get_matches_from(&["range", "--port", "http"]);
+    assert!(res.is_err());
+    assert_eq!(res.unwrap_err().kind(), ErrorKind::ValueValidation);
+}
+
+#[test]
+fn half_open_range_only_bounds_one_side() {
+    let app = || App::new("range").arg(Arg::new("jobs").long("jobs").value_range(1..));
+
+    let m = app()
+        .try_get_matches_from(&["range", "--jobs", "400"])
+        .unwrap();
+    assert_eq!(m.value_of("jobs"), Some("400"));
+
+    let res = app().try_get_matches_from(&["range", "--jobs", "0"]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::ValueValidation);
+    assert!(err.to_string().contains("value must be at least 1"), "{}", err);
+}
+
+#[test]
+fn exclusive_end_is_normalized() {
+    let res = App::new("range")
+        .arg(Arg::new("percent").long("percent").value_range(0..100))
+        .try_get_matches_from(&["range", "--percent", "100"]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert!(
+        err.to_string().contains("value must be between 0 and 99"),
+        "{}",
+        err
+    );
+}
+
+#[test]
+fn range_is_shown_in_help() {
+    let mut app = app();
+    let mut help = Vec::new();
+    app.write_help(&mut help).unwrap();
+    let help = String::from_utf8(help).unwrap();
+    assert!(help.contains("[range: 1..=65535]"), "{}", help);
+}
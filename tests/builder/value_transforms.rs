@@ -0,0 +1,131 @@
+use clap::{App, Arg, ErrorKind, ValueTransform};
+
+#[test]
+fn trim_whitespace() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("name")
+                .long("name")
+                .takes_value(true)
+                .value_transform(ValueTransform::TrimWhitespace),
+        )
+        .try_get_matches_from(&["prog", "--name", "  bob  "])
+        .unwrap();
+    assert_eq!(m.value_of("name"), Some("bob"));
+}
+
+#[test]
+fn lowercase() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .takes_value(true)
+                .value_transform(ValueTransform::Lowercase),
+        )
+        .try_get_matches_from(&["prog", "--format", "JSON"])
+        .unwrap();
+    assert_eq!(m.value_of("format"), Some("json"));
+}
+
+#[test]
+fn transforms_run_in_order() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .takes_value(true)
+                .value_transform(ValueTransform::TrimWhitespace)
+                .value_transform(ValueTransform::Lowercase),
+        )
+        .try_get_matches_from(&["prog", "--format", " YAML "])
+        .unwrap();
+    assert_eq!(m.value_of("format"), Some("yaml"));
+}
+
+#[test]
+fn custom_transform() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("name").value_transform(ValueTransform::custom(|s| {
+                let mut v = std::ffi::OsString::from("user-");
+                v.push(s);
+                v
+            })),
+        )
+        .try_get_matches_from(&["prog", "alice"])
+        .unwrap();
+    assert_eq!(m.value_of("name"), Some("user-alice"));
+}
+
+#[test]
+fn possible_values_see_transformed_value() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .possible_values(["json", "yaml"])
+                .value_transform(ValueTransform::Lowercase),
+        )
+        .try_get_matches_from(&["prog", "--format", "JSON"])
+        .unwrap();
+    assert_eq!(m.value_of("format"), Some("json"));
+}
+
+#[test]
+fn error_shows_original_input() {
+    let res = App::new("prog")
+        .arg(
+            Arg::new("format")
+                .long("format")
+                .possible_values(["json", "yaml"])
+                .value_transform(ValueTransform::TrimWhitespace)
+                .value_transform(ValueTransform::Lowercase),
+        )
+        .try_get_matches_from(&["prog", "--format", " TOML "]);
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::InvalidValue);
+    assert!(err.to_string().contains(" TOML "), "{}", err);
+}
+
+#[test]
+fn validator_sees_transformed_value() {
+    let res = App::new("prog")
+        .arg(
+            Arg::new("num")
+                .value_transform(ValueTransform::TrimWhitespace)
+                .validator(|s| s.parse::<u32>().map_err(|e| e.to_string())),
+        )
+        .try_get_matches_from(&["prog", " 42 "]);
+    assert!(res.is_ok(), "{:?}", res);
+    assert_eq!(res.unwrap().value_of("num"), Some("42"));
+}
+
+#[cfg(feature = "env")]
+#[test]
+fn expand_tilde() {
+    let m = App::new("prog")
+        .arg(
+            Arg::new("path")
+                .long("path")
+                .takes_value(true)
+                .allow_invalid_utf8(true)
+                .value_transform(ValueTransform::ExpandTilde),
+        )
+        .try_get_matches_from(&["prog", "--path", "~/file.txt"])
+        .unwrap();
+    #[cfg(not(windows))]
+    let home = std::env::var_os("HOME");
+    #[cfg(windows)]
+    let home = std::env::var_os("USERPROFILE");
+    if let Some(home) = home {
+        let expected = std::path::Path::new(&home).join("file.txt");
+        assert_eq!(m.value_of_os("path"), Some(expected.as_os_str()));
+    } else {
+        assert_eq!(
+            m.value_of_os("path"),
+            Some(std::ffi::OsStr::new("~/file.txt"))
+        );
+    }
+}
@@ -238,3 +238,46 @@ fn propagate_version_no_version_info() {
         .subcommand(App::new("bar"))
         .try_get_matches_from("foo".split(' '));
 }
+
+#[test]
+fn version_sections_render_on_long_version() {
+    let res = common()
+        .version("3.0")
+        .version_section("commit", "deadbeef")
+        .version_section("features", "env, regex")
+        .try_get_matches_from("foo --version".split(' '));
+
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::DisplayVersion);
+    assert_eq!(
+        err.to_string(),
+        "foo 3.0\ncommit: deadbeef\nfeatures: env, regex\n"
+    );
+}
+
+#[test]
+fn version_sections_absent_from_short_version() {
+    let res = common()
+        .version("3.0")
+        .version_section("commit", "deadbeef")
+        .try_get_matches_from("foo -V".split(' '));
+
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::DisplayVersion);
+    assert_eq!(err.to_string(), "foo 3.0\n");
+}
+
+#[test]
+fn version_sections_follow_long_version_string() {
+    let res = common()
+        .long_version("3.0 (abcdefg)")
+        .version_section("built", "2024-05-01")
+        .try_get_matches_from("foo --version".split(' '));
+
+    assert!(res.is_err());
+    let err = res.unwrap_err();
+    assert_eq!(err.kind(), ErrorKind::DisplayVersion);
+    assert_eq!(err.to_string(), "foo 3.0 (abcdefg)\nbuilt: 2024-05-01\n");
+}
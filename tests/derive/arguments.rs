@@ -118,3 +118,86 @@ fn vec_type_is_multiple_values() {
         Opt::try_parse_from(&["test", "NOPE"]).err().unwrap().kind()
     );
 }
+
+#[test]
+fn required_if_eq_any_attribute() {
+    #[derive(Parser, PartialEq, Debug)]
+    struct Opt {
+        #[clap(long)]
+        mode: Option<String>,
+        #[clap(long)]
+        dry_run: bool,
+        #[clap(long, required_if_eq_any(&[("mode", "deploy"), ("mode", "rollback")]))]
+        target: Option<String>,
+    }
+    assert!(Opt::try_parse_from(&["test", "--mode", "deploy"]).is_err());
+    assert_eq!(
+        Opt {
+            mode: Some("deploy".into()),
+            dry_run: false,
+            target: Some("prod".into()),
+        },
+        Opt::try_parse_from(&["test", "--mode", "deploy", "--target", "prod"]).unwrap()
+    );
+    assert_eq!(
+        Opt {
+            mode: Some("check".into()),
+            dry_run: false,
+            target: None,
+        },
+        Opt::try_parse_from(&["test", "--mode", "check"]).unwrap()
+    );
+}
+
+#[test]
+fn required_unless_present_all_attribute() {
+    #[derive(Parser, PartialEq, Debug)]
+    struct Opt {
+        #[clap(long)]
+        user: Option<String>,
+        #[clap(long)]
+        host: Option<String>,
+        #[clap(long, required_unless_present_all(&["user", "host"]))]
+        url: Option<String>,
+    }
+    assert!(Opt::try_parse_from(&["test", "--user", "me"]).is_err());
+    assert_eq!(
+        Opt {
+            user: Some("me".into()),
+            host: Some("example.com".into()),
+            url: None,
+        },
+        Opt::try_parse_from(&["test", "--user", "me", "--host", "example.com"]).unwrap()
+    );
+    assert_eq!(
+        Opt {
+            user: None,
+            host: None,
+            url: Some("https://example.com".into()),
+        },
+        Opt::try_parse_from(&["test", "--url", "https://example.com"]).unwrap()
+    );
+}
+
+#[test]
+fn conflicts_with_all_attribute() {
+    #[derive(Parser, PartialEq, Debug)]
+    struct Opt {
+        #[clap(long)]
+        quiet: bool,
+        #[clap(long)]
+        json: bool,
+        #[clap(long, conflicts_with_all(&["quiet", "json"]))]
+        verbose: bool,
+    }
+    assert!(Opt::try_parse_from(&["test", "--verbose", "--quiet"]).is_err());
+    assert!(Opt::try_parse_from(&["test", "--verbose", "--json"]).is_err());
+    assert_eq!(
+        Opt {
+            quiet: false,
+            json: false,
+            verbose: true,
+        },
+        Opt::try_parse_from(&["test", "--verbose"]).unwrap()
+    );
+}
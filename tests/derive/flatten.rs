@@ -254,3 +254,117 @@ fn docstrings_ordering_with_multiple_clap_partial() {
 
     assert!(short_help.contains("This is the docstring for Flattened"));
 }
+
+#[test]
+fn optional_flatten_absent_without_args() {
+    #[derive(Args, PartialEq, Debug)]
+    struct Tls {
+        #[clap(long)]
+        cert: String,
+        #[clap(long)]
+        key: Option<String>,
+    }
+
+    #[derive(Parser, PartialEq, Debug)]
+    struct Opt {
+        #[clap(long)]
+        port: Option<u32>,
+        #[clap(flatten)]
+        tls: Option<Tls>,
+    }
+
+    assert_eq!(
+        Opt {
+            port: Some(80),
+            tls: None
+        },
+        Opt::try_parse_from(&["test", "--port", "80"]).unwrap()
+    );
+}
+
+#[test]
+fn optional_flatten_present_when_any_arg_given() {
+    #[derive(Args, PartialEq, Debug)]
+    struct Tls {
+        #[clap(long)]
+        cert: String,
+        #[clap(long)]
+        key: Option<String>,
+    }
+
+    #[derive(Parser, PartialEq, Debug)]
+    struct Opt {
+        #[clap(flatten)]
+        tls: Option<Tls>,
+    }
+
+    assert_eq!(
+        Opt {
+            tls: Some(Tls {
+                cert: "c.pem".into(),
+                key: None
+            })
+        },
+        Opt::try_parse_from(&["test", "--cert", "c.pem"]).unwrap()
+    );
+}
+
+#[test]
+fn optional_flatten_requires_required_members_once_used() {
+    #[derive(Args, PartialEq, Debug)]
+    struct Tls {
+        #[clap(long)]
+        cert: String,
+        #[clap(long)]
+        key: Option<String>,
+    }
+
+    #[derive(Parser, PartialEq, Debug)]
+    struct Opt {
+        #[clap(flatten)]
+        tls: Option<Tls>,
+    }
+
+    // `--key` alone pulls in the group, so the required `--cert` is enforced
+    let res = Opt::try_parse_from(&["test", "--key", "k.pem"]);
+    assert!(res.is_err());
+    assert_eq!(
+        res.unwrap_err().kind(),
+        clap::ErrorKind::MissingRequiredArgument
+    );
+}
+
+#[test]
+fn update_optional_flatten() {
+    #[derive(Args, PartialEq, Debug)]
+    struct Tls {
+        #[clap(long)]
+        cert: String,
+        #[clap(long)]
+        key: Option<String>,
+    }
+
+    #[derive(Parser, PartialEq, Debug)]
+    struct Opt {
+        #[clap(flatten)]
+        tls: Option<Tls>,
+    }
+
+    let mut opt = Opt::try_parse_from(&["test", "--cert", "c.pem"]).unwrap();
+    opt.try_update_from(&["test", "--key", "k.pem"]).unwrap();
+    assert_eq!(
+        Opt {
+            tls: Some(Tls {
+                cert: "c.pem".into(),
+                key: Some("k.pem".into())
+            })
+        },
+        opt
+    );
+
+    // An update with none of the group's args leaves the field untouched
+    let mut opt = Opt::try_parse_from(&["test"]).unwrap();
+    assert_eq!(Opt { tls: None }, opt);
+    opt.try_update_from(&["test"]).unwrap();
+    assert_eq!(Opt { tls: None }, opt);
+}
@@ -153,3 +153,48 @@ fn skip_val() {
         }
     );
 }
+
+fn sum_args(matches: &clap::ArgMatches) -> u32 {
+    matches.value_of_t::<u32>("first").unwrap_or(0) + matches.value_of_t::<u32>("second").unwrap_or(0)
+}
+
+#[test]
+fn skip_fn() {
+    #[derive(Parser, Debug, PartialEq)]
+    pub struct Opt {
+        #[clap(long)]
+        first: u32,
+
+        #[clap(long)]
+        second: u32,
+
+        #[clap(skip = "self::sum_args")]
+        total: u32,
+    }
+
+    assert_eq!(
+        Opt::try_parse_from(&["test", "--first", "10", "--second", "32"]).unwrap(),
+        Opt {
+            first: 10,
+            second: 32,
+            total: 42
+        }
+    );
+}
+
+#[test]
+fn skip_string_with_path_separator_is_a_function() {
+    // A literal without `::` keeps plain value semantics
+    #[derive(Parser, Debug, PartialEq)]
+    pub struct Opt {
+        #[clap(skip = "key")]
+        k: String,
+    }
+
+    assert_eq!(
+        Opt::try_parse_from(&["test"]).unwrap(),
+        Opt {
+            k: "key".to_string()
+        }
+    );
+}
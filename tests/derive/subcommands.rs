@@ -292,6 +292,96 @@ fn external_subcommand_optional() {
     assert_eq!(Opt::try_parse_from(&["test"]).unwrap(), Opt { sub: None });
 }
 
+#[test]
+fn external_subcommand_with_name_field() {
+    #[derive(Debug, PartialEq, Parser)]
+    struct Opt {
+        #[clap(subcommand)]
+        sub: Subcommands,
+    }
+
+    #[derive(Debug, PartialEq, Subcommand)]
+    enum Subcommands {
+        Add,
+        #[clap(external_subcommand)]
+        Other(String, Vec<String>),
+    }
+
+    assert_eq!(
+        Opt::try_parse_from(&["test", "git", "status", "-s"]).unwrap(),
+        Opt {
+            sub: Subcommands::Other("git".into(), vec!["status".into(), "-s".into()])
+        }
+    );
+
+    assert_eq!(
+        Opt::try_parse_from(&["test", "git"]).unwrap(),
+        Opt {
+            sub: Subcommands::Other("git".into(), vec![])
+        }
+    );
+}
+
+#[test]
+fn external_subcommand_with_name_field_os_string() {
+    use std::ffi::OsString;
+
+    #[derive(Debug, PartialEq, Parser)]
+    struct Opt {
+        #[clap(subcommand)]
+        sub: Subcommands,
+    }
+
+    #[derive(Debug, PartialEq, Subcommand)]
+    enum Subcommands {
+        #[clap(external_subcommand)]
+        Other(OsString, Vec<OsString>),
+    }
+
+    assert_eq!(
+        Opt::try_parse_from(&["test", "git", "status"]).unwrap(),
+        Opt {
+            sub: Subcommands::Other("git".into(), vec!["status".into()])
+        }
+    );
+}
+
+#[test]
+fn external_subcommand_in_nested_subcommand() {
+    #[derive(Debug, PartialEq, Parser)]
+    struct Opt {
+        #[clap(subcommand)]
+        sub: Top,
+    }
+
+    #[derive(Debug, PartialEq, Subcommand)]
+    enum Top {
+        #[clap(subcommand)]
+        Remote(Remote),
+    }
+
+    #[derive(Debug, PartialEq, Subcommand)]
+    enum Remote {
+        List,
+        #[clap(external_subcommand)]
+        Other(String, Vec<String>),
+    }
+
+    assert_eq!(
+        Opt::try_parse_from(&["test", "remote", "list"]).unwrap(),
+        Opt {
+            sub: Top::Remote(Remote::List)
+        }
+    );
+
+    assert_eq!(
+        Opt::try_parse_from(&["test", "remote", "prune", "origin"]).unwrap(),
+        Opt {
+            sub: Top::Remote(Remote::Other("prune".into(), vec!["origin".into()]))
+        }
+    );
+}
+
 #[test]
 fn enum_in_enum_subsubcommand() {
     #[derive(Parser, Debug, PartialEq)]
@@ -536,3 +626,86 @@ fn skip_subcommand() {
     let res = Opt::try_parse_from(&["test", "skip"]);
     assert_eq!(res.unwrap_err().kind(), clap::ErrorKind::UnknownArgument,);
 }
+
+#[test]
+fn update_optional_subcommand() {
+    #[derive(Parser, PartialEq, Debug)]
+    struct Opt {
+        #[clap(long)]
+        verbose: bool,
+        #[clap(subcommand)]
+        sub: Option<Subcommands>,
+    }
+
+    #[derive(Subcommand, PartialEq, Debug)]
+    enum Subcommands {
+        Command1(Command1),
+        Command2(Command2),
+    }
+
+    #[derive(Args, PartialEq, Debug)]
+    struct Command1 {
+        arg1: i32,
+    }
+
+    #[derive(Args, PartialEq, Debug)]
+    struct Command2 {
+        arg2: i32,
+    }
+
+    // A command line without a subcommand leaves the field alone
+    let mut opt = Opt {
+        verbose: false,
+        sub: Some(Subcommands::Command1(Command1 { arg1: 12 })),
+    };
+    opt.try_update_from(&["test", "--verbose"]).unwrap();
+    assert_eq!(
+        Opt {
+            verbose: true,
+            sub: Some(Subcommands::Command1(Command1 { arg1: 12 })),
+        },
+        opt
+    );
+
+    // ... also when there is nothing to keep
+    let mut opt = Opt {
+        verbose: false,
+        sub: None,
+    };
+    opt.try_update_from(&["test"]).unwrap();
+    assert_eq!(
+        Opt {
+            verbose: false,
+            sub: None,
+        },
+        opt
+    );
+
+    // Naming a subcommand fills the field in
+    let mut opt = Opt {
+        verbose: false,
+        sub: None,
+    };
+    opt.try_update_from(&["test", "command1", "42"]).unwrap();
+    assert_eq!(
+        Opt {
+            verbose: false,
+            sub: Some(Subcommands::Command1(Command1 { arg1: 42 })),
+        },
+        opt
+    );
+
+    // ... and switches the variant when it differs
+    let mut opt = Opt {
+        verbose: false,
+        sub: Some(Subcommands::Command1(Command1 { arg1: 12 })),
+    };
+    opt.try_update_from(&["test", "command2", "43"]).unwrap();
+    assert_eq!(
+        Opt {
+            verbose: false,
+            sub: Some(Subcommands::Command2(Command2 { arg2: 43 })),
+        },
+        opt
+    );
+}
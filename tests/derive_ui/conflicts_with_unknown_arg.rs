@@ -0,0 +1,16 @@
+use clap::Parser;
+
+#[derive(Parser, Debug)]
+#[clap(name = "basic")]
+struct Opt {
+    #[clap(long)]
+    quiet: bool,
+
+    #[clap(long, conflicts_with("silent"))]
+    verbose: bool,
+}
+
+fn main() {
+    let opt = Opt::parse();
+    println!("{:?}", opt);
+}
@@ -0,0 +1,16 @@
+use clap::Parser;
+
+#[derive(Parser, Debug)]
+#[clap(name = "basic")]
+struct Opt {
+    #[clap(long)]
+    dry_run: bool,
+
+    #[clap(long, requires("dry_run"))]
+    log_file: Option<String>,
+}
+
+fn main() {
+    let opt = Opt::parse();
+    println!("{:?}", opt);
+}
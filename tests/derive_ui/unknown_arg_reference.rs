@@ -0,0 +1,16 @@
+use clap::Parser;
+
+#[derive(Parser, Debug)]
+#[clap(name = "basic")]
+struct Opt {
+    #[clap(long)]
+    mode: Option<String>,
+
+    #[clap(long, required_if_eq_any(&[("mod", "deploy")]))]
+    target: Option<String>,
+}
+
+fn main() {
+    let opt = Opt::parse();
+    println!("{:?}", opt);
+}
@@ -26,17 +26,10 @@ fn example_tests() {
         "wrap_help",
         #[cfg(feature = "unstable-replace")]
         "unstable-replace",
-        #[cfg(feature = "unstable-multicall")]
-        "unstable-multicall",
         #[cfg(feature = "unstable-grouped")]
         "unstable-grouped",
     ]
     .join(" ");
     t.register_bins(trycmd::cargo::compile_examples(["--features", &features]).unwrap());
     t.case("examples/**/*.md");
-    #[cfg(not(feature = "unstable-multicall"))]
-    {
-        t.skip("examples/24a_multicall_busybox.md");
-        t.skip("examples/24b_multicall_hostname.md");
-    }
 }